@@ -45,7 +45,7 @@ use socketcan::{CanSocket, Socket};
 
 use canadensis::core::transfer::{MessageTransfer, ServiceTransfer};
 use canadensis::core::transport::Transport;
-use canadensis::node::{BasicNode, CoreNode};
+use canadensis::node::{BasicNode, CoreNodeBuilder};
 use canadensis::requester::TransferIdFixedMap;
 use canadensis::{Node, ResponseToken, TransferHandler};
 use canadensis_can::queue::{ArrayQueue, SingleQueueDriver};
@@ -86,13 +86,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Set up information about this node
     let node_info = GetInfoResponse {
         protocol_version: Version { major: 1, minor: 0 },
-        hardware_version: Version { major: 0, minor: 0 },
         software_version: Version { major: 0, minor: 1 },
-        software_vcs_revision_id: 0,
         unique_id: rand::random(),
         name: heapless::Vec::from_slice(b"org.samcrow.basic_node").unwrap(),
-        software_image_crc: heapless::Vec::new(),
-        certificate_of_authenticity: Default::default(),
+        ..Default::default()
     };
 
     const QUEUE_CAPACITY: usize = 1210;
@@ -107,25 +104,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     const PUBLISHERS: usize = 8;
     const REQUESTERS: usize = 8;
 
-    let core_node: CoreNode<
-        SystemClock,
-        CanTransmitter<SystemClock, Queue>,
-        CanReceiver<SystemClock, Queue>,
-        TransferIdFixedMap<CanTransport, TRANSFER_IDS>,
-        Queue,
-        PUBLISHERS,
-        REQUESTERS,
-    > = CoreNode::new(
-        SystemClock::new(),
-        node_id,
-        transmitter,
-        receiver,
-        queue_driver,
-    );
+    let core_node = CoreNodeBuilder::new(SystemClock::new(), transmitter, receiver, queue_driver)
+        .node_id(node_id)
+        .publishers::<PUBLISHERS>()
+        .requesters::<REQUESTERS>()
+        .build::<TransferIdFixedMap<CanTransport, TRANSFER_IDS>>();
     let mut node = BasicNode::new(core_node, node_info).unwrap();
 
-    let start_time = std::time::Instant::now();
-    let mut prev_seconds = 0;
     loop {
         match node.receive(&mut EmptyHandler) {
             Ok(_) => {}
@@ -133,14 +118,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             Err(e) => panic!("{:?}", e),
         }
 
-        let seconds = std::time::Instant::now()
-            .duration_since(start_time)
-            .as_secs();
-        if seconds != prev_seconds {
-            prev_seconds = seconds;
-            node.run_per_second_tasks().unwrap();
-            node.flush().unwrap();
-        }
+        node.run_periodic_tasks().unwrap();
+        node.flush().unwrap();
     }
 }
 
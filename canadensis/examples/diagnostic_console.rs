@@ -42,7 +42,8 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     loop {
         match receiver.receive(&mut clock, &mut can) {
-            Ok(Some(transfer)) => {
+            Ok(Some(received)) => {
+                let transfer = received.transfer;
                 match Record::deserialize(&mut ReadCursor::new(&transfer.payload)) {
                     Ok(log_record) => {
                         let node_text = transfer
@@ -0,0 +1,207 @@
+//! Stores a node ID in flash memory using the `Storage` trait and the `embedded-storage` traits
+//!
+//! Usage: `flash_storage`
+//!
+//! This example simulates a flash memory device in RAM. On a microcontroller, the HAL's flash
+//! driver (which implements `NorFlash`) can be used instead.
+//!
+//! All values are saved in one flash sector. Each value is saved as the length of the key (1 byte),
+//! the key, the length of the value (2 bytes, little-endian), and the value. A key length of 0xff
+//! (erased flash) marks the end of the values.
+
+extern crate canadensis;
+extern crate embedded_storage;
+
+use canadensis::core::storage::{MemoryStorage, Storage};
+use embedded_storage::nor_flash::{ErrorType, NorFlash, NorFlashErrorKind, ReadNorFlash};
+use std::convert::TryFrom;
+
+/// The size of the simulated flash sector
+const SECTOR_SIZE: usize = 1024;
+/// The key used to store the node ID
+const NODE_ID_KEY: &str = "uavcan.node.id";
+
+fn main() {
+    let mut flash = RamFlash::default();
+    {
+        let mut storage = FlashStorage::open(&mut flash).expect("Failed to read flash");
+        println!("Node ID before storing: {:?}", storage.load(NODE_ID_KEY));
+        storage
+            .store(NODE_ID_KEY, &42u16.to_le_bytes())
+            .expect("Failed to store node ID");
+        storage
+            .store("uavcan.node.description", b"Flash storage example")
+            .expect("Failed to store description");
+    }
+    // Read the values from flash again, as if the node had restarted
+    let storage = FlashStorage::open(&mut flash).expect("Failed to read flash");
+    let node_id = storage
+        .load(NODE_ID_KEY)
+        .and_then(|bytes| <[u8; 2]>::try_from(bytes).ok())
+        .map(u16::from_le_bytes);
+    println!("Node ID after restarting: {:?}", node_id);
+}
+
+/// A storage that keeps its values in one sector of flash memory
+///
+/// The values are also kept in RAM. Each call to `store` erases the sector and writes all
+/// values again.
+struct FlashStorage<F> {
+    flash: F,
+    values: MemoryStorage,
+}
+
+impl<F> FlashStorage<F>
+where
+    F: NorFlash,
+{
+    /// Reads the stored values from flash
+    fn open(mut flash: F) -> Result<Self, StorageError<F::Error>> {
+        let mut sector = [0u8; SECTOR_SIZE];
+        flash.read(0, &mut sector).map_err(StorageError::Flash)?;
+
+        let mut values = MemoryStorage::new();
+        let mut remaining = &sector[..];
+        while let Some((&key_length, rest)) = remaining.split_first() {
+            if key_length == 0xff {
+                break;
+            }
+            let (key, rest) = rest
+                .split_at_checked(usize::from(key_length))
+                .ok_or(StorageError::Corrupt)?;
+            let (value_length, rest) = rest.split_at_checked(2).ok_or(StorageError::Corrupt)?;
+            let value_length = u16::from_le_bytes([value_length[0], value_length[1]]);
+            let (value, rest) = rest
+                .split_at_checked(usize::from(value_length))
+                .ok_or(StorageError::Corrupt)?;
+            let key = std::str::from_utf8(key).map_err(|_| StorageError::Corrupt)?;
+            values.store(key, value).map_err(|_| StorageError::Memory)?;
+            remaining = rest;
+        }
+        Ok(FlashStorage { flash, values })
+    }
+}
+
+impl<F> Storage for FlashStorage<F>
+where
+    F: NorFlash,
+{
+    type Error = StorageError<F::Error>;
+
+    fn load(&self, key: &str) -> Option<&[u8]> {
+        self.values.load(key)
+    }
+
+    fn store(&mut self, key: &str, value: &[u8]) -> Result<(), Self::Error> {
+        if key.len() >= 0xff || value.len() > usize::from(u16::MAX) {
+            return Err(StorageError::Full);
+        }
+        let mut new_values = self.values.clone();
+        new_values
+            .store(key, value)
+            .map_err(|_| StorageError::Memory)?;
+
+        let mut sector = [0xffu8; SECTOR_SIZE];
+        let mut length = 0;
+        for (key, value) in new_values.iter() {
+            let entry_length = 1 + key.len() + 2 + value.len();
+            // Leave space for the end marker
+            if length + entry_length >= SECTOR_SIZE {
+                return Err(StorageError::Full);
+            }
+            let entry = &mut sector[length..][..entry_length];
+            entry[0] = key.len() as u8;
+            entry[1..][..key.len()].copy_from_slice(key.as_bytes());
+            entry[1 + key.len()..][..2].copy_from_slice(&(value.len() as u16).to_le_bytes());
+            entry[3 + key.len()..].copy_from_slice(value);
+            length += entry_length;
+        }
+        // Include the end marker and round up to a multiple of the write size
+        let write_length = (length + 1).div_ceil(F::WRITE_SIZE) * F::WRITE_SIZE;
+
+        self.flash
+            .erase(0, SECTOR_SIZE as u32)
+            .map_err(StorageError::Flash)?;
+        self.flash
+            .write(0, &sector[..write_length])
+            .map_err(StorageError::Flash)?;
+        self.values = new_values;
+        Ok(())
+    }
+}
+
+/// Errors that can occur when reading or writing values in flash
+#[derive(Debug)]
+enum StorageError<E> {
+    /// The flash driver reported an error
+    Flash(E),
+    /// The values do not fit into one sector
+    Full,
+    /// The values in flash are not valid
+    Corrupt,
+    /// Memory allocation failed
+    Memory,
+}
+
+/// A flash sector simulated in RAM
+struct RamFlash {
+    memory: [u8; SECTOR_SIZE],
+}
+
+impl Default for RamFlash {
+    fn default() -> Self {
+        // Erased flash contains all ones
+        RamFlash {
+            memory: [0xff; SECTOR_SIZE],
+        }
+    }
+}
+
+impl ErrorType for RamFlash {
+    type Error = NorFlashErrorKind;
+}
+
+impl ReadNorFlash for RamFlash {
+    const READ_SIZE: usize = 1;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let offset = offset as usize;
+        let source = self
+            .memory
+            .get(offset..offset + bytes.len())
+            .ok_or(NorFlashErrorKind::OutOfBounds)?;
+        bytes.copy_from_slice(source);
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        SECTOR_SIZE
+    }
+}
+
+impl NorFlash for RamFlash {
+    const WRITE_SIZE: usize = 4;
+    const ERASE_SIZE: usize = SECTOR_SIZE;
+
+    fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        let region = self
+            .memory
+            .get_mut(from as usize..to as usize)
+            .ok_or(NorFlashErrorKind::OutOfBounds)?;
+        region.fill(0xff);
+        Ok(())
+    }
+
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        let offset = offset as usize;
+        let region = self
+            .memory
+            .get_mut(offset..offset + bytes.len())
+            .ok_or(NorFlashErrorKind::OutOfBounds)?;
+        // Writing can only change bits from 1 to 0
+        for (stored, &new) in region.iter_mut().zip(bytes) {
+            *stored &= new;
+        }
+        Ok(())
+    }
+}
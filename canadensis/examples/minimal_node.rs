@@ -36,7 +36,7 @@ use std::time::Duration;
 
 use socketcan::{CanSocket, Socket};
 
-use canadensis::node::{CoreNode, MinimalNode};
+use canadensis::node::{CoreNodeBuilder, MinimalNode};
 use canadensis::requester::TransferIdFixedMap;
 use canadensis::Node;
 use canadensis_can::{CanNodeId, CanReceiver, CanTransmitter, CanTransport, Mtu};
@@ -65,31 +65,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     const TRANSFER_IDS: usize = 2;
     const PUBLISHERS: usize = 2;
     const REQUESTERS: usize = 2;
-    let core_node: CoreNode<
-        SystemClock,
-        CanTransmitter<SystemClock, LinuxCan>,
-        CanReceiver<SystemClock, LinuxCan>,
-        TransferIdFixedMap<CanTransport, TRANSFER_IDS>,
-        LinuxCan,
-        PUBLISHERS,
-        REQUESTERS,
-    > = CoreNode::new(SystemClock::new(), node_id, transmitter, receiver, can);
+    let core_node = CoreNodeBuilder::new(SystemClock::new(), transmitter, receiver, can)
+        .node_id(node_id)
+        .publishers::<PUBLISHERS>()
+        .requesters::<REQUESTERS>()
+        .build::<TransferIdFixedMap<CanTransport, TRANSFER_IDS>>();
     let mut node = MinimalNode::new(core_node).unwrap();
 
-    let start_time = std::time::Instant::now();
-    let mut prev_seconds = 0;
     loop {
         // Don't need to check for incoming frames because this node does not receive anything.
 
-        let seconds = std::time::Instant::now()
-            .duration_since(start_time)
-            .as_secs();
-        if seconds != prev_seconds {
-            prev_seconds = seconds;
-            node.run_per_second_tasks().unwrap();
-            node.node_mut().flush().unwrap();
-        }
+        node.run_periodic_tasks().unwrap();
+        node.node_mut().flush().unwrap();
 
-        thread::sleep(Duration::from_millis(500));
+        thread::sleep(Duration::from_millis(100));
     }
 }
@@ -35,7 +35,7 @@ use canadensis::core::time::milliseconds;
 use canadensis::core::transfer::ServiceTransfer;
 use canadensis::core::Priority;
 use canadensis::encoding::Deserialize;
-use canadensis::node::{BasicNode, CoreNode};
+use canadensis::node::{BasicNode, CoreNodeBuilder};
 use canadensis::requester::TransferIdFixedMap;
 use canadensis::{Node, ServiceToken, TransferHandler};
 use canadensis_can::queue::{ArrayQueue, SingleQueueDriver};
@@ -85,13 +85,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Set up information about this node
     let node_info = GetInfoResponse {
         protocol_version: Version { major: 1, minor: 0 },
-        hardware_version: Version { major: 0, minor: 0 },
         software_version: Version { major: 0, minor: 1 },
-        software_vcs_revision_id: 0,
         unique_id: rand::random(),
         name: heapless::Vec::from_slice(b"org.samcrow.register_client").unwrap(),
-        software_image_crc: heapless::Vec::new(),
-        certificate_of_authenticity: Default::default(),
+        ..Default::default()
     };
 
     // Create a node with capacity for 2 publishers and 2 requesters
@@ -104,15 +101,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let queue = Queue::new(ArrayQueue::new(), can);
     let transmitter = CanTransmitter::new(Mtu::Can8);
     let receiver = CanReceiver::new(node_id);
-    let core_node: CoreNode<
-        SystemClock,
-        CanTransmitter<SystemClock, Queue>,
-        CanReceiver<SystemClock, Queue>,
-        TransferIdFixedMap<CanTransport, TRANSFER_IDS>,
-        Queue,
-        PUBLISHERS,
-        REQUESTERS,
-    > = CoreNode::new(SystemClock::new(), node_id, transmitter, receiver, queue);
+    let core_node = CoreNodeBuilder::new(SystemClock::new(), transmitter, receiver, queue)
+        .node_id(node_id)
+        .publishers::<PUBLISHERS>()
+        .requesters::<REQUESTERS>()
+        .build::<TransferIdFixedMap<CanTransport, TRANSFER_IDS>>();
     let mut node = BasicNode::new(core_node, node_info).unwrap();
     let list_request_token: ServiceToken<ListRequest> = node
         .start_sending_requests(list_1_0::SERVICE, milliseconds(1000), 256, Priority::Low)
@@ -145,8 +138,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         delay_time,
     };
 
-    let start_time = std::time::Instant::now();
-    let mut prev_seconds = 0;
     while !handler.done && handler.timeout > std::time::Instant::now() {
         match node.receive(&mut handler) {
             Ok(_) => { /* Keep receiving */ }
@@ -156,13 +147,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             Err(e) => panic!("{:?}", e),
         }
 
-        let seconds = std::time::Instant::now()
-            .duration_since(start_time)
-            .as_secs();
-        if seconds != prev_seconds {
-            prev_seconds = seconds;
-            node.run_per_second_tasks().unwrap();
-        }
+        node.run_periodic_tasks().unwrap();
         node.flush().unwrap();
     }
     // Either finished or timed out
@@ -53,7 +53,7 @@ use std::time::Duration;
 use socketcan::{CanSocket, Socket};
 
 use canadensis::core::transfer::{MessageTransfer, ServiceTransfer};
-use canadensis::node::{BasicNode, CoreNode};
+use canadensis::node::{BasicNode, CoreNodeBuilder};
 use canadensis::register::basic::{RegisterString, SimpleRegister};
 use canadensis::register::{RegisterBlock, RegisterHandler};
 use canadensis::requester::TransferIdFixedMap;
@@ -84,13 +84,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Set up information about this node
     let node_info = GetInfoResponse {
         protocol_version: Version { major: 1, minor: 0 },
-        hardware_version: Version { major: 0, minor: 0 },
         software_version: Version { major: 0, minor: 1 },
-        software_vcs_revision_id: 0,
         unique_id: rand::random(),
         name: heapless::Vec::from_slice(b"org.samcrow.register_node").unwrap(),
-        software_image_crc: heapless::Vec::new(),
-        certificate_of_authenticity: Default::default(),
+        ..Default::default()
     };
 
     // Create a node with capacity for 82 publishers and 2 requesters
@@ -102,15 +99,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let queue = Queue::new(ArrayQueue::new(), can);
     let transmitter = CanTransmitter::new(Mtu::Can8);
     let receiver = CanReceiver::new(node_id);
-    let core_node: CoreNode<
-        SystemClock,
-        CanTransmitter<SystemClock, Queue>,
-        CanReceiver<SystemClock, Queue>,
-        TransferIdFixedMap<CanTransport, TRANSFER_IDS>,
-        Queue,
-        PUBLISHERS,
-        REQUESTERS,
-    > = CoreNode::new(SystemClock::new(), node_id, transmitter, receiver, queue);
+    let core_node = CoreNodeBuilder::new(SystemClock::new(), transmitter, receiver, queue)
+        .node_id(node_id)
+        .publishers::<PUBLISHERS>()
+        .requesters::<REQUESTERS>()
+        .build::<TransferIdFixedMap<CanTransport, TRANSFER_IDS>>();
     let mut node = BasicNode::new(core_node, node_info).unwrap();
 
     // Define the registers that can be accessed
@@ -129,8 +122,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut handler: TransferHandlerChain<RegisterHandler<Registers>, EmptyHandler> =
         registers.chain(EmptyHandler);
 
-    let start_time = std::time::Instant::now();
-    let mut prev_seconds = 0;
     loop {
         match node.receive(&mut handler) {
             Ok(_) => {}
@@ -138,13 +129,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             Err(e) => panic!("{:?}", e),
         };
 
-        let seconds = std::time::Instant::now()
-            .duration_since(start_time)
-            .as_secs();
-        if seconds != prev_seconds {
-            prev_seconds = seconds;
-            node.run_per_second_tasks().unwrap();
-        }
+        node.run_periodic_tasks().unwrap();
         node.flush().unwrap();
     }
 }
@@ -32,7 +32,7 @@ use std::{env, thread};
 
 use canadensis::core::transfer::{MessageTransfer, ServiceTransfer};
 use canadensis::core::transport::Transport;
-use canadensis::node::{BasicNode, CoreNode};
+use canadensis::node::{BasicNode, CoreNodeBuilder};
 use canadensis::requester::TransferIdFixedMap;
 use canadensis::{Node, ResponseToken, TransferHandler};
 use canadensis_core::session::SessionDynamicMap;
@@ -58,13 +58,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Set up information about this node
     let node_info = GetInfoResponse {
         protocol_version: Version { major: 1, minor: 0 },
-        hardware_version: Version { major: 0, minor: 0 },
         software_version: Version { major: 0, minor: 1 },
-        software_vcs_revision_id: 0,
         unique_id: rand::random(),
         name: heapless::Vec::from_slice(b"org.samcrow.udp_basic_node").unwrap(),
-        software_image_crc: heapless::Vec::new(),
-        certificate_of_authenticity: Default::default(),
+        ..Default::default()
     };
 
     // Create a node with capacity for 8 publishers and 8 requesters
@@ -75,38 +72,26 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let socket = StdUdpSocket::bind(Ipv4Addr::UNSPECIFIED, DEFAULT_PORT).unwrap();
     let transmitter = UdpTransmitter::<StdUdpSocket, MTU>::new(DEFAULT_PORT);
-    let receiver = UdpReceiver::new(Some(node_id), Ipv4Addr::LOCALHOST);
-    let core_node: CoreNode<
+    let receiver: UdpReceiver<
         SystemClock,
-        UdpTransmitter<StdUdpSocket, MTU>,
-        UdpReceiver<
-            SystemClock,
-            SessionDynamicMap<UdpNodeId, UdpTransferId, UdpSessionData>,
-            StdUdpSocket,
-            MTU,
-        >,
-        TransferIdFixedMap<UdpTransport, TRANSFER_IDS>,
+        SessionDynamicMap<UdpNodeId, UdpTransferId, UdpSessionData>,
         StdUdpSocket,
-        PUBLISHERS,
-        REQUESTERS,
-    > = CoreNode::new(SystemClock::new(), node_id, transmitter, receiver, socket);
+        MTU,
+    > = UdpReceiver::new(Some(node_id), Ipv4Addr::LOCALHOST);
+    let core_node = CoreNodeBuilder::new(SystemClock::new(), transmitter, receiver, socket)
+        .node_id(node_id)
+        .publishers::<PUBLISHERS>()
+        .requesters::<REQUESTERS>()
+        .build::<TransferIdFixedMap<UdpTransport, TRANSFER_IDS>>();
     let mut node = BasicNode::new(core_node, node_info).unwrap();
 
-    let start_time = std::time::Instant::now();
-    let mut prev_seconds = 0;
     loop {
         match node.receive(&mut EmptyHandler) {
             Ok(_) => {}
             Err(e) => panic!("{:?}", e),
         };
 
-        let seconds = std::time::Instant::now()
-            .duration_since(start_time)
-            .as_secs();
-        if seconds != prev_seconds {
-            prev_seconds = seconds;
-            node.run_per_second_tasks().unwrap();
-        }
+        node.run_periodic_tasks().unwrap();
 
         node.flush().unwrap();
         thread::sleep(Duration::from_millis(100));
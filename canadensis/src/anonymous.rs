@@ -51,6 +51,17 @@ where
         }
     }
 
+    /// Returns the transfer ID that the next message will have
+    pub fn next_transfer_id(&self) -> <T::Transport as Transport>::TransferId {
+        self.next_transfer_id.clone()
+    }
+    /// Sets the transfer ID that the next message will have
+    ///
+    /// This can be used to restore a transfer ID that was saved before the node restarted.
+    pub fn set_next_transfer_id(&mut self, transfer_id: <T::Transport as Transport>::TransferId) {
+        self.next_transfer_id = transfer_id;
+    }
+
     /// Prepares an anonymous message for sending and pushes it into the provided transmitter
     ///
     /// This function returns an error if the message is too long to fit into one frame, or if
@@ -0,0 +1,119 @@
+//!
+//! An async run loop for nodes
+//!
+//! This module is available when the `async` feature is enabled. It does not depend on any
+//! particular async runtime. An application provides an implementation of [`Events`] that waits
+//! using its runtime (for example, an embassy timer and a signal from the CAN receive interrupt,
+//! or a tokio timer and socket readiness), and then calls [`BasicNode::run`].
+//!
+//! Bare-metal applications without an async runtime can keep calling
+//! [`receive`](crate::Node::receive),
+//! [`run_periodic_tasks`](crate::node::BasicNode::run_periodic_tasks), and
+//! [`flush`](crate::Node::flush) from a loop.
+//!
+
+use core::future::Future;
+
+use crate::node::BasicNode;
+use crate::{Node, PublishError, ReceiveError, TransferHandler, TransmitError};
+use canadensis_core::nb;
+use canadensis_core::time::{Clock, MicrosecondDuration32, Microseconds32};
+
+/// Something that an async node waits for between steps of its run loop
+pub trait Events {
+    /// Waits until an incoming frame may be available, or until the node's clock reaches
+    /// `deadline`, whichever happens first
+    ///
+    /// The run loop receives all available frames before it calls this function. The returned
+    /// future must complete immediately if any frames have arrived since then, even if they
+    /// arrived before this function was called. For example, an implementation that waits for a
+    /// signal from a receive interrupt must not clear a signal that was raised during the
+    /// previous step of the run loop.
+    ///
+    /// The future may also complete early for any other reason.
+    fn wait(&mut self, deadline: Microseconds32) -> impl Future<Output = ()>;
+}
+
+/// An error that stops an async run loop
+#[derive(Debug)]
+pub enum RunError<T, R> {
+    /// Publishing a heartbeat or port list message, or sending queued frames, failed
+    Transmit(PublishError<T>),
+    /// Receiving a transfer failed
+    Receive(R),
+}
+
+impl<N> BasicNode<N>
+where
+    N: Node,
+{
+    /// Runs this node until an error occurs
+    ///
+    /// * `events`: Waits for incoming frames and timer deadlines
+    /// * `handler`: Handles incoming transfers
+    /// * `period`: The longest time to wait before running periodic tasks again. This should be
+    ///   no longer than the heartbeat period.
+    ///
+    /// Each time `events` completes, this function receives all available transfers, runs
+    /// periodic tasks, and flushes outgoing frames (see [`run_once`](#method.run_once)).
+    ///
+    /// A full transmit queue is not an error. A heartbeat or port list message that does not fit
+    /// is dropped, and the loop continues. Any other transport error stops the loop.
+    pub async fn run<E, H>(
+        &mut self,
+        events: &mut E,
+        handler: &mut H,
+        period: MicrosecondDuration32,
+    ) -> RunError<TransmitError<N>, ReceiveError<N>>
+    where
+        E: Events,
+        H: TransferHandler<N::Transport>,
+    {
+        loop {
+            if let Err(e) = self.run_once(events, handler, period).await {
+                break e;
+            }
+        }
+    }
+
+    /// Runs one step of the run loop
+    ///
+    /// This function receives and handles transfers until no incoming frames remain, runs periodic
+    /// tasks, flushes outgoing frames, and then waits until `events` completes or `period` has
+    /// passed.
+    pub async fn run_once<E, H>(
+        &mut self,
+        events: &mut E,
+        handler: &mut H,
+        period: MicrosecondDuration32,
+    ) -> Result<(), RunError<TransmitError<N>, ReceiveError<N>>>
+    where
+        E: Events,
+        H: TransferHandler<N::Transport>,
+    {
+        while self.receive_one(handler).map_err(RunError::Receive)? {}
+        ignore_queue_full(self.run_periodic_tasks()).map_err(RunError::Transmit)?;
+        ignore_would_block(self.flush())
+            .map_err(|e| RunError::Transmit(PublishError::Transport(e)))?;
+
+        let deadline = self.clock_mut().now() + period;
+        events.wait(deadline).await;
+        Ok(())
+    }
+}
+
+/// Converts `Err(nb::Error::WouldBlock)` and [`PublishError::QueueFull`] into `Ok(())`
+fn ignore_queue_full<T>(result: nb::Result<(), PublishError<T>>) -> Result<(), PublishError<T>> {
+    match result {
+        Err(nb::Error::Other(PublishError::QueueFull)) => Ok(()),
+        result => ignore_would_block(result),
+    }
+}
+
+/// Converts `Err(nb::Error::WouldBlock)` into `Ok(())`
+fn ignore_would_block<E>(result: nb::Result<(), E>) -> Result<(), E> {
+    match result {
+        Ok(()) | Err(nb::Error::WouldBlock) => Ok(()),
+        Err(nb::Error::Other(e)) => Err(e),
+    }
+}
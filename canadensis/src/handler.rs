@@ -0,0 +1,115 @@
+//!
+//! Transfer handlers that deserialize incoming messages
+//!
+
+use crate::core::time::MicrosecondDuration32;
+use crate::core::transfer::{MessageHeader, MessageTransfer};
+use crate::core::transport::Transport;
+use crate::core::SubjectId;
+use crate::encoding::{Deserialize, Message, Serialize};
+use crate::{Node, ReceiveError, TransferHandler};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// A transfer handler that deserializes messages on one subject and passes them to a function
+///
+/// Like any other handler, this needs to be passed to [`Node::receive`]. It can be
+/// [chained](TransferHandler::chain) with other handlers.
+///
+/// Example:
+///
+/// ```ignore
+/// let mut records = MessageHandler::subscribe(
+///     &mut node,
+///     record_1_1::SUBJECT,
+///     milliseconds(1000),
+///     |record: Record, header: &MessageHeader<CanTransport>| {
+///         println!("{:?}: {:?}", header.source, record.text);
+///     },
+/// )?;
+/// node.receive(&mut records)?;
+/// ```
+pub struct MessageHandler<M, F> {
+    /// The subject of the messages to handle
+    subject: SubjectId,
+    /// The function that gets called with each message
+    handler: F,
+    _message: PhantomData<M>,
+}
+
+impl<M, F> MessageHandler<M, F>
+where
+    M: Message + Deserialize,
+{
+    /// Creates a handler for messages on a subject
+    ///
+    /// The node must already be subscribed to the subject.
+    pub fn new(subject: SubjectId, handler: F) -> Self {
+        MessageHandler {
+            subject,
+            handler,
+            _message: PhantomData,
+        }
+    }
+
+    /// Subscribes a node to messages on a subject and returns a handler for those messages
+    ///
+    /// The maximum payload size is the extent of `M`, or the maximum serialized size of `M` if
+    /// it is sealed. `timeout` is passed to
+    /// [`Node::subscribe_message`](crate::Node::subscribe_message).
+    pub fn subscribe<N>(
+        node: &mut N,
+        subject: SubjectId,
+        timeout: MicrosecondDuration32,
+        handler: F,
+    ) -> Result<Self, ReceiveError<N>>
+    where
+        N: Node,
+        M: Serialize,
+    {
+        let payload_size_max = M::EXTENT_BYTES
+            .map(|extent| extent as usize)
+            .unwrap_or(M::MAX_SIZE_BYTES);
+        node.subscribe_message(subject, payload_size_max, timeout)?;
+        Ok(Self::new(subject, handler))
+    }
+
+    /// Returns the subject of the messages that this handler handles
+    pub fn subject(&self) -> SubjectId {
+        self.subject
+    }
+
+    /// Consumes this handler and returns its function
+    pub fn into_inner(self) -> F {
+        self.handler
+    }
+}
+
+impl<T, M, F> TransferHandler<T> for MessageHandler<M, F>
+where
+    T: Transport,
+    M: Message + Deserialize,
+    F: FnMut(M, &MessageHeader<T>),
+{
+    /// Deserializes a message on this handler's subject and passes it to the function
+    ///
+    /// Messages on this subject that can't be deserialized are handled by ignoring them.
+    fn handle_message<N: Node<Transport = T>>(
+        &mut self,
+        _node: &mut N,
+        transfer: &MessageTransfer<Vec<u8>, T>,
+    ) -> bool {
+        if transfer.header.subject != self.subject {
+            return false;
+        }
+        match M::deserialize_from_bytes(&transfer.payload) {
+            Ok(message) => (self.handler)(message, &transfer.header),
+            Err(e) => log::debug!(
+                "Ignoring invalid message on subject {:?}: {:?}",
+                self.subject,
+                e
+            ),
+        }
+        true
+    }
+}
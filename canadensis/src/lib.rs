@@ -27,6 +27,9 @@ pub mod encoding {
 pub use canadensis_core::nb;
 
 pub mod anonymous;
+#[cfg(feature = "async")]
+pub mod async_run;
+pub mod handler;
 pub mod node;
 mod publisher;
 pub mod register;
@@ -46,6 +49,12 @@ use canadensis_core::transport::{Receiver, Transmitter};
 use canadensis_core::{ServiceId, SubjectId};
 use canadensis_encoding::{Message, Request, Response, Serialize};
 
+/// The error type of a node's transmitter
+pub(crate) type TransmitError<N> =
+    <<N as Node>::Transmitter as Transmitter<<N as Node>::Clock>>::Error;
+/// The error type of a node's receiver
+pub(crate) type ReceiveError<N> = <<N as Node>::Receiver as Receiver<<N as Node>::Clock>>::Error;
+
 /// A token from a request that is needed to send a response
 pub struct ResponseToken<T: Transport> {
     /// ID of the service that this is a response for
@@ -159,6 +168,11 @@ pub trait TransferHandler<T: Transport> {
     /// Chains another handler after this handler and returns the combined handler
     ///
     /// For each incoming transfer, this handler will be given the transfer before the next handler.
+    /// The next handler only gets the transfer if this handler returns false.
+    ///
+    /// Any number of handlers can be combined by calling this function repeatedly. For example,
+    /// `logger.chain(&mut registers).chain(telemetry)` gives each transfer to `logger`, then
+    /// `registers`, and then `telemetry`, stopping at the first handler that returns true.
     fn chain<H>(self, next: H) -> TransferHandlerChain<Self, H>
     where
         Self: Sized,
@@ -517,6 +531,15 @@ pub trait Node {
 pub enum PublishError<T> {
     /// [`Node::start_publishing`](Node#tymethod.start_publishing) has not been called for this subject
     NotPublishing,
+    /// The node is anonymous, and the message is too long to fit into one frame
+    ///
+    /// Anonymous nodes can only send single-frame transfers.
+    AnonymousMultiFrame,
+    /// The queue of outgoing frames did not have space for the message
+    ///
+    /// This is usually temporary. The message can be published again after some queued frames
+    /// have been transmitted.
+    QueueFull,
     /// A transport error occurred
     Transport(T),
 }
@@ -1,9 +1,12 @@
 use crate::core::transport::Transmitter;
 use crate::node::{MinimalNode, NodeError};
-use crate::{Node, PublishError, ResponseToken, ServiceToken, StartSendError, TransferHandler};
+use crate::{
+    Node, PublishError, ResponseToken, ServiceToken, StartSendError, TransferHandler, TransmitError,
+};
+use alloc::boxed::Box;
 use alloc::vec::Vec;
-use canadensis_core::time::{milliseconds, MicrosecondDuration32};
-use canadensis_core::transfer::ServiceTransfer;
+use canadensis_core::time::{milliseconds, Clock, MicrosecondDuration32, Microseconds32};
+use canadensis_core::transfer::{MessageTransfer, ServiceTransfer, Transfer};
 use canadensis_core::transport::{Receiver, Transport};
 use canadensis_core::{nb, Priority, ServiceId, ServiceSubscribeError, SubjectId};
 use canadensis_data_types::uavcan::node::get_info_1_0::{self, GetInfoResponse};
@@ -17,6 +20,12 @@ use canadensis_data_types::uavcan::node::port::subject_id_list_1_0::SubjectIDLis
 use canadensis_encoding::bits::BitArray;
 use canadensis_encoding::{Message, Request, Response, Serialize};
 
+/// The time between port list messages sent by `run_periodic_tasks`
+const PORT_LIST_PERIOD: MicrosecondDuration32 = MicrosecondDuration32::from_ticks(10_000_000);
+
+/// A function that can modify the node information before it is sent in a response
+type NodeInfoHook = Box<dyn FnMut(&mut GetInfoResponse)>;
+
 /// A node that provides all basic application-layer functionality
 ///
 /// This node performs the following functions:
@@ -35,7 +44,11 @@ where
     node: MinimalNode<N>,
     port_list: List,
     node_info: GetInfoResponse,
+    node_info_hook: Option<NodeInfoHook>,
     seconds_since_port_list_published: u8,
+    /// The time when `run_periodic_tasks` should publish the next port list, or None if it has
+    /// not published one
+    next_port_list: Option<Microseconds32>,
 }
 
 impl<N> BasicNode<N>
@@ -103,11 +116,38 @@ where
             node: minimal,
             port_list,
             node_info,
+            node_info_hook: None,
             seconds_since_port_list_published: 0,
+            next_port_list: None,
         })
     }
 
+    /// Sends heartbeat and port list messages when they are due
+    ///
+    /// This function checks the node's clock, so it can be called as often as needed (for
+    /// example, every time through the main loop). It sends a heartbeat message once per
+    /// heartbeat period (see [`MinimalNode::set_heartbeat_period`]) and a port list message
+    /// every 10 seconds. The first call sends both.
+    ///
+    /// Either `run_periodic_tasks` or `run_per_second_tasks` should be called, but not both.
+    pub fn run_periodic_tasks(&mut self) -> nb::Result<(), PublishError<TransmitError<N>>> {
+        self.node.run_periodic_tasks()?;
+        let now = self.node.node_mut().clock_mut().now();
+        let port_list_due = match self.next_port_list {
+            Some(next) => now >= next,
+            None => true,
+        };
+        if port_list_due {
+            self.next_port_list = Some(now + PORT_LIST_PERIOD);
+            self.publish_port_list()?;
+        }
+        Ok(())
+    }
+
     /// This function must be called once per second to send heartbeat and port list messages
+    ///
+    /// Unlike [`run_periodic_tasks`](#method.run_periodic_tasks), this function does not check
+    /// if one second has passed since the last time it was called.
     pub fn run_per_second_tasks(
         &mut self,
     ) -> nb::Result<(), PublishError<<N::Transmitter as Transmitter<N::Clock>>::Error>> {
@@ -142,6 +182,44 @@ where
         self.node.set_status_code(status);
     }
 
+    /// Returns the information that this node sends in response to `uavcan.node.GetInfo`
+    /// requests
+    pub fn node_info(&self) -> &GetInfoResponse {
+        &self.node_info
+    }
+    /// Changes the information that this node sends in response to `uavcan.node.GetInfo`
+    /// requests
+    ///
+    /// This can be used to fill in fields that are not known when the node is created, such as
+    /// `software_image_crc`. The change applies to all requests received after this function
+    /// returns.
+    pub fn update_node_info<F>(&mut self, update: F)
+    where
+        F: FnOnce(&mut GetInfoResponse),
+    {
+        update(&mut self.node_info);
+    }
+
+    /// Sets a function that is called with the node information each time this node receives a
+    /// `uavcan.node.GetInfo` request, before it sends the response
+    ///
+    /// This can be used to fill in fields that are expensive to calculate, such as
+    /// `software_image_crc`, only when another node asks for them. Changes that the function
+    /// makes to the node information are kept, so the function can check if a field has already
+    /// been filled in and skip calculating it again.
+    ///
+    /// If a function was already set, it is replaced.
+    pub fn set_node_info_hook<F>(&mut self, hook: F)
+    where
+        F: FnMut(&mut GetInfoResponse) + 'static,
+    {
+        self.node_info_hook = Some(Box::new(hook));
+    }
+    /// Removes the function set by [`set_node_info_hook`](#method.set_node_info_hook)
+    pub fn clear_node_info_hook(&mut self) {
+        self.node_info_hook = None;
+    }
+
     /// Returns a reference to the enclosed node
     pub fn node(&self) -> &N {
         self.node.node()
@@ -159,6 +237,30 @@ where
     pub fn minimal_node_mut(&mut self) -> &mut MinimalNode<N> {
         &mut self.node
     }
+
+    /// Receives incoming frames and handles up to one transfer, like
+    /// [`receive`](crate::Node::receive)
+    ///
+    /// This function returns true if it handled a transfer.
+    pub(crate) fn receive_one<H>(
+        &mut self,
+        handler: &mut H,
+    ) -> Result<bool, <N::Receiver as Receiver<N::Clock>>::Error>
+    where
+        H: TransferHandler<N::Transport>,
+    {
+        let mut received = ReceivedFlag(false);
+        let mut chained_handler = TransferHandler::<N::Transport>::chain(
+            &mut received,
+            NodeInfoHandler {
+                response: &mut self.node_info,
+                hook: self.node_info_hook.as_mut(),
+            },
+        )
+        .chain(handler);
+        self.node.node_mut().receive(&mut chained_handler)?;
+        Ok(received.0)
+    }
 }
 
 impl<N> Node for BasicNode<N>
@@ -177,11 +279,7 @@ where
     where
         H: TransferHandler<Self::Transport>,
     {
-        let mut chained_handler = NodeInfoHandler {
-            response: &self.node_info,
-        }
-        .chain(handler);
-        self.node.node_mut().receive(&mut chained_handler)
+        self.receive_one(handler).map(|_| ())
     }
 
     fn start_publishing(
@@ -451,9 +549,10 @@ fn remove_from_list(subject_list: &mut SubjectIDList, subject: SubjectId) {
     }
 }
 
-/// Responds to NodeInfo requests with the provided response
+/// Responds to NodeInfo requests with the provided response, after calling the hook if present
 struct NodeInfoHandler<'r> {
-    response: &'r GetInfoResponse,
+    response: &'r mut GetInfoResponse,
+    hook: Option<&'r mut NodeInfoHook>,
 }
 
 impl<'r, T> TransferHandler<T> for NodeInfoHandler<'r>
@@ -467,10 +566,58 @@ where
         transfer: &ServiceTransfer<Vec<u8>, T>,
     ) -> bool {
         if transfer.header.service == get_info_1_0::SERVICE {
-            let _ = node.send_response(token, milliseconds(1000), self.response);
+            if let Some(hook) = self.hook.as_mut() {
+                hook(self.response);
+            }
+            let _ = node.send_response(token, milliseconds(1000), &*self.response);
             true
         } else {
             false
         }
     }
 }
+
+/// Records that a transfer was received, and passes it on to the next handler
+struct ReceivedFlag(bool);
+
+impl<T> TransferHandler<T> for ReceivedFlag
+where
+    T: Transport,
+{
+    fn handle_message<N: Node<Transport = T>>(
+        &mut self,
+        _node: &mut N,
+        _transfer: &MessageTransfer<Vec<u8>, T>,
+    ) -> bool {
+        self.0 = true;
+        false
+    }
+
+    fn handle_request<N: Node<Transport = T>>(
+        &mut self,
+        _node: &mut N,
+        _token: ResponseToken<T>,
+        _transfer: &ServiceTransfer<Vec<u8>, T>,
+    ) -> bool {
+        self.0 = true;
+        false
+    }
+
+    fn handle_response<N: Node<Transport = T>>(
+        &mut self,
+        _node: &mut N,
+        _transfer: &ServiceTransfer<Vec<u8>, T>,
+    ) -> bool {
+        self.0 = true;
+        false
+    }
+
+    fn handle_loopback<N: Node<Transport = T>>(
+        &mut self,
+        _node: &mut N,
+        _transfer: &Transfer<Vec<u8>, T>,
+    ) -> bool {
+        self.0 = true;
+        false
+    }
+}
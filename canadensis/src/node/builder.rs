@@ -0,0 +1,149 @@
+use canadensis_core::time::Clock;
+use canadensis_core::transport::{Receiver, Transmitter, Transport};
+use canadensis_core::TransferIdTracker;
+
+use crate::node::CoreNode;
+
+/// A builder that creates a [`CoreNode`]
+///
+/// The publisher and requester capacities are set with named methods, so they can't be swapped
+/// accidentally. Both capacities must be set before calling `build`. If either one is missing,
+/// the code will fail to compile.
+///
+/// Type parameters:
+/// * `C`: The clock used to get the current time
+/// * `T`: The transmitter used to send transfers
+/// * `U`: The receiver used to receive transfers
+/// * `D`: The driver used to send and receive frames
+/// * `P`: The maximum number of topics that can be published
+/// * `R`: The maximum number of services for which requests can be sent
+///
+/// # Examples
+///
+/// ```
+/// # use canadensis::node::{CoreNode, CoreNodeBuilder};
+/// # use canadensis::requester::TransferIdFixedMap;
+/// # use canadensis_can::{CanNodeId, CanReceiver, CanTransmitter, CanTransport, LoopbackBus, Mtu};
+/// # use canadensis_core::time::{Clock, Microseconds32};
+/// # use std::convert::TryFrom;
+/// # struct ZeroClock;
+/// # impl Clock for ZeroClock {
+/// #     fn now(&mut self) -> Microseconds32 {
+/// #         Microseconds32::from_ticks(0)
+/// #     }
+/// # }
+/// # let clock = ZeroClock;
+/// # let node_id = CanNodeId::try_from(3_u8).unwrap();
+/// # let transmitter = CanTransmitter::new(Mtu::Can8);
+/// # let receiver = CanReceiver::new(node_id);
+/// # let driver = LoopbackBus::new().add_driver();
+/// let node: CoreNode<_, _, _, TransferIdFixedMap<CanTransport, 8>, _, _, _> =
+///     CoreNodeBuilder::new(clock, transmitter, receiver, driver)
+///         .node_id(node_id)
+///         .publishers::<8>()
+///         .requesters::<8>()
+///         .build();
+/// ```
+#[derive(Debug)]
+pub struct CoreNodeBuilder<C, T, U, D, const P: usize = 0, const R: usize = 0>
+where
+    C: Clock,
+    T: Transmitter<C>,
+{
+    clock: C,
+    transmitter: T,
+    receiver: U,
+    driver: D,
+    node_id: Option<<T::Transport as Transport>::NodeId>,
+}
+
+impl<C, T, U, N, D> CoreNodeBuilder<C, T, U, D>
+where
+    C: Clock,
+    N: Transport,
+    U: Receiver<C, Transport = N, Driver = D>,
+    T: Transmitter<C, Transport = N, Driver = D>,
+{
+    /// Creates a builder for an anonymous node with no publisher or requester capacity
+    ///
+    /// * `clock`: A clock to use for frame deadlines and timeouts
+    /// * `transmitter`: A transport transmitter (this determines the MTU)
+    /// * `receiver`: A transport receiver
+    /// * `driver`: A driver compatible with `receiver` and `transmitter` (this contains the
+    ///   outgoing frame queue, if any)
+    pub fn new(clock: C, transmitter: T, receiver: U, driver: D) -> Self {
+        CoreNodeBuilder {
+            clock,
+            transmitter,
+            receiver,
+            driver,
+            node_id: None,
+        }
+    }
+}
+
+impl<C, T, U, N, D, const P: usize, const R: usize> CoreNodeBuilder<C, T, U, D, P, R>
+where
+    C: Clock,
+    N: Transport,
+    U: Receiver<C, Transport = N, Driver = D>,
+    T: Transmitter<C, Transport = N, Driver = D>,
+{
+    /// Sets the ID of the node
+    ///
+    /// If this function is not called, the node will be anonymous.
+    pub fn node_id(mut self, node_id: N::NodeId) -> Self {
+        self.node_id = Some(node_id);
+        self
+    }
+
+    /// Sets the maximum number of topics that the node can publish
+    ///
+    /// This must be greater than 0, or the code will fail to compile. It also must be a power of
+    /// two, or the software may behave incorrectly.
+    pub fn publishers<const P2: usize>(self) -> CoreNodeBuilder<C, T, U, D, P2, R> {
+        CoreNodeBuilder {
+            clock: self.clock,
+            transmitter: self.transmitter,
+            receiver: self.receiver,
+            driver: self.driver,
+            node_id: self.node_id,
+        }
+    }
+
+    /// Sets the maximum number of services for which the node can send requests
+    ///
+    /// This must be greater than 0, or the code will fail to compile. It also must be a power of
+    /// two, or the software may behave incorrectly.
+    pub fn requesters<const R2: usize>(self) -> CoreNodeBuilder<C, T, U, D, P, R2> {
+        CoreNodeBuilder {
+            clock: self.clock,
+            transmitter: self.transmitter,
+            receiver: self.receiver,
+            driver: self.driver,
+            node_id: self.node_id,
+        }
+    }
+
+    /// Creates the node
+    ///
+    /// `TR` is the transfer ID tracker used to manage transfer IDs for outgoing transfers. It can
+    /// usually be inferred from the type of the resulting node.
+    pub fn build<TR>(self) -> CoreNode<C, T, U, TR, D, P, R>
+    where
+        TR: TransferIdTracker<N>,
+    {
+        match self.node_id {
+            Some(node_id) => CoreNode::new(
+                self.clock,
+                node_id,
+                self.transmitter,
+                self.receiver,
+                self.driver,
+            ),
+            None => {
+                CoreNode::new_anonymous(self.clock, self.transmitter, self.receiver, self.driver)
+            }
+        }
+    }
+}
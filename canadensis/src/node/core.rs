@@ -4,9 +4,9 @@ use heapless::index_map::FnvIndexMap;
 
 use canadensis_core::time::{Clock, MicrosecondDuration32, Microseconds32};
 use canadensis_core::transfer::{
-    Header, MessageTransfer, ServiceHeader, ServiceTransfer, Transfer,
+    Header, MessageTransfer, ReceivedTransfer, ServiceHeader, ServiceTransfer, Transfer,
 };
-use canadensis_core::transport::{Receiver, Transmitter, Transport};
+use canadensis_core::transport::{Receiver, StreamingTransmitter, Transmitter, Transport};
 use canadensis_core::{
     nb, OutOfMemoryError, ServiceId, ServiceSubscribeError, SubjectId, TransferIdTracker,
 };
@@ -63,6 +63,9 @@ where
     /// * `transmitter`: A transport transmitter
     /// * `receiver`: A transport receiver
     /// * `driver`: A driver compatible with `receiver` and `transmitter`
+    ///
+    /// [`CoreNodeBuilder`](crate::node::CoreNodeBuilder) is usually easier to use, because it
+    /// sets the publisher and requester capacities with named methods.
     pub fn new(
         clock: C,
         node_id: <T::Transport as Transport>::NodeId,
@@ -110,15 +113,87 @@ where
         &mut self.driver
     }
 
+    /// Returns the priority of messages published on a subject, or None if this node is not
+    /// publishing on the subject
+    pub fn publish_priority(&self, subject: SubjectId) -> Option<&N::Priority> {
+        self.publishers.get(&subject).map(Publisher::priority)
+    }
+    /// Changes the priority of messages published on a subject
+    ///
+    /// The transfer IDs of messages on the subject continue from where they were.
+    ///
+    /// This function returns false if this node is not publishing on the subject.
+    pub fn set_publish_priority(&mut self, subject: SubjectId, priority: N::Priority) -> bool {
+        match self.publishers.get_mut(&subject) {
+            Some(publisher) => {
+                publisher.set_priority(priority);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the timeout for sending messages on a subject, or None if this node is not
+    /// publishing on the subject
+    pub fn publish_timeout(&self, subject: SubjectId) -> Option<MicrosecondDuration32> {
+        self.publishers.get(&subject).map(Publisher::timeout)
+    }
+    /// Changes the timeout for sending messages on a subject
+    ///
+    /// The transfer IDs of messages on the subject continue from where they were.
+    ///
+    /// This function returns false if this node is not publishing on the subject.
+    pub fn set_publish_timeout(
+        &mut self,
+        subject: SubjectId,
+        timeout: MicrosecondDuration32,
+    ) -> bool {
+        match self.publishers.get_mut(&subject) {
+            Some(publisher) => {
+                publisher.set_timeout(timeout);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the transfer ID of the next message that will be published on a subject, or None
+    /// if this node is not publishing on the subject
+    ///
+    /// This can be saved before the node restarts and restored with
+    /// [`set_next_transfer_id`](#method.set_next_transfer_id), so that subscribers do not
+    /// mistake new messages for duplicates of old ones.
+    pub fn next_transfer_id(&self, subject: SubjectId) -> Option<N::TransferId> {
+        self.publishers
+            .get(&subject)
+            .map(Publisher::next_transfer_id)
+    }
+    /// Sets the transfer ID of the next message that will be published on a subject
+    ///
+    /// This function returns false if this node is not publishing on the subject.
+    pub fn set_next_transfer_id(&mut self, subject: SubjectId, transfer_id: N::TransferId) -> bool {
+        match self.publishers.get_mut(&subject) {
+            Some(publisher) => {
+                publisher.set_next_transfer_id(transfer_id);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Categorizes a transfer as a message, request, response, or loopback,
     /// and calls the corresponding method of the handler
     fn handle_incoming_transfer<H>(
         &mut self,
-        transfer: Transfer<Vec<u8>, U::Transport>,
+        received: ReceivedTransfer<Vec<u8>, U::Transport>,
         handler: &mut H,
     ) where
         H: TransferHandler<U::Transport>,
     {
+        let ReceivedTransfer {
+            transfer,
+            completion_timestamp,
+        } = received;
         if transfer.loopback {
             handler.handle_loopback(self, &transfer);
         } else {
@@ -127,6 +202,7 @@ where
                     let message_transfer = MessageTransfer {
                         header: message_header,
                         loopback: transfer.loopback,
+                        completion_timestamp,
                         payload: transfer.payload,
                     };
                     handler.handle_message(self, &message_transfer);
@@ -141,6 +217,7 @@ where
                     let service_transfer = ServiceTransfer {
                         header: service_header,
                         loopback: transfer.loopback,
+                        completion_timestamp,
                         payload: transfer.payload,
                     };
                     handler.handle_request(self, token, &service_transfer);
@@ -149,6 +226,7 @@ where
                     let service_transfer = ServiceTransfer {
                         header: service_header,
                         loopback: transfer.loopback,
+                        completion_timestamp,
                         payload: transfer.payload,
                     };
                     handler.handle_response(self, &service_transfer);
@@ -180,6 +258,80 @@ where
     }
 }
 
+impl<C, T, U, N, TR, D, const P: usize, const R: usize> CoreNode<C, T, U, TR, D, P, R>
+where
+    C: Clock,
+    N: Transport,
+    U: Receiver<C, Transport = N, Driver = D>,
+    T: StreamingTransmitter<C, Transport = N, Driver = D>,
+    TR: TransferIdTracker<N>,
+{
+    /// Publishes a message, serializing it while it is being sent
+    ///
+    /// This works like [`publish`](Node::publish), but the transmitter sends each part of the
+    /// message as soon as it has been serialized. This avoids the need for a buffer that can hold
+    /// the whole serialized message, which is useful for large messages on nodes with little
+    /// memory.
+    pub fn publish_streaming<M>(
+        &mut self,
+        subject: SubjectId,
+        payload: &M,
+    ) -> nb::Result<(), PublishError<T::Error>>
+    where
+        M: Message + Serialize,
+    {
+        let publisher = match self.publishers.get_mut(&subject) {
+            Some(publisher) => publisher,
+            None => return Err(nb::Error::Other(PublishError::NotPublishing)),
+        };
+        check_anonymous_length(&self.node_id, &self.transmitter, payload)
+            .map_err(nb::Error::Other)?;
+        publisher
+            .publish_streaming(
+                &mut self.clock,
+                self.node_id.clone(),
+                subject,
+                payload,
+                &mut self.transmitter,
+                &mut self.driver,
+            )
+            .map_err(publish_error::<C, T>)
+    }
+}
+
+/// Converts a transmitter error into a publish error
+fn publish_error<C, T>(error: nb::Error<T::Error>) -> nb::Error<PublishError<T::Error>>
+where
+    C: Clock,
+    T: Transmitter<C>,
+{
+    error.map(|e| {
+        if T::is_queue_full(&e) {
+            PublishError::QueueFull
+        } else {
+            PublishError::Transport(e)
+        }
+    })
+}
+
+/// Returns an error if the node is anonymous and a message payload will not fit into one frame
+fn check_anonymous_length<C, T, M>(
+    node_id: &Option<<T::Transport as Transport>::NodeId>,
+    transmitter: &T,
+    payload: &M,
+) -> Result<(), PublishError<T::Error>>
+where
+    C: Clock,
+    T: Transmitter<C>,
+    M: Serialize,
+{
+    if node_id.is_none() && payload.size_bits().div_ceil(8) > transmitter.mtu() {
+        Err(PublishError::AnonymousMultiFrame)
+    } else {
+        Ok(())
+    }
+}
+
 impl<C, T, U, N, TR, D, const P: usize, const R: usize> Node for CoreNode<C, T, U, TR, D, P, R>
 where
     C: Clock,
@@ -235,6 +387,8 @@ where
             Some(publisher) => publisher,
             None => return Err(nb::Error::Other(PublishError::NotPublishing)),
         };
+        check_anonymous_length(&self.node_id, &self.transmitter, payload)
+            .map_err(nb::Error::Other)?;
         publisher
             .publish(
                 &mut self.clock,
@@ -244,10 +398,7 @@ where
                 &mut self.transmitter,
                 &mut self.driver,
             )
-            .map_err(|e| match e {
-                nb::Error::WouldBlock => nb::Error::WouldBlock,
-                nb::Error::Other(e) => nb::Error::Other(PublishError::Transport(e)),
-            })
+            .map_err(publish_error::<C, T>)
     }
 
     fn publish_loopback<M>(
@@ -262,6 +413,8 @@ where
             Some(publisher) => publisher,
             None => return Err(nb::Error::Other(PublishError::NotPublishing)),
         };
+        check_anonymous_length(&self.node_id, &self.transmitter, payload)
+            .map_err(nb::Error::Other)?;
         publisher
             .publish_loopback(
                 &mut self.clock,
@@ -271,10 +424,7 @@ where
                 &mut self.transmitter,
                 &mut self.driver,
             )
-            .map_err(|e| match e {
-                nb::Error::WouldBlock => nb::Error::WouldBlock,
-                nb::Error::Other(e) => nb::Error::Other(PublishError::Transport(e)),
-            })
+            .map_err(publish_error::<C, T>)
     }
 
     /// Sets up to send requests for a service
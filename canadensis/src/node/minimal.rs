@@ -1,17 +1,24 @@
-use crate::{Node, PublishError, StartSendError};
-use canadensis_core::time::MicrosecondDuration32;
-use canadensis_core::transport::Transmitter;
-use canadensis_core::{nb, Priority};
+use crate::{Node, PublishError, StartSendError, TransmitError};
+use canadensis_core::time::{Clock, MicrosecondDuration32, Microseconds32};
+use canadensis_core::transport::{Receiver, Transmitter};
+use canadensis_core::{nb, InvalidValue, Priority};
 use canadensis_data_types::uavcan::node::health_1_0::Health;
 use canadensis_data_types::uavcan::node::heartbeat_1_0::{self, Heartbeat};
 use canadensis_data_types::uavcan::node::mode_1_0::Mode;
 
+/// The longest time between heartbeat messages that the Cyphal specification allows
+const MAX_HEARTBEAT_PERIOD: MicrosecondDuration32 = MicrosecondDuration32::from_ticks(1_000_000);
+
 /// A node with the minimum required application-layer functionality
 ///
 /// A `BasicNode` wraps a [`Node`](crate::Node) and adds functionality to send a
 /// `uavcan.node.Heartbeat.1.0` message every second. This is the only application-layer function
 /// that is required for all nodes.
 ///
+/// Heartbeat messages can also be sent more often (see
+/// [`set_heartbeat_period`](#method.set_heartbeat_period)), but never less often than once per
+/// second.
+///
 /// A BasicNode uses up one publisher slot in the underlying Node.
 ///
 /// The underlying node type `N` is usually a [`CoreNode`](crate::node::CoreNode).
@@ -23,6 +30,15 @@ where
     node: N,
     /// The heartbeat message that will be periodically sent
     heartbeat: Heartbeat,
+    /// The time between heartbeat messages sent by `run_periodic_tasks`
+    heartbeat_period: MicrosecondDuration32,
+    /// The time when `run_periodic_tasks` last sent a heartbeat, or None if it has not sent one
+    last_heartbeat: Option<Microseconds32>,
+    /// The time when `run_periodic_tasks` should send the next heartbeat
+    next_heartbeat: Option<Microseconds32>,
+    /// Time that has passed but has not been counted in the uptime because it is less than
+    /// one second
+    uptime_remainder: MicrosecondDuration32,
 }
 
 impl<N> MinimalNode<N>
@@ -54,7 +70,52 @@ where
             Priority::Nominal.into(),
         )?;
 
-        Ok(MinimalNode { node, heartbeat })
+        Ok(MinimalNode {
+            node,
+            heartbeat,
+            heartbeat_period: MAX_HEARTBEAT_PERIOD,
+            last_heartbeat: None,
+            next_heartbeat: None,
+            uptime_remainder: MicrosecondDuration32::from_ticks(0),
+        })
+    }
+
+    /// Sends a heartbeat message if it is time to send one
+    ///
+    /// This function checks the node's clock, so it can be called as often as needed. The first
+    /// call sends a heartbeat immediately, and later heartbeats are sent once per heartbeat
+    /// period. The uptime in the heartbeat messages is based on the time that has passed since the
+    /// first call.
+    ///
+    /// This function also discards partially received transfers that have timed out (see
+    /// [`Receiver::clean_expired`]).
+    ///
+    /// Either `run_periodic_tasks` or `run_per_second_tasks` should be called, but not both.
+    pub fn run_periodic_tasks(&mut self) -> nb::Result<(), PublishError<TransmitError<N>>> {
+        let now = self.node.clock_mut().now();
+        self.node.receiver_mut().clean_expired(now);
+        if let Some((last, next)) = self.last_heartbeat.zip(self.next_heartbeat) {
+            if now < next {
+                return Ok(());
+            }
+            let elapsed = self.uptime_remainder
+                + now
+                    .checked_duration_since(last)
+                    .unwrap_or(MicrosecondDuration32::from_ticks(0));
+            self.uptime_remainder = MicrosecondDuration32::from_ticks(elapsed.ticks() % 1_000_000);
+            self.heartbeat.uptime = self
+                .heartbeat
+                .uptime
+                .saturating_add(elapsed.ticks() / 1_000_000);
+        }
+        // Keep the heartbeats on schedule, unless this function was called so late that
+        // a whole period was missed
+        self.next_heartbeat = Some(match self.next_heartbeat {
+            Some(next) if now < next + self.heartbeat_period => next + self.heartbeat_period,
+            _ => now + self.heartbeat_period,
+        });
+        self.last_heartbeat = Some(now);
+        self.send_heartbeat()
     }
 
     /// This function must be called once per second to send heartbeat messages
@@ -66,6 +127,7 @@ where
     pub fn run_per_second_tasks(
         &mut self,
     ) -> nb::Result<(), PublishError<<N::Transmitter as Transmitter<N::Clock>>::Error>> {
+        self.heartbeat.uptime = self.heartbeat.uptime.saturating_add(1);
         self.send_heartbeat()
     }
 
@@ -73,10 +135,33 @@ where
     fn send_heartbeat(
         &mut self,
     ) -> nb::Result<(), PublishError<<N::Transmitter as Transmitter<N::Clock>>::Error>> {
-        self.heartbeat.uptime = self.heartbeat.uptime.saturating_add(1);
         self.node.publish(heartbeat_1_0::SUBJECT, &self.heartbeat)
     }
 
+    /// Sets the time between heartbeat messages sent by
+    /// [`run_periodic_tasks`](#method.run_periodic_tasks)
+    ///
+    /// The default period is one second. The Cyphal specification requires at least one
+    /// heartbeat message every second, so this function returns an error if `period` is longer
+    /// than one second (or zero).
+    ///
+    /// This does not affect [`run_per_second_tasks`](#method.run_per_second_tasks).
+    pub fn set_heartbeat_period(
+        &mut self,
+        period: MicrosecondDuration32,
+    ) -> Result<(), InvalidValue> {
+        if period.ticks() == 0 || period > MAX_HEARTBEAT_PERIOD {
+            return Err(InvalidValue);
+        }
+        self.heartbeat_period = period;
+        Ok(())
+    }
+    /// Returns the time between heartbeat messages sent by
+    /// [`run_periodic_tasks`](#method.run_periodic_tasks)
+    pub fn heartbeat_period(&self) -> MicrosecondDuration32 {
+        self.heartbeat_period
+    }
+
     /// Sets the operating mode that will be reported in the heartbeat messages
     pub fn set_mode(&mut self, mode: Mode) {
         self.heartbeat.mode = mode;
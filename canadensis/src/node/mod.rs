@@ -12,10 +12,12 @@
 //!
 
 mod basic;
+mod builder;
 mod core;
 mod minimal;
 
 pub use self::basic::BasicNode;
+pub use self::builder::CoreNodeBuilder;
 pub use self::core::CoreNode;
 pub use self::minimal::MinimalNode;
 
@@ -1,13 +1,21 @@
 use crate::serialize::do_serialize;
 use canadensis_core::time::{Clock, MicrosecondDuration32, Microseconds32};
 use canadensis_core::transfer::{Header, MessageHeader, Transfer};
-use canadensis_core::transport::{TransferId, Transmitter, Transport};
+use canadensis_core::transport::{StreamingTransmitter, TransferId, Transmitter, Transport};
 use canadensis_core::{nb, SubjectId};
 use canadensis_encoding::{Message, Serialize};
 
+/// The number of bytes that [`Publisher::publish_streaming`] serializes at a time
+const STREAMING_WINDOW: usize = 16;
+
 /// Assembles transfers and manages transfer IDs to send messages
 ///
 /// The subject ID is not part of this struct because it is used as a key in the map of publishers.
+///
+/// Each publisher must be used with only one subject. The Cyphal specification requires a
+/// separate transfer ID sequence for each subject, and a publisher has only one transfer ID
+/// counter. Every function that takes a `subject` parameter must get the same subject ID that
+/// this publisher is stored under.
 pub struct Publisher<C: Clock, T: Transmitter<C>> {
     /// The ID of the next transfer sent
     next_transfer_id: <T::Transport as Transport>::TransferId,
@@ -32,9 +40,44 @@ impl<C: Clock, T: Transmitter<C>> Publisher<C, T> {
         }
     }
 
+    /// Returns the priority of messages from this publisher
+    pub fn priority(&self) -> &<T::Transport as Transport>::Priority {
+        &self.priority
+    }
+    /// Sets the priority of messages published after this call
+    ///
+    /// This does not affect the transfer ID sequence.
+    pub fn set_priority(&mut self, priority: <T::Transport as Transport>::Priority) {
+        self.priority = priority;
+    }
+
+    /// Returns the timeout for sending messages from this publisher
+    pub fn timeout(&self) -> MicrosecondDuration32 {
+        self.timeout
+    }
+    /// Sets the timeout for sending messages published after this call
+    ///
+    /// This does not affect the transfer ID sequence.
+    pub fn set_timeout(&mut self, timeout: MicrosecondDuration32) {
+        self.timeout = timeout;
+    }
+
+    /// Returns the transfer ID that the next message will have
+    pub fn next_transfer_id(&self) -> <T::Transport as Transport>::TransferId {
+        self.next_transfer_id.clone()
+    }
+    /// Sets the transfer ID that the next message will have
+    ///
+    /// This can be used to restore a transfer ID that was saved before the node restarted.
+    pub fn set_next_transfer_id(&mut self, transfer_id: <T::Transport as Transport>::TransferId) {
+        self.next_transfer_id = transfer_id;
+    }
+
     /// Publishes a message
     ///
     /// The loopback flag is set to false
+    ///
+    /// `subject` must be the subject that this publisher is used for.
     pub fn publish<M>(
         &mut self,
         clock: &mut C,
@@ -64,6 +107,8 @@ impl<C: Clock, T: Transmitter<C>> Publisher<C, T> {
         })
     }
     /// Publishes a loopback message
+    ///
+    /// `subject` must be the subject that this publisher is used for.
     pub fn publish_loopback<M>(
         &mut self,
         clock: &mut C,
@@ -93,6 +138,23 @@ impl<C: Clock, T: Transmitter<C>> Publisher<C, T> {
         })
     }
 
+    fn make_header(
+        &mut self,
+        source: Option<<T::Transport as Transport>::NodeId>,
+        subject: SubjectId,
+        deadline: Microseconds32,
+    ) -> Header<T::Transport> {
+        let header = Header::Message(MessageHeader {
+            timestamp: deadline,
+            transfer_id: self.next_transfer_id.clone(),
+            priority: self.priority.clone(),
+            subject,
+            source,
+        });
+        self.next_transfer_id = self.next_transfer_id.clone().increment();
+        header
+    }
+
     fn send_payload(
         &mut self,
         source: Option<<T::Transport as Transport>::NodeId>,
@@ -106,22 +168,51 @@ impl<C: Clock, T: Transmitter<C>> Publisher<C, T> {
     ) -> nb::Result<(), T::Error> {
         // Assemble the transfer
         let transfer = Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: deadline,
-                transfer_id: self.next_transfer_id.clone(),
-                priority: self.priority.clone(),
-                subject,
-                source,
-            }),
+            header: self.make_header(source, subject, deadline),
             loopback,
             payload,
         };
-        self.next_transfer_id = self.next_transfer_id.clone().increment();
 
         transmitter.push(transfer, clock, driver)
     }
 }
 
+impl<C: Clock, T: StreamingTransmitter<C>> Publisher<C, T> {
+    /// Publishes a message, serializing it while it is being sent
+    ///
+    /// Unlike [`publish`](Self::publish), this does not need a buffer that can hold the whole
+    /// serialized message. The loopback flag is set to false.
+    ///
+    /// `subject` must be the subject that this publisher is used for.
+    pub fn publish_streaming<M>(
+        &mut self,
+        clock: &mut C,
+        source: Option<<T::Transport as Transport>::NodeId>,
+        subject: SubjectId,
+        payload: &M,
+        transmitter: &mut T,
+        driver: &mut T::Driver,
+    ) -> nb::Result<(), T::Error>
+    where
+        M: Message + Serialize,
+    {
+        let deadline = clock.now() + self.timeout;
+        let header = self.make_header(source, subject, deadline);
+        let payload_length = payload.size_bits().div_ceil(8);
+        transmitter.push_streaming(
+            header,
+            false,
+            payload_length,
+            |sink| {
+                let mut buffer = [0u8; STREAMING_WINDOW];
+                payload.serialize_streaming(&mut buffer, sink)
+            },
+            clock,
+            driver,
+        )
+    }
+}
+
 mod fmt_impl {
     use crate::publisher::Publisher;
     use canadensis_core::time::Clock;
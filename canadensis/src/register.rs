@@ -225,7 +225,12 @@ where
     }
 }
 
-fn register_handle_access(register: &mut dyn Register, request: &AccessRequest) -> AccessResponse {
+/// Writes the requested value to a register if the register is mutable and the value is not
+/// empty, then reads the register and returns a response
+pub(crate) fn register_handle_access(
+    register: &mut dyn Register,
+    request: &AccessRequest,
+) -> AccessResponse {
     let access = register.access();
     if access.mutable
         && !matches!(
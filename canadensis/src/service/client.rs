@@ -0,0 +1,196 @@
+use crate::core::time::{Clock, MicrosecondDuration32, Microseconds32};
+use crate::core::transfer::ServiceTransfer;
+use crate::core::transport::Transport;
+use crate::core::{nb, ServiceId};
+use crate::encoding::{Request, Serialize};
+use crate::{Node, ReceiveError, ServiceToken, StartSendError, TransmitError};
+use core::fmt::{Debug, Formatter};
+
+/// Sends requests for a service and keeps track of the requests that are waiting for responses
+///
+/// Type parameters:
+/// * `N`: The node used to send requests
+/// * `Q`: The request type
+/// * `P`: The maximum number of requests that can be waiting for responses at the same time
+pub struct ServiceClient<N: Node, Q, const P: usize> {
+    /// The token used to send requests
+    token: ServiceToken<Q>,
+    /// The time to wait for a response after sending a request
+    response_timeout: MicrosecondDuration32,
+    /// The requests that are waiting for responses
+    pending: heapless::Vec<PendingCall<N::Transport>, P>,
+}
+
+impl<N, Q, const P: usize> ServiceClient<N, Q, P>
+where
+    N: Node,
+    Q: Request + Serialize,
+{
+    /// Creates a client and sets up the node to send requests for a service
+    ///
+    /// * `service`: The service to send requests for
+    /// * `response_timeout`: The time to wait for a response after sending each request
+    /// * `response_payload_size_max`: The maximum size of a response payload, in bytes
+    /// * `priority`: The priority of the requests
+    pub fn new(
+        node: &mut N,
+        service: ServiceId,
+        response_timeout: MicrosecondDuration32,
+        response_payload_size_max: usize,
+        priority: <N::Transport as Transport>::Priority,
+    ) -> Result<Self, StartSendError<ReceiveError<N>>> {
+        let token = node.start_sending_requests(
+            service,
+            response_timeout,
+            response_payload_size_max,
+            priority,
+        )?;
+        Ok(ServiceClient {
+            token,
+            response_timeout,
+            pending: heapless::Vec::new(),
+        })
+    }
+
+    /// Sends a request to another node
+    ///
+    /// On success, this function returns a token that identifies the request. The same token will
+    /// be returned from [`handle_response`](#method.handle_response) when the response arrives,
+    /// or passed to the callback of [`remove_timed_out`](#method.remove_timed_out) if no
+    /// response arrives in time.
+    pub fn call(
+        &mut self,
+        node: &mut N,
+        request: &Q,
+        destination: <N::Transport as Transport>::NodeId,
+    ) -> nb::Result<CallToken<N::Transport>, CallError<TransmitError<N>>> {
+        if self.pending.is_full() {
+            return Err(nb::Error::Other(CallError::TooManyPending));
+        }
+        let deadline = node.clock_mut().now() + self.response_timeout;
+        let transfer_id = node
+            .send_request(&self.token, request, destination.clone())
+            .map_err(|e| e.map(CallError::Transmitter))?;
+        let call = CallToken {
+            destination,
+            transfer_id,
+        };
+        // There is space because of the above check
+        let _ = self.pending.push(PendingCall {
+            call: call.clone(),
+            deadline,
+        });
+        Ok(call)
+    }
+
+    /// Checks if a response matches a request that this client sent and is still waiting for
+    ///
+    /// This function should be called from
+    /// [`TransferHandler::handle_response`](crate::TransferHandler::handle_response).
+    /// If the response matches, this function stops waiting for it and returns the token that
+    /// [`call`](#method.call) returned when sending the request. Otherwise, it returns `None`.
+    pub fn handle_response<A>(
+        &mut self,
+        transfer: &ServiceTransfer<A, N::Transport>,
+    ) -> Option<CallToken<N::Transport>> {
+        if transfer.header.service != self.token.service_id() {
+            return None;
+        }
+        let index = self.pending.iter().position(|pending| {
+            pending.call.destination == transfer.header.source
+                && pending.call.transfer_id == transfer.header.transfer_id
+        })?;
+        Some(self.pending.swap_remove(index).call)
+    }
+
+    /// Stops waiting for responses to requests whose deadlines are before `now`
+    ///
+    /// `on_timeout` is called with the token of each request that timed out.
+    pub fn remove_timed_out<F>(&mut self, now: Microseconds32, mut on_timeout: F)
+    where
+        F: FnMut(CallToken<N::Transport>),
+    {
+        self.pending.retain(|pending| {
+            if pending.deadline < now {
+                on_timeout(pending.call.clone());
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Returns the number of requests that are waiting for responses
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns the ID of the service that this client sends requests for
+    pub fn service_id(&self) -> ServiceId {
+        self.token.service_id()
+    }
+
+    /// Stops sending requests and waiting for responses
+    pub fn stop(self, node: &mut N) {
+        node.stop_sending_requests(self.token);
+    }
+}
+
+/// A request that is waiting for a response
+struct PendingCall<T: Transport> {
+    call: CallToken<T>,
+    /// The time after which the response is no longer expected
+    deadline: Microseconds32,
+}
+
+/// Identifies a request sent from a [`ServiceClient`]
+pub struct CallToken<T: Transport> {
+    /// The node that the request was sent to
+    destination: T::NodeId,
+    /// The transfer ID of the request
+    transfer_id: T::TransferId,
+}
+
+impl<T: Transport> CallToken<T> {
+    /// Returns the ID of the node that the request was sent to
+    pub fn destination(&self) -> &T::NodeId {
+        &self.destination
+    }
+    /// Returns the transfer ID of the request
+    pub fn transfer_id(&self) -> &T::TransferId {
+        &self.transfer_id
+    }
+}
+
+impl<T: Transport> Clone for CallToken<T> {
+    fn clone(&self) -> Self {
+        CallToken {
+            destination: self.destination.clone(),
+            transfer_id: self.transfer_id.clone(),
+        }
+    }
+}
+
+impl<T: Transport> PartialEq for CallToken<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.destination == other.destination && self.transfer_id == other.transfer_id
+    }
+}
+
+impl<T: Transport> Debug for CallToken<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CallToken")
+            .field("destination", &self.destination)
+            .field("transfer_id", &self.transfer_id)
+            .finish()
+    }
+}
+
+/// Errors that can occur when sending a request
+#[derive(Debug)]
+pub enum CallError<E> {
+    /// The maximum number of requests are already waiting for responses
+    TooManyPending,
+    /// The transmitter returned an error
+    Transmitter(E),
+}
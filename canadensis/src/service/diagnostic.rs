@@ -0,0 +1,95 @@
+use crate::core::time::milliseconds;
+use crate::core::Priority;
+use crate::{nb, Node, PublishError, StartSendError, TransmitError};
+use canadensis_data_types::uavcan::diagnostic::record_1_1::{Record, SUBJECT};
+use canadensis_data_types::uavcan::diagnostic::severity_1_0::Severity;
+use canadensis_data_types::uavcan::time::synchronized_timestamp_1_0::SynchronizedTimestamp;
+use core::marker::PhantomData;
+
+/// The maximum length of the text in a log message, in bytes
+pub const MAX_TEXT_LENGTH: usize = 255;
+
+/// Publishes log messages (`uavcan.diagnostic.Record.1.1`)
+pub struct DiagnosticService<N> {
+    _node: PhantomData<N>,
+}
+
+impl<N> DiagnosticService<N>
+where
+    N: Node,
+{
+    /// Creates a new DiagnosticService
+    ///
+    /// * `node`: The node to use for publishing
+    ///
+    /// Log messages are published with optional priority, so they never delay more important
+    /// transfers.
+    pub fn new(node: &mut N) -> Result<Self, StartSendError<TransmitError<N>>> {
+        node.start_publishing(SUBJECT, milliseconds(1000), Priority::Optional.into())?;
+        Ok(Self { _node: PhantomData })
+    }
+
+    /// Publishes a log message with an unknown timestamp
+    ///
+    /// The node's clock is not synchronized with the rest of the network, so its time is not
+    /// used. To send a timestamp, use [`log_with_timestamp`](#method.log_with_timestamp).
+    ///
+    /// If `text` is longer than [`MAX_TEXT_LENGTH`] bytes, only the first part of it is sent.
+    /// The text is never cut off in the middle of a character.
+    pub fn log(
+        &mut self,
+        node: &mut N,
+        severity: Severity,
+        text: &str,
+    ) -> nb::Result<(), PublishError<TransmitError<N>>> {
+        self.publish(node, SynchronizedTimestamp::UNKNOWN, severity, text)
+    }
+
+    /// Publishes a log message with a synchronized timestamp
+    ///
+    /// `timestamp` is the synchronized network time in microseconds, for example from
+    /// [`TimeSyncSlaveService::now`](crate::service::time_sync::TimeSyncSlaveService::now).
+    /// If it is None, the message has an unknown timestamp.
+    ///
+    /// If `text` is longer than [`MAX_TEXT_LENGTH`] bytes, only the first part of it is sent.
+    /// The text is never cut off in the middle of a character.
+    pub fn log_with_timestamp(
+        &mut self,
+        node: &mut N,
+        timestamp: Option<u64>,
+        severity: Severity,
+        text: &str,
+    ) -> nb::Result<(), PublishError<TransmitError<N>>> {
+        let microsecond = timestamp.unwrap_or(SynchronizedTimestamp::UNKNOWN);
+        self.publish(node, microsecond, severity, text)
+    }
+
+    fn publish(
+        &mut self,
+        node: &mut N,
+        microsecond: u64,
+        severity: Severity,
+        text: &str,
+    ) -> nb::Result<(), PublishError<TransmitError<N>>> {
+        let record = Record {
+            timestamp: SynchronizedTimestamp { microsecond },
+            severity,
+            text: heapless::Vec::from_slice(truncate(text, MAX_TEXT_LENGTH).as_bytes())
+                .expect("Text too long"),
+        };
+        node.publish(SUBJECT, &record)
+    }
+}
+
+/// Returns the longest prefix of `text` that is no longer than `max_length` bytes and does not
+/// end in the middle of a character
+fn truncate(text: &str, max_length: usize) -> &str {
+    if text.len() <= max_length {
+        return text;
+    }
+    let mut end = max_length;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
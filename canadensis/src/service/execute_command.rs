@@ -0,0 +1,131 @@
+use crate::core::time::milliseconds;
+use crate::core::transfer::ServiceTransfer;
+use crate::core::ServiceSubscribeError;
+use crate::encoding::{DataType, Deserialize};
+use crate::{Node, ReceiveError, ResponseToken, TransferHandler};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use canadensis_data_types::uavcan::node::execute_command_1_3::{
+    ExecuteCommandRequest, ExecuteCommandResponse, SERVICE,
+};
+use core::marker::PhantomData;
+
+/// A function that executes a command and returns an `ExecuteCommandResponse` status code
+///
+/// The argument is the command parameter.
+type CommandFunction<'h> = Box<dyn FnMut(&[u8]) -> u8 + 'h>;
+
+/// A service that responds to `uavcan.node.ExecuteCommand` requests by calling a function
+/// registered for each command
+///
+/// This uses version 1.3 of the data type, which is compatible with earlier 1.x versions.
+/// Responses have an empty `output` field.
+/// The standard command values, such as
+/// [`COMMAND_RESTART`](ExecuteCommandRequest::COMMAND_RESTART), are defined on
+/// [`ExecuteCommandRequest`].
+///
+/// Requests for commands that have no registered function get a response with the status
+/// [`STATUS_BAD_COMMAND`](ExecuteCommandResponse::STATUS_BAD_COMMAND).
+pub struct ExecuteCommandService<'h, N> {
+    commands: Vec<(u16, CommandFunction<'h>)>,
+    _node: PhantomData<N>,
+}
+
+impl<'h, N> ExecuteCommandService<'h, N>
+where
+    N: Node,
+{
+    /// Creates a new ExecuteCommand service with no commands
+    ///
+    /// * `node`: The node to use for responding to requests
+    pub fn new(node: &mut N) -> Result<Self, ServiceSubscribeError<ReceiveError<N>>> {
+        node.subscribe_request(
+            SERVICE,
+            ExecuteCommandRequest::EXTENT_BYTES.unwrap() as usize,
+            milliseconds(1000),
+        )?;
+        Ok(ExecuteCommandService {
+            commands: Vec::new(),
+            _node: PhantomData,
+        })
+    }
+
+    /// Registers a function that executes a command
+    ///
+    /// The function receives the command parameter and returns the response status code.
+    /// If a function was already registered for this command, it is replaced.
+    pub fn register<F>(&mut self, command: u16, function: F)
+    where
+        F: FnMut(&[u8]) -> u8 + 'h,
+    {
+        let function: CommandFunction<'h> = Box::new(function);
+        match self
+            .commands
+            .iter_mut()
+            .find(|(value, _)| *value == command)
+        {
+            Some((_, existing)) => *existing = function,
+            None => self.commands.push((command, function)),
+        }
+    }
+
+    /// Removes the function for a command
+    ///
+    /// This function returns true if a function was registered for the command.
+    pub fn unregister(&mut self, command: u16) -> bool {
+        let length_before = self.commands.len();
+        self.commands.retain(|(value, _)| *value != command);
+        self.commands.len() != length_before
+    }
+
+    /// Returns the handler for this service
+    pub fn handler(&mut self) -> ExecuteCommandServiceHandler<'_, 'h, N> {
+        ExecuteCommandServiceHandler { service: self }
+    }
+
+    /// Calls the function for a command and returns the status code
+    fn execute(&mut self, request: &ExecuteCommandRequest) -> u8 {
+        match self
+            .commands
+            .iter_mut()
+            .find(|(value, _)| *value == request.command)
+        {
+            Some((_, function)) => function(&request.parameter),
+            None => ExecuteCommandResponse::STATUS_BAD_COMMAND,
+        }
+    }
+}
+
+/// The [`TransferHandler`] for the [`ExecuteCommandService`]
+pub struct ExecuteCommandServiceHandler<'a, 'h, N> {
+    service: &'a mut ExecuteCommandService<'h, N>,
+}
+
+impl<N> TransferHandler<N::Transport> for ExecuteCommandServiceHandler<'_, '_, N>
+where
+    N: Node,
+{
+    fn handle_request<N2: Node<Transport = N::Transport>>(
+        &mut self,
+        node: &mut N2,
+        token: ResponseToken<N2::Transport>,
+        transfer: &ServiceTransfer<Vec<u8>, N2::Transport>,
+    ) -> bool {
+        if transfer.header.service != SERVICE {
+            return false;
+        }
+        let request = match ExecuteCommandRequest::deserialize_from_bytes(&transfer.payload) {
+            Ok(request) => request,
+            Err(_) => return false,
+        };
+        log::debug!("Received command {}", { request.command });
+        let response = ExecuteCommandResponse {
+            status: self.service.execute(&request),
+            output: heapless::Vec::new(),
+        };
+        if let Err(err) = node.send_response(token, milliseconds(1000), &response) {
+            log::warn!("Failed to send response: {:?}", err);
+        }
+        true
+    }
+}
@@ -0,0 +1,455 @@
+use crate::core::time::{Clock, MicrosecondDuration32};
+use crate::core::transfer::ServiceTransfer;
+use crate::core::transport::Transport;
+use crate::core::{nb, Priority};
+use crate::encoding::{DataType, Deserialize, Request, Serialize};
+use crate::service::client::{CallError, ServiceClient};
+use crate::{Node, ReceiveError, StartSendError, TransferHandler, TransmitError};
+use alloc::vec::Vec;
+use canadensis_data_types::uavcan::file::error_1_0::Error;
+use canadensis_data_types::uavcan::file::path_2_0::Path;
+use canadensis_data_types::uavcan::file::read_1_1::{self, ReadRequest, ReadResponse};
+use canadensis_data_types::uavcan::file::write_1_1::{self, WriteRequest, WriteResponse};
+use canadensis_data_types::uavcan::primitive::unstructured_1_0::Unstructured;
+use core::convert::TryFrom;
+use core::fmt::Debug;
+
+/// The maximum number of bytes that one read response or write request can carry
+const CHUNK_SIZE: usize = 256;
+
+/// Something that receives the data of a file that is being read
+pub trait FileSink {
+    /// The error type that writing can return
+    type Error: Debug;
+
+    /// Stores data from the file, starting at `offset` bytes from the beginning of the file
+    ///
+    /// The client calls this function with increasing offsets, with no gaps between chunks.
+    fn write(&mut self, offset: u64, data: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// A sink that appends the file data to a vector
+impl FileSink for Vec<u8> {
+    type Error = core::convert::Infallible;
+
+    fn write(&mut self, _offset: u64, data: &[u8]) -> Result<(), Self::Error> {
+        self.extend_from_slice(data);
+        Ok(())
+    }
+}
+
+/// Something that provides the data of a file that is being written
+pub trait FileSource {
+    /// The error type that reading can return
+    type Error: Debug;
+
+    /// Reads data starting at `offset` bytes from the beginning of the file into `buffer`
+    ///
+    /// This function returns the number of bytes read. It returns 0 when there is no more data.
+    fn read(&mut self, offset: u64, buffer: &mut [u8]) -> Result<usize, Self::Error>;
+}
+
+/// A source that provides data from a slice
+impl FileSource for &[u8] {
+    type Error = core::convert::Infallible;
+
+    fn read(&mut self, offset: u64, buffer: &mut [u8]) -> Result<usize, Self::Error> {
+        let remaining = usize::try_from(offset)
+            .ok()
+            .and_then(|offset| self.get(offset..))
+            .unwrap_or(&[]);
+        let length = core::cmp::min(remaining.len(), buffer.len());
+        buffer[..length].copy_from_slice(&remaining[..length]);
+        Ok(length)
+    }
+}
+
+/// The status of a file read or write operation
+#[derive(Debug)]
+pub enum FileStatus<E> {
+    /// The operation has not finished
+    InProgress,
+    /// The whole file has been read or written
+    Done,
+    /// The operation failed and will not continue
+    Failed(FileError<E>),
+}
+
+/// Errors that can stop a file read or write operation
+#[derive(Debug)]
+pub enum FileError<E> {
+    /// The server returned a `uavcan.file.Error` code other than `OK`
+    Remote(u16),
+    /// The local sink or source returned an error
+    Local(E),
+    /// The server did not respond after the maximum number of attempts
+    TimedOut,
+}
+
+/// Errors that can occur when creating a file client
+#[derive(Debug)]
+pub enum NewError<E> {
+    /// The file path is longer than 255 bytes
+    PathTooLong,
+    /// The client could not subscribe to responses
+    Start(StartSendError<E>),
+}
+
+impl<E> From<StartSendError<E>> for NewError<E> {
+    fn from(inner: StartSendError<E>) -> Self {
+        NewError::Start(inner)
+    }
+}
+
+/// Progress through a file that is shared by the read and write clients
+struct Progress<N: Node> {
+    /// The node that holds the file
+    server: <N::Transport as Transport>::NodeId,
+    /// The path to the file on the server
+    path: heapless::Vec<u8, 255>,
+    /// The offset of the next chunk to read or write
+    offset: u64,
+    /// The number of times the current request has been sent
+    attempts: u8,
+    /// The number of times to send each request before giving up
+    max_attempts: u8,
+}
+
+impl<N: Node> Progress<N> {
+    /// Creates a progress tracker at the beginning of a file
+    ///
+    /// This function returns an error if `path` is longer than 255 bytes.
+    fn new<E>(
+        server: <N::Transport as Transport>::NodeId,
+        path: &[u8],
+        max_attempts: u8,
+    ) -> Result<Self, NewError<E>> {
+        Ok(Progress {
+            server,
+            path: heapless::Vec::from_slice(path).map_err(|_| NewError::PathTooLong)?,
+            offset: 0,
+            attempts: 0,
+            max_attempts,
+        })
+    }
+
+    /// Removes requests that have timed out and returns true if another attempt is allowed
+    fn check_timeout<Q, E, const P: usize>(
+        &mut self,
+        client: &mut ServiceClient<N, Q, P>,
+        node: &mut N,
+        status: &mut FileStatus<E>,
+    ) -> bool
+    where
+        Q: Request + Serialize,
+    {
+        let now = node.clock_mut().now();
+        let mut timed_out = false;
+        client.remove_timed_out(now, |_| timed_out = true);
+        if timed_out && self.attempts >= self.max_attempts {
+            *status = FileStatus::Failed(FileError::TimedOut);
+            return false;
+        }
+        client.pending_count() == 0
+    }
+}
+
+/// Reads a file from another node using `uavcan.file.Read` requests
+///
+/// The client requests chunks of the file in order and passes them to a [`FileSink`].
+/// The file ends when the server sends a chunk shorter than 256 bytes.
+///
+/// To read a file, call [`poll`](Self::poll) regularly and pass the [`handler`](Self::handler)
+/// to the node when receiving transfers, until [`status`](Self::status) is no longer
+/// [`FileStatus::InProgress`].
+pub struct FileReadClient<N: Node, S: FileSink> {
+    client: ServiceClient<N, ReadRequest, 1>,
+    progress: Progress<N>,
+    sink: S,
+    status: FileStatus<S::Error>,
+}
+
+impl<N, S> FileReadClient<N, S>
+where
+    N: Node,
+    S: FileSink,
+{
+    /// Creates a client that reads a file
+    ///
+    /// * `server`: The node to read the file from
+    /// * `path`: The path to the file on the server
+    /// * `sink`: Where to put the file data
+    /// * `response_timeout`: The time to wait for each response
+    /// * `max_attempts`: The number of times to send each request before giving up
+    ///
+    /// This function returns an error if `path` is longer than 255 bytes.
+    pub fn new(
+        node: &mut N,
+        server: <N::Transport as Transport>::NodeId,
+        path: &[u8],
+        sink: S,
+        response_timeout: MicrosecondDuration32,
+        max_attempts: u8,
+    ) -> Result<Self, NewError<ReceiveError<N>>> {
+        let progress = Progress::new(server, path, max_attempts)?;
+        let client = ServiceClient::new(
+            node,
+            read_1_1::SERVICE,
+            response_timeout,
+            ReadResponse::EXTENT_BYTES.unwrap() as usize,
+            Priority::Nominal.into(),
+        )?;
+        Ok(FileReadClient {
+            client,
+            progress,
+            sink,
+            status: FileStatus::InProgress,
+        })
+    }
+
+    /// Handles timeouts and sends the next request if needed
+    pub fn poll(&mut self, node: &mut N) -> nb::Result<(), CallError<TransmitError<N>>> {
+        if !matches!(self.status, FileStatus::InProgress)
+            || !self
+                .progress
+                .check_timeout(&mut self.client, node, &mut self.status)
+        {
+            return Ok(());
+        }
+        let request = ReadRequest {
+            offset: self.progress.offset,
+            path: Path {
+                path: self.progress.path.clone(),
+            },
+        };
+        self.client
+            .call(node, &request, self.progress.server.clone())?;
+        self.progress.attempts += 1;
+        Ok(())
+    }
+
+    /// Returns the status of the read operation
+    pub fn status(&self) -> &FileStatus<S::Error> {
+        &self.status
+    }
+    /// Returns the number of bytes that have been read
+    pub fn offset(&self) -> u64 {
+        self.progress.offset
+    }
+    /// Returns a reference to the sink
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+    /// Stops sending requests and returns the sink
+    pub fn finish(self, node: &mut N) -> S {
+        self.client.stop(node);
+        self.sink
+    }
+
+    /// Returns a handler that processes responses
+    pub fn handler(&mut self) -> FileReadClientHandler<'_, N, S> {
+        FileReadClientHandler { client: self }
+    }
+
+    fn handle_response(&mut self, response: ReadResponse) {
+        self.progress.attempts = 0;
+        if response.error.value != Error::OK {
+            self.status = FileStatus::Failed(FileError::Remote(response.error.value));
+            return;
+        }
+        let data = &response.data.value;
+        if let Err(e) = self.sink.write(self.progress.offset, data) {
+            self.status = FileStatus::Failed(FileError::Local(e));
+            return;
+        }
+        self.progress.offset += data.len() as u64;
+        if data.len() < CHUNK_SIZE {
+            self.status = FileStatus::Done;
+        }
+    }
+}
+
+/// The [`TransferHandler`] for a [`FileReadClient`]
+pub struct FileReadClientHandler<'a, N: Node, S: FileSink> {
+    client: &'a mut FileReadClient<N, S>,
+}
+
+impl<N, S> TransferHandler<N::Transport> for FileReadClientHandler<'_, N, S>
+where
+    N: Node,
+    S: FileSink,
+{
+    fn handle_response<N2: Node<Transport = N::Transport>>(
+        &mut self,
+        _node: &mut N2,
+        transfer: &ServiceTransfer<Vec<u8>, N2::Transport>,
+    ) -> bool {
+        if self.client.client.handle_response(transfer).is_none() {
+            return false;
+        }
+        match ReadResponse::deserialize_from_bytes(&transfer.payload) {
+            Ok(response) => self.client.handle_response(response),
+            Err(_) => log::warn!("Invalid file read response"),
+        }
+        true
+    }
+}
+
+/// Writes a file to another node using `uavcan.file.Write` requests
+///
+/// The client sends chunks of data from a [`FileSource`] in order. After the last chunk, it sends
+/// an empty chunk, which makes the server truncate the file at the end of the data.
+///
+/// To write a file, call [`poll`](Self::poll) regularly and pass the [`handler`](Self::handler)
+/// to the node when receiving transfers, until [`status`](Self::status) is no longer
+/// [`FileStatus::InProgress`].
+pub struct FileWriteClient<N: Node, S: FileSource> {
+    client: ServiceClient<N, WriteRequest, 1>,
+    progress: Progress<N>,
+    source: S,
+    /// The data in the current request
+    chunk: heapless::Vec<u8, CHUNK_SIZE>,
+    /// True if the chunk has been read from the source
+    chunk_ready: bool,
+    status: FileStatus<S::Error>,
+}
+
+impl<N, S> FileWriteClient<N, S>
+where
+    N: Node,
+    S: FileSource,
+{
+    /// Creates a client that writes a file
+    ///
+    /// * `server`: The node to write the file to
+    /// * `path`: The path to the file on the server
+    /// * `source`: Where to get the file data
+    /// * `response_timeout`: The time to wait for each response
+    /// * `max_attempts`: The number of times to send each request before giving up
+    ///
+    /// This function returns an error if `path` is longer than 255 bytes.
+    pub fn new(
+        node: &mut N,
+        server: <N::Transport as Transport>::NodeId,
+        path: &[u8],
+        source: S,
+        response_timeout: MicrosecondDuration32,
+        max_attempts: u8,
+    ) -> Result<Self, NewError<ReceiveError<N>>> {
+        let progress = Progress::new(server, path, max_attempts)?;
+        let client = ServiceClient::new(
+            node,
+            write_1_1::SERVICE,
+            response_timeout,
+            WriteResponse::EXTENT_BYTES.unwrap() as usize,
+            Priority::Nominal.into(),
+        )?;
+        Ok(FileWriteClient {
+            client,
+            progress,
+            source,
+            chunk: heapless::Vec::new(),
+            chunk_ready: false,
+            status: FileStatus::InProgress,
+        })
+    }
+
+    /// Handles timeouts and sends the next request if needed
+    pub fn poll(&mut self, node: &mut N) -> nb::Result<(), CallError<TransmitError<N>>> {
+        if !matches!(self.status, FileStatus::InProgress)
+            || !self
+                .progress
+                .check_timeout(&mut self.client, node, &mut self.status)
+        {
+            return Ok(());
+        }
+        if !self.chunk_ready {
+            let mut buffer = [0u8; CHUNK_SIZE];
+            match self.source.read(self.progress.offset, &mut buffer) {
+                Ok(length) => {
+                    self.chunk.clear();
+                    // The length is at most the capacity
+                    let _ = self.chunk.extend_from_slice(&buffer[..length]);
+                    self.chunk_ready = true;
+                }
+                Err(e) => {
+                    self.status = FileStatus::Failed(FileError::Local(e));
+                    return Ok(());
+                }
+            }
+        }
+        let request = WriteRequest {
+            offset: self.progress.offset,
+            path: Path {
+                path: self.progress.path.clone(),
+            },
+            data: Unstructured {
+                value: self.chunk.clone(),
+            },
+        };
+        self.client
+            .call(node, &request, self.progress.server.clone())?;
+        self.progress.attempts += 1;
+        Ok(())
+    }
+
+    /// Returns the status of the write operation
+    pub fn status(&self) -> &FileStatus<S::Error> {
+        &self.status
+    }
+    /// Returns the number of bytes that have been written
+    pub fn offset(&self) -> u64 {
+        self.progress.offset
+    }
+    /// Stops sending requests and returns the source
+    pub fn finish(self, node: &mut N) -> S {
+        self.client.stop(node);
+        self.source
+    }
+
+    /// Returns a handler that processes responses
+    pub fn handler(&mut self) -> FileWriteClientHandler<'_, N, S> {
+        FileWriteClientHandler { client: self }
+    }
+
+    fn handle_response(&mut self, response: WriteResponse) {
+        self.progress.attempts = 0;
+        if response.error.value != Error::OK {
+            self.status = FileStatus::Failed(FileError::Remote(response.error.value));
+            return;
+        }
+        if self.chunk.is_empty() {
+            // The empty chunk at the end was written
+            self.status = FileStatus::Done;
+        } else {
+            self.progress.offset += self.chunk.len() as u64;
+            self.chunk_ready = false;
+        }
+    }
+}
+
+/// The [`TransferHandler`] for a [`FileWriteClient`]
+pub struct FileWriteClientHandler<'a, N: Node, S: FileSource> {
+    client: &'a mut FileWriteClient<N, S>,
+}
+
+impl<N, S> TransferHandler<N::Transport> for FileWriteClientHandler<'_, N, S>
+where
+    N: Node,
+    S: FileSource,
+{
+    fn handle_response<N2: Node<Transport = N::Transport>>(
+        &mut self,
+        _node: &mut N2,
+        transfer: &ServiceTransfer<Vec<u8>, N2::Transport>,
+    ) -> bool {
+        if self.client.client.handle_response(transfer).is_none() {
+            return false;
+        }
+        match WriteResponse::deserialize_from_bytes(&transfer.payload) {
+            Ok(response) => self.client.handle_response(response),
+            Err(_) => log::warn!("Invalid file write response"),
+        }
+        true
+    }
+}
@@ -1,6 +1,18 @@
 //!
 //! Cyphal services intended for use with Nodes
 
+/// Service client that matches responses to requests
+pub mod client;
+
+/// Publishes log messages
+pub mod diagnostic;
+
+/// Handles ExecuteCommand requests
+pub mod execute_command;
+
+/// File read and write clients
+pub mod file_client;
+
 /// Handles GetInfo requests
 pub mod get_info;
 
@@ -10,8 +22,14 @@ pub mod heartbeat;
 /// Cyphal plug-and-play client
 pub mod pnp_client;
 
+/// Cyphal plug-and-play node ID allocator
+pub mod pnp_server;
+
 /// Port list service
 pub mod port_list;
 
 /// Register server
 pub mod register_server;
+
+/// Time synchronization master and slave
+pub mod time_sync;
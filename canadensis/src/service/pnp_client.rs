@@ -167,7 +167,7 @@ impl<T: Transport> AllocationMessage<T> for node_id_allocation_data_1_0::NodeIDA
 
 /// Calculates a CRC-64WE hash of the provided ID and returns the less significant 48 bits of the
 /// result
-fn crc_64we_48_bits(id: &[u8; 16]) -> u64 {
+pub(crate) fn crc_64we_48_bits(id: &[u8; 16]) -> u64 {
     let mut crc = CRCu64::crc64we();
     crc.digest(id);
     let value = crc.get_crc();
@@ -0,0 +1,348 @@
+use crate::core::storage::Storage;
+use crate::core::time::milliseconds;
+use crate::core::transfer::MessageTransfer;
+use crate::core::transport::Transport;
+use crate::core::Priority;
+use crate::encoding::{Deserialize, Serialize};
+use crate::service::pnp_client::crc_64we_48_bits;
+use crate::{Node, ReceiveError, StartSendError, TransferHandler, TransmitError};
+use alloc::vec::Vec;
+use canadensis_data_types::uavcan::node::id_1_0::ID;
+use canadensis_data_types::uavcan::pnp::{
+    node_id_allocation_data_1_0, node_id_allocation_data_2_0,
+};
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A node ID that has been allocated to a node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Allocation {
+    /// The less significant 48 bits of the CRC-64WE hash of the node's unique ID
+    pub unique_id_hash: u64,
+    /// The node's full unique ID, if it is known
+    ///
+    /// This is None for allocations made in response to version 1.0 requests, which contain
+    /// only the hash of the unique ID.
+    pub unique_id: Option<[u8; 16]>,
+    /// The node ID allocated to the node
+    pub node_id: u16,
+}
+
+/// The storage key of the allocation table
+///
+/// The value is a sequence of allocations, each [`ALLOCATION_LENGTH`] bytes long:
+///
+/// * The allocated node ID (2 bytes, little-endian)
+/// * The unique ID hash (6 bytes, little-endian)
+/// * 1 if the unique ID is known, otherwise 0 (1 byte)
+/// * The unique ID if it is known, otherwise zeros (16 bytes)
+pub const ALLOCATIONS_KEY: &str = "canadensis.pnp_server.allocations";
+
+/// The length in bytes of one allocation in the stored allocation table
+pub const ALLOCATION_LENGTH: usize = 25;
+
+impl Allocation {
+    /// Appends the stored representation of this allocation to `bytes`
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.node_id.to_le_bytes());
+        bytes.extend_from_slice(&self.unique_id_hash.to_le_bytes()[..6]);
+        match self.unique_id {
+            Some(unique_id) => {
+                bytes.push(1);
+                bytes.extend_from_slice(&unique_id);
+            }
+            None => bytes.extend_from_slice(&[0; 17]),
+        }
+    }
+
+    /// Decodes an allocation from exactly [`ALLOCATION_LENGTH`] bytes
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        let mut hash_bytes = [0u8; 8];
+        hash_bytes[..6].copy_from_slice(&bytes[2..8]);
+        let unique_id = match bytes[8] {
+            0 => None,
+            1 => Some(<[u8; 16]>::try_from(&bytes[9..25]).ok()?),
+            _ => return None,
+        };
+        Some(Allocation {
+            unique_id_hash: u64::from_le_bytes(hash_bytes),
+            unique_id,
+            node_id: u16::from_le_bytes([bytes[0], bytes[1]]),
+        })
+    }
+}
+
+/// A plug-and-play node ID allocator
+///
+/// This server responds to anonymous `uavcan.pnp.NodeIDAllocationData` requests of versions
+/// 1.0 and 2.0. A node that requests an ID again gets the same ID that it got before. Other nodes
+/// get the highest node ID that is not allocated, reserved, or used by this node.
+///
+/// The server keeps its allocation table in a [`Storage`] with the key [`ALLOCATIONS_KEY`].
+///
+/// The node must have a node ID.
+pub struct PnpServerService<N, S> {
+    /// Allocation storage
+    storage: S,
+    /// All allocations, including those loaded from storage
+    allocations: Vec<Allocation>,
+    /// Node IDs that will never be allocated
+    reserved: Vec<u16>,
+    /// The largest node ID that can be allocated
+    max_node_id: u16,
+    _node: PhantomData<N>,
+}
+
+impl<N, S> PnpServerService<N, S>
+where
+    N: Node,
+    S: Storage,
+{
+    /// Creates a plug-and-play server, loads the existing allocations from storage, and subscribes
+    /// to allocation requests
+    ///
+    /// * `max_node_id`: The largest node ID to allocate. On Cyphal/CAN, this should be 125 because
+    ///   node IDs 126 and 127 are reserved for diagnostic and debugging tools.
+    pub fn new(node: &mut N, storage: S, max_node_id: u16) -> Result<Self, NewError<N>> {
+        let allocations = match storage.load(ALLOCATIONS_KEY) {
+            Some(bytes) => {
+                if bytes.len() % ALLOCATION_LENGTH != 0 {
+                    return Err(NewError::InvalidStorage);
+                }
+                bytes
+                    .chunks_exact(ALLOCATION_LENGTH)
+                    .map(Allocation::decode)
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or(NewError::InvalidStorage)?
+            }
+            None => Vec::new(),
+        };
+
+        for (subject, payload_size_max) in [
+            (
+                node_id_allocation_data_1_0::SUBJECT,
+                payload_size_max::<node_id_allocation_data_1_0::NodeIDAllocationData>(),
+            ),
+            (
+                node_id_allocation_data_2_0::SUBJECT,
+                payload_size_max::<node_id_allocation_data_2_0::NodeIDAllocationData>(),
+            ),
+        ] {
+            node.subscribe_message(subject, payload_size_max, milliseconds(1000))
+                .map_err(NewError::Subscribe)?;
+            node.start_publishing(subject, milliseconds(1000), Priority::Nominal.into())
+                .map_err(|err| match err {
+                    StartSendError::Memory(_) => NewError::OutOfMemory,
+                    StartSendError::Duplicate => NewError::Duplicate,
+                    StartSendError::Transport(err) => NewError::Publish(err),
+                    StartSendError::AnonymousRequest => unreachable!(), // we are publishing a message, not a request
+                })?;
+        }
+
+        Ok(PnpServerService {
+            storage,
+            allocations,
+            reserved: Vec::new(),
+            max_node_id,
+            _node: PhantomData,
+        })
+    }
+
+    /// Prevents this server from allocating a node ID
+    ///
+    /// This does not affect a node that has already been allocated the node ID.
+    pub fn reserve(&mut self, node_id: u16) {
+        if !self.reserved.contains(&node_id) {
+            self.reserved.push(node_id);
+        }
+    }
+
+    /// Returns all allocations that this server knows about
+    pub fn allocations(&self) -> &[Allocation] {
+        &self.allocations
+    }
+
+    /// Returns a reference to the allocation storage
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Returns a handler for the server
+    pub fn handler(&mut self) -> PnpServerServiceHandler<'_, N, S> {
+        PnpServerServiceHandler { server: self }
+    }
+
+    /// Finds or creates an allocation for a node and returns its node ID
+    ///
+    /// `own_node_id` is the ID of the node that this server runs on.
+    ///
+    /// This function returns None if no node ID is available or the allocation could not be saved.
+    fn allocate<T: Transport>(
+        &mut self,
+        own_node_id: Option<u16>,
+        unique_id_hash: u64,
+        unique_id: Option<[u8; 16]>,
+    ) -> Option<u16> {
+        let existing =
+            self.allocations
+                .iter()
+                .find(|allocation| match (unique_id, allocation.unique_id) {
+                    (Some(unique_id), Some(allocated_unique_id)) => {
+                        unique_id == allocated_unique_id
+                    }
+                    // A version 1.0 request or allocation has only the hash
+                    _ => allocation.unique_id_hash == unique_id_hash,
+                });
+        if let Some(existing) = existing {
+            return Some(existing.node_id);
+        }
+
+        let node_id = (0..=self.max_node_id).rev().find(|&id| {
+            Some(id) != own_node_id
+                && !self.reserved.contains(&id)
+                && !self.allocations.iter().any(|other| other.node_id == id)
+                && T::NodeId::try_from(id).is_ok()
+        });
+        let node_id = match node_id {
+            Some(node_id) => node_id,
+            None => {
+                log::warn!("No node IDs available for allocation");
+                return None;
+            }
+        };
+        let allocation = Allocation {
+            unique_id_hash,
+            unique_id,
+            node_id,
+        };
+        let mut table = Vec::new();
+        if table
+            .try_reserve_exact((self.allocations.len() + 1) * ALLOCATION_LENGTH)
+            .is_err()
+            || self.allocations.try_reserve(1).is_err()
+        {
+            log::warn!("Not enough memory to save node ID allocation");
+            return None;
+        }
+        for existing in self.allocations.iter().chain(Some(&allocation)) {
+            existing.encode(&mut table);
+        }
+        if let Err(e) = self.storage.store(ALLOCATIONS_KEY, &table) {
+            log::warn!("Failed to save node ID allocation: {:?}", e);
+            return None;
+        }
+        self.allocations.push(allocation);
+        Some(node_id)
+    }
+}
+
+/// Returns the maximum payload size to accept for a message of type `M`
+///
+/// This is the extent of `M`, or its maximum serialized size if it is sealed.
+fn payload_size_max<M: Serialize>() -> usize {
+    M::EXTENT_BYTES
+        .map(|extent| extent as usize)
+        .unwrap_or(M::MAX_SIZE_BYTES)
+}
+
+/// Error type returned by [`PnpServerService::new`]
+pub enum NewError<N: Node> {
+    /// The allocation table in storage is not valid
+    InvalidStorage,
+    /// The server could not subscribe to the message subjects due to a receiver error
+    Subscribe(ReceiveError<N>),
+    /// The server could not allocate a publish token due to an out of memory error
+    OutOfMemory,
+    /// The server could not allocate a publish token as the subject is already in use
+    Duplicate,
+    /// The server could not allocate a publish token due to a transmitter error
+    Publish(TransmitError<N>),
+}
+
+impl<N: Node> Debug for NewError<N>
+where
+    ReceiveError<N>: Debug,
+    TransmitError<N>: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NewError::InvalidStorage => f.write_str("InvalidStorage"),
+            NewError::Subscribe(e) => f.debug_tuple("Subscribe").field(e).finish(),
+            NewError::OutOfMemory => f.write_str("OutOfMemory"),
+            NewError::Duplicate => f.write_str("Duplicate"),
+            NewError::Publish(e) => f.debug_tuple("Publish").field(e).finish(),
+        }
+    }
+}
+
+/// Handler for the server
+pub struct PnpServerServiceHandler<'a, N, S> {
+    server: &'a mut PnpServerService<N, S>,
+}
+
+impl<N, S> TransferHandler<N::Transport> for PnpServerServiceHandler<'_, N, S>
+where
+    N: Node,
+    S: Storage,
+{
+    fn handle_message<N2: Node<Transport = N::Transport>>(
+        &mut self,
+        node: &mut N2,
+        transfer: &MessageTransfer<Vec<u8>, N2::Transport>,
+    ) -> bool {
+        if transfer.header.source.is_some() {
+            // Allocation requests are anonymous. This is a response from another allocator.
+            return false;
+        }
+        let own_node_id = node
+            .node_id()
+            .and_then(|id| u16::try_from(Into::<usize>::into(id)).ok());
+
+        if transfer.header.subject == node_id_allocation_data_1_0::SUBJECT {
+            let request =
+                match node_id_allocation_data_1_0::NodeIDAllocationData::deserialize_from_bytes(
+                    &transfer.payload,
+                ) {
+                    Ok(request) => request,
+                    Err(_) => return false,
+                };
+            if let Some(node_id) =
+                self.server
+                    .allocate::<N2::Transport>(own_node_id, request.unique_id_hash, None)
+            {
+                let mut allocated_node_id = heapless::Vec::new();
+                // The Vec has space for one element
+                let _ = allocated_node_id.push(ID { value: node_id });
+                let response = node_id_allocation_data_1_0::NodeIDAllocationData {
+                    unique_id_hash: request.unique_id_hash,
+                    allocated_node_id,
+                };
+                let _ = node.publish(node_id_allocation_data_1_0::SUBJECT, &response);
+            }
+            true
+        } else if transfer.header.subject == node_id_allocation_data_2_0::SUBJECT {
+            let request =
+                match node_id_allocation_data_2_0::NodeIDAllocationData::deserialize_from_bytes(
+                    &transfer.payload,
+                ) {
+                    Ok(request) => request,
+                    Err(_) => return false,
+                };
+            if let Some(node_id) = self.server.allocate::<N2::Transport>(
+                own_node_id,
+                crc_64we_48_bits(&request.unique_id),
+                Some(request.unique_id),
+            ) {
+                let response = node_id_allocation_data_2_0::NodeIDAllocationData {
+                    node_id: ID { value: node_id },
+                    unique_id: request.unique_id,
+                };
+                let _ = node.publish(node_id_allocation_data_2_0::SUBJECT, &response);
+            }
+            true
+        } else {
+            false
+        }
+    }
+}
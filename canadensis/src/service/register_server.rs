@@ -1,4 +1,4 @@
-use crate::register::RegisterBlock;
+use crate::register::{register_handle_access, RegisterBlock};
 use crate::{Node, ResponseToken, TransferHandler};
 use alloc::vec::Vec;
 use canadensis_core::transfer::ServiceTransfer;
@@ -120,16 +120,8 @@ where
                     if let Ok(name) = str::from_utf8(&request.name.name) {
                         debug!("Received access request for {}", name);
                         if let Some(register) = self.server.registers.register_by_name_mut(name) {
-                            let access = register.access();
-                            if access.mutable {
-                                let _ = register.write(&request.value);
-                            }
-                            response = AccessResponse {
-                                timestamp: SynchronizedTimestamp { microsecond: 0 },
-                                mutable: access.mutable,
-                                persistent: access.persistent,
-                                value: register.read(),
-                            }
+                            // An empty value only reads the register
+                            response = register_handle_access(register, &request);
                         }
                     };
                     if let Err(err) = node.send_response(token, milliseconds(1000), &response) {
@@ -0,0 +1,268 @@
+use crate::core::time::{milliseconds, Clock, MicrosecondDuration32, Microseconds32};
+use crate::core::transfer::MessageTransfer;
+use crate::core::transport::{TransferId, Transport};
+use crate::core::Priority;
+use crate::encoding::{Deserialize, Serialize};
+use crate::{nb, Node, PublishError, ReceiveError, StartSendError, TransferHandler, TransmitError};
+use alloc::vec::Vec;
+use canadensis_data_types::uavcan::time::synchronization_1_0::{Synchronization, SUBJECT};
+use core::marker::PhantomData;
+
+/// The maximum time between two synchronization messages from a master
+const MAX_PUBLICATION_PERIOD: MicrosecondDuration32 =
+    milliseconds(Synchronization::MAX_PUBLICATION_PERIOD as u32 * 1000);
+
+/// Publishes `uavcan.time.Synchronization` messages as a time synchronization master
+///
+/// Each message contains the time when the previous message was sent. The time base is chosen
+/// by the application, and all masters on a network must use the same time base.
+pub struct TimeSyncMasterService<N> {
+    /// The time when the previous message was sent, in microseconds, or 0 if unknown
+    previous_transmission: u64,
+    _node: PhantomData<N>,
+}
+
+impl<N> TimeSyncMasterService<N>
+where
+    N: Node,
+{
+    /// Creates a time synchronization master
+    ///
+    /// * `node`: The node to use for publishing
+    pub fn new(node: &mut N) -> Result<Self, StartSendError<TransmitError<N>>> {
+        node.start_publishing(SUBJECT, milliseconds(1000), Priority::Fast.into())?;
+        Ok(TimeSyncMasterService {
+            previous_transmission: 0,
+            _node: PhantomData,
+        })
+    }
+
+    /// Publishes a synchronization message
+    ///
+    /// `now` is the current time in the synchronized time base, in microseconds. It is recorded
+    /// as the transmission time of this message and sent in the next message. If the transport
+    /// can report a more accurate transmission time, call
+    /// [`set_transmission_time`](Self::set_transmission_time) after this function.
+    ///
+    /// Call this at least once per second.
+    pub fn publish(
+        &mut self,
+        node: &mut N,
+        now: u64,
+    ) -> nb::Result<(), PublishError<TransmitError<N>>> {
+        // The previous time must be zero if it was more than one second ago
+        let previous = if now.saturating_sub(self.previous_transmission)
+            > u64::from(MAX_PUBLICATION_PERIOD.to_micros())
+        {
+            0
+        } else {
+            self.previous_transmission
+        };
+        let message = Synchronization {
+            previous_transmission_timestamp_microsecond: previous,
+        };
+        node.publish(SUBJECT, &message)?;
+        self.previous_transmission = now;
+        Ok(())
+    }
+
+    /// Sets the time when the most recent message was transmitted, in microseconds
+    ///
+    /// This time will be sent in the next message.
+    pub fn set_transmission_time(&mut self, time: u64) {
+        self.previous_transmission = time;
+    }
+}
+
+/// Receives `uavcan.time.Synchronization` messages and estimates the offset between the local
+/// clock and the time base of the master
+///
+/// This follows the slave algorithm in the `uavcan.time.Synchronization` documentation.
+/// When more than one master is active, the slave uses the master with the lowest node ID.
+///
+/// The local clock is never adjusted. Instead, the slave records the local time when each message
+/// was received and calculates the master time from the local time.
+pub struct TimeSyncSlaveService<N: Node> {
+    /// The node ID of the current master
+    master: Option<<N::Transport as Transport>::NodeId>,
+    /// The transfer ID of the previous message from the master
+    previous_transfer_id: Option<<N::Transport as Transport>::TransferId>,
+    /// The local time when the previous message from the master was received
+    previous_receive_time: Option<Microseconds32>,
+    /// The time between the two most recent messages from the master
+    interval: Option<MicrosecondDuration32>,
+    /// A local time and the corresponding master time in microseconds
+    reference: Option<(Microseconds32, u64)>,
+}
+
+impl<N> TimeSyncSlaveService<N>
+where
+    N: Node,
+{
+    /// Creates a time synchronization slave and subscribes to synchronization messages
+    ///
+    /// * `node`: The node to use for receiving messages
+    pub fn new(node: &mut N) -> Result<Self, ReceiveError<N>> {
+        node.subscribe_message(
+            SUBJECT,
+            <Synchronization as Serialize>::MAX_SIZE_BYTES,
+            milliseconds(1000),
+        )?;
+        Ok(TimeSyncSlaveService {
+            master: None,
+            previous_transfer_id: None,
+            previous_receive_time: None,
+            interval: None,
+            reference: None,
+        })
+    }
+
+    /// Returns the node ID of the master that this slave is synchronized with, if any
+    pub fn master(&self) -> Option<&<N::Transport as Transport>::NodeId> {
+        self.master.as_ref()
+    }
+
+    /// Returns the estimated master time minus the local time, in microseconds
+    ///
+    /// The local time is the number of ticks of the local [`Microseconds32`] clock when the
+    /// most recent valid message was received. Because the local clock wraps around, this offset
+    /// is only meaningful near that time. [`master_time`](Self::master_time) handles this
+    /// correctly.
+    ///
+    /// This function returns None if the slave has not received enough messages to estimate
+    /// the offset.
+    pub fn offset(&self) -> Option<i64> {
+        self.reference
+            .map(|(local, master)| master as i64 - i64::from(local.ticks()))
+    }
+
+    /// Returns true if this slave has an estimate of the master time and the master has not
+    /// timed out
+    ///
+    /// `now` is the current local time.
+    pub fn is_synchronized(&self, now: Microseconds32) -> bool {
+        match (self.reference, self.previous_receive_time) {
+            (Some(_), Some(previous)) => match now.checked_duration_since(previous) {
+                Some(since_previous) => since_previous <= self.publisher_timeout(),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Converts a local time into the time base of the master, in microseconds
+    ///
+    /// This function returns None if the slave has not received enough messages to estimate
+    /// the offset, or if `local` is before the local time of the latest estimate.
+    pub fn master_time(&self, local: Microseconds32) -> Option<u64> {
+        let (reference_local, reference_master) = self.reference?;
+        let since_reference = local.checked_duration_since(reference_local)?;
+        Some(reference_master + u64::from(since_reference.ticks()))
+    }
+
+    /// Reads the current local time from a clock and returns the corresponding master time,
+    /// in microseconds
+    pub fn now<C: Clock>(&self, clock: &mut C) -> Option<u64> {
+        self.master_time(clock.now())
+    }
+
+    /// Returns the handler for this service
+    pub fn handler(&mut self) -> TimeSyncSlaveServiceHandler<'_, N> {
+        TimeSyncSlaveServiceHandler { slave: self }
+    }
+
+    /// Returns the time after the latest message after which the master is considered
+    /// to have timed out
+    fn publisher_timeout(&self) -> MicrosecondDuration32 {
+        self.interval.unwrap_or(MAX_PUBLICATION_PERIOD)
+            * u32::from(Synchronization::PUBLISHER_TIMEOUT_PERIOD_MULTIPLIER)
+    }
+
+    fn handle_message(
+        &mut self,
+        source: <N::Transport as Transport>::NodeId,
+        transfer_id: <N::Transport as Transport>::TransferId,
+        receive_time: Microseconds32,
+        message: &Synchronization,
+    ) {
+        let since_previous = self
+            .previous_receive_time
+            .and_then(|previous| receive_time.checked_duration_since(previous));
+
+        let switch_master = match &self.master {
+            None => true,
+            Some(master) => {
+                let timed_out = match since_previous {
+                    Some(since_previous) => since_previous > self.publisher_timeout(),
+                    None => true,
+                };
+                Into::<usize>::into(source.clone()) < Into::<usize>::into(master.clone())
+                    || timed_out
+            }
+        };
+
+        if switch_master {
+            self.master = Some(source);
+            self.interval = None;
+            self.reference = None;
+        } else if self.master.as_ref() == Some(&source) {
+            let expected_transfer_id = self.previous_transfer_id.clone().map(TransferId::increment);
+            let valid = message.previous_transmission_timestamp_microsecond != 0
+                && expected_transfer_id.as_ref() == Some(&transfer_id)
+                && matches!(since_previous, Some(since_previous) if since_previous <= MAX_PUBLICATION_PERIOD);
+            if valid {
+                // The previous message was sent at the provided master time and received at
+                // the previous local time
+                if let Some(previous_receive_time) = self.previous_receive_time {
+                    self.reference = Some((
+                        previous_receive_time,
+                        message.previous_transmission_timestamp_microsecond,
+                    ));
+                }
+            }
+            self.interval = since_previous;
+        } else {
+            // Message from a master with a higher node ID
+            return;
+        }
+        self.previous_transfer_id = Some(transfer_id);
+        self.previous_receive_time = Some(receive_time);
+    }
+}
+
+/// The [`TransferHandler`] for the [`TimeSyncSlaveService`]
+pub struct TimeSyncSlaveServiceHandler<'a, N: Node> {
+    slave: &'a mut TimeSyncSlaveService<N>,
+}
+
+impl<N> TransferHandler<N::Transport> for TimeSyncSlaveServiceHandler<'_, N>
+where
+    N: Node,
+{
+    fn handle_message<N2: Node<Transport = N::Transport>>(
+        &mut self,
+        _node: &mut N2,
+        transfer: &MessageTransfer<Vec<u8>, N2::Transport>,
+    ) -> bool {
+        if transfer.header.subject != SUBJECT {
+            return false;
+        }
+        let source = match &transfer.header.source {
+            Some(source) => source.clone(),
+            // Masters must have node IDs
+            None => return false,
+        };
+        match Synchronization::deserialize_from_bytes(&transfer.payload) {
+            Ok(message) => {
+                self.slave.handle_message(
+                    source,
+                    transfer.header.transfer_id.clone(),
+                    transfer.header.timestamp,
+                    &message,
+                );
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
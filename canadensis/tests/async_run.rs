@@ -0,0 +1,200 @@
+//! Tests the async run loop of a basic node
+#![cfg(feature = "async")]
+
+extern crate canadensis;
+extern crate canadensis_can;
+extern crate canadensis_data_types;
+
+mod common;
+
+use canadensis::async_run::Events;
+use canadensis::node::data_types::{GetInfoResponse, Version};
+use canadensis::node::{BasicNode, CoreNode};
+use canadensis::Node;
+use canadensis_can::{CanId, CanNodeId, CanReceiver, CanTransmitter, CanTransport, Frame, Mtu};
+use canadensis_core::time::{milliseconds, Microseconds32};
+use canadensis_core::transfer::MessageTransfer;
+use canadensis_core::SubjectId;
+use common::{NoHandler, RecordingDriver, TestClock, TestNode};
+use std::cell::Cell;
+use std::convert::TryFrom;
+use std::future::Future;
+use std::pin::pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+#[test]
+fn run_once_waits_for_period() {
+    let time = Rc::new(Cell::new(0));
+    let node_id = CanNodeId::try_from(3_u8).unwrap();
+    let core_node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock(Rc::clone(&time)),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    );
+    let node_info = GetInfoResponse {
+        protocol_version: Version { major: 1, minor: 0 },
+        ..Default::default()
+    };
+    let mut node = BasicNode::new(core_node, node_info).unwrap();
+    let mut events = TimerEvents {
+        time: Rc::clone(&time),
+        deadlines: Vec::new(),
+    };
+
+    // Each step waits 400 ms, so 10 steps take 4 seconds
+    for _ in 0..10 {
+        block_on(node.run_once(&mut events, &mut NoHandler, milliseconds(400))).unwrap();
+    }
+    let expected_deadlines: Vec<u32> = (1..=10).map(|i| i * 400_000).collect();
+    assert_eq!(events.deadlines, expected_deadlines);
+
+    // Count the transfers on each subject (the start of transfer bit is in the tail byte)
+    let transfers_on = |subject: u32| {
+        node.node()
+            .driver()
+            .frames
+            .iter()
+            .filter(|frame| (u32::from(frame.id()) >> 8) & 0x1fff == subject)
+            .filter(|frame| frame.data().last().unwrap() & 0x80 != 0)
+            .count()
+    };
+    // Heartbeats at 0, 1.2, 2.4, and 3.6 seconds
+    assert_eq!(transfers_on(7509), 4);
+    // Port list at 0 seconds
+    assert_eq!(transfers_on(7510), 1);
+}
+
+#[test]
+fn run_once_receives_all_transfers() {
+    let time = Rc::new(Cell::new(0));
+    let node_id = CanNodeId::try_from(3_u8).unwrap();
+    let core_node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock(Rc::clone(&time)),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    );
+    let node_info = GetInfoResponse {
+        protocol_version: Version { major: 1, minor: 0 },
+        ..Default::default()
+    };
+    let mut node = BasicNode::new(core_node, node_info).unwrap();
+    let subject = SubjectId::try_from(100_u16).unwrap();
+    node.subscribe_message(subject, 8, milliseconds(1000))
+        .unwrap();
+    let mut events = TimerEvents {
+        time: Rc::clone(&time),
+        deadlines: Vec::new(),
+    };
+
+    // A GetInfo request that the basic node handles, and two messages for the handler
+    let get_info_request = (4 << 26) | (3 << 24) | (430 << 14) | (3 << 7) | 10;
+    let message_from = |source: u32| (4 << 26) | (3 << 21) | (100 << 8) | source;
+    let incoming = &mut node.node_mut().driver_mut().incoming;
+    for id in [get_info_request, message_from(10), message_from(11)] {
+        incoming.push_back(Frame::new(
+            Microseconds32::from_ticks(0),
+            CanId::try_from(id).unwrap(),
+            &[0xe0],
+        ));
+    }
+
+    let mut handler = MessageCounter(0);
+    block_on(node.run_once(&mut events, &mut handler, milliseconds(400))).unwrap();
+    assert_eq!(handler.0, 2);
+    assert!(node.node().driver().incoming.is_empty());
+    // The node waited once, after receiving everything
+    assert_eq!(events.deadlines, [400_000]);
+    // Response to the GetInfo request
+    assert!(node
+        .node()
+        .driver()
+        .frames
+        .iter()
+        .any(|frame| (u32::from(frame.id()) >> 14) & 0x1ff == 430));
+}
+
+#[test]
+fn run_once_continues_when_queue_full() {
+    let time = Rc::new(Cell::new(0));
+    let node_id = CanNodeId::try_from(3_u8).unwrap();
+    let core_node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock(Rc::clone(&time)),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver {
+            full: true,
+            ..RecordingDriver::default()
+        },
+    );
+    let node_info = GetInfoResponse {
+        protocol_version: Version { major: 1, minor: 0 },
+        ..Default::default()
+    };
+    let mut node = BasicNode::new(core_node, node_info).unwrap();
+    let mut events = TimerEvents {
+        time: Rc::clone(&time),
+        deadlines: Vec::new(),
+    };
+
+    // The heartbeats do not fit, but the loop keeps running
+    for _ in 0..3 {
+        block_on(node.run_once(&mut events, &mut NoHandler, milliseconds(1000))).unwrap();
+    }
+    assert!(node.node().driver().frames.is_empty());
+
+    // Heartbeats are sent again when the queue has space
+    node.node_mut().driver_mut().full = false;
+    block_on(node.run_once(&mut events, &mut NoHandler, milliseconds(1000))).unwrap();
+    assert!(node
+        .node()
+        .driver()
+        .frames
+        .iter()
+        .any(|frame| (u32::from(frame.id()) >> 8) & 0x1fff == 7509));
+}
+
+/// Polls a future that must complete without waiting
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    match future
+        .as_mut()
+        .poll(&mut Context::from_waker(Waker::noop()))
+    {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("Future did not complete"),
+    }
+}
+
+/// Events that advance the clock to each deadline without waiting
+struct TimerEvents {
+    time: Rc<Cell<u32>>,
+    deadlines: Vec<u32>,
+}
+
+impl Events for TimerEvents {
+    fn wait(&mut self, deadline: Microseconds32) -> impl Future<Output = ()> {
+        self.deadlines.push(deadline.ticks());
+        self.time.set(deadline.ticks());
+        std::future::ready(())
+    }
+}
+
+/// A handler that counts the messages it receives
+struct MessageCounter(usize);
+
+impl canadensis::TransferHandler<CanTransport> for MessageCounter {
+    fn handle_message<N: Node<Transport = CanTransport>>(
+        &mut self,
+        _node: &mut N,
+        _transfer: &MessageTransfer<Vec<u8>, CanTransport>,
+    ) -> bool {
+        self.0 += 1;
+        true
+    }
+}
@@ -0,0 +1,135 @@
+//! Tests GetInfo responses from a basic node with a node information hook
+
+extern crate canadensis;
+extern crate canadensis_can;
+extern crate canadensis_data_types;
+
+mod common;
+
+use canadensis::core::transport::Receiver;
+use canadensis::encoding::Deserialize;
+use canadensis::node::data_types::{GetInfoResponse, Version};
+use canadensis::node::{BasicNode, CoreNode};
+use canadensis::Node;
+use canadensis_can::driver::TransmitDriver;
+use canadensis_can::{CanId, CanNodeId, CanReceiver, CanTransmitter, Frame, Mtu};
+use canadensis_can::{LoopbackBus, LoopbackDriver};
+use canadensis_core::time::{milliseconds, Microseconds32};
+use canadensis_data_types::uavcan::node::get_info_1_0;
+use common::{NoHandler, TestClock, TestNode};
+use std::cell::Cell;
+use std::convert::TryFrom;
+use std::rc::Rc;
+
+const LOCAL: u8 = 3;
+const CLIENT: u8 = 8;
+const CRC: u64 = 0x0123_4567_89ab_cdef;
+
+#[test]
+fn node_info_hook() {
+    let bus = LoopbackBus::new();
+    let node_id = CanNodeId::try_from(LOCAL).unwrap();
+    let core_node: TestNode<LoopbackDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        bus.add_driver(),
+    );
+    let mut client = Client::new(&bus);
+    let node_info = GetInfoResponse {
+        protocol_version: Version { major: 1, minor: 0 },
+        name: heapless::Vec::from_slice(b"org.example.node").unwrap(),
+        ..Default::default()
+    };
+    let mut node = BasicNode::new(core_node, node_info).unwrap();
+
+    // Calculate the CRC only once, when the first request arrives
+    let calls = Rc::new(Cell::new(0));
+    let crcs_calculated = Rc::new(Cell::new(0));
+    {
+        let calls = Rc::clone(&calls);
+        let crcs_calculated = Rc::clone(&crcs_calculated);
+        node.set_node_info_hook(move |info| {
+            calls.set(calls.get() + 1);
+            if info.software_image_crc.is_empty() {
+                crcs_calculated.set(crcs_calculated.get() + 1);
+                info.software_image_crc.push(CRC).unwrap();
+            }
+        });
+    }
+    assert!(node.node_info().software_image_crc.is_empty());
+
+    client.send_request(0);
+    client.send_request(1);
+    node.receive(&mut NoHandler).unwrap();
+    node.receive(&mut NoHandler).unwrap();
+    assert_eq!(calls.get(), 2);
+    assert_eq!(crcs_calculated.get(), 1);
+    assert_eq!(&node.node_info().software_image_crc[..], &[CRC]);
+
+    let responses = client.receive_responses();
+    assert_eq!(responses.len(), 2);
+    for response in responses {
+        assert_eq!(&response.software_image_crc[..], &[CRC]);
+        assert_eq!(&response.name[..], b"org.example.node");
+    }
+
+    // Without the hook, requests do not change the node information
+    node.clear_node_info_hook();
+    client.send_request(2);
+    node.receive(&mut NoHandler).unwrap();
+    assert_eq!(calls.get(), 2);
+}
+
+/// A node on the bus that sends GetInfo requests and receives the responses
+struct Client {
+    driver: LoopbackDriver,
+    receiver: CanReceiver<TestClock, LoopbackDriver>,
+}
+
+impl Client {
+    fn new(bus: &LoopbackBus) -> Self {
+        let mut driver = bus.add_driver();
+        let mut receiver = CanReceiver::new(CanNodeId::try_from(CLIENT).unwrap());
+        receiver
+            .subscribe_response(get_info_1_0::SERVICE, 313, milliseconds(1000), &mut driver)
+            .unwrap();
+        Client { driver, receiver }
+    }
+
+    /// Sends a GetInfo request to the local node
+    fn send_request(&mut self, transfer_id: u8) {
+        // Priority nominal, service, request, service ID 430, destination, source
+        let id = (4 << 26)
+            | (1 << 25)
+            | (1 << 24)
+            | (430 << 14)
+            | (u32::from(LOCAL) << 7)
+            | u32::from(CLIENT);
+        // The request is empty, so the frame contains only the tail byte (start, end, toggle,
+        // and transfer ID)
+        let frame = Frame::new(
+            Microseconds32::from_ticks(0),
+            CanId::try_from(id).unwrap(),
+            &[0xe0 | transfer_id],
+        );
+        self.driver
+            .transmit(frame, &mut TestClock::default())
+            .unwrap();
+    }
+
+    /// Receives and deserializes all GetInfo responses that have arrived
+    fn receive_responses(&mut self) -> Vec<GetInfoResponse> {
+        let mut responses = Vec::new();
+        while let Some(transfer) = self
+            .receiver
+            .receive(&mut TestClock::default(), &mut self.driver)
+            .unwrap()
+        {
+            responses
+                .push(GetInfoResponse::deserialize_from_bytes(&transfer.transfer.payload).unwrap());
+        }
+        responses
+    }
+}
@@ -0,0 +1,149 @@
+//! Clocks, drivers, and other fixtures shared by the node integration tests
+//!
+//! Each test file uses a different subset of these items.
+#![allow(dead_code)]
+
+use canadensis::node::CoreNode;
+use canadensis::requester::TransferIdFixedMap;
+use canadensis_can::driver::{ReceiveDriver, TransmitDriver};
+use canadensis_can::{CanNodeId, CanReceiver, CanTransmitter, CanTransport, Frame};
+use canadensis_core::subscription::Subscription;
+use canadensis_core::time::{Clock, Microseconds32};
+use canadensis_core::OutOfMemoryError;
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::rc::Rc;
+
+/// A CAN node with a test clock, the driver `D`, and space for 4 publishers and 4 requesters
+pub type TestNode<D> = CoreNode<
+    TestClock,
+    CanTransmitter<TestClock, D>,
+    CanReceiver<TestClock, D>,
+    TransferIdFixedMap<CanTransport, 4>,
+    D,
+    4,
+    4,
+>;
+
+/// A clock that returns a time that the test controls
+///
+/// The default clock always returns zero. Clones of a clock share the same time.
+#[derive(Debug, Clone, Default)]
+pub struct TestClock(pub Rc<Cell<u32>>);
+
+impl TestClock {
+    /// Creates a clock that returns `ticks` until the time is changed
+    pub fn new(ticks: u32) -> Self {
+        TestClock(Rc::new(Cell::new(ticks)))
+    }
+
+    /// Sets the time that this clock and all its clones return
+    pub fn set(&self, ticks: u32) {
+        self.0.set(ticks)
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&mut self) -> Microseconds32 {
+        Microseconds32::from_ticks(self.0.get())
+    }
+}
+
+/// A handler that does not handle any transfers
+pub struct NoHandler;
+
+impl canadensis::TransferHandler<CanTransport> for NoHandler {}
+
+/// A CAN driver that discards all outgoing frames and never receives any frames
+pub struct NullDriver;
+
+impl TransmitDriver<TestClock> for NullDriver {
+    type Error = Infallible;
+
+    fn try_reserve(&mut self, _frames: usize) -> Result<(), OutOfMemoryError> {
+        Ok(())
+    }
+
+    fn transmit(
+        &mut self,
+        _frame: Frame,
+        _clock: &mut TestClock,
+    ) -> canadensis::nb::Result<Option<Frame>, Self::Error> {
+        Ok(None)
+    }
+
+    fn flush(&mut self, _clock: &mut TestClock) -> canadensis::nb::Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl ReceiveDriver<TestClock> for NullDriver {
+    type Error = Infallible;
+
+    fn receive(&mut self, _clock: &mut TestClock) -> canadensis::nb::Result<Frame, Self::Error> {
+        Err(canadensis::nb::Error::WouldBlock)
+    }
+
+    fn apply_filters<S>(&mut self, _local_node: Option<CanNodeId>, _subscriptions: S)
+    where
+        S: IntoIterator<Item = Subscription>,
+    {
+    }
+
+    fn apply_accept_all(&mut self) {}
+}
+
+/// A CAN driver that records all outgoing frames and receives frames from a queue
+#[derive(Default)]
+pub struct RecordingDriver {
+    /// The frames that have been transmitted, oldest first
+    pub frames: Vec<Frame>,
+    /// The frames that the driver will receive, oldest first
+    pub incoming: VecDeque<Frame>,
+    /// If this is true, the driver has no space for any outgoing frames
+    pub full: bool,
+}
+
+impl TransmitDriver<TestClock> for RecordingDriver {
+    type Error = Infallible;
+
+    fn try_reserve(&mut self, _frames: usize) -> Result<(), OutOfMemoryError> {
+        if self.full {
+            Err(OutOfMemoryError)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn transmit(
+        &mut self,
+        frame: Frame,
+        _clock: &mut TestClock,
+    ) -> canadensis::nb::Result<Option<Frame>, Self::Error> {
+        self.frames.push(frame);
+        Ok(None)
+    }
+
+    fn flush(&mut self, _clock: &mut TestClock) -> canadensis::nb::Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl ReceiveDriver<TestClock> for RecordingDriver {
+    type Error = Infallible;
+
+    fn receive(&mut self, _clock: &mut TestClock) -> canadensis::nb::Result<Frame, Self::Error> {
+        self.incoming
+            .pop_front()
+            .ok_or(canadensis::nb::Error::WouldBlock)
+    }
+
+    fn apply_filters<S>(&mut self, _local_node: Option<CanNodeId>, _subscriptions: S)
+    where
+        S: IntoIterator<Item = Subscription>,
+    {
+    }
+
+    fn apply_accept_all(&mut self) {}
+}
@@ -0,0 +1,87 @@
+//! Tests publishing log messages with the diagnostic service
+
+extern crate canadensis;
+extern crate canadensis_can;
+extern crate canadensis_data_types;
+
+mod common;
+
+use canadensis::core::transport::Receiver;
+use canadensis::encoding::Deserialize;
+use canadensis::node::CoreNode;
+use canadensis::service::diagnostic::{DiagnosticService, MAX_TEXT_LENGTH};
+use canadensis_can::{CanNodeId, CanReceiver, CanTransmitter, Mtu};
+use canadensis_can::{LoopbackBus, LoopbackDriver};
+use canadensis_core::time::milliseconds;
+use canadensis_data_types::uavcan::diagnostic::record_1_1::{self, Record};
+use canadensis_data_types::uavcan::diagnostic::severity_1_0::Severity;
+use common::{TestClock, TestNode};
+use std::convert::TryFrom;
+
+const NOW: u32 = 1_234_567;
+const SYNCHRONIZED_TIME: u64 = 1_700_000_000_000_000;
+
+#[test]
+fn log_messages() {
+    let bus = LoopbackBus::new();
+    let mut listener = bus.add_driver();
+    let node_id = CanNodeId::try_from(3_u8).unwrap();
+    let mut node: TestNode<LoopbackDriver> = CoreNode::new(
+        TestClock::new(NOW),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        bus.add_driver(),
+    );
+    let mut service = DiagnosticService::new(&mut node).unwrap();
+    service
+        .log_with_timestamp(
+            &mut node,
+            Some(SYNCHRONIZED_TIME),
+            Severity {
+                value: Severity::WARNING,
+            },
+            "Low battery",
+        )
+        .unwrap();
+    // Too long, and the limit is in the middle of the last character
+    let long_text = format!("{}é", "a".repeat(MAX_TEXT_LENGTH - 1));
+    service
+        .log(
+            &mut node,
+            Severity {
+                value: Severity::ERROR,
+            },
+            &long_text,
+        )
+        .unwrap();
+
+    let records = receive_records(&mut listener);
+    assert_eq!(records.len(), 2);
+
+    assert_eq!(records[0].timestamp.microsecond, SYNCHRONIZED_TIME);
+    assert_eq!(records[0].severity.value, Severity::WARNING);
+    assert_eq!(&records[0].text[..], b"Low battery");
+
+    // The local clock time is not sent
+    assert_eq!(records[1].timestamp.microsecond, 0);
+    assert_eq!(records[1].severity.value, Severity::ERROR);
+    assert_eq!(
+        &records[1].text[..],
+        &long_text.as_bytes()[..MAX_TEXT_LENGTH - 1]
+    );
+}
+
+/// Receives and deserializes all records that have arrived at a driver
+fn receive_records(driver: &mut LoopbackDriver) -> Vec<Record> {
+    let mut receiver: CanReceiver<TestClock, LoopbackDriver> =
+        CanReceiver::new(CanNodeId::try_from(10_u8).unwrap());
+    receiver
+        .subscribe_message(record_1_1::SUBJECT, 300, milliseconds(1000), driver)
+        .unwrap();
+    let mut records = Vec::new();
+    while let Some(transfer) = receiver.receive(&mut TestClock::default(), driver).unwrap() {
+        records.push(Record::deserialize_from_bytes(&transfer.transfer.payload).unwrap());
+    }
+    records
+}
@@ -0,0 +1,96 @@
+//! Tests the ExecuteCommand service with requests received through a node
+
+extern crate canadensis;
+extern crate canadensis_can;
+extern crate canadensis_data_types;
+
+mod common;
+
+use canadensis::node::CoreNode;
+use canadensis::service::execute_command::ExecuteCommandService;
+use canadensis::Node;
+use canadensis_can::{CanId, CanNodeId, CanReceiver, CanTransmitter, Frame, Mtu};
+use canadensis_core::time::Microseconds32;
+use canadensis_data_types::uavcan::node::execute_command_1_3::{
+    ExecuteCommandRequest, ExecuteCommandResponse,
+};
+use common::{RecordingDriver, TestClock, TestNode};
+use std::convert::TryFrom;
+
+const LOCAL: u8 = 3;
+const CLIENT: u8 = 8;
+
+#[test]
+fn dispatch_commands() {
+    let node_id = CanNodeId::try_from(LOCAL).unwrap();
+    let mut node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    );
+
+    let mut restarts = 0;
+    let mut parameters = Vec::new();
+    {
+        let mut service = ExecuteCommandService::new(&mut node).unwrap();
+        service.register(ExecuteCommandRequest::COMMAND_RESTART, |_| {
+            restarts += 1;
+            ExecuteCommandResponse::STATUS_SUCCESS
+        });
+        service.register(100, |parameter: &[u8]| {
+            parameters.push(parameter.to_vec());
+            ExecuteCommandResponse::STATUS_BAD_PARAMETER
+        });
+
+        let driver = node.driver_mut();
+        push_request(driver, 0, ExecuteCommandRequest::COMMAND_RESTART, b"");
+        push_request(driver, 1, 100, b"abcd");
+        // No function registered
+        push_request(driver, 2, ExecuteCommandRequest::COMMAND_POWER_OFF, b"");
+        // Each call handles one frame
+        for _ in 0..3 {
+            node.receive(&mut service.handler()).unwrap();
+        }
+    }
+    assert_eq!(restarts, 1);
+    assert_eq!(parameters, vec![b"abcd".to_vec()]);
+
+    // One single-frame response for each request: status, empty output, and tail byte
+    let responses: Vec<&[u8]> = node
+        .driver()
+        .frames
+        .iter()
+        .map(|frame| frame.data())
+        .collect();
+    assert_eq!(
+        responses,
+        [
+            &[ExecuteCommandResponse::STATUS_SUCCESS, 0, 0xe0][..],
+            &[ExecuteCommandResponse::STATUS_BAD_PARAMETER, 0, 0xe1][..],
+            &[ExecuteCommandResponse::STATUS_BAD_COMMAND, 0, 0xe2][..],
+        ]
+    );
+}
+
+/// Adds a single-frame ExecuteCommand request from the client node to the incoming queue
+fn push_request(driver: &mut RecordingDriver, transfer_id: u8, command: u16, parameter: &[u8]) {
+    // Priority nominal, service, request, service ID 435, destination, source
+    let id = (4 << 26)
+        | (1 << 25)
+        | (1 << 24)
+        | (435 << 14)
+        | (u32::from(LOCAL) << 7)
+        | u32::from(CLIENT);
+    let mut data = command.to_le_bytes().to_vec();
+    data.push(parameter.len() as u8);
+    data.extend_from_slice(parameter);
+    // Tail byte: start, end, toggle, and transfer ID
+    data.push(0xe0 | transfer_id);
+    driver.incoming.push_back(Frame::new(
+        Microseconds32::from_ticks(0),
+        CanId::try_from(id).unwrap(),
+        &data,
+    ));
+}
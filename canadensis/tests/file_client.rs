@@ -0,0 +1,224 @@
+//! Tests the file read and write clients
+
+extern crate canadensis;
+extern crate canadensis_can;
+extern crate canadensis_data_types;
+extern crate canadensis_encoding;
+
+mod common;
+
+use canadensis::node::CoreNode;
+use canadensis::service::file_client::{
+    FileError, FileReadClient, FileStatus, FileWriteClient, NewError,
+};
+use canadensis::{Node, TransferHandler};
+use canadensis_can::{CanNodeId, CanReceiver, CanTransferId, CanTransmitter, CanTransport, Mtu};
+use canadensis_core::time::{milliseconds, Microseconds32};
+use canadensis_core::transfer::{ServiceHeader, ServiceTransfer};
+use canadensis_core::{Priority, ServiceId};
+use canadensis_data_types::uavcan::file::error_1_0::Error;
+use canadensis_data_types::uavcan::file::read_1_1::{self, ReadResponse};
+use canadensis_data_types::uavcan::file::write_1_1::{self, WriteResponse};
+use canadensis_data_types::uavcan::primitive::unstructured_1_0::Unstructured;
+use canadensis_encoding::Serialize;
+use common::{NullDriver, TestClock, TestNode};
+use std::convert::TryFrom;
+
+#[test]
+fn read_file() {
+    let mut node = make_node();
+    let mut client = FileReadClient::new(
+        &mut node,
+        server(),
+        b"firmware.bin",
+        Vec::new(),
+        milliseconds(100),
+        3,
+    )
+    .unwrap();
+
+    client.poll(&mut node).unwrap();
+    // Polling again does not send another request while waiting for a response
+    client.poll(&mut node).unwrap();
+    let full_chunk = read_response(Error::OK, &[0xaa; 256]);
+    assert!(client
+        .handler()
+        .handle_response(&mut node, &response(read_1_1::SERVICE, 0, full_chunk)));
+    assert!(matches!(client.status(), FileStatus::InProgress));
+    assert_eq!(client.offset(), 256);
+
+    client.poll(&mut node).unwrap();
+    let short_chunk = read_response(Error::OK, &[0x55; 10]);
+    // A response with the wrong transfer ID is ignored
+    assert!(!client.handler().handle_response(
+        &mut node,
+        &response(read_1_1::SERVICE, 0, short_chunk.clone())
+    ));
+    assert!(client
+        .handler()
+        .handle_response(&mut node, &response(read_1_1::SERVICE, 1, short_chunk)));
+    assert!(matches!(client.status(), FileStatus::Done));
+
+    let data = client.finish(&mut node);
+    assert_eq!(data.len(), 266);
+    assert_eq!(data[255], 0xaa);
+    assert_eq!(data[256], 0x55);
+}
+
+#[test]
+fn read_timeout() {
+    let mut node = make_node();
+    let mut client = FileReadClient::new(
+        &mut node,
+        server(),
+        b"firmware.bin",
+        Vec::new(),
+        milliseconds(100),
+        2,
+    )
+    .unwrap();
+
+    client.poll(&mut node).unwrap();
+    node.clock_mut().set(200_000);
+    // Second attempt
+    client.poll(&mut node).unwrap();
+    assert!(matches!(client.status(), FileStatus::InProgress));
+    node.clock_mut().set(400_000);
+    client.poll(&mut node).unwrap();
+    assert!(matches!(
+        client.status(),
+        FileStatus::Failed(FileError::TimedOut)
+    ));
+}
+
+#[test]
+fn write_file() {
+    let mut node = make_node();
+    let data = [0x11_u8; 300];
+    let mut client = FileWriteClient::new(
+        &mut node,
+        server(),
+        b"config.txt",
+        &data[..],
+        milliseconds(100),
+        3,
+    )
+    .unwrap();
+
+    // 256 bytes, 44 bytes, and the empty chunk at the end
+    for transfer_id in 0..3 {
+        assert!(matches!(client.status(), FileStatus::InProgress));
+        client.poll(&mut node).unwrap();
+        assert!(client.handler().handle_response(
+            &mut node,
+            &response(write_1_1::SERVICE, transfer_id, write_response(Error::OK))
+        ));
+    }
+    assert!(matches!(client.status(), FileStatus::Done));
+    assert_eq!(client.offset(), 300);
+}
+
+#[test]
+fn write_error() {
+    let mut node = make_node();
+    let data = [0x11_u8; 10];
+    let mut client = FileWriteClient::new(
+        &mut node,
+        server(),
+        b"config.txt",
+        &data[..],
+        milliseconds(100),
+        3,
+    )
+    .unwrap();
+
+    client.poll(&mut node).unwrap();
+    assert!(client.handler().handle_response(
+        &mut node,
+        &response(write_1_1::SERVICE, 0, write_response(Error::ACCESS_DENIED))
+    ));
+    assert!(matches!(
+        client.status(),
+        FileStatus::Failed(FileError::Remote(Error::ACCESS_DENIED))
+    ));
+    assert_eq!(client.offset(), 0);
+}
+
+#[test]
+fn path_too_long() {
+    let mut node = make_node();
+    let path = [b'a'; 256];
+    let result = FileReadClient::new(&mut node, server(), &path, Vec::new(), milliseconds(100), 3);
+    assert!(matches!(result, Err(NewError::PathTooLong)));
+    let result = FileWriteClient::new(&mut node, server(), &path, &[][..], milliseconds(100), 3);
+    assert!(matches!(result, Err(NewError::PathTooLong)));
+    // A 255-byte path is allowed
+    assert!(FileReadClient::new(
+        &mut node,
+        server(),
+        &path[..255],
+        Vec::new(),
+        milliseconds(100),
+        3,
+    )
+    .is_ok());
+}
+
+fn read_response(error: u16, data: &[u8]) -> Vec<u8> {
+    let response = ReadResponse {
+        error: Error { value: error },
+        data: Unstructured {
+            value: heapless::Vec::from_slice(data).unwrap(),
+        },
+    };
+    let mut payload = vec![0u8; response.size_bits() / 8];
+    response.serialize_to_bytes(&mut payload);
+    payload
+}
+
+fn write_response(error: u16) -> Vec<u8> {
+    let response = WriteResponse {
+        error: Error { value: error },
+    };
+    let mut payload = vec![0u8; response.size_bits() / 8];
+    response.serialize_to_bytes(&mut payload);
+    payload
+}
+
+fn response(
+    service: ServiceId,
+    transfer_id: u8,
+    payload: Vec<u8>,
+) -> ServiceTransfer<Vec<u8>, CanTransport> {
+    ServiceTransfer {
+        header: ServiceHeader {
+            timestamp: Microseconds32::from_ticks(0),
+            transfer_id: CanTransferId::try_from(transfer_id).unwrap(),
+            priority: Priority::Nominal,
+            service,
+            source: server(),
+            destination: local(),
+        },
+        loopback: false,
+        completion_timestamp: Microseconds32::from_ticks(0),
+        payload,
+    }
+}
+
+fn local() -> CanNodeId {
+    CanNodeId::try_from(3_u8).unwrap()
+}
+
+fn server() -> CanNodeId {
+    CanNodeId::try_from(8_u8).unwrap()
+}
+
+fn make_node() -> TestNode<NullDriver> {
+    CoreNode::new(
+        TestClock::default(),
+        local(),
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(local()),
+        NullDriver,
+    )
+}
@@ -0,0 +1,120 @@
+//! Tests two nodes connected by an in-memory loopback bus
+
+extern crate canadensis;
+extern crate canadensis_can;
+extern crate canadensis_data_types;
+extern crate canadensis_encoding;
+
+mod common;
+
+use canadensis::node::CoreNode;
+use canadensis::{Node, TransferHandler};
+use canadensis_can::{CanNodeId, CanReceiver, CanTransmitter, CanTransport, Mtu};
+use canadensis_can::{LoopbackBus, LoopbackDriver};
+use canadensis_core::time::milliseconds;
+use canadensis_core::transfer::MessageTransfer;
+use canadensis_core::Priority;
+use canadensis_core::SubjectId;
+use canadensis_data_types::uavcan::primitive::string_1_0::String as CyphalString;
+use canadensis_encoding::Deserialize;
+use common::{TestClock, TestNode};
+use std::convert::TryFrom;
+
+#[test]
+fn publish_and_receive() {
+    let bus = LoopbackBus::new();
+    let mut publisher = make_node(&bus, 10);
+    let mut subscriber = make_node(&bus, 11);
+    let subject = SubjectId::try_from(100).unwrap();
+
+    subscriber
+        .subscribe_message(subject, 64, milliseconds(1000))
+        .unwrap();
+    publisher
+        .start_publishing(subject, milliseconds(1000), Priority::Nominal)
+        .unwrap();
+
+    // A multi-frame message
+    let text = b"Hello from the other node";
+    let message = CyphalString {
+        value: heapless::Vec::from_slice(text).unwrap(),
+    };
+    publisher.publish(subject, &message).unwrap();
+    publisher.flush().unwrap();
+
+    let mut collector = Collector::default();
+    subscriber.receive(&mut collector).unwrap();
+    assert_eq!(1, collector.messages.len());
+    let (source, received) = &collector.messages[0];
+    assert_eq!(*source, Some(CanNodeId::try_from(10_u8).unwrap()));
+    assert_eq!(&received[..], &text[..]);
+
+    // The publisher does not receive its own message
+    let mut collector = Collector::default();
+    publisher.receive(&mut collector).unwrap();
+    assert!(collector.messages.is_empty());
+}
+
+#[test]
+fn publish_streaming() {
+    let bus = LoopbackBus::new();
+    let mut publisher = make_node(&bus, 10);
+    let mut subscriber = make_node(&bus, 11);
+    let subject = SubjectId::try_from(100).unwrap();
+
+    subscriber
+        .subscribe_message(subject, 256, milliseconds(1000))
+        .unwrap();
+    publisher
+        .start_publishing(subject, milliseconds(1000), Priority::Nominal)
+        .unwrap();
+
+    // A message much longer than the serialization window
+    let text: Vec<u8> = (0..200).map(|i| b'a' + (i % 26) as u8).collect();
+    let message = CyphalString {
+        value: heapless::Vec::from_slice(&text).unwrap(),
+    };
+    publisher.publish(subject, &message).unwrap();
+    publisher.publish_streaming(subject, &message).unwrap();
+    publisher.flush().unwrap();
+
+    // The same message arrives twice
+    let mut collector = Collector::default();
+    subscriber.receive(&mut collector).unwrap();
+    subscriber.receive(&mut collector).unwrap();
+    assert_eq!(2, collector.messages.len());
+    for (source, received) in &collector.messages {
+        assert_eq!(*source, Some(CanNodeId::try_from(10_u8).unwrap()));
+        assert_eq!(&received[..], &text[..]);
+    }
+}
+
+fn make_node(bus: &LoopbackBus, node_id: u8) -> TestNode<LoopbackDriver> {
+    let node_id = CanNodeId::try_from(node_id).unwrap();
+    CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        bus.add_driver(),
+    )
+}
+
+/// Collects the source and text of received string messages
+#[derive(Default)]
+struct Collector {
+    messages: Vec<(Option<CanNodeId>, Vec<u8>)>,
+}
+
+impl TransferHandler<CanTransport> for Collector {
+    fn handle_message<N: Node<Transport = CanTransport>>(
+        &mut self,
+        _node: &mut N,
+        transfer: &MessageTransfer<Vec<u8>, CanTransport>,
+    ) -> bool {
+        let message = CyphalString::deserialize_from_bytes(&transfer.payload).unwrap();
+        self.messages
+            .push((transfer.header.source, message.value.to_vec()));
+        true
+    }
+}
@@ -0,0 +1,89 @@
+//! Tests MessageHandler deserialization and subject matching
+
+extern crate canadensis;
+extern crate canadensis_can;
+extern crate canadensis_data_types;
+extern crate canadensis_encoding;
+
+mod common;
+
+use canadensis::handler::MessageHandler;
+use canadensis::node::CoreNode;
+use canadensis::TransferHandler;
+use canadensis_can::{CanNodeId, CanReceiver, CanTransmitter, CanTransport, Mtu};
+use canadensis_core::time::{milliseconds, Microseconds32};
+use canadensis_core::transfer::{MessageHeader, MessageTransfer};
+use canadensis_core::{Priority, SubjectId};
+use canadensis_data_types::uavcan::node::health_1_0::Health;
+use canadensis_data_types::uavcan::node::heartbeat_1_0::{self, Heartbeat};
+use canadensis_data_types::uavcan::node::mode_1_0::Mode;
+use canadensis_encoding::Serialize;
+use common::{NullDriver, TestClock, TestNode};
+use std::convert::TryFrom;
+
+#[test]
+fn deserialize_heartbeat() {
+    let node_id = CanNodeId::try_from(3_u8).unwrap();
+    let mut node: TestNode<NullDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        NullDriver,
+    );
+
+    let mut received: Vec<(u32, Option<CanNodeId>)> = Vec::new();
+    let mut handler = MessageHandler::subscribe(
+        &mut node,
+        heartbeat_1_0::SUBJECT,
+        milliseconds(1000),
+        |heartbeat: Heartbeat, header: &MessageHeader<CanTransport>| {
+            received.push((heartbeat.uptime, header.source))
+        },
+    )
+    .unwrap();
+
+    let heartbeat = Heartbeat {
+        uptime: 37,
+        health: Health {
+            value: Health::NOMINAL,
+        },
+        mode: Mode {
+            value: Mode::OPERATIONAL,
+        },
+        vendor_specific_status_code: 0,
+    };
+    let mut payload = vec![0u8; 7];
+    heartbeat.serialize_to_bytes(&mut payload);
+    let source = CanNodeId::try_from(9_u8).unwrap();
+
+    assert!(handler.handle_message(
+        &mut node,
+        &transfer(heartbeat_1_0::SUBJECT, source, payload.clone())
+    ));
+    // Another subject
+    let other_subject = SubjectId::try_from(100).unwrap();
+    assert!(!handler.handle_message(&mut node, &transfer(other_subject, source, payload)));
+    // Release the borrow of received
+    let _ = handler.into_inner();
+    assert_eq!(received, vec![(37, Some(source))]);
+}
+
+fn transfer(
+    subject: SubjectId,
+    source: CanNodeId,
+    payload: Vec<u8>,
+) -> MessageTransfer<Vec<u8>, CanTransport> {
+    MessageTransfer {
+        header: MessageHeader {
+            timestamp: Microseconds32::from_ticks(0),
+            transfer_id: Default::default(),
+            priority: Priority::Nominal,
+            subject,
+            source: Some(source),
+        },
+        loopback: false,
+        completion_timestamp: Microseconds32::from_ticks(0),
+        payload,
+    }
+}
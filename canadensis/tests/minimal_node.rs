@@ -0,0 +1,104 @@
+//! Tests the heartbeat and port list schedules of minimal and basic nodes
+
+extern crate canadensis;
+extern crate canadensis_can;
+extern crate canadensis_data_types;
+
+mod common;
+
+use canadensis::node::data_types::{GetInfoResponse, Version};
+use canadensis::node::{BasicNode, CoreNode, MinimalNode};
+use canadensis_can::{CanNodeId, CanReceiver, CanTransmitter, Mtu};
+use canadensis_core::time::milliseconds;
+use common::{RecordingDriver, TestClock, TestNode};
+use std::cell::Cell;
+use std::convert::{TryFrom, TryInto};
+use std::rc::Rc;
+
+#[test]
+fn heartbeat_period() {
+    let time = Rc::new(Cell::new(0));
+    let mut node = make_node(&time);
+    assert_eq!(node.heartbeat_period(), milliseconds(1000));
+    assert!(node.set_heartbeat_period(milliseconds(1001)).is_err());
+    assert!(node.set_heartbeat_period(milliseconds(0)).is_err());
+    node.set_heartbeat_period(milliseconds(500)).unwrap();
+
+    // Call every 100 ms for 3 seconds
+    for _ in 0..30 {
+        node.run_periodic_tasks().unwrap();
+        time.set(time.get() + 100_000);
+    }
+    // Heartbeats at 0, 0.5, 1.0, ..., 2.5 seconds
+    assert_eq!(uptimes(&node), [0, 0, 1, 1, 2, 2]);
+}
+
+#[test]
+fn heartbeat_late_calls() {
+    let time = Rc::new(Cell::new(0));
+    let mut node = make_node(&time);
+    for t in [
+        0, 1_200_000, 2_100_000, 2_900_000, 5_500_000, 6_000_000, 6_500_000,
+    ] {
+        time.set(t);
+        node.run_periodic_tasks().unwrap();
+    }
+    // Heartbeats at 0, 1.2 s, 2.1 s (scheduled for 2.0 s), 5.5 s (scheduled for 3.0 s, so the
+    // schedule starts again), and 6.5 s
+    assert_eq!(uptimes(&node), [0, 1, 2, 5, 6]);
+}
+
+#[test]
+fn port_list_period() {
+    let time = Rc::new(Cell::new(0));
+    let node_info = GetInfoResponse {
+        protocol_version: Version { major: 1, minor: 0 },
+        software_version: Version { major: 0, minor: 1 },
+        ..Default::default()
+    };
+    let mut node = BasicNode::new(make_core_node(&time), node_info).unwrap();
+    // Call every 300 ms for 25 seconds
+    for _ in 0..84 {
+        node.run_periodic_tasks().unwrap();
+        time.set(time.get() + 300_000);
+    }
+    // Count the transfers on each subject (the start of transfer bit is in the tail byte)
+    let transfers_on = |subject: u32| {
+        node.node()
+            .driver()
+            .frames
+            .iter()
+            .filter(|frame| (u32::from(frame.id()) >> 8) & 0x1fff == subject)
+            .filter(|frame| frame.data().last().unwrap() & 0x80 != 0)
+            .count()
+    };
+    // Heartbeats at 0, 1.2, 2.1, ..., 24.9 seconds
+    assert_eq!(transfers_on(7509), 25);
+    // Port lists at 0, 10.2, and 20.4 seconds
+    assert_eq!(transfers_on(7510), 3);
+}
+
+fn make_node(time: &Rc<Cell<u32>>) -> MinimalNode<TestNode<RecordingDriver>> {
+    MinimalNode::new(make_core_node(time)).unwrap()
+}
+
+fn make_core_node(time: &Rc<Cell<u32>>) -> TestNode<RecordingDriver> {
+    let node_id = CanNodeId::try_from(3_u8).unwrap();
+    CoreNode::new(
+        TestClock(Rc::clone(time)),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    )
+}
+
+/// Returns the uptime values from all heartbeat messages that the node has sent
+fn uptimes(node: &MinimalNode<TestNode<RecordingDriver>>) -> Vec<u32> {
+    node.node()
+        .driver()
+        .frames
+        .iter()
+        .map(|frame| u32::from_le_bytes(frame.data()[..4].try_into().unwrap()))
+        .collect()
+}
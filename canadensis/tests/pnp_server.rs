@@ -0,0 +1,259 @@
+//! Tests the plug-and-play node ID allocator
+
+extern crate canadensis;
+extern crate canadensis_can;
+extern crate canadensis_data_types;
+extern crate canadensis_encoding;
+
+mod common;
+
+use canadensis::node::CoreNode;
+use canadensis::service::pnp_server::{Allocation, PnpServerService};
+use canadensis::TransferHandler;
+use canadensis_can::{CanNodeId, CanReceiver, CanTransmitter, CanTransport, Mtu};
+use canadensis_core::storage::MemoryStorage;
+use canadensis_core::time::Microseconds32;
+use canadensis_core::transfer::{MessageHeader, MessageTransfer};
+use canadensis_core::{Priority, SubjectId};
+use canadensis_data_types::uavcan::node::id_1_0::ID;
+use canadensis_data_types::uavcan::pnp::{
+    node_id_allocation_data_1_0, node_id_allocation_data_2_0,
+};
+use canadensis_encoding::Serialize;
+use common::{RecordingDriver, TestClock, TestNode};
+use std::convert::TryFrom;
+
+#[test]
+fn allocate_v1_and_v2() {
+    let node_id = CanNodeId::try_from(125_u8).unwrap();
+    let mut node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    );
+    let mut server = PnpServerService::new(&mut node, MemoryStorage::new(), 125).unwrap();
+    server.reserve(124);
+
+    // Version 1.0 request
+    let request_v1 = node_id_allocation_data_1_0::NodeIDAllocationData {
+        unique_id_hash: 0x1234_5678_9abc,
+        allocated_node_id: heapless::Vec::new(),
+    };
+    let payload = serialize(&request_v1, 7);
+    assert!(server.handler().handle_message(
+        &mut node,
+        &anonymous_transfer(node_id_allocation_data_1_0::SUBJECT, payload.clone())
+    ));
+    // The same request again gets the same node ID
+    assert!(server.handler().handle_message(
+        &mut node,
+        &anonymous_transfer(node_id_allocation_data_1_0::SUBJECT, payload)
+    ));
+
+    // Version 2.0 request from another node
+    let request_v2 = node_id_allocation_data_2_0::NodeIDAllocationData {
+        node_id: ID { value: 0 },
+        unique_id: [7; 16],
+    };
+    let payload = serialize(&request_v2, 18);
+    assert!(server.handler().handle_message(
+        &mut node,
+        &anonymous_transfer(node_id_allocation_data_2_0::SUBJECT, payload)
+    ));
+
+    // 125 is this node and 124 is reserved
+    assert_eq!(
+        server.allocations(),
+        [
+            Allocation {
+                unique_id_hash: 0x1234_5678_9abc,
+                unique_id: None,
+                node_id: 123,
+            },
+            Allocation {
+                unique_id_hash: server.allocations()[1].unique_id_hash,
+                unique_id: Some([7; 16]),
+                node_id: 122,
+            },
+        ]
+    );
+    // Two version 1.0 responses with two frames each, and one version 2.0 response with
+    // three frames
+    let frames = &node.driver().frames;
+    assert_eq!(frames.len(), 2 + 2 + 3);
+    // Unique ID hash and allocated node ID in the first response
+    assert_eq!(
+        frames[0].data()[..7],
+        [0xbc, 0x9a, 0x78, 0x56, 0x34, 0x12, 1]
+    );
+    assert_eq!(frames[1].data()[..2], [123, 0]);
+}
+
+#[test]
+fn allocate_v1_then_v2() {
+    let node_id = CanNodeId::try_from(125_u8).unwrap();
+    let mut node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    );
+    let request_v2 = node_id_allocation_data_2_0::NodeIDAllocationData {
+        node_id: ID { value: 0 },
+        unique_id: [7; 16],
+    };
+    let payload_v2 = serialize(&request_v2, 18);
+
+    // Get the hash of the unique ID from another server
+    let unique_id_hash = {
+        let mut server = PnpServerService::new(&mut node, MemoryStorage::new(), 125).unwrap();
+        assert!(server.handler().handle_message(
+            &mut node,
+            &anonymous_transfer(node_id_allocation_data_2_0::SUBJECT, payload_v2.clone())
+        ));
+        server.allocations()[0].unique_id_hash
+    };
+    let mut node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    );
+    let mut server = PnpServerService::new(&mut node, MemoryStorage::new(), 125).unwrap();
+
+    // The node first sends a version 1.0 request with the hash of its unique ID
+    let request_v1 = node_id_allocation_data_1_0::NodeIDAllocationData {
+        unique_id_hash,
+        allocated_node_id: heapless::Vec::new(),
+    };
+    assert!(server.handler().handle_message(
+        &mut node,
+        &anonymous_transfer(
+            node_id_allocation_data_1_0::SUBJECT,
+            serialize(&request_v1, 7)
+        )
+    ));
+    // Then it sends a version 2.0 request with its full unique ID and gets the same node ID
+    assert!(server.handler().handle_message(
+        &mut node,
+        &anonymous_transfer(node_id_allocation_data_2_0::SUBJECT, payload_v2)
+    ));
+
+    assert_eq!(
+        server.allocations(),
+        [Allocation {
+            unique_id_hash,
+            unique_id: None,
+            node_id: 124,
+        }]
+    );
+    // One version 1.0 response with two frames, and one version 2.0 response with three frames
+    let frames = &node.driver().frames;
+    assert_eq!(frames.len(), 2 + 3);
+    assert_eq!(frames[1].data()[..2], [124, 0]);
+    // Node ID at the beginning of the version 2.0 response
+    assert_eq!(frames[2].data()[..2], [124, 0]);
+}
+
+#[test]
+fn load_allocations() {
+    let node_id = CanNodeId::try_from(125_u8).unwrap();
+    let mut node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    );
+    let mut server = PnpServerService::new(&mut node, MemoryStorage::new(), 125).unwrap();
+    let request_v1 = node_id_allocation_data_1_0::NodeIDAllocationData {
+        unique_id_hash: 0x1234_5678_9abc,
+        allocated_node_id: heapless::Vec::new(),
+    };
+    let request_v2 = node_id_allocation_data_2_0::NodeIDAllocationData {
+        node_id: ID { value: 0 },
+        unique_id: [7; 16],
+    };
+    assert!(server.handler().handle_message(
+        &mut node,
+        &anonymous_transfer(
+            node_id_allocation_data_1_0::SUBJECT,
+            serialize(&request_v1, 7)
+        )
+    ));
+    assert!(server.handler().handle_message(
+        &mut node,
+        &anonymous_transfer(
+            node_id_allocation_data_2_0::SUBJECT,
+            serialize(&request_v2, 18)
+        )
+    ));
+    let allocations = server.allocations().to_vec();
+    assert_eq!(allocations.len(), 2);
+
+    // A new server with the same storage remembers the allocations
+    let storage = server.storage().clone();
+    let mut node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    );
+    let server = PnpServerService::new(&mut node, storage, 125).unwrap();
+    assert_eq!(server.allocations(), allocations);
+}
+
+#[test]
+fn ignore_non_anonymous() {
+    let node_id = CanNodeId::try_from(125_u8).unwrap();
+    let mut node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    );
+    let mut server = PnpServerService::new(&mut node, MemoryStorage::new(), 125).unwrap();
+
+    let response = node_id_allocation_data_2_0::NodeIDAllocationData {
+        node_id: ID { value: 10 },
+        unique_id: [7; 16],
+    };
+    let mut transfer = anonymous_transfer(
+        node_id_allocation_data_2_0::SUBJECT,
+        serialize(&response, 18),
+    );
+    transfer.header.source = Some(CanNodeId::try_from(9_u8).unwrap());
+    assert!(!server.handler().handle_message(&mut node, &transfer));
+    assert!(server.allocations().is_empty());
+    assert!(node.driver().frames.is_empty());
+}
+
+fn serialize<T: Serialize>(value: &T, length: usize) -> Vec<u8> {
+    let mut payload = vec![0u8; length];
+    value.serialize_to_bytes(&mut payload);
+    payload
+}
+
+fn anonymous_transfer(
+    subject: SubjectId,
+    payload: Vec<u8>,
+) -> MessageTransfer<Vec<u8>, CanTransport> {
+    MessageTransfer {
+        header: MessageHeader {
+            timestamp: Microseconds32::from_ticks(0),
+            transfer_id: Default::default(),
+            priority: Priority::Nominal,
+            subject,
+            source: None,
+        },
+        loopback: false,
+        completion_timestamp: Microseconds32::from_ticks(0),
+        payload,
+    }
+}
@@ -0,0 +1,227 @@
+//! Tests changing the priority, timeout, and transfer ID of a publisher
+
+extern crate canadensis;
+extern crate canadensis_can;
+extern crate canadensis_data_types;
+
+mod common;
+
+use canadensis::node::{CoreNode, CoreNodeBuilder};
+use canadensis::requester::TransferIdFixedMap;
+use canadensis::{nb, Node, PublishError};
+use canadensis_can::{CanNodeId, CanReceiver, CanTransferId, CanTransmitter, CanTransport, Mtu};
+use canadensis_core::time::milliseconds;
+use canadensis_core::{Priority, SubjectId};
+use canadensis_data_types::uavcan::diagnostic::record_1_1::{self, Record};
+use canadensis_data_types::uavcan::diagnostic::severity_1_0::Severity;
+use canadensis_data_types::uavcan::node::health_1_0::Health;
+use canadensis_data_types::uavcan::node::heartbeat_1_0::{self, Heartbeat};
+use canadensis_data_types::uavcan::node::mode_1_0::Mode;
+use canadensis_data_types::uavcan::time::synchronized_timestamp_1_0::SynchronizedTimestamp;
+use common::{RecordingDriver, TestClock, TestNode};
+use std::convert::TryFrom;
+
+#[test]
+fn change_priority_and_timeout() {
+    let node_id = CanNodeId::try_from(3_u8).unwrap();
+    let mut node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    );
+    let subject = heartbeat_1_0::SUBJECT;
+    assert_eq!(node.publish_priority(subject), None);
+    assert!(!node.set_publish_priority(subject, Priority::High));
+
+    node.start_publishing(subject, milliseconds(100), Priority::Nominal)
+        .unwrap();
+    let heartbeat = heartbeat();
+    node.publish(subject, &heartbeat).unwrap();
+
+    assert!(node.set_publish_priority(subject, Priority::Exceptional));
+    assert!(node.set_publish_timeout(subject, milliseconds(200)));
+    assert_eq!(node.publish_priority(subject), Some(&Priority::Exceptional));
+    assert_eq!(node.publish_timeout(subject), Some(milliseconds(200)));
+    node.publish(subject, &heartbeat).unwrap();
+
+    let frames = &node.driver().frames;
+    assert_eq!(frames.len(), 2);
+    // Priority is in bits 26-28 of the CAN ID
+    let priorities: Vec<u32> = frames
+        .iter()
+        .map(|frame| u32::from(frame.id()) >> 26)
+        .collect();
+    assert_eq!(priorities, [4, 0]);
+    // Deadlines
+    let deadlines: Vec<u32> = frames
+        .iter()
+        .map(|frame| frame.timestamp().ticks())
+        .collect();
+    assert_eq!(deadlines, [100_000, 200_000]);
+    // The transfer IDs continue in sequence
+    let transfer_ids: Vec<u8> = frames
+        .iter()
+        .map(|frame| frame.data().last().unwrap() & 0x1f)
+        .collect();
+    assert_eq!(transfer_ids, [0, 1]);
+}
+
+#[test]
+fn restore_transfer_id() {
+    let node_id = CanNodeId::try_from(3_u8).unwrap();
+    let mut node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    );
+    let subject = heartbeat_1_0::SUBJECT;
+    let saved = CanTransferId::try_from(30_u8).unwrap();
+    assert_eq!(node.next_transfer_id(subject), None);
+    assert!(!node.set_next_transfer_id(subject, saved));
+
+    node.start_publishing(subject, milliseconds(100), Priority::Nominal)
+        .unwrap();
+    assert_eq!(
+        node.next_transfer_id(subject),
+        Some(CanTransferId::default())
+    );
+    assert!(node.set_next_transfer_id(subject, saved));
+    for _ in 0..3 {
+        node.publish(subject, &heartbeat()).unwrap();
+    }
+    // Transfer IDs continue from the restored value and wrap around
+    let transfer_ids: Vec<u8> = node
+        .driver()
+        .frames
+        .iter()
+        .map(|frame| frame.data().last().unwrap() & 0x1f)
+        .collect();
+    assert_eq!(transfer_ids, [30, 31, 0]);
+    assert_eq!(
+        node.next_transfer_id(subject),
+        Some(CanTransferId::try_from(1_u8).unwrap())
+    );
+}
+
+#[test]
+fn builder() {
+    let node_id = CanNodeId::try_from(3_u8).unwrap();
+    let mut node = CoreNodeBuilder::new(
+        TestClock::default(),
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver::default(),
+    )
+    .node_id(node_id)
+    .publishers::<2>()
+    .requesters::<4>()
+    .build::<TransferIdFixedMap<CanTransport, 4>>();
+    assert_eq!(node.node_id(), Some(node_id));
+
+    // Capacity for exactly two publishers
+    for subject in 0..2_u16 {
+        node.start_publishing(
+            SubjectId::try_from(subject).unwrap(),
+            milliseconds(100),
+            Priority::Nominal,
+        )
+        .unwrap();
+    }
+    assert!(node
+        .start_publishing(
+            SubjectId::try_from(2_u16).unwrap(),
+            milliseconds(100),
+            Priority::Nominal
+        )
+        .is_err());
+}
+
+#[test]
+fn builder_anonymous() {
+    let node: TestNode<RecordingDriver> = CoreNodeBuilder::new(
+        TestClock::default(),
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new_anonymous(),
+        RecordingDriver::default(),
+    )
+    .publishers::<4>()
+    .requesters::<4>()
+    .build();
+    assert_eq!(node.node_id(), None);
+}
+
+#[test]
+fn anonymous_multi_frame() {
+    let mut node: TestNode<RecordingDriver> = CoreNode::new_anonymous(
+        TestClock::default(),
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new_anonymous(),
+        RecordingDriver::default(),
+    );
+    let subject = record_1_1::SUBJECT;
+    node.start_publishing(subject, milliseconds(100), Priority::Nominal)
+        .unwrap();
+    let record = |text: &[u8]| Record {
+        timestamp: SynchronizedTimestamp { microsecond: 0 },
+        severity: Severity {
+            value: Severity::INFO,
+        },
+        text: heapless::Vec::from_slice(text).unwrap(),
+    };
+    // 7 timestamp bytes, 1 severity byte, 1 length byte
+    assert!(matches!(
+        node.publish(subject, &record(b"a")),
+        Err(nb::Error::Other(PublishError::AnonymousMultiFrame))
+    ));
+    assert!(node.driver().frames.is_empty());
+
+    // Heartbeat messages fit into one frame
+    node.start_publishing(heartbeat_1_0::SUBJECT, milliseconds(100), Priority::Nominal)
+        .unwrap();
+    node.publish(heartbeat_1_0::SUBJECT, &heartbeat()).unwrap();
+    assert_eq!(node.driver().frames.len(), 1);
+}
+
+#[test]
+fn queue_full() {
+    let node_id = CanNodeId::try_from(3_u8).unwrap();
+    let mut node: TestNode<RecordingDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        RecordingDriver {
+            full: true,
+            ..RecordingDriver::default()
+        },
+    );
+    let subject = heartbeat_1_0::SUBJECT;
+    node.start_publishing(subject, milliseconds(100), Priority::Nominal)
+        .unwrap();
+    assert!(matches!(
+        node.publish(subject, &heartbeat()),
+        Err(nb::Error::Other(PublishError::QueueFull))
+    ));
+    assert!(node.driver().frames.is_empty());
+
+    node.driver_mut().full = false;
+    node.publish(subject, &heartbeat()).unwrap();
+    assert_eq!(node.driver().frames.len(), 1);
+}
+
+fn heartbeat() -> Heartbeat {
+    Heartbeat {
+        uptime: 0,
+        health: Health {
+            value: Health::NOMINAL,
+        },
+        mode: Mode {
+            value: Mode::OPERATIONAL,
+        },
+        vendor_specific_status_code: 0,
+    }
+}
@@ -0,0 +1,109 @@
+//! Tests the matching of responses and timeouts in ServiceClient
+
+extern crate canadensis;
+extern crate canadensis_can;
+extern crate canadensis_data_types;
+
+mod common;
+
+use canadensis::node::CoreNode;
+use canadensis::service::client::ServiceClient;
+use canadensis_can::{CanNodeId, CanReceiver, CanTransferId, CanTransmitter, CanTransport, Mtu};
+use canadensis_core::time::{milliseconds, Microseconds32};
+use canadensis_core::transfer::{ServiceHeader, ServiceTransfer};
+use canadensis_core::{Priority, ServiceId};
+use canadensis_data_types::uavcan::node::get_info_1_0::{self, GetInfoRequest};
+use common::{NullDriver, TestClock, TestNode};
+use std::convert::TryFrom;
+
+#[test]
+fn match_responses() {
+    let node_id = CanNodeId::try_from(3_u8).unwrap();
+    let server_id = CanNodeId::try_from(8_u8).unwrap();
+    let mut node: TestNode<NullDriver> = CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        NullDriver,
+    );
+    let mut client: ServiceClient<TestNode<NullDriver>, GetInfoRequest, 2> = ServiceClient::new(
+        &mut node,
+        get_info_1_0::SERVICE,
+        milliseconds(100),
+        313,
+        Priority::Nominal,
+    )
+    .unwrap();
+
+    let call0 = client
+        .call(&mut node, &GetInfoRequest {}, server_id)
+        .unwrap();
+    let call1 = client
+        .call(&mut node, &GetInfoRequest {}, server_id)
+        .unwrap();
+    assert_eq!(u8::from(*call0.transfer_id()), 0);
+    assert_eq!(u8::from(*call1.transfer_id()), 1);
+    assert_eq!(client.pending_count(), 2);
+    // No space for another request
+    assert!(client
+        .call(&mut node, &GetInfoRequest {}, server_id)
+        .is_err());
+
+    // Responses from the wrong node, for the wrong service, or with an unknown transfer ID
+    let other_node = CanNodeId::try_from(9_u8).unwrap();
+    assert!(client
+        .handle_response(&response(get_info_1_0::SERVICE, other_node, node_id, 1))
+        .is_none());
+    let other_service = ServiceId::try_from(385).unwrap();
+    assert!(client
+        .handle_response(&response(other_service, server_id, node_id, 1))
+        .is_none());
+    assert!(client
+        .handle_response(&response(get_info_1_0::SERVICE, server_id, node_id, 2))
+        .is_none());
+
+    // Correct response
+    assert_eq!(
+        client.handle_response(&response(get_info_1_0::SERVICE, server_id, node_id, 1)),
+        Some(call1.clone())
+    );
+    assert_eq!(client.pending_count(), 1);
+    // A duplicate response does not match
+    assert!(client
+        .handle_response(&response(get_info_1_0::SERVICE, server_id, node_id, 1))
+        .is_none());
+
+    // The first request times out
+    let mut timed_out = Vec::new();
+    client.remove_timed_out(Microseconds32::from_ticks(100_000), |call| {
+        timed_out.push(call)
+    });
+    assert!(timed_out.is_empty());
+    client.remove_timed_out(Microseconds32::from_ticks(100_001), |call| {
+        timed_out.push(call)
+    });
+    assert_eq!(timed_out, vec![call0]);
+    assert_eq!(client.pending_count(), 0);
+}
+
+fn response(
+    service: ServiceId,
+    source: CanNodeId,
+    destination: CanNodeId,
+    transfer_id: u8,
+) -> ServiceTransfer<Vec<u8>, CanTransport> {
+    ServiceTransfer {
+        header: ServiceHeader {
+            timestamp: Microseconds32::from_ticks(0),
+            transfer_id: CanTransferId::try_from(transfer_id).unwrap(),
+            priority: Priority::Nominal,
+            service,
+            source,
+            destination,
+        },
+        loopback: false,
+        completion_timestamp: Microseconds32::from_ticks(0),
+        payload: Vec::new(),
+    }
+}
@@ -0,0 +1,103 @@
+//! Tests the time synchronization slave
+
+extern crate canadensis;
+extern crate canadensis_can;
+extern crate canadensis_data_types;
+extern crate canadensis_encoding;
+
+mod common;
+
+use canadensis::node::CoreNode;
+use canadensis::service::time_sync::TimeSyncSlaveService;
+use canadensis::TransferHandler;
+use canadensis_can::{CanNodeId, CanReceiver, CanTransferId, CanTransmitter, CanTransport, Mtu};
+use canadensis_core::time::Microseconds32;
+use canadensis_core::transfer::{MessageHeader, MessageTransfer};
+use canadensis_core::Priority;
+use canadensis_data_types::uavcan::time::synchronization_1_0::{self, Synchronization};
+use canadensis_encoding::Serialize;
+use common::{NullDriver, TestClock, TestNode};
+use std::convert::TryFrom;
+
+#[test]
+fn synchronize() {
+    let mut node = make_node();
+    let mut slave = TimeSyncSlaveService::new(&mut node).unwrap();
+    let master = CanNodeId::try_from(10_u8).unwrap();
+    assert!(!slave.is_synchronized(Microseconds32::from_ticks(0)));
+
+    // The master clock is 5 seconds ahead, and each message takes 100 microseconds to arrive
+    handle(&mut slave, &mut node, master, 0, 1_000_100, 0);
+    assert_eq!(slave.offset(), None);
+    assert_eq!(slave.master(), Some(&master));
+    handle(&mut slave, &mut node, master, 1, 2_000_100, 6_000_000);
+    assert_eq!(slave.offset(), Some(4_999_900));
+    assert!(slave.is_synchronized(Microseconds32::from_ticks(2_500_000)));
+    assert_eq!(
+        slave.master_time(Microseconds32::from_ticks(2_500_000)),
+        Some(7_499_900)
+    );
+    // The master has timed out
+    assert!(!slave.is_synchronized(Microseconds32::from_ticks(6_000_000)));
+
+    // A message with a skipped transfer ID does not change the estimate
+    handle(&mut slave, &mut node, master, 3, 3_000_100, 7_000_000);
+    assert_eq!(slave.offset(), Some(4_999_900));
+}
+
+#[test]
+fn lowest_master_wins() {
+    let mut node = make_node();
+    let mut slave = TimeSyncSlaveService::new(&mut node).unwrap();
+    let high = CanNodeId::try_from(20_u8).unwrap();
+    let low = CanNodeId::try_from(10_u8).unwrap();
+
+    handle(&mut slave, &mut node, high, 0, 1_000_000, 0);
+    assert_eq!(slave.master(), Some(&high));
+    handle(&mut slave, &mut node, low, 0, 1_000_200, 0);
+    assert_eq!(slave.master(), Some(&low));
+    // Messages from the higher master are ignored
+    handle(&mut slave, &mut node, high, 1, 2_000_000, 90_000_000);
+    handle(&mut slave, &mut node, low, 1, 2_000_200, 2_000_000);
+    assert_eq!(slave.master(), Some(&low));
+    assert_eq!(slave.offset(), Some(999_800));
+}
+
+fn handle(
+    slave: &mut TimeSyncSlaveService<TestNode<NullDriver>>,
+    node: &mut TestNode<NullDriver>,
+    source: CanNodeId,
+    transfer_id: u8,
+    receive_time: u32,
+    previous_transmission: u64,
+) {
+    let message = Synchronization {
+        previous_transmission_timestamp_microsecond: previous_transmission,
+    };
+    let mut payload = vec![0u8; 7];
+    message.serialize_to_bytes(&mut payload);
+    let transfer: MessageTransfer<Vec<u8>, CanTransport> = MessageTransfer {
+        header: MessageHeader {
+            timestamp: Microseconds32::from_ticks(receive_time),
+            transfer_id: CanTransferId::try_from(transfer_id).unwrap(),
+            priority: Priority::Fast,
+            subject: synchronization_1_0::SUBJECT,
+            source: Some(source),
+        },
+        loopback: false,
+        completion_timestamp: Microseconds32::from_ticks(receive_time),
+        payload,
+    };
+    assert!(slave.handler().handle_message(node, &transfer));
+}
+
+fn make_node() -> TestNode<NullDriver> {
+    let node_id = CanNodeId::try_from(3_u8).unwrap();
+    CoreNode::new(
+        TestClock::default(),
+        node_id,
+        CanTransmitter::new(Mtu::Can8),
+        CanReceiver::new(node_id),
+        NullDriver,
+    )
+}
@@ -5,6 +5,7 @@ mod operator;
 
 use std::cmp::Ordering;
 use std::collections::BTreeSet;
+use std::convert::TryFrom;
 use std::ops::{RangeToInclusive, Rem};
 use std::{iter, mem};
 
@@ -57,6 +58,34 @@ impl BitLengthSet {
         }
     }
 
+    /// Creates a bit length set containing the lengths from `min` to `max` (inclusive) in
+    /// increments of `step`
+    ///
+    /// If `min` is greater than `max`, this function returns None.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use canadensis_bit_length_set::{BitLengthSet, bit_length};
+    /// let lengths = BitLengthSet::range(8, 40, 8).unwrap();
+    /// assert_eq!(lengths.expand(), bit_length![8, 16, 24, 32, 40].expand());
+    ///
+    /// // max is included only if it is reachable from min
+    /// let lengths = BitLengthSet::range(0, 10, 4).unwrap();
+    /// assert_eq!(lengths.expand(), bit_length![0, 4, 8].expand());
+    ///
+    /// assert!(BitLengthSet::range(9, 8, 1).is_none());
+    /// ```
+    pub fn range(min: u64, max: u64, step: u64) -> Option<BitLengthSet> {
+        assert_ne!(step, 0, "Bit length range step must not be zero");
+        let step = usize::try_from(step).unwrap_or(usize::MAX);
+        BitLengthSet::from_lengths((min..=max).step_by(step))
+    }
+
     /// Returns the minimum length value in this set
     ///
     /// # Examples
@@ -178,6 +207,33 @@ impl BitLengthSet {
         self.operator.expand()
     }
 
+    /// Returns true if this set contains the provided length
+    ///
+    /// Lengths outside the range from [`min_value`](#method.min_value) to
+    /// [`max_value`](#method.max_value) are rejected quickly. For other lengths, this function
+    /// expands the set, which may be slow for some sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use canadensis_bit_length_set::{BitLengthSet, bit_length};
+    /// // uint8 length, followed by up to three uint16 values
+    /// let lengths = bit_length![8].concatenate([bit_length![16].repeat_range(..=3)]);
+    /// assert!(lengths.contains(8));
+    /// assert!(lengths.contains(40));
+    /// assert!(!lengths.contains(16));
+    /// assert!(!lengths.contains(72));
+    /// ```
+    pub fn contains(&self, length: u64) -> bool {
+        if length < self.min_value() || length > self.max_value() {
+            return false;
+        }
+        match &self.operator {
+            Operator::Leaf(values) => values.contains(&length),
+            _ => self.expand().contains(&length),
+        }
+    }
+
     /// Converts this bit length set into a new set that aligns up all its values to a multiple
     /// of the given alignment
     ///
@@ -0,0 +1,129 @@
+//!
+//! CAN FD frame conversion
+//!
+//! bxCAN peripherals only support classic CAN, but the FDCAN peripherals on some newer STM32
+//! microcontrollers (G4, H7, ...) can send frames with up to 64 bytes of data. The types in this
+//! module convert Canadensis frames into a form that is easy to pass to an FDCAN driver.
+//!
+
+use canadensis_can::{CanId, Frame};
+
+/// The largest number of data bytes in a CAN FD frame
+const FD_MAX_LENGTH: usize = 64;
+
+/// A CAN FD data frame with an extended ID, padded to a length that a data length code can
+/// represent
+#[derive(Debug, Clone)]
+pub struct FdFrame {
+    /// 29-bit extended ID
+    id: CanId,
+    /// Frame data, including padding (only the first `length` bytes are used)
+    data: [u8; FD_MAX_LENGTH],
+    /// Number of bytes of data, including padding
+    length: u8,
+    /// Number of zero bytes that were added to the frame
+    padding: u8,
+}
+
+impl FdFrame {
+    /// Returns the ID of this frame
+    pub fn id(&self) -> CanId {
+        self.id
+    }
+    /// Returns the data in this frame, including any padding bytes
+    pub fn data(&self) -> &[u8] {
+        &self.data[..usize::from(self.length)]
+    }
+    /// Returns the DLC (data length code) of this frame
+    pub fn dlc(&self) -> u8 {
+        length_to_dlc(usize::from(self.length))
+    }
+    /// Returns the number of zero bytes that were added to make the frame length valid
+    pub fn padding(&self) -> usize {
+        usize::from(self.padding)
+    }
+}
+
+/// Converts a Canadensis frame into a CAN FD frame
+///
+/// If the length of the frame data can't be represented by a CAN FD data length code, zero bytes
+/// are added to round the length up to the next valid value (12, 16, 20, 24, 32, 48, or 64).
+/// The padding is inserted before the tail byte, so the tail byte remains the last byte
+/// of the frame as the Cyphal/CAN specification requires.
+///
+/// Only single-frame transfers can be padded this way. In a multi-frame transfer, the padding
+/// must come before the transfer CRC so that the CRC covers it. If a frame of a multi-frame
+/// transfer does not have a valid length, this function returns an error.
+///
+/// Frames produced by [`CanTransmitter`](canadensis_can::CanTransmitter) already have valid
+/// lengths, so they never need padding. Frames longer than 8 bytes require the `can-fd` feature
+/// of `canadensis_can`.
+pub fn cyphal_frame_to_fdcan(frame: &Frame) -> Result<FdFrame, PaddingError> {
+    let frame_data = frame.data();
+    let length = round_up_length(frame_data.len());
+    let padding = length - frame_data.len();
+
+    let mut data = [0u8; FD_MAX_LENGTH];
+    if let Some((&tail, payload)) = frame_data.split_last() {
+        if padding != 0 && !is_single_frame(tail) {
+            return Err(PaddingError);
+        }
+        data[..payload.len()].copy_from_slice(payload);
+        // Padding bytes stay zero
+        data[length - 1] = tail;
+    }
+    Ok(FdFrame {
+        id: frame.id(),
+        data,
+        length: length as u8,
+        padding: padding as u8,
+    })
+}
+
+/// An error indicating that a frame of a multi-frame transfer does not have a length that a
+/// CAN FD data length code can represent
+///
+/// Padding this frame would put the padding after the transfer CRC, so receivers would reject
+/// the transfer.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PaddingError;
+
+/// Returns true if a tail byte has both the start of transfer and end of transfer bits set
+fn is_single_frame(tail: u8) -> bool {
+    const START_AND_END: u8 = 0xc0;
+    tail & START_AND_END == START_AND_END
+}
+
+/// Rounds up a frame length to a value that can be represented by a CAN FD data length code
+///
+/// # Panics
+///
+/// This function panics if length is greater than 64.
+fn round_up_length(length: usize) -> usize {
+    match length {
+        0..=8 => length,
+        9..=12 => 12,
+        13..=16 => 16,
+        17..=20 => 20,
+        21..=24 => 24,
+        25..=32 => 32,
+        33..=48 => 48,
+        49..=64 => 64,
+        _ => panic!("Frame data more than 64 bytes"),
+    }
+}
+
+/// Converts a valid CAN FD frame length into a data length code
+fn length_to_dlc(length: usize) -> u8 {
+    match length {
+        0..=8 => length as u8,
+        12 => 9,
+        16 => 10,
+        20 => 11,
+        24 => 12,
+        32 => 13,
+        48 => 14,
+        64 => 15,
+        _ => unreachable!("Invalid CAN FD frame length"),
+    }
+}
@@ -0,0 +1,117 @@
+//!
+//! Filter bank bookkeeping
+//!
+
+use bxcan::filter::{Mask32, MasterFilters};
+use bxcan::{ExtendedId, Fifo, FilterOwner};
+use canadensis_filter_config::Filter;
+use heapless::Vec;
+
+/// The largest number of filter banks that a bxCAN peripheral can have
+pub(crate) const MAX_BANKS: usize = 28;
+
+/// The ID and mask of a filter applied to a filter bank
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct BankFilter {
+    id: u32,
+    mask: u32,
+}
+
+impl From<&Filter> for BankFilter {
+    fn from(filter: &Filter) -> Self {
+        BankFilter {
+            id: filter.id(),
+            mask: filter.mask(),
+        }
+    }
+}
+
+/// Keeps track of the filter applied to each filter bank
+///
+/// When the filters change, only the banks whose filters are no longer needed get reconfigured.
+/// Banks with filters that remain the same keep accepting frames the whole time.
+pub(crate) struct FilterBanks {
+    /// The filter in each bank, or None if the filter configuration is unknown
+    banks: Option<[Option<BankFilter>; MAX_BANKS]>,
+}
+
+impl FilterBanks {
+    /// Creates a filter bank tracker
+    ///
+    /// The filter configuration is initially unknown, so the first call to `apply` will clear
+    /// all banks.
+    pub fn new() -> Self {
+        FilterBanks { banks: None }
+    }
+
+    /// Marks the filter configuration as unknown, so that the next call to `apply` will clear
+    /// all banks
+    ///
+    /// This must be called after modifying the filters in some other way.
+    pub fn invalidate(&mut self) {
+        self.banks = None;
+    }
+
+    /// Updates the filter banks so that they contain exactly the provided filters
+    ///
+    /// Only the first `num_banks` banks are used, and `optimized` must not contain more filters
+    /// than that. `num_banks` must not be greater than the number of banks that `filters`
+    /// controls. `select_fifo` returns the receive FIFO that each filter should put frames into.
+    ///
+    /// If the current filter configuration is unknown, this function disables the first
+    /// `num_banks` banks and then enables one bank for each filter. Otherwise, it leaves the banks
+    /// that already contain one of the filters unchanged and reconfigures or disables only the
+    /// other banks. Banks after the first `num_banks` are never changed.
+    pub fn apply<I>(
+        &mut self,
+        filters: &mut MasterFilters<'_, I>,
+        num_banks: usize,
+        optimized: &[Filter],
+        select_fifo: fn(&Filter) -> Fifo,
+    ) where
+        I: FilterOwner,
+    {
+        if self.banks.is_none() {
+            for i in 0..num_banks {
+                filters.disable_bank(i as u8);
+            }
+        }
+        let banks = self.banks.get_or_insert([None; MAX_BANKS]);
+
+        // Find the filters that are not already applied, and the banks that can be changed
+        let mut new_filters: Vec<(BankFilter, Fifo), MAX_BANKS> = Vec::new();
+        for filter in optimized {
+            let bank_filter = BankFilter::from(filter);
+            if !banks[..num_banks].contains(&Some(bank_filter)) {
+                let pushed = new_filters.push((bank_filter, select_fifo(filter)));
+                assert!(pushed.is_ok(), "More filters than filter banks");
+            }
+        }
+        let mut new_filters = new_filters.into_iter();
+        for (i, bank) in banks[..num_banks].iter_mut().enumerate() {
+            let still_needed = match bank {
+                Some(applied) => optimized
+                    .iter()
+                    .map(BankFilter::from)
+                    .any(|filter| filter == *applied),
+                None => false,
+            };
+            if still_needed {
+                continue;
+            }
+            match new_filters.next() {
+                Some((filter, fifo)) => {
+                    let id = ExtendedId::new(filter.id).unwrap();
+                    let mask = ExtendedId::new(filter.mask).unwrap();
+                    filters.enable_bank(i as u8, fifo, Mask32::frames_with_ext_id(id, mask));
+                    *bank = Some(filter);
+                }
+                None => {
+                    if bank.take().is_some() {
+                        filters.disable_bank(i as u8);
+                    }
+                }
+            }
+        }
+    }
+}
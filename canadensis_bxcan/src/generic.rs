@@ -0,0 +1,290 @@
+//!
+//! A CAN driver for any CAN controller that implements the `embedded-can` traits
+//!
+//! This is useful for microcontrollers that do not have bxCAN peripherals, but have a HAL that
+//! implements [`embedded_can::nb::Can`].
+//!
+//! Unlike [`BxCanDriver`](crate::BxCanDriver), this driver can't abort frames that miss their
+//! deadlines after they have been placed in the controller's transmit buffer. It also does not
+//! configure any hardware filters, so the controller should be set up to accept all frames with
+//! extended IDs.
+//!
+//! The deadline, loopback, and frame conversion logic in this module is also used by
+//! [`BxCanDriver`](crate::BxCanDriver).
+//!
+
+use crate::InvalidFrameFormat;
+use canadensis::core::subscription::Subscription;
+use canadensis::core::time::{Clock, Microseconds32};
+use canadensis::core::OutOfMemoryError;
+use canadensis_can::driver::{ReceiveDriver, TransmitDriver};
+use canadensis_can::{CanId, CanNodeId, Frame};
+use core::convert::TryFrom;
+use embedded_can::nb::Can;
+use embedded_can::{ExtendedId, Id};
+use heapless::{Deque, Vec};
+
+/// Maximum number of loopback frames that can be stored
+const LOOPBACK_CAPACITY: usize = 2;
+/// Maximum number of transmitted frames to remember
+///
+/// This should be at least the number of transmit buffers in the CAN controller.
+const PENDING_CAPACITY: usize = 8;
+
+/// A CAN driver that wraps any CAN controller that implements [`embedded_can::nb::Can`]
+pub struct EmbeddedCanDriver<T> {
+    can: T,
+    /// Copies of the frames most recently placed in the transmit buffer
+    ///
+    /// When the controller replaces a lower-priority frame, this is used to find the deadline
+    /// of the replaced frame.
+    pending_frames: Vec<Frame, PENDING_CAPACITY>,
+    /// Copies of transmitted loopback frames that have not yet been received
+    loopback_frames: LoopbackQueue,
+}
+
+impl<T> EmbeddedCanDriver<T>
+where
+    T: Can,
+{
+    /// Creates a CAN driver
+    pub fn new(can: T) -> Self {
+        EmbeddedCanDriver {
+            can,
+            pending_frames: Vec::new(),
+            loopback_frames: LoopbackQueue::new(),
+        }
+    }
+
+    /// Consumes this driver and returns its CAN object
+    pub fn into_can(self) -> T {
+        self.can
+    }
+
+    /// Returns a reference to the CAN object
+    pub fn can(&self) -> &T {
+        &self.can
+    }
+    /// Returns a mutable reference to the CAN object
+    pub fn can_mut(&mut self) -> &mut T {
+        &mut self.can
+    }
+
+    /// Returns true if at least one loopback frame is ready to receive
+    pub fn loopback_frame_waiting(&self) -> bool {
+        self.loopback_frames.frame_waiting()
+    }
+}
+
+impl<C, T> TransmitDriver<C> for EmbeddedCanDriver<T>
+where
+    C: Clock,
+    T: Can,
+{
+    type Error = T::Error;
+
+    fn try_reserve(&mut self, frames: usize) -> Result<(), OutOfMemoryError> {
+        if frames == 1 {
+            // There's likely space for at least one frame
+            Ok(())
+        } else {
+            // However, there is no in-memory queue.
+            Err(OutOfMemoryError)
+        }
+    }
+
+    /// Tries to place a frame in the controller's transmit buffer
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the CAN controller can't represent a frame with the length of the
+    /// provided frame.
+    fn transmit(&mut self, frame: Frame, clock: &mut C) -> nb::Result<Option<Frame>, Self::Error> {
+        let now = clock.now();
+        let can = &mut self.can;
+        let pending_frames = &mut self.pending_frames;
+        transmit_before_deadline(frame, now, &mut self.loopback_frames, |frame| {
+            let hardware_frame = cyphal_frame_to_embedded::<T::Frame>(frame);
+            let removed_frame = can.transmit(&hardware_frame)?;
+            add_pending(pending_frames, frame.clone());
+            // If the removed frame's deadline is not known, it can't be sent again later
+            Ok(
+                removed_frame
+                    .and_then(|removed_frame| take_pending(pending_frames, &removed_frame)),
+            )
+        })
+    }
+
+    fn flush(&mut self, _clock: &mut C) -> nb::Result<(), Self::Error> {
+        // The hardware does this automatically
+        Ok(())
+    }
+}
+
+impl<C, T> ReceiveDriver<C> for EmbeddedCanDriver<T>
+where
+    C: Clock,
+    T: Can,
+{
+    type Error = T::Error;
+
+    /// Tries to receive a frame from the CAN controller or the loopback frame queue
+    ///
+    /// If both loopback and non-loopback frames are waiting, this function returns a non-loopback
+    /// frame.
+    fn receive(&mut self, clock: &mut C) -> nb::Result<Frame, Self::Error> {
+        match receive_from_bus(&mut self.can, clock.now(), |_| {}) {
+            Ok(frame) => Ok(frame),
+            Err(nb::Error::Other(e)) => Err(nb::Error::Other(e)),
+            // No frames waiting from the bus. Try the loopback queue.
+            Err(nb::Error::WouldBlock) => self.loopback_frames.pop(),
+        }
+    }
+
+    /// Does nothing, because the `embedded-can` traits do not support filters
+    fn apply_filters<S>(&mut self, _local_node: Option<CanNodeId>, _subscriptions: S)
+    where
+        S: IntoIterator<Item = Subscription>,
+    {
+    }
+
+    /// Does nothing, because the `embedded-can` traits do not support filters
+    fn apply_accept_all(&mut self) {}
+}
+
+/// Remembers a frame that was just placed in the transmit buffer
+fn add_pending(pending_frames: &mut Vec<Frame, PENDING_CAPACITY>, frame: Frame) {
+    if pending_frames.is_full() {
+        pending_frames.remove(0);
+    }
+    // There is always space after removing a frame
+    let _ = pending_frames.push(frame);
+}
+
+/// Finds and removes the copy of a frame that the controller removed from its transmit buffer
+///
+/// This function returns None if the frame was not found, which means that its deadline is
+/// unknown.
+fn take_pending<F: embedded_can::Frame>(
+    pending_frames: &mut Vec<Frame, PENDING_CAPACITY>,
+    removed: &F,
+) -> Option<Frame> {
+    let id = frame_extended_id(removed)?;
+    let index = pending_frames.iter().position(|pending| {
+        u32::from(pending.id()) == id.as_raw() && pending.data() == removed.data()
+    })?;
+    Some(pending_frames.remove(index))
+}
+
+/// Copies of transmitted loopback frames that have not yet been received
+pub(crate) struct LoopbackQueue {
+    frames: Deque<Frame, LOOPBACK_CAPACITY>,
+}
+
+impl LoopbackQueue {
+    /// Creates an empty queue
+    pub(crate) fn new() -> Self {
+        LoopbackQueue {
+            frames: Deque::new(),
+        }
+    }
+
+    /// Returns true if at least one loopback frame is ready to receive
+    pub(crate) fn frame_waiting(&self) -> bool {
+        !self.frames.is_empty()
+    }
+
+    /// Removes and returns the oldest loopback frame
+    pub(crate) fn pop<E>(&mut self) -> nb::Result<Frame, E> {
+        self.frames.pop_front().ok_or(nb::Error::WouldBlock)
+    }
+}
+
+/// Transmits a frame if its deadline has not passed, and stores a copy of the frame if it is a
+/// loopback frame
+///
+/// `transmit` places a frame in the controller's transmit buffer. It returns a frame that was
+/// removed from the buffer to make space, if that frame can be sent again later.
+///
+/// The copy of a loopback frame has its timestamp set to `now`, the time just before it was
+/// sent.
+pub(crate) fn transmit_before_deadline<E, F>(
+    frame: Frame,
+    now: Microseconds32,
+    loopback_frames: &mut LoopbackQueue,
+    transmit: F,
+) -> nb::Result<Option<Frame>, E>
+where
+    F: FnOnce(&Frame) -> nb::Result<Option<Frame>, E>,
+{
+    // Check that the frame's deadline has not passed
+    if frame.timestamp() < now {
+        // Deadline passed, ignore frame
+        return Ok(None);
+    }
+    let removed_frame = transmit(&frame)?;
+    if frame.loopback() {
+        let mut loopback_frame = frame;
+        loopback_frame.set_timestamp(now);
+        // If the loopback queue is full, drop this frame
+        let _ = loopback_frames.frames.push_back(loopback_frame);
+    }
+    Ok(removed_frame)
+}
+
+/// Tries to receive a frame from a CAN controller
+///
+/// Frames that are not compatible with Cyphal are passed to `on_invalid` and skipped.
+pub(crate) fn receive_from_bus<T, F>(
+    can: &mut T,
+    now: Microseconds32,
+    mut on_invalid: F,
+) -> nb::Result<Frame, T::Error>
+where
+    T: Can,
+    F: FnMut(InvalidFrameFormat),
+{
+    loop {
+        let frame = can.receive()?;
+        match embedded_frame_to_cyphal(&frame, now) {
+            Ok(frame) => break Ok(frame),
+            // The frame is not compatible with Cyphal. Try to receive another frame.
+            Err(e) => on_invalid(e),
+        }
+    }
+}
+
+/// Returns the extended ID of a frame, or None if the frame has a standard ID
+fn frame_extended_id<F: embedded_can::Frame>(frame: &F) -> Option<ExtendedId> {
+    match frame.id() {
+        Id::Extended(id) => Some(id),
+        Id::Standard(_) => None,
+    }
+}
+
+/// Converts a Canadensis frame into an `embedded-can` frame
+///
+/// # Panics
+///
+/// This function panics if the frame type can't hold the data in the provided frame.
+pub(crate) fn cyphal_frame_to_embedded<F: embedded_can::Frame>(frame: &Frame) -> F {
+    let id = ExtendedId::new(frame.id().into()).unwrap();
+    F::new(id, frame.data()).expect("Frame data too long for CAN controller")
+}
+
+/// Converts an `embedded-can` frame into a Canadensis frame
+///
+/// This function returns an error if the frame does not have an extended ID, has an ID with an
+/// invalid format, or is a remote frame.
+pub(crate) fn embedded_frame_to_cyphal<F: embedded_can::Frame>(
+    frame: &F,
+    timestamp: Microseconds32,
+) -> Result<Frame, InvalidFrameFormat> {
+    if frame.is_remote_frame() {
+        return Err(InvalidFrameFormat::NoData);
+    }
+    let id = frame_extended_id(frame).ok_or(InvalidFrameFormat::StandardIdNotSupported)?;
+    let cyphal_id =
+        CanId::try_from(id.as_raw()).map_err(|_| InvalidFrameFormat::MalformedExtendedId)?;
+    Ok(Frame::new(timestamp, cyphal_id, frame.data()))
+}
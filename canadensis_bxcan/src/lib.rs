@@ -7,6 +7,10 @@
 //! This library provides various utilities that make it easier to use `canadensis` with the
 //! bxCAN CAN controllers found on many STM32 microcontrollers.
 //!
+//! The `generic` module also provides a driver for any CAN controller that implements the
+//! `embedded-can` traits. [`BxCanDriver`] uses the same transmit, receive, and deadline logic,
+//! and adds the bxCAN-specific mailbox and filter bank handling.
+//!
 
 extern crate alloc;
 
@@ -16,27 +20,36 @@ extern crate canadensis_can;
 extern crate canadensis_filter_config;
 extern crate canadensis_pnp_client;
 extern crate cortex_m;
+extern crate embedded_can;
 extern crate fallible_collections;
 extern crate heapless;
 extern crate log;
 extern crate nb;
 
+pub mod fd;
+mod filters;
+pub mod generic;
 pub mod pnp;
+pub mod timing;
 
-pub use bxcan::OverrunError;
+pub use crate::fd::{cyphal_frame_to_fdcan, FdFrame, PaddingError};
+pub use crate::timing::{bit_timing, BitTiming};
+pub use bxcan::{Fifo, OverrunError};
 
+use crate::filters::{FilterBanks, MAX_BANKS};
+use crate::generic::{
+    cyphal_frame_to_embedded, embedded_frame_to_cyphal, receive_from_bus, transmit_before_deadline,
+    LoopbackQueue,
+};
 use bxcan::filter::Mask32;
-use bxcan::{Can, ExtendedId, Fifo, FilterOwner, Instance, Mailbox};
+use bxcan::{Can, FilterOwner, Instance, Mailbox};
 use canadensis::core::subscription::Subscription;
 use canadensis::core::time::{Clock, Microseconds32};
 use canadensis::core::OutOfMemoryError;
 use canadensis_can::driver::{optimize_filters, ReceiveDriver, TransmitDriver};
 use canadensis_can::{CanNodeId, Frame};
-use core::convert::{Infallible, TryFrom};
-use heapless::Deque;
-
-/// Maximum number of loopback frames that can be stored
-const LOOPBACK_CAPACITY: usize = 2;
+use canadensis_filter_config::Filter;
+use core::convert::Infallible;
 
 /// A CAN driver that wraps a bxCAN device and keeps track of deadlines for queued frames
 pub struct BxCanDriver<N>
@@ -46,7 +59,17 @@ where
     can: Can<N>,
     deadlines: DeadlineTracker,
     /// Copies of transmitted loopback frames that have not yet been received
-    loopback_frames: Deque<Frame, LOOPBACK_CAPACITY>,
+    loopback_frames: LoopbackQueue,
+    /// Counts of received, rejected, and lost frames
+    receive_stats: ReceiveStats,
+    /// Counts of frames removed from transmit mailboxes
+    transmit_stats: TransmitStats,
+    /// The filters currently applied to the filter banks
+    filter_banks: FilterBanks,
+    /// Returns the receive FIFO for frames that match each filter
+    select_fifo: fn(&Filter) -> Fifo,
+    /// The largest number of filter banks to use
+    max_filter_banks: usize,
 }
 
 impl<N> BxCanDriver<N>
@@ -58,10 +81,42 @@ where
         BxCanDriver {
             can,
             deadlines: DeadlineTracker::new(),
-            loopback_frames: Deque::new(),
+            loopback_frames: LoopbackQueue::new(),
+            receive_stats: ReceiveStats::default(),
+            transmit_stats: TransmitStats::default(),
+            filter_banks: FilterBanks::new(),
+            select_fifo: |_| Fifo::Fifo0,
+            max_filter_banks: MAX_BANKS,
         }
     }
 
+    /// Sets a function that chooses the receive FIFO for frames that match each filter
+    ///
+    /// By default, all frames go into FIFO 0. On peripherals with two receive FIFOs, this can be
+    /// used to separate high-priority subjects from bulk traffic so that a flood of frames in one
+    /// FIFO does not cause frames in the other FIFO to be lost. Frames in FIFO 0 are received
+    /// before frames in FIFO 1.
+    ///
+    /// The new FIFO assignments take effect the next time the filters are applied.
+    pub fn set_fifo_selector(&mut self, select_fifo: fn(&Filter) -> Fifo) {
+        self.select_fifo = select_fifo;
+        // Reconfigure all banks next time
+        self.filter_banks.invalidate();
+    }
+
+    /// Sets the largest number of filter banks that this driver will use
+    ///
+    /// By default, the driver uses all filter banks that the peripheral assigns to it. A smaller
+    /// limit is useful when the filter banks are shared with another peripheral. The driver
+    /// always uses the banks with the lowest numbers.
+    ///
+    /// The new limit takes effect the next time the filters are applied.
+    pub fn set_max_filter_banks(&mut self, max_filter_banks: usize) {
+        self.max_filter_banks = max_filter_banks;
+        // Reconfigure all banks next time, so that banks beyond a lower limit get disabled
+        self.filter_banks.invalidate();
+    }
+
     /// Consumes this driver and returns its CAN object
     pub fn into_can(self) -> Can<N> {
         self.can
@@ -76,40 +131,45 @@ where
         &mut self.can
     }
 
+    /// Returns the counts of frames received from the bus since this driver was created or since
+    /// the last call to `reset_receive_stats()`
+    pub fn receive_stats(&self) -> &ReceiveStats {
+        &self.receive_stats
+    }
+
+    /// Sets all the receive counts to zero
+    pub fn reset_receive_stats(&mut self) {
+        self.receive_stats = ReceiveStats::default();
+    }
+
+    /// Returns the counts of frames removed from transmit mailboxes since this driver was created
+    /// or since the last call to `reset_transmit_stats()`
+    pub fn transmit_stats(&self) -> &TransmitStats {
+        &self.transmit_stats
+    }
+
+    /// Sets all the transmit counts to zero
+    pub fn reset_transmit_stats(&mut self) {
+        self.transmit_stats = TransmitStats::default();
+    }
+
     /// Returns true if at least one loopback frame is ready to receive
     pub fn loopback_frame_waiting(&self) -> bool {
-        !self.loopback_frames.is_empty()
+        self.loopback_frames.frame_waiting()
     }
 
-    /// Tries to transmit a frame, and assumes that the frame's deadline has not passed
-    fn transmit_inner<C: Clock>(
-        &mut self,
-        frame: &Frame,
-        deadline: Microseconds32,
-    ) -> nb::Result<Option<Frame>, <Self as TransmitDriver<C>>::Error> {
-        let frame = cyphal_frame_to_bxcan(frame);
-        match self.can.transmit(&frame) {
-            Ok(status) => {
-                // Store the deadline for this frame
-                let replaced_deadline = self.deadlines.replace(status.mailbox(), deadline);
-                if let (Some(removed_frame), Some(removed_frame_deadline)) =
-                    (status.dequeued_frame(), replaced_deadline)
-                {
-                    if let Ok(removed_frame) =
-                        bxcan_frame_to_cyphal(removed_frame, removed_frame_deadline)
-                    {
-                        Ok(Some(removed_frame))
-                    } else {
-                        // Frame that was removed is not compatible with Cyphal, so ignore it
-                        Ok(None)
-                    }
-                } else {
-                    // No frame was removed
-                    Ok(None)
-                }
-            }
-            Err(nb::Error::WouldBlock) => Err(nb::Error::WouldBlock),
-            Err(nb::Error::Other(infallible)) => match infallible {},
+    /// Aborts transmission of the frames in all transmit mailboxes and forgets their deadlines
+    ///
+    /// Frames that have already started transmission on the bus are not affected. This is
+    /// useful before entering a low-power mode or starting a firmware update. Frames queued
+    /// before this driver, for example in a
+    /// [`SingleQueueDriver`](canadensis_can::queue::SingleQueueDriver), can be discarded using
+    /// [`drain_queue`](canadensis_can::queue::SingleQueueDriver::drain_queue).
+    pub fn abort_all_transmissions(&mut self) {
+        for mailbox in MAILBOXES.iter() {
+            // Ignore if the mailbox is empty or the frame has been transmitted
+            self.can.abort(*mailbox);
+            self.deadlines.clear(*mailbox);
         }
     }
 }
@@ -122,27 +182,19 @@ where
         &mut self,
         clock: &mut C,
     ) -> nb::Result<Frame, <Self as ReceiveDriver<C>>::Error> {
-        loop {
-            match self.can.receive() {
-                Ok(frame) => {
-                    if let Ok(frame) = bxcan_frame_to_cyphal(&frame, clock.now()) {
-                        break Ok(frame);
-                    }
-                    // Otherwise the frame is remote or basic ID, not compatible with Cyphal.
-                    // Try to receive another frame.
-                }
-                Err(nb::Error::WouldBlock) => break Err(nb::Error::WouldBlock),
-                Err(nb::Error::Other(e)) => break Err(nb::Error::Other(e)),
+        let receive_stats = &mut self.receive_stats;
+        match receive_from_bus(&mut self.can, clock.now(), |e| {
+            receive_stats.record_invalid(e)
+        }) {
+            Ok(frame) => {
+                receive_stats.accepted = receive_stats.accepted.wrapping_add(1);
+                Ok(frame)
+            }
+            Err(nb::Error::WouldBlock) => Err(nb::Error::WouldBlock),
+            Err(nb::Error::Other(e)) => {
+                receive_stats.overruns = receive_stats.overruns.wrapping_add(1);
+                Err(nb::Error::Other(e))
             }
-        }
-    }
-    /// Tries to receive a frame from the loopback queue
-    fn receive_loopback<C: Clock>(
-        &mut self,
-    ) -> nb::Result<Frame, <Self as ReceiveDriver<C>>::Error> {
-        match self.loopback_frames.pop_front() {
-            Some(frame) => Ok(frame),
-            None => Err(nb::Error::WouldBlock),
         }
     }
 }
@@ -167,28 +219,23 @@ where
     fn transmit(&mut self, frame: Frame, clock: &mut C) -> nb::Result<Option<Frame>, Self::Error> {
         let now = clock.now();
         clean_expired_frames(&mut self.deadlines, &mut self.can, now);
-        // Check that the frame's deadline has not passed
         let deadline = frame.timestamp();
-        if deadline >= now {
-            // Deadline is now or in the future. Continue to transmit.
-            let transmit_status = self.transmit_inner::<C>(&frame, deadline);
-            if transmit_status.is_ok() && frame.loopback() {
-                // Loopback frame successfully sent; store a copy with its timestamp set to
-                // the time just before it was sent
-                let mut loopback_frame = frame;
-                loopback_frame.set_timestamp(now);
-                // If the loopback queue is full, drop this frame
-                let _ = self.loopback_frames.push_back(loopback_frame);
-            }
-            transmit_status
-        } else {
-            // Deadline passed, ignore frame
-            Ok(None)
-        }
+        let BxCanDriver {
+            can,
+            deadlines,
+            transmit_stats,
+            loopback_frames,
+            ..
+        } = self;
+        transmit_before_deadline(frame, now, loopback_frames, |frame| {
+            transmit_to_mailbox(can, deadlines, transmit_stats, frame, deadline)
+        })
     }
 
-    fn flush(&mut self, _clock: &mut C) -> nb::Result<(), Self::Error> {
-        // The hardware does this automatically
+    fn flush(&mut self, clock: &mut C) -> nb::Result<(), Self::Error> {
+        // The hardware sends frames automatically, but frames that have missed their deadlines
+        // still need to be removed
+        clean_expired_frames(&mut self.deadlines, &mut self.can, clock.now());
         Ok(())
     }
 }
@@ -211,34 +258,36 @@ where
             Ok(frame) => Ok(frame),
             Err(nb::Error::Other(e)) => Err(nb::Error::Other(e)),
             // No frames waiting from the bus. Try the loopback queue.
-            Err(nb::Error::WouldBlock) => self.receive_loopback::<C>(),
+            Err(nb::Error::WouldBlock) => self.loopback_frames.pop(),
         }
     }
 
+    /// Sets up the filter banks to accept frames matching the provided subscriptions
+    ///
+    /// The first time this function is called, it disables all filter banks that it can use (see
+    /// [`set_max_filter_banks`](BxCanDriver::set_max_filter_banks)) and then configures one bank
+    /// for each filter. After that, it only reconfigures the banks whose filters need to
+    /// change. Banks with filters that stay the same continue accepting frames while the other
+    /// banks are reconfigured.
     fn apply_filters<S>(&mut self, local_node: Option<CanNodeId>, subscriptions: S)
     where
         S: IntoIterator<Item = Subscription>,
     {
         let mut filters = self.can.modify_filters();
-        let status = optimize_filters(
-            local_node,
-            subscriptions,
-            filters.num_banks().into(),
-            |optimized| {
-                // Apply filters
-                filters.clear();
-                for (i, filter) in optimized.iter().enumerate() {
-                    let id = ExtendedId::new(filter.id()).unwrap();
-                    let mask = ExtendedId::new(filter.mask()).unwrap();
-                    filters.enable_bank(i as u8, Fifo::Fifo0, Mask32::frames_with_ext_id(id, mask));
-                }
-            },
-        );
+        let filter_banks = &mut self.filter_banks;
+        let select_fifo = self.select_fifo;
+        let num_banks = usize::from(filters.num_banks())
+            .min(MAX_BANKS)
+            .min(self.max_filter_banks);
+        let status = optimize_filters(local_node, subscriptions, num_banks, |optimized| {
+            filter_banks.apply(&mut filters, num_banks, optimized, select_fifo)
+        });
         if status.is_err() {
             // Not enough memory to apply the ideal filters. Just accept all frames.
             filters
                 .clear()
                 .enable_bank(0, Fifo::Fifo0, Mask32::accept_all());
+            filter_banks.invalidate();
         }
     }
 
@@ -247,6 +296,159 @@ where
             .modify_filters()
             .clear()
             .enable_bank(0, Fifo::Fifo0, Mask32::accept_all());
+        self.filter_banks.invalidate();
+    }
+}
+
+/// Counts of frames that a [`BxCanDriver`] has received from the CAN bus
+///
+/// All counts wrap around to zero when they overflow.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ReceiveStats {
+    overruns: u32,
+    invalid: u32,
+    standard_id: u32,
+    accepted: u32,
+}
+
+impl ReceiveStats {
+    /// Returns the number of times a receive FIFO overflowed and frames were lost
+    #[inline]
+    pub fn overruns(&self) -> u32 {
+        self.overruns
+    }
+    /// Returns the number of frames that were received but ignored because they did not have the
+    /// correct format for Cyphal (see [`InvalidFrameFormat`])
+    #[inline]
+    pub fn invalid(&self) -> u32 {
+        self.invalid
+    }
+    /// Returns the number of frames that were ignored because they had standard (11-bit) IDs
+    ///
+    /// These frames are also included in [`invalid`](#method.invalid). On a bus shared with other
+    /// protocols, the difference between the two counts is the number of frames that were
+    /// actually malformed.
+    #[inline]
+    pub fn standard_id(&self) -> u32 {
+        self.standard_id
+    }
+    /// Returns the number of frames that were received and passed on to the transport
+    #[inline]
+    pub fn accepted(&self) -> u32 {
+        self.accepted
+    }
+
+    fn record_invalid(&mut self, error: InvalidFrameFormat) {
+        self.invalid = self.invalid.wrapping_add(1);
+        if error == InvalidFrameFormat::StandardIdNotSupported {
+            self.standard_id = self.standard_id.wrapping_add(1);
+        }
+    }
+}
+
+/// Counts of frames that a [`BxCanDriver`] has removed from its transmit mailboxes to make
+/// space for higher-priority frames
+///
+/// All counts wrap around to zero when they overflow.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct TransmitStats {
+    displaced: u32,
+    dropped: u32,
+}
+
+impl TransmitStats {
+    /// Returns the number of frames that were removed from a mailbox and returned from
+    /// `transmit()` so that they can be queued again
+    #[inline]
+    pub fn displaced(&self) -> u32 {
+        self.displaced
+    }
+    /// Returns the number of frames that were removed from a mailbox and discarded because they
+    /// could not be returned
+    ///
+    /// This happens when the removed frame was placed in the mailbox by something other than
+    /// this driver, so its deadline is unknown, or when it does not have the correct format for
+    /// Cyphal.
+    #[inline]
+    pub fn dropped(&self) -> u32 {
+        self.dropped
+    }
+}
+
+/// Repeatedly flushes a driver until all its queued frames have been sent to the CAN controller or
+/// a deadline passes
+///
+/// This is useful in a simple loop that does not have anything else to do while waiting for
+/// transmit mailboxes to become available. `driver` is usually a
+/// [`SingleQueueDriver`](canadensis_can::queue::SingleQueueDriver) that contains a
+/// [`BxCanDriver`]. On each attempt, the `BxCanDriver` aborts any frames in the transmit
+/// mailboxes that have missed their deadlines.
+///
+/// This function returns `Err(nb::Error::WouldBlock)` if some frames are still queued when
+/// `deadline` passes.
+pub fn flush_blocking<C, D>(
+    driver: &mut D,
+    clock: &mut C,
+    deadline: Microseconds32,
+) -> nb::Result<(), D::Error>
+where
+    C: Clock,
+    D: TransmitDriver<C>,
+{
+    loop {
+        match driver.flush(clock) {
+            Err(nb::Error::WouldBlock) => {
+                if clock.now() > deadline {
+                    break Err(nb::Error::WouldBlock);
+                }
+            }
+            result => break result,
+        }
+    }
+}
+
+/// Tries to transmit a frame, and assumes that the frame's deadline has not passed
+fn transmit_to_mailbox<N>(
+    can: &mut Can<N>,
+    deadlines: &mut DeadlineTracker,
+    transmit_stats: &mut TransmitStats,
+    frame: &Frame,
+    deadline: Microseconds32,
+) -> nb::Result<Option<Frame>, Infallible>
+where
+    N: Instance,
+{
+    let frame = cyphal_frame_to_embedded::<bxcan::Frame>(frame);
+    match can.transmit(&frame) {
+        Ok(status) => {
+            // Store the deadline for this frame
+            let replaced_deadline = deadlines.replace(status.mailbox(), deadline);
+            match (status.dequeued_frame(), replaced_deadline) {
+                (Some(removed_frame), Some(removed_frame_deadline)) => {
+                    if let Ok(removed_frame) =
+                        embedded_frame_to_cyphal(removed_frame, removed_frame_deadline)
+                    {
+                        transmit_stats.displaced = transmit_stats.displaced.wrapping_add(1);
+                        Ok(Some(removed_frame))
+                    } else {
+                        // Frame that was removed is not compatible with Cyphal, so it can't
+                        // be returned
+                        transmit_stats.dropped = transmit_stats.dropped.wrapping_add(1);
+                        Ok(None)
+                    }
+                }
+                (Some(_), None) => {
+                    // The removed frame was not placed in the mailbox by this driver, so its
+                    // deadline is unknown and it can't be returned
+                    transmit_stats.dropped = transmit_stats.dropped.wrapping_add(1);
+                    Ok(None)
+                }
+                // No frame was removed
+                (None, _) => Ok(None),
+            }
+        }
+        Err(nb::Error::WouldBlock) => Err(nb::Error::WouldBlock),
+        Err(nb::Error::Other(infallible)) => match infallible {},
     }
 }
 
@@ -258,78 +460,117 @@ fn clean_expired_frames<C>(deadlines: &mut DeadlineTracker, can: &mut Can<C>, no
 where
     C: Instance,
 {
-    for mailbox in [Mailbox::Mailbox0, Mailbox::Mailbox1, Mailbox::Mailbox2].iter() {
-        if let Some(deadline) = deadlines.get(*mailbox) {
-            if now > deadline {
-                // Deadline has passed, abort transmission
-                // Ignore if the mailbox is really empty or the frame has been transmitted.
-                can.abort(*mailbox);
-            }
-        }
+    for index in deadlines.expired(now) {
+        // Deadline has passed, abort transmission
+        // Ignore if the mailbox is really empty or the frame has been transmitted.
+        can.abort(MAILBOXES[index]);
     }
 }
 
+/// The transmit mailboxes of a bxCAN peripheral
+const MAILBOXES: [Mailbox; BXCAN_MAILBOXES] =
+    [Mailbox::Mailbox0, Mailbox::Mailbox1, Mailbox::Mailbox2];
+
+/// The number of transmit mailboxes in a bxCAN peripheral
+pub const BXCAN_MAILBOXES: usize = 3;
+
 /// Keeps track of the deadline for each frame in a CAN transmit mailbox
 ///
-/// This struct does not have any public associated functions except `new()`.
-#[derive(Default)]
-pub struct DeadlineTracker {
-    deadlines: [Option<Microseconds32>; 3],
+/// This can be used to implement a custom transmit loop that aborts frames whose deadlines have
+/// passed.
+///
+/// `N` is the number of transmit mailboxes or buffers. The default is the number of mailboxes in
+/// a bxCAN peripheral. Other CAN controllers with more transmit buffers can use the
+/// `*_index` functions with a larger `N`.
+pub struct DeadlineTracker<const N: usize = BXCAN_MAILBOXES> {
+    deadlines: [Option<Microseconds32>; N],
 }
 
-impl DeadlineTracker {
+impl<const N: usize> DeadlineTracker<N> {
     /// Creates a deadline tracker with no deadlines
     pub fn new() -> Self {
-        DeadlineTracker::default()
+        DeadlineTracker {
+            deadlines: [None; N],
+        }
+    }
+    /// Returns the deadline for the mailbox or buffer with the provided index
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is not less than `N`.
+    pub fn get_index(&self, index: usize) -> Option<Microseconds32> {
+        self.deadlines[index]
+    }
+    /// Stores the deadline for a mailbox or buffer and returns the deadline for the previous
+    /// frame in that mailbox or buffer, if any
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is not less than `N`.
+    pub fn replace_index(
+        &mut self,
+        index: usize,
+        new_deadline: Microseconds32,
+    ) -> Option<Microseconds32> {
+        self.deadlines[index].replace(new_deadline)
     }
+    /// Removes the deadline for a mailbox or buffer and returns it, if any
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is not less than `N`.
+    pub fn clear_index(&mut self, index: usize) -> Option<Microseconds32> {
+        self.deadlines[index].take()
+    }
+    /// Returns the indices of the mailboxes or buffers that contain frames whose deadlines are
+    /// before `now`
+    pub fn expired(&self, now: Microseconds32) -> impl Iterator<Item = usize> + '_ {
+        self.deadlines
+            .iter()
+            .enumerate()
+            .filter(move |(_, deadline)| matches!(deadline, Some(deadline) if now > *deadline))
+            .map(|(index, _)| index)
+    }
+}
+
+impl DeadlineTracker<BXCAN_MAILBOXES> {
     /// Returns the deadline for a mailbox
-    pub(crate) fn get(&self, mailbox: Mailbox) -> Option<Microseconds32> {
-        self.deadlines[mailbox as usize]
+    pub fn get(&self, mailbox: Mailbox) -> Option<Microseconds32> {
+        self.get_index(mailbox as usize)
     }
     /// Stores the deadline for a mailbox and returns the deadline for the previous frame in that
     /// mailbox, if any
-    pub(crate) fn replace(
+    pub fn replace(
         &mut self,
         mailbox: Mailbox,
         new_deadline: Microseconds32,
     ) -> Option<Microseconds32> {
-        let slot = &mut self.deadlines[mailbox as usize];
-        slot.replace(new_deadline)
+        self.replace_index(mailbox as usize, new_deadline)
+    }
+    /// Removes the deadline for a mailbox and returns it, if any
+    ///
+    /// This should be called after manually aborting the frame in the mailbox.
+    pub fn clear(&mut self, mailbox: Mailbox) -> Option<Microseconds32> {
+        self.clear_index(mailbox as usize)
     }
 }
 
-/// Converts a Canadensis frame into a bxCAN frame
-///
-/// # Panics
-///
-/// This function panics if the provided frame has more than 8 bytes of data.
-fn cyphal_frame_to_bxcan(frame: &Frame) -> bxcan::Frame {
-    let bxcan_id = ExtendedId::new(frame.id().into()).unwrap();
-    let bxcan_data = bxcan::Data::new(frame.data()).expect("Frame data more than 8 bytes");
-    bxcan::Frame::new_data(bxcan_id, bxcan_data)
-}
-
-/// Converts a bxCAN frame into a Canadensis frame
-///
-/// This function returns an error if the frame does not have an extended ID, has an ID with an
-/// invalid format, or does not have any data.
-fn bxcan_frame_to_cyphal(
-    frame: &bxcan::Frame,
-    timestamp: Microseconds32,
-) -> Result<Frame, InvalidFrameFormat> {
-    let id_bits = match frame.id() {
-        bxcan::Id::Extended(extended_id) => extended_id.as_raw(),
-        bxcan::Id::Standard(_) => return Err(InvalidFrameFormat),
-    };
-    let cyphal_id = canadensis_can::CanId::try_from(id_bits).map_err(|_| InvalidFrameFormat)?;
-    let cyphal_data = frame.data().ok_or(InvalidFrameFormat)?;
-    Ok(canadensis_can::Frame::new(
-        timestamp,
-        cyphal_id,
-        cyphal_data.as_ref(),
-    ))
+impl<const N: usize> Default for DeadlineTracker<N> {
+    fn default() -> Self {
+        DeadlineTracker::new()
+    }
 }
 
 /// An error indicating that a frame did not have the correct format for use with Cyphal
-#[derive(Debug)]
-pub struct InvalidFrameFormat;
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InvalidFrameFormat {
+    /// The frame has a standard (11-bit) ID
+    ///
+    /// Cyphal/CAN uses only extended IDs, so these frames usually come from other protocols
+    /// that share the bus.
+    StandardIdNotSupported,
+    /// The frame has an extended ID, but the ID is not a valid Cyphal/CAN ID
+    MalformedExtendedId,
+    /// The frame is a remote frame, which has no data
+    NoData,
+}
@@ -0,0 +1,210 @@
+//!
+//! CAN bit timing calculation
+//!
+//! A CAN controller divides each bit into time quanta. The quanta are grouped into a
+//! synchronization segment (always one quantum), time segment 1, and time segment 2. The bus
+//! level is sampled between the two time segments.
+//!
+//! The functions in this module find a prescaler and segment lengths that produce a requested
+//! bitrate exactly from a peripheral clock frequency, with a sample point as close as possible
+//! to 87.5%. This is the sample point that CiA 301 and the Cyphal/CAN specification recommend
+//! for bitrates up to 1 Mbit/s.
+//!
+
+use core::fmt;
+
+/// The sample point to aim for, in tenths of a percent
+const TARGET_SAMPLE_POINT_PER_MILLE: u32 = 875;
+
+/// The ranges of bit timing values that a CAN controller supports
+///
+/// All values are actual lengths, not register values (which are usually one less).
+#[derive(Debug, Clone)]
+pub struct BitTimingLimits {
+    /// The largest prescaler value (the smallest is 1)
+    pub max_prescaler: u32,
+    /// The largest number of time quanta in time segment 1 (the smallest is 1)
+    pub max_seg1: u32,
+    /// The largest number of time quanta in time segment 2 (the smallest is 1)
+    pub max_seg2: u32,
+    /// The largest synchronization jump width, in time quanta
+    pub max_sjw: u32,
+}
+
+impl BitTimingLimits {
+    /// The limits of bxCAN peripherals
+    pub const BXCAN: BitTimingLimits = BitTimingLimits {
+        max_prescaler: 1024,
+        max_seg1: 16,
+        max_seg2: 8,
+        max_sjw: 4,
+    };
+    /// The limits of the nominal (arbitration) bit timing of FDCAN peripherals
+    pub const FDCAN_NOMINAL: BitTimingLimits = BitTimingLimits {
+        max_prescaler: 512,
+        max_seg1: 256,
+        max_seg2: 128,
+        max_sjw: 128,
+    };
+    /// The limits of the data bit timing of FDCAN peripherals, used for the data phase of
+    /// CAN FD frames with bit rate switching
+    pub const FDCAN_DATA: BitTimingLimits = BitTimingLimits {
+        max_prescaler: 32,
+        max_seg1: 32,
+        max_seg2: 16,
+        max_sjw: 16,
+    };
+}
+
+/// Bit timing settings for a CAN controller
+///
+/// All values are actual lengths, not register values.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BitTiming {
+    /// The number of peripheral clock cycles in each time quantum
+    pub prescaler: u32,
+    /// The length of time segment 1 (including the propagation segment), in time quanta
+    pub seg1: u32,
+    /// The length of time segment 2, in time quanta
+    pub seg2: u32,
+    /// The synchronization jump width, in time quanta
+    pub sjw: u32,
+}
+
+impl BitTiming {
+    /// Returns the number of time quanta in each bit
+    pub fn quanta_per_bit(&self) -> u32 {
+        1 + self.seg1 + self.seg2
+    }
+
+    /// Returns the sample point, in tenths of a percent of the bit time
+    pub fn sample_point_per_mille(&self) -> u32 {
+        (1 + self.seg1) * 1000 / self.quanta_per_bit()
+    }
+
+    /// Returns the value of the bxCAN bit timing register (CAN_BTR) for these settings
+    ///
+    /// The value can be passed to `bxcan::CanConfig::set_bit_timing`. The loopback and silent
+    /// mode bits are zero.
+    pub fn to_bxcan_btr(&self) -> u32 {
+        ((self.sjw - 1) << 24)
+            | ((self.seg2 - 1) << 20)
+            | ((self.seg1 - 1) << 16)
+            | (self.prescaler - 1)
+    }
+}
+
+/// An error indicating that a bitrate can't be produced exactly from a peripheral clock
+/// frequency
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BitrateError {
+    /// The peripheral clock frequency, in hertz
+    pub peripheral_clock_hz: u32,
+    /// The requested bitrate, in bits per second
+    pub bitrate: u32,
+}
+
+impl fmt::Display for BitrateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Bitrate {} bit/s can't be produced from a {} Hz clock",
+            self.bitrate, self.peripheral_clock_hz
+        )
+    }
+}
+
+/// Calculates bxCAN bit timing for a bitrate
+///
+/// * `peripheral_clock_hz`: The frequency of the clock that drives the CAN peripheral (usually
+///   the APB1 clock)
+/// * `bitrate`: The nominal bitrate, in bits per second
+///
+/// This function returns an error if the bitrate can't be produced exactly.
+///
+/// # Examples
+///
+/// ```
+/// # use canadensis_bxcan::timing::{bit_timing, BitTiming};
+/// // 1 Mbit/s from a 36 MHz clock
+/// let timing = bit_timing(36_000_000, 1_000_000).unwrap();
+/// assert_eq!(timing, BitTiming { prescaler: 2, seg1: 15, seg2: 2, sjw: 2 });
+/// assert_eq!(timing.sample_point_per_mille(), 888);
+/// assert_eq!(timing.to_bxcan_btr(), 0x011e_0001);
+///
+/// assert!(bit_timing(36_000_000, 1_234_567).is_err());
+/// ```
+///
+/// The result is usually applied when configuring the peripheral:
+///
+/// ```ignore
+/// let timing = canadensis_bxcan::timing::bit_timing(36_000_000, 1_000_000).unwrap();
+/// let can = bxcan::Can::builder(peripheral)
+///     .set_bit_timing(timing.to_bxcan_btr())
+///     .enable();
+/// ```
+pub fn bit_timing(peripheral_clock_hz: u32, bitrate: u32) -> Result<BitTiming, BitrateError> {
+    calculate_bit_timing(peripheral_clock_hz, bitrate, &BitTimingLimits::BXCAN)
+}
+
+/// Calculates bit timing for a bitrate and a CAN controller with the provided limits
+///
+/// For CAN FD, call this function once with [`BitTimingLimits::FDCAN_NOMINAL`] and the nominal
+/// bitrate, and again with [`BitTimingLimits::FDCAN_DATA`] and the data bitrate.
+///
+/// If more than one setting has the same sample point, this function chooses the one with the
+/// most time quanta per bit. The synchronization jump width is as long as time segment 2,
+/// up to the limit.
+///
+/// This function returns an error if the bitrate can't be produced exactly.
+pub fn calculate_bit_timing(
+    peripheral_clock_hz: u32,
+    bitrate: u32,
+    limits: &BitTimingLimits,
+) -> Result<BitTiming, BitrateError> {
+    let error = BitrateError {
+        peripheral_clock_hz,
+        bitrate,
+    };
+    if bitrate == 0 || !peripheral_clock_hz.is_multiple_of(bitrate) {
+        return Err(error);
+    }
+    // The number of clock cycles per bit, which is the prescaler times the quanta per bit
+    let cycles_per_bit = peripheral_clock_hz / bitrate;
+    let max_quanta = 1 + limits.max_seg1 + limits.max_seg2;
+
+    let mut best: Option<(u32, BitTiming)> = None;
+    // Try longer bits (with more quanta) first, so they win ties
+    for quanta in (3..=max_quanta).rev() {
+        if !cycles_per_bit.is_multiple_of(quanta) {
+            continue;
+        }
+        let prescaler = cycles_per_bit / quanta;
+        if prescaler > limits.max_prescaler {
+            // Larger prescalers will follow for fewer quanta
+            break;
+        }
+        for seg2 in 1..=limits.max_seg2 {
+            let seg1 = match (quanta - 1).checked_sub(seg2) {
+                Some(seg1) if (1..=limits.max_seg1).contains(&seg1) => seg1,
+                _ => continue,
+            };
+            let timing = BitTiming {
+                prescaler,
+                seg1,
+                seg2,
+                sjw: seg2.min(limits.max_sjw),
+            };
+            let distance = timing
+                .sample_point_per_mille()
+                .abs_diff(TARGET_SAMPLE_POINT_PER_MILLE);
+            if best
+                .as_ref()
+                .is_none_or(|(best_distance, _)| distance < *best_distance)
+            {
+                best = Some((distance, timing));
+            }
+        }
+    }
+    best.map(|(_, timing)| timing).ok_or(error)
+}
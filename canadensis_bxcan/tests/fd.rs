@@ -0,0 +1,161 @@
+//!
+//! CAN FD frame conversion tests
+//!
+
+extern crate canadensis;
+extern crate canadensis_bxcan;
+extern crate canadensis_can;
+
+use core::convert::{Infallible, TryFrom};
+use std::collections::VecDeque;
+
+use canadensis::core::subscription::Subscription;
+use canadensis::core::time::{milliseconds, Clock, Microseconds32};
+use canadensis::core::transfer::{Header, MessageHeader, Transfer};
+use canadensis::core::transport::{Receiver, Transmitter};
+use canadensis::core::{nb, OutOfMemoryError, Priority, SubjectId};
+use canadensis_bxcan::{cyphal_frame_to_fdcan, PaddingError};
+use canadensis_can::driver::{ReceiveDriver, TransmitDriver};
+use canadensis_can::{CanId, CanNodeId, CanReceiver, CanTransferId, CanTransmitter, Frame, Mtu};
+
+#[test]
+fn test_single_frame_padding() {
+    // 39 bytes of payload and a tail byte (start of transfer, end of transfer, toggle,
+    // transfer ID 3)
+    let mut data = [0xaau8; 40];
+    data[39] = 0xe3;
+    let frame = Frame::new(instant(0), heartbeat_id(), &data);
+
+    let fd_frame = cyphal_frame_to_fdcan(&frame).unwrap();
+    assert_eq!(14, fd_frame.dlc());
+    assert_eq!(48, fd_frame.data().len());
+    assert_eq!(8, fd_frame.padding());
+    assert_eq!(&data[..39], &fd_frame.data()[..39]);
+    assert_eq!(&[0u8; 8], &fd_frame.data()[39..47]);
+    // The tail byte is still last
+    assert_eq!(0xe3, fd_frame.data()[47]);
+}
+
+#[test]
+fn test_multi_frame_padding_rejected() {
+    // 9 bytes of payload and a tail byte (end of transfer, transfer ID 3)
+    let mut data = [0xaau8; 10];
+    data[9] = 0x43;
+    let frame = Frame::new(instant(0), heartbeat_id(), &data);
+
+    assert_eq!(Err(PaddingError), cyphal_frame_to_fdcan(&frame).map(|_| ()));
+}
+
+#[test]
+fn test_multi_frame_round_trip() {
+    let subject = SubjectId::try_from(4919u16).unwrap();
+    // 63 bytes in the first frame and 37 in the second. The transmitter pads the second frame
+    // from 40 to 48 bytes before the transfer CRC.
+    let payload: Vec<u8> = (0..100).collect();
+    let mut driver = MockDriver::default();
+    CanTransmitter::new(Mtu::CanFd64)
+        .push(
+            Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(0),
+                    transfer_id: CanTransferId::try_from(0u8).unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(59u8).unwrap()),
+                }),
+                loopback: false,
+                payload: &payload[..],
+            },
+            &mut ZeroClock,
+            &mut driver,
+        )
+        .unwrap();
+    assert_eq!(2, driver.frames.len());
+    assert_eq!(48, driver.frames[1].data().len());
+
+    // Convert the frames to CAN FD and back
+    let frames: Vec<Frame> = driver.frames.drain(..).collect();
+    for frame in frames {
+        let fd_frame = cyphal_frame_to_fdcan(&frame).unwrap();
+        assert_eq!(0, fd_frame.padding());
+        assert_eq!(frame.data(), fd_frame.data());
+        driver.frames.push_back(Frame::new(
+            frame.timestamp(),
+            fd_frame.id(),
+            fd_frame.data(),
+        ));
+    }
+
+    let mut rx = CanReceiver::new_anonymous();
+    rx.subscribe_message(subject, payload.len(), milliseconds(1000), &mut driver)
+        .unwrap();
+    let received = rx
+        .receive(&mut ZeroClock, &mut driver)
+        .unwrap()
+        .expect("Didn't get a transfer");
+    assert_eq!(payload, received.transfer.payload);
+}
+
+fn instant(ticks: u32) -> Microseconds32 {
+    Microseconds32::from_ticks(ticks)
+}
+
+/// Returns the ID of a heartbeat message from node 42
+fn heartbeat_id() -> CanId {
+    CanId::try_from(0x107d552a).unwrap()
+}
+
+/// A driver that stores frames in a `VecDeque`
+#[derive(Default)]
+struct MockDriver {
+    frames: VecDeque<Frame>,
+}
+
+impl<C> TransmitDriver<C> for MockDriver
+where
+    C: Clock,
+{
+    type Error = Infallible;
+
+    fn try_reserve(&mut self, frames: usize) -> Result<(), OutOfMemoryError> {
+        self.frames.reserve(frames);
+        Ok(())
+    }
+
+    fn transmit(&mut self, frame: Frame, _clock: &mut C) -> nb::Result<Option<Frame>, Self::Error> {
+        self.frames.push_back(frame);
+        Ok(None)
+    }
+
+    fn flush(&mut self, _clock: &mut C) -> nb::Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<C> ReceiveDriver<C> for MockDriver
+where
+    C: Clock,
+{
+    type Error = Infallible;
+
+    fn receive(&mut self, _clock: &mut C) -> nb::Result<Frame, Self::Error> {
+        self.frames.pop_front().ok_or(nb::Error::WouldBlock)
+    }
+
+    fn apply_filters<S>(&mut self, _local_node: Option<CanNodeId>, _subscriptions: S)
+    where
+        S: IntoIterator<Item = Subscription>,
+    {
+    }
+
+    fn apply_accept_all(&mut self) {}
+}
+
+/// A clock that always returns zero
+struct ZeroClock;
+
+impl Clock for ZeroClock {
+    fn now(&mut self) -> Microseconds32 {
+        instant(0)
+    }
+}
@@ -0,0 +1,179 @@
+//!
+//! Estimation of CAN bus load from the frames sent and received
+//!
+
+use canadensis_core::time::{MicrosecondDuration32, Microseconds32};
+
+use crate::data::Frame;
+
+/// Estimates the fraction of time that a CAN bus is busy, based on the frames that pass through
+/// a driver
+///
+/// Call [`record`](#method.record) for every frame sent or received, and call
+/// [`load_percent`](#method.load_percent) periodically. The load is calculated over fixed
+/// windows of time, and `load_percent` returns the load during the most recent complete window.
+///
+/// The length of each frame includes the start-of-frame bit, arbitration field, control field,
+/// CRC, ACK, end-of-frame, and interframe space. The number of stuff bits depends on the
+/// content of each frame, so this estimator uses the largest possible number. The result is
+/// therefore slightly higher than the true load.
+///
+/// CAN FD frames with more than 8 bytes of data are counted as if all their bits were sent at
+/// the nominal bit rate. If the bus uses bit rate switching, the result will be too high.
+#[derive(Debug, Clone)]
+pub struct BusLoadEstimator {
+    /// The nominal bit rate of the bus, in bits per second
+    bit_rate: u32,
+    /// The length of each window
+    window: MicrosecondDuration32,
+    /// The time when the current window started, or None if load_percent has not been called
+    window_start: Option<Microseconds32>,
+    /// The number of bits recorded in the current window
+    window_bits: u64,
+    /// The load during the most recent complete window, in percent
+    previous_load: Option<f32>,
+}
+
+impl BusLoadEstimator {
+    /// Creates an estimator
+    ///
+    /// * `bit_rate`: The nominal bit rate of the bus, in bits per second
+    /// * `window`: The length of time to accumulate frames before calculating the load
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bit_rate` is zero.
+    pub fn new(bit_rate: u32, window: MicrosecondDuration32) -> Self {
+        assert_ne!(bit_rate, 0, "Bit rate must not be zero");
+        BusLoadEstimator {
+            bit_rate,
+            window,
+            window_start: None,
+            window_bits: 0,
+            previous_load: None,
+        }
+    }
+
+    /// Records a frame that was sent or received
+    pub fn record(&mut self, frame: &Frame) {
+        self.window_bits += u64::from(frame_bits(frame.data().len()));
+    }
+
+    /// Returns the estimated bus load, in percent, during the most recent complete window
+    ///
+    /// * `now`: The current time
+    ///
+    /// The first call starts the first window. Frames recorded before the first call are ignored.
+    /// This function returns None until the first window is complete.
+    ///
+    /// If this function is called late, the window that has just ended is longer than the
+    /// configured length. The load is calculated using the actual length of the window.
+    pub fn load_percent(&mut self, now: Microseconds32) -> Option<f32> {
+        match self.window_start {
+            None => {
+                self.start_window(now);
+            }
+            Some(start) => {
+                if let Some(elapsed) = now.checked_duration_since(start) {
+                    if elapsed >= self.window {
+                        let available_bits =
+                            u64::from(self.bit_rate) * u64::from(elapsed.ticks()) / 1_000_000;
+                        self.previous_load =
+                            Some(100.0 * self.window_bits as f32 / available_bits.max(1) as f32);
+                        self.start_window(now);
+                    }
+                }
+            }
+        }
+        self.previous_load
+    }
+
+    fn start_window(&mut self, now: Microseconds32) {
+        self.window_start = Some(now);
+        self.window_bits = 0;
+    }
+}
+
+/// Returns the maximum number of bits on the bus used by an extended-ID data frame with the
+/// provided number of data bytes, including stuff bits and the interframe space
+fn frame_bits(data_length: usize) -> u32 {
+    let data_bits = 8 * data_length as u32;
+    // ACK slot and delimiter, end of frame, interframe space
+    const TRAILER_BITS: u32 = 2 + 7 + 3;
+    if data_length <= 8 {
+        // Start of frame, 11-bit base ID, SRR, IDE, 18-bit ID extension, RTR, two reserved bits,
+        // DLC, data, 15-bit CRC
+        let stuffed_bits = 1 + 11 + 1 + 1 + 18 + 1 + 2 + 4 + data_bits + 15;
+        // A stuff bit can be inserted after every 4 bits, following the first 5
+        let stuff_bits = (stuffed_bits - 1) / 4;
+        // CRC delimiter
+        stuffed_bits + stuff_bits + 1 + TRAILER_BITS
+    } else {
+        // Start of frame, 11-bit base ID, SRR, IDE, 18-bit ID extension, RRS, FDF, reserved,
+        // BRS, ESI, DLC, data
+        let stuffed_bits = 1 + 11 + 1 + 1 + 18 + 1 + 1 + 1 + 1 + 1 + 4 + data_bits;
+        let stuff_bits = (stuffed_bits - 1) / 4;
+        // Stuff count, CRC, and fixed stuff bits before the stuff count and after every
+        // 4 bits of the stuff count and CRC
+        let crc_bits = if data_length <= 16 { 17 } else { 21 };
+        let crc_field_bits = 4 + crc_bits;
+        let fixed_stuff_bits = 1 + crc_field_bits / 4;
+        // CRC delimiter
+        stuffed_bits + stuff_bits + crc_field_bits + fixed_stuff_bits + 1 + TRAILER_BITS
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{frame_bits, BusLoadEstimator};
+    use crate::data::{CanId, Frame};
+    use canadensis_core::time::{milliseconds, Microseconds32};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn classic_frame_bits() {
+        // 67 bits without stuff bits, and at most 13 stuff bits
+        assert_eq!(frame_bits(0), 80);
+        // 131 bits without stuff bits, and at most 29 stuff bits
+        assert_eq!(frame_bits(8), 160);
+    }
+
+    #[test]
+    fn load() {
+        let mut estimator = BusLoadEstimator::new(125_000, milliseconds(100));
+        let frame = Frame::new(
+            Microseconds32::from_ticks(0),
+            CanId::try_from(0x107d_552a).unwrap(),
+            &[0, 1, 2, 3, 4, 5, 6, 0xe0],
+        );
+        // Ignored, first window not started
+        estimator.record(&frame);
+        assert_eq!(estimator.load_percent(Microseconds32::from_ticks(0)), None);
+
+        // 100 ms at 125 kbit/s is 12500 bits, so 25 frames of 160 bits make 32 percent
+        for _ in 0..25 {
+            estimator.record(&frame);
+        }
+        assert_eq!(
+            estimator.load_percent(Microseconds32::from_ticks(50_000)),
+            None
+        );
+        assert_eq!(
+            estimator.load_percent(Microseconds32::from_ticks(100_000)),
+            Some(32.0)
+        );
+        // Still the result from the first window
+        assert_eq!(
+            estimator.load_percent(Microseconds32::from_ticks(150_000)),
+            Some(32.0)
+        );
+        // Called late, so the second window is 200 ms long
+        for _ in 0..25 {
+            estimator.record(&frame);
+        }
+        assert_eq!(
+            estimator.load_percent(Microseconds32::from_ticks(300_000)),
+            Some(16.0)
+        );
+    }
+}
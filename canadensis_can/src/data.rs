@@ -6,7 +6,9 @@ use core::convert::TryFrom;
 use core::fmt;
 
 use canadensis_core::time::Microseconds32;
-use canadensis_core::InvalidValue;
+use canadensis_core::{InvalidValue, Priority, ServiceId, SubjectId};
+
+use crate::types::{CanNodeId, Header};
 
 /// Bit mask for a 29-bit CAN ID
 const CAN_ID_MASK: u32 = 0x1f_ff_ff_ff;
@@ -21,6 +23,44 @@ impl fmt::Debug for CanId {
     }
 }
 
+/// Formats the decoded fields of the ID
+///
+/// Examples:
+/// * `Nominal message 7509 from 42`
+/// * `Nominal message 4919 from anonymous`
+/// * `Nominal request 430 from 123 to 42`
+impl fmt::Display for CanId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let priority = self.priority();
+        match self.kind() {
+            TransferKind::Message => {
+                let subject = self.subject_id().expect("Bug: No subject ID in message");
+                match self.source_node() {
+                    Some(source) => {
+                        write!(f, "{:?} message {} from {}", priority, subject, source)
+                    }
+                    None => write!(f, "{:?} message {} from anonymous", priority, subject),
+                }
+            }
+            kind => {
+                let kind = if kind == TransferKind::Request {
+                    "request"
+                } else {
+                    "response"
+                };
+                let service = self.service_id().expect("Bug: No service ID in service");
+                let source = self.source_node().expect("Bug: No source node in service");
+                let destination = self.dest_node().expect("Bug: No destination in service");
+                write!(
+                    f,
+                    "{:?} {} {} from {} to {}",
+                    priority, kind, service, source, destination
+                )
+            }
+        }
+    }
+}
+
 impl TryFrom<u32> for CanId {
     type Error = InvalidValue;
 
@@ -40,6 +80,93 @@ impl From<CanId> for u32 {
     }
 }
 
+/// Accessors that decode the fields of a Cyphal/CAN ID
+///
+/// These functions do not check the reserved bits. A frame with an invalid ID is ignored by
+/// the receiver, but its fields can still be inspected here.
+impl CanId {
+    /// Returns the priority of this frame
+    pub fn priority(&self) -> Priority {
+        Priority::try_from(((self.0 >> 26) & 0x7) as u8).expect("Bug: Invalid priority")
+    }
+
+    /// Returns the kind of transfer that this frame is part of
+    pub fn kind(&self) -> TransferKind {
+        if !self.is_service() {
+            TransferKind::Message
+        } else if self.0 & (1 << 24) != 0 {
+            TransferKind::Request
+        } else {
+            TransferKind::Response
+        }
+    }
+
+    /// Returns the node that sent this frame, or None if this frame is part of an anonymous
+    /// message
+    pub fn source_node(&self) -> Option<CanNodeId> {
+        if !self.is_service() && self.0 & (1 << 24) != 0 {
+            // The source node ID field contains a pseudo-ID
+            None
+        } else {
+            Some(CanNodeId::from_truncating(self.0 as u8))
+        }
+    }
+
+    /// Returns the subject ID of this frame, or None if this frame is part of a service transfer
+    pub fn subject_id(&self) -> Option<SubjectId> {
+        if self.is_service() {
+            None
+        } else {
+            Some(SubjectId::from_truncating((self.0 >> 8) as u16))
+        }
+    }
+
+    /// Returns the service ID of this frame, or None if this frame is part of a message
+    pub fn service_id(&self) -> Option<ServiceId> {
+        if self.is_service() {
+            Some(ServiceId::from_truncating((self.0 >> 14) as u16))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the node that this frame is addressed to, or None if this frame is part of
+    /// a message
+    pub fn dest_node(&self) -> Option<CanNodeId> {
+        if self.is_service() {
+            Some(CanNodeId::from_truncating((self.0 >> 7) as u8))
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if this frame is part of a service transfer
+    fn is_service(&self) -> bool {
+        self.0 & (1 << 25) != 0
+    }
+}
+
+/// Types of transfers
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TransferKind {
+    /// A message
+    Message,
+    /// A service request
+    Request,
+    /// A service response
+    Response,
+}
+
+impl TransferKind {
+    pub(crate) fn from_header(header: &Header) -> Self {
+        match header {
+            Header::Message(_) => TransferKind::Message,
+            Header::Request(_) => TransferKind::Request,
+            Header::Response(_) => TransferKind::Response,
+        }
+    }
+}
+
 /// Allowed maximum transmission unit (MTU) values
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub enum Mtu {
@@ -58,14 +185,24 @@ impl Mtu {
 }
 
 /// Maximum number of bytes in a frame
+///
+/// This is 64 bytes (enough for any CAN FD frame) if the `can-fd` feature is enabled.
 #[cfg(feature = "can-fd")]
 pub const FRAME_CAPACITY: usize = 64;
 /// Maximum number of bytes in a frame
+///
+/// This is 8 bytes (enough for any classic CAN frame) because the `can-fd` feature is not
+/// enabled. With the `can-fd` feature, it is 64 bytes.
 #[cfg(not(feature = "can-fd"))]
 pub const FRAME_CAPACITY: usize = 8;
 
 /// CAN or CAN FD data frame with up to 64 bytes of data and an extended 29-bit ID
 ///
+/// A frame can hold up to [`FRAME_CAPACITY`] bytes of data. That is 8 bytes by default, or
+/// 64 bytes if the `can-fd` feature is enabled. The data is stored inline, so every frame
+/// takes up the same amount of memory regardless of its length, and frames can be cloned
+/// cheaply (for example, to forward a received frame to several interfaces).
+///
 /// RTR/Error frames are not used and therefore not modeled here.
 /// CAN frames with 11-bit ID are not used by Cyphal/CAN and so they are not supported by the library.
 ///
@@ -113,7 +250,7 @@ impl Frame {
     /// The loopback flag is set to false.
     ///
     /// # Panics
-    /// This function will panic if the length of data is greater than FRAME_CAPACITY.
+    /// This function will panic if the length of data is greater than [`FRAME_CAPACITY`].
     pub fn new(timestamp: Microseconds32, id: CanId, data: &[u8]) -> Self {
         Frame {
             timestamp,
@@ -175,3 +312,113 @@ impl Frame {
         self.timestamp
     }
 }
+
+#[cfg(feature = "defmt")]
+mod defmt_impl {
+    use super::{CanId, Frame, TransferKind};
+    use defmt::{Format, Formatter};
+
+    /// Formats the decoded fields of the ID, like the `Display` implementation
+    impl Format for CanId {
+        fn format(&self, f: Formatter<'_>) {
+            let priority = self.priority();
+            match self.kind() {
+                TransferKind::Message => {
+                    let subject = self.subject_id().expect("Bug: No subject ID in message");
+                    match self.source_node() {
+                        Some(source) => {
+                            defmt::write!(f, "{} message {} from {}", priority, subject, source)
+                        }
+                        None => defmt::write!(f, "{} message {} from anonymous", priority, subject),
+                    }
+                }
+                kind => {
+                    let kind = if kind == TransferKind::Request {
+                        "request"
+                    } else {
+                        "response"
+                    };
+                    let service = self.service_id().expect("Bug: No service ID in service");
+                    let source = self.source_node().expect("Bug: No source node in service");
+                    let destination = self.dest_node().expect("Bug: No destination in service");
+                    defmt::write!(
+                        f,
+                        "{} {=str} {} from {} to {}",
+                        priority,
+                        kind,
+                        service,
+                        source,
+                        destination
+                    )
+                }
+            }
+        }
+    }
+
+    impl Format for Frame {
+        fn format(&self, f: Formatter<'_>) {
+            defmt::write!(
+                f,
+                "Frame {{ timestamp: {=u32}, id: {}, loopback: {=bool}, data: {=[u8]:02x} }}",
+                self.timestamp.ticks(),
+                self.id,
+                self.loopback,
+                &self.data[..]
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CanId, TransferKind};
+    use crate::CanNodeId;
+    use alloc::string::ToString;
+    use canadensis_core::{Priority, ServiceId, SubjectId};
+    use core::convert::TryFrom;
+
+    fn node(id: u8) -> Option<CanNodeId> {
+        Some(CanNodeId::try_from(id).unwrap())
+    }
+
+    #[test]
+    fn can_id_fields() {
+        // Examples from section 4.2.3 of the specification
+        // Heartbeat
+        let id = CanId::try_from(0x107d552a).unwrap();
+        assert_eq!(id.priority(), Priority::Nominal);
+        assert_eq!(id.kind(), TransferKind::Message);
+        assert_eq!(id.source_node(), node(42));
+        assert_eq!(id.subject_id(), Some(SubjectId::try_from(7509).unwrap()));
+        assert_eq!(id.service_id(), None);
+        assert_eq!(id.dest_node(), None);
+        // Anonymous string primitive
+        let id = CanId::try_from(0x11733775).unwrap();
+        assert_eq!(id.kind(), TransferKind::Message);
+        assert_eq!(id.source_node(), None);
+        assert_eq!(id.subject_id(), Some(SubjectId::try_from(4919).unwrap()));
+        // Node info request
+        let id = CanId::try_from(0x136b957b).unwrap();
+        assert_eq!(id.priority(), Priority::Nominal);
+        assert_eq!(id.kind(), TransferKind::Request);
+        assert_eq!(id.source_node(), node(123));
+        assert_eq!(id.subject_id(), None);
+        assert_eq!(id.service_id(), Some(ServiceId::try_from(430).unwrap()));
+        assert_eq!(id.dest_node(), node(42));
+        // Node info response
+        let id = CanId::try_from(0x126bbdaa).unwrap();
+        assert_eq!(id.kind(), TransferKind::Response);
+        assert_eq!(id.source_node(), node(42));
+        assert_eq!(id.service_id(), Some(ServiceId::try_from(430).unwrap()));
+        assert_eq!(id.dest_node(), node(123));
+    }
+
+    #[test]
+    fn can_id_display() {
+        let formatted = |id: u32| CanId::try_from(id).unwrap().to_string();
+        assert_eq!(formatted(0x107d552a), "Nominal message 7509 from 42");
+        assert_eq!(formatted(0x11733775), "Nominal message 4919 from anonymous");
+        assert_eq!(formatted(0x136b957b), "Nominal request 430 from 123 to 42");
+        assert_eq!(formatted(0x126bbdaa), "Nominal response 430 from 42 to 123");
+    }
+}
@@ -28,6 +28,11 @@ where
     ///
     /// If this driver does not contain a queue, this function may return an error if `frames`
     /// is greater than 1.
+    ///
+    /// After this function returns `Ok(())`, the next `frames` calls to
+    /// [`transmit`](#tymethod.transmit) must not fail because of a lack of space. The transmitter
+    /// reserves space for all the frames of a transfer before sending the first one, so a
+    /// transfer is either queued completely or not at all.
     fn try_reserve(&mut self, frames: usize) -> Result<(), OutOfMemoryError>;
 
     /// Attempts to send a frame without blocking
@@ -85,6 +90,25 @@ pub fn optimize_filters<F, S>(
 where
     F: FnOnce(&[Filter]),
     S: IntoIterator<Item = Subscription>,
+{
+    let filters = optimized_filters(local_node, subscriptions, max_filters)?;
+    f(&filters);
+    Ok(())
+}
+
+/// Creates a set of filters from the provided subscriptions, optimizes them to reduce the number
+/// of filters, and returns the resulting filters
+///
+/// This does the same thing as [`optimize_filters`], but returns the filters so that they can be
+/// logged or applied to a CAN controller that does not have a driver in this library.
+/// The returned vector contains at most `max_filters` filters.
+pub fn optimized_filters<S>(
+    local_node: Option<CanNodeId>,
+    subscriptions: S,
+    max_filters: usize,
+) -> Result<Vec<Filter>, OutOfMemoryError>
+where
+    S: IntoIterator<Item = Subscription>,
 {
     let mut filters: Vec<Filter> = Vec::new();
     for subscription in subscriptions {
@@ -92,9 +116,10 @@ where
             filters.try_push(filter)?;
         }
     }
-    let filters = optimize(&mut filters, max_filters);
-    f(filters);
-    Ok(())
+    let optimized_length = optimize(&mut filters, max_filters).len();
+    // The optimized filters are at the beginning
+    filters.truncate(optimized_length);
+    Ok(filters)
 }
 
 /// Creates and returns a filter that matches the provided subscription, or None if the subscription
@@ -154,3 +179,33 @@ fn response_filter(service: ServiceId, destination: CanNodeId) -> Filter {
     let mask: u32 = 0b0_0011_1111_1111_1111_1111_1000_0000;
     Filter::new(mask, m_id)
 }
+
+#[cfg(test)]
+mod test {
+    use super::optimized_filters;
+    use canadensis_core::subscription::Subscription;
+    use canadensis_core::{ServiceId, SubjectId};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn optimized_filters_limit() {
+        let subjects: [u16; 3] = [7509, 7510, 32];
+        let subscriptions = subjects
+            .iter()
+            .map(|&subject| Subscription::Message(SubjectId::try_from(subject).unwrap()))
+            // Without a node ID, service subscriptions have no filters
+            .chain(core::iter::once(Subscription::Request(
+                ServiceId::try_from(430).unwrap(),
+            )));
+
+        let filters = optimized_filters(None, subscriptions.clone(), 8).unwrap();
+        assert_eq!(filters.len(), 3);
+        let filters = optimized_filters(None, subscriptions, 1).unwrap();
+        assert_eq!(filters.len(), 1);
+        for &subject in subjects.iter() {
+            // Nominal priority, source node 42
+            let id = (4 << 26) | (0b11 << 21) | (u32::from(subject) << 8) | 42;
+            assert!(filters[0].accepts(id));
+        }
+    }
+}
@@ -15,13 +15,19 @@ extern crate fallible_collections;
 extern crate heapless;
 extern crate log;
 
+#[cfg(feature = "defmt")]
+extern crate defmt;
+
 pub use crate::data::*;
+pub use crate::loopback_bus::{LoopbackBus, LoopbackDriver};
 pub use crate::rx::CanReceiver;
 pub use crate::tx::CanTransmitter;
 pub use crate::types::*;
 
+pub mod bus_load;
 mod data;
 pub mod driver;
+pub mod loopback_bus;
 pub mod queue;
 pub mod redundant;
 mod rx;
@@ -6,6 +6,11 @@ use core::ptr;
 
 /// A frame queue implemented as a ring buffer in a fixed-capacity array
 ///
+/// Frames are kept in CAN ID arbitration order, so a frame with a lower CAN ID (higher priority)
+/// is removed before any frames with higher CAN IDs, even if they were added earlier. Frames
+/// with equal CAN IDs, including all frames of one transfer, are removed in the order they were
+/// added.
+///
 /// `N` is the maximum number of frames that the queue can hold. This should be at least as large
 /// as the number of frames required for the largest outgoing transfer that will be sent.
 #[derive(Debug)]
@@ -19,6 +24,10 @@ pub struct ArrayQueue<const N: usize> {
     head: usize,
     /// The number of valid frames in the queue
     length: usize,
+    /// The largest number of frames that this queue has held
+    high_water_mark: usize,
+    /// The number of frames that could not be added because this queue was full
+    dropped_frames: u64,
 }
 
 impl<const N: usize> ArrayQueue<N> {
@@ -46,6 +55,8 @@ impl<const N: usize> ArrayQueue<N> {
             items,
             head: 0,
             length: 0,
+            high_water_mark: 0,
+            dropped_frames: 0,
         }
     }
 
@@ -63,6 +74,32 @@ impl<const N: usize> ArrayQueue<N> {
         N
     }
 
+    /// Returns the largest number of frames that this queue has held since it was created
+    ///
+    /// This can be compared to [`capacity`](Self::capacity) to check how close the queue has come
+    /// to running out of space.
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water_mark
+    }
+
+    /// Returns the number of frames that could not be added to this queue because it was full
+    ///
+    /// This includes frames for which [`try_reserve`](FrameQueue::try_reserve) failed, which
+    /// means that the transfer containing those frames was not sent.
+    pub fn dropped_frames(&self) -> u64 {
+        self.dropped_frames
+    }
+
+    /// Updates the high-water mark after a frame has been added
+    fn update_high_water_mark(&mut self) {
+        self.high_water_mark = self.high_water_mark.max(self.length);
+    }
+
+    /// Records frames that could not be added because this queue was full
+    fn record_dropped(&mut self, frames: usize) {
+        self.dropped_frames = self.dropped_frames.saturating_add(frames as u64);
+    }
+
     fn increment_head(&mut self) {
         if N != 0 {
             self.head = self.head.wrapping_add(1) % N;
@@ -83,6 +120,7 @@ impl<const N: usize> FrameQueue for ArrayQueue<N> {
             Ok(())
         } else {
             // Too full, can't allocate memory
+            self.record_dropped(additional);
             Err(OutOfMemoryError)
         }
     }
@@ -93,6 +131,7 @@ impl<const N: usize> FrameQueue for ArrayQueue<N> {
 
     fn push_frame(&mut self, frame: Frame) -> Result<(), OutOfMemoryError> {
         if self.length == N {
+            self.record_dropped(1);
             Err(OutOfMemoryError)
         } else {
             let inserted_frame_id = frame.id();
@@ -100,6 +139,7 @@ impl<const N: usize> FrameQueue for ArrayQueue<N> {
             let tail_index = self.head.wrapping_add(self.length) % N;
             self.items[tail_index] = frame;
             self.length += 1;
+            self.update_high_water_mark();
 
             // Move the frame towards the front (lower index) until the frame in front of it
             // has a lesser or equal CAN ID
@@ -150,6 +190,7 @@ impl<const N: usize> FrameQueue for ArrayQueue<N> {
             self.decrement_head();
             self.items[self.head] = frame;
             self.length += 1;
+            self.update_high_water_mark();
 
             // Move the frame towards the back (higher index) until the frame behind it
             // has a greater or equal CAN ID
@@ -336,4 +377,34 @@ mod test {
         assert_eq!(queue.pop_frame(), Some(frame_with_id(128, 6)));
         assert_eq!(queue.pop_frame(), Some(frame_with_id(128, 7)));
     }
+
+    #[test]
+    fn statistics() {
+        let mut queue = ArrayQueue::<4>::new();
+        assert_eq!(queue.high_water_mark(), 0);
+        assert_eq!(queue.dropped_frames(), 0);
+
+        queue.try_reserve(3).unwrap();
+        queue.push_frame(frame_with_id(1, 0)).unwrap();
+        queue.push_frame(frame_with_id(1, 1)).unwrap();
+        queue.push_frame(frame_with_id(1, 2)).unwrap();
+        assert_eq!(queue.high_water_mark(), 3);
+
+        // A transfer with 2 frames does not fit
+        assert!(queue.try_reserve(2).is_err());
+        assert_eq!(queue.dropped_frames(), 2);
+
+        // The high-water mark does not decrease when frames are removed
+        queue.pop_frame().unwrap();
+        queue.pop_frame().unwrap();
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.high_water_mark(), 3);
+
+        queue.push_frame(frame_with_id(1, 3)).unwrap();
+        queue.push_frame(frame_with_id(1, 4)).unwrap();
+        queue.push_frame(frame_with_id(1, 5)).unwrap();
+        assert_eq!(queue.high_water_mark(), 4);
+        assert!(queue.push_frame(frame_with_id(1, 6)).is_err());
+        assert_eq!(queue.dropped_frames(), 3);
+    }
 }
@@ -70,6 +70,13 @@ impl<C, Q, D> SingleQueueDriver<C, Q, D> {
         (self.queue, self.driver)
     }
 
+    /// Returns a reference to the queue
+    ///
+    /// This can be used to check the queue's depth and other statistics.
+    pub fn queue(&self) -> &Q {
+        &self.queue
+    }
+
     /// Returns a reference to the driver
     pub fn driver(&self) -> &D {
         &self.driver
@@ -80,6 +87,24 @@ impl<C, Q, D> SingleQueueDriver<C, Q, D> {
     }
 }
 
+impl<C, Q, D> SingleQueueDriver<C, Q, D>
+where
+    Q: FrameQueue,
+{
+    /// Removes and discards all frames in the queue, and returns the number of frames removed
+    ///
+    /// This does not affect frames that have already been passed to the driver. Before shutting
+    /// down, call this function and then abort any frames that the driver or hardware is
+    /// still holding.
+    pub fn drain_queue(&mut self) -> usize {
+        let mut removed = 0;
+        while self.queue.pop_frame().is_some() {
+            removed += 1;
+        }
+        removed
+    }
+}
+
 impl<C, Q, D> TransmitDriver<C> for SingleQueueDriver<C, Q, D>
 where
     C: Clock,
@@ -185,3 +210,28 @@ where
 fn frame_is_expired(frame: &Frame, now: Microseconds32) -> bool {
     now > frame.timestamp()
 }
+
+#[cfg(test)]
+mod test {
+    use super::{ArrayQueue, FrameQueue, QueueOnlyDriver, SingleQueueDriver};
+    use crate::{CanId, Frame};
+    use canadensis_core::time::Microseconds32;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn drain_queue() {
+        let mut driver: SingleQueueDriver<(), _, _> =
+            SingleQueueDriver::new(ArrayQueue::<4>::new(), QueueOnlyDriver::<1, 1>::new());
+        for id in 0..3 {
+            let frame = Frame::new(
+                Microseconds32::from_ticks(0),
+                CanId::try_from(id).unwrap(),
+                &[],
+            );
+            driver.queue.push_frame(frame).unwrap();
+        }
+        assert_eq!(3, driver.drain_queue());
+        assert!(driver.queue().is_empty());
+        assert_eq!(0, driver.drain_queue());
+    }
+}
@@ -4,3 +4,7 @@ mod deduplicator;
 pub use self::deduplicator::Deduplicator;
 mod redundant_queue;
 pub use self::redundant_queue::RedundantDriver;
+mod redundant_receiver;
+pub use self::redundant_receiver::RedundantReceiver;
+mod redundant_transmitter;
+pub use self::redundant_transmitter::RedundantTransmitter;
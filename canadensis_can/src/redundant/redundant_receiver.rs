@@ -0,0 +1,383 @@
+use crate::driver::ReceiveDriver;
+use crate::types::{CanNodeId, CanTransferId, CanTransport, Error};
+use crate::{CanReceiver, TransferKind};
+use alloc::vec::Vec;
+use canadensis_core::time::{Clock, MicrosecondDuration32, Microseconds32};
+use canadensis_core::transfer::{ReceivedTransfer, Transfer};
+use canadensis_core::transport::Receiver;
+use canadensis_core::{OutOfMemoryError, PortId, ServiceId, ServiceSubscribeError, SubjectId};
+use core::array;
+use fallible_collections::FallibleVec;
+
+/// A receiver that receives transfers from several CAN interfaces and removes duplicates
+///
+/// Type parameters:
+/// * `C`: The clock type
+/// * `D`: The driver type for each interface
+/// * `N`: The number of interfaces (must not be 0)
+///
+/// The driver for this receiver is an array with one driver for each interface.
+///
+/// # Behavior
+///
+/// Each interface has its own [`CanReceiver`] that reassembles frames into transfers, so a frame
+/// lost on one interface does not prevent the transfer from being received on another.
+/// (The frame-level [`Deduplicator`](crate::redundant::Deduplicator) can't do this.)
+///
+/// Complete transfers are deduplicated using the transfer kind, port ID, source node ID,
+/// and transfer ID. For each combination of kind, port, and source, the receiver remembers the
+/// transfer ID and time of the most recently accepted transfer. A later transfer is accepted
+/// if its transfer ID is ahead of the remembered one (modulo 32, by less than half of the
+/// transfer ID range), or if the transfer-ID timeout of the subscription has expired since the
+/// remembered transfer. This keeps working when the transfer ID wraps around from 31 to 0.
+pub struct RedundantReceiver<C, D, const N: usize> {
+    /// A receiver for each interface
+    receivers: [CanReceiver<C, D>; N],
+    /// The transfer-ID timeout for each subscription
+    timeouts: Vec<(TransferKind, PortId, MicrosecondDuration32)>,
+    /// The most recently accepted transfer for each session
+    sessions: Vec<Session>,
+    /// The index of the interface to check first in the next call to receive()
+    next_interface: usize,
+}
+
+/// Information about the most recent transfer accepted from a session
+#[derive(Debug)]
+struct Session {
+    kind: TransferKind,
+    port: PortId,
+    source: Option<CanNodeId>,
+    transfer_id: CanTransferId,
+    timestamp: Microseconds32,
+}
+
+impl<C, D, const N: usize> RedundantReceiver<C, D, N>
+where
+    C: Clock,
+    D: ReceiveDriver<C>,
+{
+    /// Creates a redundant receiver
+    ///
+    /// id: The ID of this node. This is used to filter incoming service requests and responses.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `N` is zero.
+    pub fn new(id: CanNodeId) -> Self {
+        Self::new_inner(|| CanReceiver::new(id))
+    }
+
+    /// Creates an anonymous redundant receiver
+    ///
+    /// An anonymous receiver cannot receive service requests or responses.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `N` is zero.
+    pub fn new_anonymous() -> Self {
+        Self::new_inner(CanReceiver::new_anonymous)
+    }
+
+    fn new_inner<F>(mut make_receiver: F) -> Self
+    where
+        F: FnMut() -> CanReceiver<C, D>,
+    {
+        assert_ne!(N, 0, "Can't receive from zero interfaces");
+        RedundantReceiver {
+            receivers: array::from_fn(|_| make_receiver()),
+            timeouts: Vec::new(),
+            sessions: Vec::new(),
+            next_interface: 0,
+        }
+    }
+
+    /// Returns the receiver for an interface
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is not less than `N`.
+    pub fn interface(&self, index: usize) -> &CanReceiver<C, D> {
+        &self.receivers[index]
+    }
+
+    /// Changes the transfer-ID timeout and maximum payload size of an existing subscription on
+    /// all interfaces
+    ///
+    /// See [`CanReceiver::resubscribe`] for details.
+    pub fn resubscribe(
+        &mut self,
+        kind: TransferKind,
+        port_id: PortId,
+        payload_size_max: usize,
+        timeout: MicrosecondDuration32,
+    ) -> Result<bool, Error<D::Error>> {
+        let mut subscribed = false;
+        for receiver in self.receivers.iter_mut() {
+            subscribed |= receiver.resubscribe(kind, port_id, payload_size_max, timeout)?;
+        }
+        if subscribed {
+            for entry in self.timeouts.iter_mut() {
+                if (entry.0, entry.1) == (kind, port_id) {
+                    entry.2 = timeout;
+                }
+            }
+        }
+        Ok(subscribed)
+    }
+
+    /// Determines if a transfer should be accepted, and records it if it is accepted
+    ///
+    /// This function returns true if the transfer is not a duplicate of a transfer that was
+    /// already accepted.
+    fn accept<A>(&mut self, transfer: &Transfer<A, CanTransport>) -> Result<bool, Error<D::Error>> {
+        let kind = TransferKind::from_header(&transfer.header);
+        let port = transfer.header.port_id();
+        let source = transfer.header.source().copied();
+        let transfer_id = *transfer.header.transfer_id();
+        let timestamp = transfer.header.timestamp();
+        let timeout = self.timeout(kind, port);
+
+        let session = self.sessions.iter_mut().find(|session| {
+            session.kind == kind && session.port == port && session.source == source
+        });
+        match session {
+            Some(session) => {
+                let expired = matches!(timestamp.checked_duration_since(session.timestamp),
+                    Some(since_previous) if since_previous > timeout);
+                if expired || is_ahead(transfer_id, session.transfer_id) {
+                    session.transfer_id = transfer_id;
+                    session.timestamp = timestamp;
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+            None => {
+                self.remove_expired_sessions(timestamp);
+                FallibleVec::try_push(
+                    &mut self.sessions,
+                    Session {
+                        kind,
+                        port,
+                        source,
+                        transfer_id,
+                        timestamp,
+                    },
+                )
+                .map_err(OutOfMemoryError::from)?;
+                Ok(true)
+            }
+        }
+    }
+
+    /// Removes sessions that have not accepted any transfers within their transfer-ID timeouts
+    fn remove_expired_sessions(&mut self, now: Microseconds32) {
+        let timeouts = &self.timeouts;
+        self.sessions.retain(|session| {
+            let timeout = find_timeout(timeouts, session.kind, session.port);
+            match now.checked_duration_since(session.timestamp) {
+                Some(since_previous) => since_previous <= timeout,
+                None => true,
+            }
+        });
+    }
+
+    /// Returns the transfer-ID timeout for a port
+    fn timeout(&self, kind: TransferKind, port: PortId) -> MicrosecondDuration32 {
+        find_timeout(&self.timeouts, kind, port)
+    }
+
+    /// Records the transfer-ID timeout for a new subscription
+    fn set_timeout(
+        &mut self,
+        kind: TransferKind,
+        port: PortId,
+        timeout: MicrosecondDuration32,
+    ) -> Result<(), Error<D::Error>> {
+        self.remove_port(kind, port);
+        FallibleVec::try_push(&mut self.timeouts, (kind, port, timeout))
+            .map_err(OutOfMemoryError::from)?;
+        Ok(())
+    }
+
+    /// Removes the timeout and all sessions for a port
+    fn remove_port(&mut self, kind: TransferKind, port: PortId) {
+        self.timeouts
+            .retain(|&(other_kind, other_port, _)| (other_kind, other_port) != (kind, port));
+        self.sessions
+            .retain(|session| (session.kind, session.port) != (kind, port));
+    }
+}
+
+impl<C, D, const N: usize> Receiver<C> for RedundantReceiver<C, D, N>
+where
+    C: Clock,
+    D: ReceiveDriver<C>,
+{
+    type Transport = CanTransport;
+    type Driver = [D; N];
+    type Error = Error<D::Error>;
+
+    /// Checks for incoming frames on all interfaces and returns the first transfer that is not
+    /// a duplicate
+    ///
+    /// If an interface returns an error, this function returns the error. The next call starts
+    /// with the following interface, so an interface that always fails does not prevent reception
+    /// on the others.
+    fn receive(
+        &mut self,
+        clock: &mut C,
+        drivers: &mut [D; N],
+    ) -> Result<Option<ReceivedTransfer<Vec<u8>, CanTransport>>, Self::Error> {
+        for offset in 0..N {
+            let index = (self.next_interface + offset) % N;
+            loop {
+                match self.receivers[index].receive(clock, &mut drivers[index]) {
+                    Ok(Some(transfer)) => {
+                        if self.accept(&transfer.transfer)? {
+                            self.next_interface = index;
+                            return Ok(Some(transfer));
+                        }
+                        // Duplicate, try to get another transfer from the same interface
+                    }
+                    Ok(None) => break,
+                    Err(e) => {
+                        self.next_interface = (index + 1) % N;
+                        return Err(e);
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    fn subscribe_message(
+        &mut self,
+        subject: SubjectId,
+        payload_size_max: usize,
+        timeout: MicrosecondDuration32,
+        drivers: &mut [D; N],
+    ) -> Result<(), Self::Error> {
+        self.set_timeout(TransferKind::Message, PortId::from(subject), timeout)?;
+        for (receiver, driver) in self.receivers.iter_mut().zip(drivers.iter_mut()) {
+            receiver.subscribe_message(subject, payload_size_max, timeout, driver)?;
+        }
+        Ok(())
+    }
+
+    fn unsubscribe_message(&mut self, subject: SubjectId, drivers: &mut [D; N]) {
+        self.remove_port(TransferKind::Message, PortId::from(subject));
+        for (receiver, driver) in self.receivers.iter_mut().zip(drivers.iter_mut()) {
+            receiver.unsubscribe_message(subject, driver);
+        }
+    }
+
+    fn subscribe_request(
+        &mut self,
+        service: ServiceId,
+        payload_size_max: usize,
+        timeout: MicrosecondDuration32,
+        drivers: &mut [D; N],
+    ) -> Result<(), ServiceSubscribeError<Self::Error>> {
+        for (receiver, driver) in self.receivers.iter_mut().zip(drivers.iter_mut()) {
+            receiver.subscribe_request(service, payload_size_max, timeout, driver)?;
+        }
+        self.set_timeout(TransferKind::Request, PortId::from(service), timeout)
+            .map_err(ServiceSubscribeError::Transport)
+    }
+
+    fn unsubscribe_request(&mut self, service: ServiceId, drivers: &mut [D; N]) {
+        self.remove_port(TransferKind::Request, PortId::from(service));
+        for (receiver, driver) in self.receivers.iter_mut().zip(drivers.iter_mut()) {
+            receiver.unsubscribe_request(service, driver);
+        }
+    }
+
+    fn subscribe_response(
+        &mut self,
+        service: ServiceId,
+        payload_size_max: usize,
+        timeout: MicrosecondDuration32,
+        drivers: &mut [D; N],
+    ) -> Result<(), ServiceSubscribeError<Self::Error>> {
+        for (receiver, driver) in self.receivers.iter_mut().zip(drivers.iter_mut()) {
+            receiver.subscribe_response(service, payload_size_max, timeout, driver)?;
+        }
+        self.set_timeout(TransferKind::Response, PortId::from(service), timeout)
+            .map_err(ServiceSubscribeError::Transport)
+    }
+
+    fn unsubscribe_response(&mut self, service: ServiceId, drivers: &mut [D; N]) {
+        self.remove_port(TransferKind::Response, PortId::from(service));
+        for (receiver, driver) in self.receivers.iter_mut().zip(drivers.iter_mut()) {
+            receiver.unsubscribe_response(service, driver);
+        }
+    }
+
+    fn set_id(&mut self, id: Option<CanNodeId>) {
+        for receiver in self.receivers.iter_mut() {
+            receiver.set_id(id);
+        }
+    }
+
+    fn subscribers(&self) -> impl Iterator<Item = SubjectId> {
+        // All receivers have the same subscriptions
+        self.receivers[0].subscribers()
+    }
+
+    fn servers(&self) -> impl Iterator<Item = ServiceId> {
+        self.receivers[0].servers()
+    }
+
+    fn clean_expired(&mut self, now: Microseconds32) -> usize {
+        self.receivers
+            .iter_mut()
+            .map(|receiver| receiver.clean_expired(now))
+            .sum()
+    }
+}
+
+/// Returns the transfer-ID timeout for a port, or zero if the port has no subscription
+fn find_timeout(
+    timeouts: &[(TransferKind, PortId, MicrosecondDuration32)],
+    kind: TransferKind,
+    port: PortId,
+) -> MicrosecondDuration32 {
+    timeouts
+        .iter()
+        .find(|&&(other_kind, other_port, _)| other_kind == kind && other_port == port)
+        .map(|&(_, _, timeout)| timeout)
+        .unwrap_or(MicrosecondDuration32::from_ticks(0))
+}
+
+/// Returns true if `id` is ahead of `previous` by at least 1 and less than half of the
+/// transfer ID range, accounting for wraparound
+fn is_ahead(id: CanTransferId, previous: CanTransferId) -> bool {
+    const RANGE: u8 = CanTransferId::MAX.to_u8() + 1;
+    let distance = id.to_u8().wrapping_sub(previous.to_u8()) % RANGE;
+    distance != 0 && distance < RANGE / 2
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_ahead;
+    use crate::CanTransferId;
+    use core::convert::TryFrom;
+
+    fn id(value: u8) -> CanTransferId {
+        CanTransferId::try_from(value).unwrap()
+    }
+
+    #[test]
+    fn transfer_id_ahead() {
+        assert!(is_ahead(id(1), id(0)));
+        assert!(is_ahead(id(15), id(0)));
+        assert!(!is_ahead(id(16), id(0)));
+        assert!(!is_ahead(id(0), id(0)));
+        assert!(!is_ahead(id(30), id(31)));
+        // Wraparound
+        assert!(is_ahead(id(0), id(31)));
+        assert!(is_ahead(id(3), id(29)));
+        assert!(!is_ahead(id(29), id(3)));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::driver::TransmitDriver;
+use crate::types::{CanTransport, Error};
+use crate::{CanTransmitter, Mtu};
+use canadensis_core::nb;
+use canadensis_core::time::Clock;
+use canadensis_core::transfer::Transfer;
+use canadensis_core::transport::Transmitter;
+
+/// A transmitter that sends every transfer on several CAN interfaces
+///
+/// Type parameters:
+/// * `C`: The clock type
+/// * `D`: The driver type for each interface
+/// * `N`: The number of interfaces (must not be 0)
+///
+/// The driver for this transmitter is an array with one driver for each interface. Each transfer
+/// is broken into frames once for each interface, and the frames are passed to all drivers.
+///
+/// [`push`](Transmitter::push) and [`flush`](Transmitter::flush) return `Ok(())` if the operation
+/// succeeded on at least one interface, so a broken interface does not prevent transmission
+/// on the others.
+pub struct RedundantTransmitter<C, D, const N: usize> {
+    /// The transmitter that breaks transfers into frames
+    inner: CanTransmitter<C, D>,
+}
+
+impl<C, D, const N: usize> RedundantTransmitter<C, D, N>
+where
+    C: Clock,
+    D: TransmitDriver<C>,
+{
+    /// Creates a redundant transmitter
+    ///
+    /// mtu: The maximum number of bytes in a frame, which must be the same for all interfaces
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `N` is zero.
+    pub fn new(mtu: Mtu) -> Self {
+        assert_ne!(N, 0, "Can't transmit on zero interfaces");
+        RedundantTransmitter {
+            inner: CanTransmitter::new(mtu),
+        }
+    }
+
+    /// Sets the MTU
+    ///
+    /// This will take effect on the next call to push().
+    pub fn set_mtu(&mut self, mtu: Mtu) {
+        self.inner.set_mtu(mtu);
+    }
+}
+
+impl<C, D, const N: usize> Transmitter<C> for RedundantTransmitter<C, D, N>
+where
+    C: Clock,
+    D: TransmitDriver<C>,
+{
+    type Transport = CanTransport;
+    type Driver = [D; N];
+    type Error = Error<D::Error>;
+
+    fn push<A>(
+        &mut self,
+        transfer: Transfer<A, CanTransport>,
+        clock: &mut C,
+        drivers: &mut [D; N],
+    ) -> nb::Result<(), Self::Error>
+    where
+        A: AsRef<[u8]>,
+    {
+        let payload = transfer.payload.as_ref();
+        let results = drivers.iter_mut().map(|driver| {
+            let transfer = Transfer {
+                header: transfer.header.clone(),
+                loopback: transfer.loopback,
+                payload,
+            };
+            self.inner.push(transfer, clock, driver)
+        });
+        combine_results(results)
+    }
+
+    fn flush(&mut self, clock: &mut C, drivers: &mut [D; N]) -> nb::Result<(), Self::Error> {
+        let results = drivers
+            .iter_mut()
+            .map(|driver| self.inner.flush(clock, driver));
+        combine_results(results)
+    }
+
+    fn mtu(&self) -> usize {
+        self.inner.mtu()
+    }
+
+    fn frame_count_for(&self, payload_length: usize) -> usize {
+        self.inner.frame_count_for(payload_length)
+    }
+
+    fn is_queue_full(error: &Self::Error) -> bool {
+        matches!(error, Error::QueueFull)
+    }
+}
+
+/// Combines the results from all interfaces
+///
+/// This returns `Ok(())` if any result was successful. Otherwise, it returns the first error other
+/// than `WouldBlock`, or `WouldBlock` if all interfaces would block.
+fn combine_results<E, I>(results: I) -> nb::Result<(), E>
+where
+    I: Iterator<Item = nb::Result<(), E>>,
+{
+    let mut combined = Err(nb::Error::WouldBlock);
+    // Consume every result so that the operation runs on every interface
+    for result in results {
+        match (&combined, result) {
+            (_, Ok(())) => combined = Ok(()),
+            (Err(nb::Error::WouldBlock), Err(nb::Error::Other(e))) => {
+                combined = Err(nb::Error::Other(e))
+            }
+            _ => {}
+        }
+    }
+    combined
+}
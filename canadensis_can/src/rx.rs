@@ -13,19 +13,27 @@ use core::marker::PhantomData;
 
 use fallible_collections::FallibleVec;
 
-use crate::data::{CanId, Frame};
+use crate::data::{CanId, Frame, TransferKind};
 use crate::driver::ReceiveDriver;
 use crate::rx::session::SessionError;
 use crate::rx::subscription::{Subscription, SubscriptionError};
 use crate::types::{CanNodeId, CanTransferId, CanTransport, Error};
 use canadensis_core::time::{Clock, MicrosecondDuration32, Microseconds32};
-use canadensis_core::transfer::{Header, MessageHeader, ServiceHeader, Transfer};
+use canadensis_core::transfer::{Header, MessageHeader, ReceivedTransfer, ServiceHeader, Transfer};
 use canadensis_core::transport::Receiver;
 use canadensis_core::{
     nb, OutOfMemoryError, PortId, Priority, ServiceId, ServiceSubscribeError, SubjectId,
 };
 
 /// Handles subscriptions and assembles incoming frames into transfers
+///
+/// The receiver does not depend on the MTU. With the `can-fd` feature enabled, a subscription
+/// accepts transfers sent in classic CAN frames and transfers sent in CAN FD frames, so it works
+/// on networks where some nodes use CAN FD and others do not. Transfers from different nodes
+/// are reassembled separately, so classic CAN and CAN FD transfers can arrive at the same time.
+///
+/// The MTU of a [`CanTransmitter`](crate::CanTransmitter) only limits the size of the frames
+/// that it sends.
 #[derive(Debug)]
 pub struct CanReceiver<C, D> {
     /// Subscriptions for messages
@@ -36,6 +44,8 @@ pub struct CanReceiver<C, D> {
     subscriptions_request: Vec<Subscription>,
     /// The ID of this node, or None if this node is anonymous
     id: Option<CanNodeId>,
+    /// The payload of the transfer most recently returned from `receive_borrowed`
+    borrowed_payload: Option<Payload>,
     /// Number of transfers successfully received
     transfer_count: u64,
     /// Number of transfers that could not be received
@@ -43,6 +53,15 @@ pub struct CanReceiver<C, D> {
     /// Errors include failure to allocate memory (when handling incoming frames only), missing
     /// frames, and malformed frames.
     error_count: u64,
+    /// The maximum number of multi-frame transfers that can be reassembled at the same time
+    max_sessions: usize,
+    /// The number of multi-frame transfers that are being reassembled, in all subscriptions
+    session_count: usize,
+    /// Number of partially received transfers that were discarded to stay within `max_sessions`
+    evicted_session_count: u64,
+    /// Number of partially received transfers that were discarded because a frame was lost
+    /// or corrupted
+    aborted_transfer_count: u64,
     /// The driver that supplies incoming frames
     _driver: PhantomData<D>,
     /// The clock used to get the current time
@@ -62,20 +81,23 @@ where
         &mut self,
         clock: &mut C,
         driver: &mut Self::Driver,
-    ) -> Result<Option<Transfer<Vec<u8>, Self::Transport>>, Self::Error> {
-        // Loop until all available frames have been handled
-        loop {
-            match driver.receive(clock) {
-                Ok(frame) => {
-                    match self.accept_frame(frame) {
-                        Ok(Some(transfer)) => break Ok(Some(transfer)),
-                        Ok(None) => { /* Keep going and try another frame */ }
-                        Err(e) => break Err(e.into()),
-                    }
+    ) -> Result<Option<ReceivedTransfer<Vec<u8>, Self::Transport>>, Self::Error> {
+        match self.receive_payload(clock, driver)? {
+            Some(received) => match received.transfer.payload.into_vec() {
+                Ok(payload) => Ok(Some(ReceivedTransfer {
+                    transfer: Transfer {
+                        header: received.transfer.header,
+                        loopback: received.transfer.loopback,
+                        payload,
+                    },
+                    completion_timestamp: received.completion_timestamp,
+                })),
+                Err(e) => {
+                    self.increment_error_count();
+                    Err(e.into())
                 }
-                Err(nb::Error::WouldBlock) => break Ok(None),
-                Err(nb::Error::Other(e)) => break Err(Error::Driver(e)),
-            }
+            },
+            None => Ok(None),
         }
     }
 
@@ -224,6 +246,21 @@ where
             .iter()
             .map(|x| x.port_id().try_into().unwrap())
     }
+
+    fn clean_expired(&mut self, now: Microseconds32) -> usize {
+        let removed = self
+            .subscriptions_message
+            .iter_mut()
+            .chain(self.subscriptions_request.iter_mut())
+            .chain(self.subscriptions_response.iter_mut())
+            .map(|subscription| subscription.remove_expired_sessions(now))
+            .sum();
+        if removed != 0 {
+            log::debug!("Discarded {} expired incomplete transfers", removed);
+        }
+        self.session_count -= removed;
+        removed
+    }
 }
 
 impl<C, D> CanReceiver<C, D>
@@ -251,17 +288,140 @@ where
             subscriptions_response: Vec::new(),
             subscriptions_request: Vec::new(),
             id,
+            borrowed_payload: None,
             transfer_count: 0,
             error_count: 0,
+            max_sessions: usize::MAX,
+            session_count: 0,
+            evicted_session_count: 0,
+            aborted_transfer_count: 0,
             _driver: PhantomData,
             _clock: PhantomData,
         }
     }
 
+    /// Changes the transfer-ID timeout and maximum payload size of an existing subscription
+    ///
+    /// If `payload_size_max` is the same as before, the subscription keeps the state of transfers
+    /// that are being reassembled, and the new timeout also applies to those transfers.
+    /// Otherwise, the subscription is replaced and the partially received transfers are
+    /// discarded, as if the port had been subscribed to again.
+    ///
+    /// `port_id` is a subject ID for messages or a service ID for requests and responses.
+    ///
+    /// This function returns `Ok(false)` if there is no subscription to the port.
+    pub fn resubscribe(
+        &mut self,
+        kind: TransferKind,
+        port_id: PortId,
+        payload_size_max: usize,
+        timeout: MicrosecondDuration32,
+    ) -> Result<bool, Error<D::Error>> {
+        let subscription = self
+            .subscriptions_for_kind(kind)
+            .iter_mut()
+            .find(|sub| sub.port_id() == port_id);
+        match subscription {
+            Some(subscription) if subscription.payload_size_max() == payload_size_max => {
+                subscription.set_timeout(timeout);
+                Ok(true)
+            }
+            Some(_) => {
+                // The set of subscribed ports does not change, so the filters stay the same.
+                self.subscribe(kind, port_id, payload_size_max, timeout)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Checks for incoming frames and processes them, possibly returning a transfer with a
+    /// borrowed payload
+    ///
+    /// This works like [`Receiver::receive`], but the payload of the returned transfer refers to
+    /// a buffer inside this receiver. The payload of a single-frame transfer is not copied,
+    /// so receiving a single-frame transfer this way does not allocate any memory.
+    /// (Multi-frame transfers still need to be reassembled into an allocated buffer.)
+    ///
+    /// The payload remains valid until the next time this function is called.
+    pub fn receive_borrowed(
+        &mut self,
+        clock: &mut C,
+        driver: &mut D,
+    ) -> Result<Option<crate::types::ReceivedTransfer<&[u8]>>, Error<D::Error>> {
+        match self.receive_payload(clock, driver)? {
+            Some(received) => {
+                let payload = self.borrowed_payload.insert(received.transfer.payload);
+                Ok(Some(ReceivedTransfer {
+                    transfer: Transfer {
+                        header: received.transfer.header,
+                        loopback: received.transfer.loopback,
+                        payload: payload.as_slice(),
+                    },
+                    completion_timestamp: received.completion_timestamp,
+                }))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Checks for incoming frames and processes them, possibly returning a transfer with
+    /// a fixed-capacity payload
+    ///
+    /// This works like [`Receiver::receive`], but the payload is copied into a `heapless::Vec`
+    /// with a capacity of `N` bytes chosen at compile time. If a transfer has more than `N` bytes
+    /// of payload, this function returns `Error::Memory`. To avoid that, subscribe with a
+    /// `payload_size_max` no greater than `N`.
+    ///
+    /// Like [`receive_borrowed`](Self::receive_borrowed), receiving a single-frame transfer
+    /// this way does not allocate any memory.
+    pub fn receive_heapless<const N: usize>(
+        &mut self,
+        clock: &mut C,
+        driver: &mut D,
+    ) -> Result<Option<crate::types::ReceivedTransfer<heapless::Vec<u8, N>>>, Error<D::Error>> {
+        match self.receive_payload(clock, driver)? {
+            Some(received) => {
+                let payload = heapless::Vec::from_slice(received.transfer.payload.as_slice())
+                    .map_err(|_| Error::Memory(OutOfMemoryError))?;
+                Ok(Some(ReceivedTransfer {
+                    transfer: Transfer {
+                        header: received.transfer.header,
+                        loopback: received.transfer.loopback,
+                        payload,
+                    },
+                    completion_timestamp: received.completion_timestamp,
+                }))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Reads and handles frames until a transfer is complete or no more frames are available
+    fn receive_payload(
+        &mut self,
+        clock: &mut C,
+        driver: &mut D,
+    ) -> Result<Option<ReceivedTransfer<Payload, CanTransport>>, Error<D::Error>> {
+        // Loop until all available frames have been handled
+        loop {
+            match driver.receive(clock) {
+                Ok(frame) => {
+                    match self.accept_frame(frame) {
+                        Ok(Some(transfer)) => break Ok(Some(transfer)),
+                        Ok(None) => { /* Keep going and try another frame */ }
+                        Err(e) => break Err(e.into()),
+                    }
+                }
+                Err(nb::Error::WouldBlock) => break Ok(None),
+                Err(nb::Error::Other(e)) => break Err(Error::Driver(e)),
+            }
+        }
+    }
+
     /// Handles an incoming CAN or CAN FD frame
     ///
     /// If this frame is the last frame in a transfer, this function returns the completed transfer.
-    /// The transfer type is `Transfer<Vec<u8>>`, which owns the payload buffer.
     ///
     /// The payload of the returned transfer does not include any tail bytes or CRC.
     ///
@@ -272,7 +432,7 @@ where
     fn accept_frame(
         &mut self,
         frame: Frame,
-    ) -> Result<Option<Transfer<Vec<u8>, CanTransport>>, OutOfMemoryError> {
+    ) -> Result<Option<ReceivedTransfer<Payload, CanTransport>>, OutOfMemoryError> {
         // Part 1: basic frame checks
         let (frame_header, tail) = match Self::frame_sanity_check(&frame) {
             Some(data) => data,
@@ -308,14 +468,37 @@ where
         frame: Frame,
         frame_header: Header<CanTransport>,
         tail: TailByte,
-    ) -> Result<Option<Transfer<Vec<u8>, CanTransport>>, OutOfMemoryError> {
+    ) -> Result<Option<ReceivedTransfer<Payload, CanTransport>>, OutOfMemoryError> {
         let kind = TransferKind::from_header(&frame_header);
+        let source = frame_header.source().cloned();
+        let max_sessions = self.max_sessions;
+        let session_count = self.session_count;
         let subscriptions = self.subscriptions_for_kind(kind);
-        if let Some(subscription) = subscriptions
-            .iter_mut()
-            .find(|subscription| subscription.port_id() == frame_header.port_id())
+        if let Some(index) = subscriptions
+            .iter()
+            .position(|subscription| subscription.port_id() == frame_header.port_id())
         {
-            match subscription.accept(frame, frame_header, tail) {
+            if let Some(source) = source {
+                if session_count >= max_sessions
+                    && subscriptions[index].would_add_session(&frame, source, &tail)
+                {
+                    self.make_room_for_session();
+                }
+            }
+            let subscription = &mut self.subscriptions_for_kind(kind)[index];
+            let had_session = source.is_some_and(|source| subscription.has_session(source));
+            let aborted_before = subscription.aborted_count();
+            let status = subscription.accept(frame, frame_header, tail);
+            let aborted = subscription.aborted_count().wrapping_sub(aborted_before);
+            let has_session = source.is_some_and(|source| subscription.has_session(source));
+            // Accepting a frame can only create or remove the session for the frame's source node
+            match (had_session, has_session) {
+                (false, true) => self.session_count += 1,
+                (true, false) => self.session_count -= 1,
+                _ => {}
+            }
+            self.aborted_transfer_count = self.aborted_transfer_count.wrapping_add(aborted);
+            match status {
                 Ok(Some(transfer)) => {
                     self.increment_transfer_count();
                     Ok(Some(transfer))
@@ -388,7 +571,16 @@ where
     }
     fn unsubscribe(&mut self, kind: TransferKind, port_id: PortId) {
         let subscriptions = self.subscriptions_for_kind(kind);
-        subscriptions.retain(|sub| sub.port_id() != port_id);
+        let mut removed_sessions = 0;
+        subscriptions.retain(|sub| {
+            if sub.port_id() == port_id {
+                removed_sessions += sub.session_count();
+                false
+            } else {
+                true
+            }
+        });
+        self.session_count -= removed_sessions;
     }
 
     fn subscriptions_for_kind(&mut self, kind: TransferKind) -> &mut Vec<Subscription> {
@@ -399,6 +591,86 @@ where
         }
     }
 
+    /// Sets the maximum number of multi-frame transfers that can be reassembled at the same time,
+    /// across all subscriptions
+    ///
+    /// Each multi-frame transfer that is being reassembled uses a session, which allocates memory
+    /// for the transfer payload. When a new transfer starts and this limit has been reached,
+    /// the receiver discards the partially received transfer that started first.
+    /// [`evicted_session_count`](Self::evicted_session_count) counts these discarded transfers.
+    /// Frames that do not start a new transfer, such as duplicates and frames from the middle of
+    /// a transfer, never cause a transfer to be discarded.
+    ///
+    /// If the limit is lower than the current number of sessions, the extra sessions are
+    /// discarded when the next new transfer starts.
+    ///
+    /// By default, there is no limit.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `max_sessions` is zero.
+    pub fn set_max_sessions(&mut self, max_sessions: usize) {
+        assert_ne!(max_sessions, 0, "max_sessions must not be zero");
+        self.max_sessions = max_sessions;
+    }
+
+    /// Returns the maximum number of multi-frame transfers that can be reassembled at the same
+    /// time
+    pub fn max_sessions(&self) -> usize {
+        self.max_sessions
+    }
+
+    /// Returns the number of multi-frame transfers that are currently being reassembled
+    pub fn session_count(&self) -> usize {
+        self.session_count
+    }
+
+    /// Returns the number of partially received transfers that were discarded because
+    /// the maximum number of sessions was reached
+    pub fn evicted_session_count(&self) -> u64 {
+        self.evicted_session_count
+    }
+
+    /// Returns the number of partially received multi-frame transfers that were discarded because
+    /// a frame was lost or corrupted
+    ///
+    /// A transfer is discarded when its transfer CRC is incorrect, which happens when a frame
+    /// in the middle of the transfer is lost, or when a frame from the next transfer arrives
+    /// before the last frame of the transfer. Discarded transfers are never returned from
+    /// [`receive`](Receiver::receive).
+    ///
+    /// This can be used to monitor the quality of the link.
+    pub fn aborted_transfer_count(&self) -> u64 {
+        self.aborted_transfer_count
+    }
+
+    /// Discards the oldest sessions until a new session can be created without exceeding
+    /// `max_sessions`
+    fn make_room_for_session(&mut self) {
+        while self.session_count >= self.max_sessions {
+            let oldest = self
+                .subscriptions_message
+                .iter_mut()
+                .chain(self.subscriptions_request.iter_mut())
+                .chain(self.subscriptions_response.iter_mut())
+                .filter_map(|sub| sub.oldest_session().map(|oldest| (sub, oldest)))
+                .min_by_key(|(_, (_, timestamp))| *timestamp);
+            match oldest {
+                Some((subscription, (source, _))) => {
+                    log::debug!(
+                        "Discarding session from node {:?} on port {:?} to stay within the session limit",
+                        source,
+                        subscription.port_id()
+                    );
+                    subscription.remove_session(source);
+                    self.session_count -= 1;
+                    self.evicted_session_count = self.evicted_session_count.wrapping_add(1);
+                }
+                None => break,
+            }
+        }
+    }
+
     /// Returns the number of transfers successfully received
     pub fn transfer_count(&self) -> u64 {
         self.transfer_count
@@ -435,6 +707,39 @@ where
     }
 }
 
+/// The payload of a transfer that a subscription has received
+#[derive(Debug)]
+pub(crate) enum Payload {
+    /// The payload of a single-frame transfer, which remains in the frame
+    ///
+    /// The payload is the first `usize` bytes of the frame data.
+    SingleFrame(Frame, usize),
+    /// The payload of a multi-frame transfer, reassembled into a buffer
+    MultiFrame(Vec<u8>),
+}
+
+impl Payload {
+    /// Returns the payload bytes
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            Payload::SingleFrame(frame, length) => &frame.data()[..*length],
+            Payload::MultiFrame(payload) => payload,
+        }
+    }
+
+    /// Converts this payload into a buffer, allocating memory if needed
+    fn into_vec(self) -> Result<Vec<u8>, OutOfMemoryError> {
+        match self {
+            Payload::SingleFrame(frame, length) => {
+                let mut payload = Vec::new();
+                payload.try_extend_from_slice(&frame.data()[..length])?;
+                Ok(payload)
+            }
+            Payload::MultiFrame(payload) => Ok(payload),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum CanIdParseError {
     /// Reserved bit 23 was set
@@ -534,6 +839,24 @@ impl GetBits for u8 {
     }
 }
 
+pub(crate) struct TailByte {
+    start: bool,
+    end: bool,
+    toggle: bool,
+    transfer_id: CanTransferId,
+}
+
+impl TailByte {
+    pub fn parse(bits: u8) -> Self {
+        TailByte {
+            start: bits.bit_set(7),
+            end: bits.bit_set(6),
+            toggle: bits.bit_set(5),
+            transfer_id: (bits & 0x1f).try_into().expect("Bug: Invalid transfer ID"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -613,39 +936,3 @@ mod test {
         assert_eq!(actual_header, expected_header);
     }
 }
-
-pub(crate) struct TailByte {
-    start: bool,
-    end: bool,
-    toggle: bool,
-    transfer_id: CanTransferId,
-}
-
-impl TailByte {
-    pub fn parse(bits: u8) -> Self {
-        TailByte {
-            start: bits.bit_set(7),
-            end: bits.bit_set(6),
-            toggle: bits.bit_set(5),
-            transfer_id: (bits & 0x1f).try_into().expect("Bug: Invalid transfer ID"),
-        }
-    }
-}
-
-/// Types of transfers
-#[derive(Debug, Copy, Clone)]
-enum TransferKind {
-    Message,
-    Request,
-    Response,
-}
-
-impl TransferKind {
-    pub fn from_header(header: &Header<CanTransport>) -> Self {
-        match header {
-            Header::Message(_) => TransferKind::Message,
-            Header::Request(_) => TransferKind::Request,
-            Header::Response(_) => TransferKind::Response,
-        }
-    }
-}
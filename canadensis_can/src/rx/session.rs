@@ -1,5 +1,5 @@
 use crate::rx::buildup::{Buildup, BuildupError};
-use crate::types::{Header, Transfer};
+use crate::types::{Header, ReceivedTransfer, Transfer};
 use crate::Frame;
 use alloc::boxed::Box;
 use alloc::vec::Vec;
@@ -57,7 +57,7 @@ impl Session {
         &mut self,
         frame: Frame,
         frame_header: Header,
-    ) -> Result<Option<Transfer<Vec<u8>>>, SessionError> {
+    ) -> Result<Option<ReceivedTransfer<Vec<u8>>>, SessionError> {
         if frame.loopback() != self.loopback {
             log::info!("Frame loopback flag does not match, ignoring");
             return Ok(None);
@@ -66,7 +66,7 @@ impl Session {
         let maybe_transfer = self
             .buildup
             .add(frame.data())?
-            .map(|data| self.handle_transfer_data(data, frame_header));
+            .map(|data| self.handle_transfer_data(data, frame_header, frame.timestamp()));
         Ok(maybe_transfer)
     }
 
@@ -74,16 +74,21 @@ impl Session {
         &mut self,
         transfer_data: Vec<u8>,
         frame_header: Header,
-    ) -> Transfer<Vec<u8>> {
+        completion_timestamp: Microseconds32,
+    ) -> ReceivedTransfer<Vec<u8>> {
         // The header for the transfer has the same priority as the final frame,
-        // but the timestamp of the first frame.
+        // but the timestamp of the first frame. The completion timestamp is the timestamp of
+        // the final frame.
         let mut transfer_header = frame_header;
         transfer_header.set_timestamp(self.transfer_timestamp);
 
-        Transfer {
-            header: transfer_header,
-            loopback: self.loopback,
-            payload: transfer_data,
+        ReceivedTransfer {
+            transfer: Transfer {
+                header: transfer_header,
+                loopback: self.loopback,
+                payload: transfer_data,
+            },
+            completion_timestamp,
         }
     }
 
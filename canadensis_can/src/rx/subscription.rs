@@ -1,16 +1,15 @@
 use crate::rx::session::{Session, SessionError};
-use crate::rx::TailByte;
-use crate::types::{CanNodeId, Header, Transfer};
+use crate::rx::{Payload, TailByte};
+use crate::types::{CanNodeId, Header, ReceivedTransfer, Transfer};
 use crate::{CanTransferId, Frame};
 use alloc::boxed::Box;
-use alloc::vec::Vec;
 use canadensis_core::time::{MicrosecondDuration32, Microseconds32};
 use canadensis_core::transport::TransferId;
 use canadensis_core::{OutOfMemoryError, PortId};
 use core::array;
 use core::fmt;
 use core::fmt::Debug;
-use fallible_collections::{FallibleVec, TryReserveError};
+use fallible_collections::TryReserveError;
 
 const NUM_NODE_IDS: usize = CanNodeId::MAX.to_u8() as usize + 1;
 
@@ -45,6 +44,9 @@ pub struct Subscription {
     port_id: PortId,
     /// State information from each possible node ID
     states: SessionStates,
+    /// Number of partially received transfers that were discarded because a frame was lost
+    /// or corrupted
+    aborted_count: u64,
 }
 
 impl Debug for Subscription {
@@ -54,6 +56,7 @@ impl Debug for Subscription {
             .field("payload_size_max", &self.payload_size_max)
             .field("port_id", &self.port_id)
             .field("states", &self.states)
+            .field("aborted_count", &self.aborted_count)
             .finish()
     }
 }
@@ -69,6 +72,7 @@ impl Subscription {
             payload_size_max,
             port_id,
             states: SessionStates::new(),
+            aborted_count: 0,
         }
     }
 
@@ -78,7 +82,7 @@ impl Subscription {
         frame: Frame,
         frame_header: Header,
         tail: TailByte,
-    ) -> Result<Option<Transfer<Vec<u8>>>, SubscriptionError> {
+    ) -> Result<Option<ReceivedTransfer<Payload>>, SubscriptionError> {
         if let Some(source_node) = frame_header.source().cloned() {
             self.checked_accept_non_anonymous(frame, frame_header, source_node, tail)
         } else {
@@ -93,23 +97,60 @@ impl Subscription {
         frame_header: Header,
         source: CanNodeId,
         tail: TailByte,
-    ) -> Result<Option<Transfer<Vec<u8>>>, SubscriptionError> {
+    ) -> Result<Option<ReceivedTransfer<Payload>>, SubscriptionError> {
+        match self.check_transfer_id(source, tail.transfer_id, frame.timestamp()) {
+            TransferIdCheck::Expected => {
+                self.accept_non_anonymous(frame, frame_header, source, tail)
+            }
+            TransferIdCheck::Duplicate => Ok(None),
+            TransferIdCheck::Restart => self.restart(frame, frame_header, source, tail),
+        }
+    }
+
+    /// Compares the transfer ID of a frame from a node with the transfer ID that this
+    /// subscription expects from that node
+    fn check_transfer_id(
+        &self,
+        source: CanNodeId,
+        transfer_id: CanTransferId,
+        frame_time: Microseconds32,
+    ) -> TransferIdCheck {
         let expected_transfer_id = self.states.get(source).expected_transfer_id;
-        if tail.transfer_id == expected_transfer_id {
-            self.accept_non_anonymous(frame, frame_header, source, tail)
-        } else if tail.transfer_id.increment() == expected_transfer_id
-            && !self.has_transfer_id_timed_out(source, frame.timestamp())
+        if transfer_id == expected_transfer_id {
+            TransferIdCheck::Expected
+        } else if transfer_id.increment() == expected_transfer_id
+            && !self.has_transfer_id_timed_out(source, frame_time)
         {
             // Drop frame, as we consider this to be a duplicate transfer.
-            Ok(None)
+            TransferIdCheck::Duplicate
         } else {
             // Either the new frame has messed with the transfer ID that isn't a simple duplication
             // (e.g. they've reset the counter to some other value), or it uses the previous
-            // transfer-ID but we've timed out. Therefore, reset the session and the expected transfer-ID.
-            self.states.get_mut(source).session = None;
-            self.states.get_mut(source).expected_transfer_id = tail.transfer_id;
-            self.accept_non_anonymous(frame, frame_header, source, tail)
+            // transfer-ID but we've timed out.
+            TransferIdCheck::Restart
+        }
+    }
+
+    /// Resets the session and the expected transfer-ID for a node, and then accepts a frame
+    /// that has a new transfer ID
+    fn restart(
+        &mut self,
+        frame: Frame,
+        frame_header: Header,
+        source: CanNodeId,
+        tail: TailByte,
+    ) -> Result<Option<ReceivedTransfer<Payload>>, SubscriptionError> {
+        if self.states.get_mut(source).session.take().is_some() {
+            // The end of the previous transfer was lost
+            log::debug!(
+                "Discarding incomplete transfer from node {:?} on port {:?}",
+                source,
+                self.port_id
+            );
+            self.increment_aborted_count();
         }
+        self.states.get_mut(source).expected_transfer_id = tail.transfer_id;
+        self.accept_non_anonymous(frame, frame_header, source, tail)
     }
 
     fn has_transfer_id_timed_out(&self, source: CanNodeId, frame_time: Microseconds32) -> bool {
@@ -134,24 +175,24 @@ impl Subscription {
         frame_header: Header,
         source: CanNodeId,
         tail: TailByte,
-    ) -> Result<Option<Transfer<Vec<u8>>>, SubscriptionError> {
+    ) -> Result<Option<ReceivedTransfer<Payload>>, SubscriptionError> {
         debug_assert!(tail.transfer_id == self.states.get(source).expected_transfer_id);
         if tail.start && tail.end {
             // Special case: Everything fits into one frame, so we don't need to allocate a session
             // Make a transfer from this frame (remove the tail byte)
             let usable_data_len = self.payload_size_max.min(frame.data().len() - 1);
-            let data_without_tail = &frame.data()[..usable_data_len];
-            let mut payload = Vec::new();
-            payload.try_extend_from_slice(data_without_tail)?;
-            let transfer = Transfer {
-                header: frame_header,
-                loopback: frame.loopback(),
-                payload,
-            };
             // Record that we got a transfer with this ID
             self.states
                 .flag_successful_transfer(source, frame.timestamp());
-            Ok(Some(transfer))
+            let completion_timestamp = frame.timestamp();
+            Ok(Some(ReceivedTransfer {
+                transfer: Transfer {
+                    header: frame_header,
+                    loopback: frame.loopback(),
+                    payload: Payload::SingleFrame(frame, usable_data_len),
+                },
+                completion_timestamp,
+            }))
         } else {
             self.accept_with_session(frame, frame_header, source, tail)
         }
@@ -163,7 +204,7 @@ impl Subscription {
         frame_header: Header,
         source: CanNodeId,
         tail: TailByte,
-    ) -> Result<Option<Transfer<Vec<u8>>>, SubscriptionError> {
+    ) -> Result<Option<ReceivedTransfer<Payload>>, SubscriptionError> {
         debug_assert!(tail.transfer_id == self.states.get(source).expected_transfer_id);
         let slot: &mut Option<Box<Session>> = &mut self.states.get_mut(source).session;
         let session: &mut Box<Session> = match slot {
@@ -208,13 +249,23 @@ impl Subscription {
                 let completion_time = session.transfer_timestamp();
                 self.states
                     .flag_successful_transfer(source, completion_time);
-                Ok(Some(transfer))
+                Ok(Some(ReceivedTransfer {
+                    transfer: Transfer {
+                        header: transfer.transfer.header,
+                        loopback: transfer.transfer.loopback,
+                        payload: Payload::MultiFrame(transfer.transfer.payload),
+                    },
+                    completion_timestamp: transfer.completion_timestamp,
+                }))
             }
             Ok(None) => Ok(None),
             Err(e) => {
                 // This is either out-of-memory or an unexpected frame that invalidates the
                 // session.
                 self.states.get_mut(source).session = None;
+                if matches!(e, SessionError::Buildup) {
+                    self.increment_aborted_count();
+                }
                 Err(e.into())
             }
         }
@@ -224,20 +275,21 @@ impl Subscription {
         &mut self,
         frame: Frame,
         frame_header: Header,
-    ) -> Result<Option<Transfer<Vec<u8>>>, SubscriptionError> {
+    ) -> Result<Option<ReceivedTransfer<Payload>>, SubscriptionError> {
         // An anonymous transfer is always a single frame and does not have a corresponding session.
         // Just convert it into a transfer.
         // Remove the tail byte
-        let data_without_tail = &frame.data()[..frame.data().len() - 1];
-
-        let mut transfer_data = Vec::new();
-        transfer_data.try_extend_from_slice(data_without_tail)?;
+        let data_without_tail_len = frame.data().len() - 1;
 
         // Don't flag as successful transfer, since it's anonymous.
-        Ok(Some(Transfer {
-            header: frame_header,
-            loopback: frame.loopback(),
-            payload: transfer_data,
+        let completion_timestamp = frame.timestamp();
+        Ok(Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: frame_header,
+                loopback: frame.loopback(),
+                payload: Payload::SingleFrame(frame, data_without_tail_len),
+            },
+            completion_timestamp,
         }))
     }
 
@@ -245,6 +297,108 @@ impl Subscription {
     pub fn port_id(&self) -> PortId {
         self.port_id
     }
+
+    /// Returns the number of partially received transfers that were discarded because a frame
+    /// was lost or corrupted
+    pub fn aborted_count(&self) -> u64 {
+        self.aborted_count
+    }
+
+    fn increment_aborted_count(&mut self) {
+        self.aborted_count = self.aborted_count.wrapping_add(1);
+    }
+
+    /// Returns the number of multi-frame transfers that are being reassembled
+    pub fn session_count(&self) -> usize {
+        self.states
+            .states
+            .iter()
+            .filter(|state| state.session.is_some())
+            .count()
+    }
+
+    /// Returns true if a multi-frame transfer from the provided node is being reassembled
+    pub fn has_session(&self, source: CanNodeId) -> bool {
+        self.states.get(source).session.is_some()
+    }
+
+    /// Returns true if accepting a frame from the provided node would start reassembling a
+    /// multi-frame transfer without replacing a transfer from the same node
+    ///
+    /// This does the same transfer ID checks as accepting the frame, but does not change
+    /// anything.
+    pub(crate) fn would_add_session(
+        &self,
+        frame: &Frame,
+        source: CanNodeId,
+        tail: &TailByte,
+    ) -> bool {
+        tail.start
+            && !tail.end
+            && !self.has_session(source)
+            && !matches!(
+                self.check_transfer_id(source, tail.transfer_id, frame.timestamp()),
+                TransferIdCheck::Duplicate
+            )
+    }
+
+    /// Returns the source node and start time of the multi-frame transfer that started first,
+    /// if any
+    pub fn oldest_session(&self) -> Option<(CanNodeId, Microseconds32)> {
+        self.states
+            .states
+            .iter()
+            .enumerate()
+            .filter_map(|(index, state)| {
+                state.session.as_ref().map(|session| {
+                    (
+                        CanNodeId::from_truncating(index as u8),
+                        session.transfer_timestamp(),
+                    )
+                })
+            })
+            .min_by_key(|&(_, timestamp)| timestamp)
+    }
+
+    /// Discards the multi-frame transfer from the provided node that is being reassembled
+    pub fn remove_session(&mut self, source: CanNodeId) {
+        self.states.get_mut(source).session = None;
+    }
+
+    /// Discards the multi-frame transfers that started more than the transfer-ID timeout before
+    /// `now`, and returns the number of transfers discarded
+    pub fn remove_expired_sessions(&mut self, now: Microseconds32) -> usize {
+        let timeout = self.timeout;
+        let mut removed = 0;
+        for state in self.states.states.iter_mut() {
+            let expired = match &state.session {
+                Some(session) => match now.checked_duration_since(session.transfer_timestamp()) {
+                    Some(age) => age > timeout,
+                    None => false,
+                },
+                None => false,
+            };
+            if expired {
+                state.session = None;
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    /// Returns the maximum number of payload bytes that this subscription can receive
+    pub fn payload_size_max(&self) -> usize {
+        self.payload_size_max
+    }
+
+    /// Sets the transfer-ID timeout
+    ///
+    /// The new timeout applies immediately, including to transfers that are being reassembled.
+    /// If the timeout becomes shorter, [`remove_expired_sessions`](#method.remove_expired_sessions)
+    /// may discard a transfer that has already started.
+    pub fn set_timeout(&mut self, timeout: MicrosecondDuration32) {
+        self.timeout = timeout;
+    }
 }
 
 /// Errors that a subscription may encounter
@@ -274,6 +428,17 @@ impl From<TryReserveError> for SubscriptionError {
     }
 }
 
+/// The result of comparing the transfer ID of a frame with the expected transfer ID
+enum TransferIdCheck {
+    /// The frame belongs to the expected transfer
+    Expected,
+    /// The frame belongs to a transfer that has already been received, and should be ignored
+    Duplicate,
+    /// The frame belongs to a new transfer, and any incomplete transfer from the same node should
+    /// be discarded
+    Restart,
+}
+
 #[derive(Debug)]
 struct SessionStates {
     states: [SessionState; NUM_NODE_IDS],
@@ -2,14 +2,15 @@
 //! Cyphal message transmission
 //!
 
+use alloc::vec::Vec;
 use core::convert::TryFrom;
-use core::iter;
 use core::marker::PhantomData;
+use fallible_collections::FallibleVec;
 
-use canadensis_core::nb;
 use canadensis_core::time::{Clock, Microseconds32};
 use canadensis_core::transfer::{Header, ServiceHeader, Transfer};
-use canadensis_core::transport::Transmitter;
+use canadensis_core::transport::{StreamingTransmitter, Transmitter};
+use canadensis_core::{nb, OutOfMemoryError};
 
 use crate::data::Frame;
 use crate::driver::TransmitDriver;
@@ -52,8 +53,8 @@ where
     ///
     /// The frames can be retrieved and sent using the peek() and pop() functions.
     ///
-    /// This function returns an error if the queue does not have enough space to hold all
-    /// the required frames.
+    /// This function returns [`Error::QueueFull`] if the queue does not have enough space to
+    /// hold all the required frames. In that case, none of the frames are added to the queue.
     fn push<A>(
         &mut self,
         transfer: Transfer<A, CanTransport>,
@@ -70,26 +71,60 @@ where
             payload: transfer.payload.as_ref(),
         };
 
-        match self.push_inner(transfer, clock, driver) {
-            Ok(()) => {
-                self.transfer_count = self.transfer_count.wrapping_add(1);
-                Ok(())
-            }
-            Err(e) => {
-                self.error_count = self.error_count.wrapping_add(1);
-                Err(e)
-            }
-        }
+        let result = self.push_inner(transfer, clock, driver);
+        self.count_result(result)
     }
 
     fn flush(&mut self, clock: &mut C, driver: &mut D) -> nb::Result<(), Self::Error> {
         driver.flush(clock).map_err(|e| e.map(Error::Driver))
     }
 
+    fn is_queue_full(error: &Self::Error) -> bool {
+        matches!(error, Error::QueueFull)
+    }
+
     fn mtu(&self) -> usize {
         // Subtract 1 for the tail byte
         self.mtu - 1
     }
+
+    fn frame_count_for(&self, payload_length: usize) -> usize {
+        crate::calculate_frame_stats(payload_length, self.mtu).frames
+    }
+}
+
+impl<C, D> StreamingTransmitter<C> for CanTransmitter<C, D>
+where
+    C: Clock,
+    D: TransmitDriver<C>,
+{
+    /// Breaks a transfer into frames while its payload is being written
+    ///
+    /// Each frame is sent to the driver as soon as it is full, so only one frame needs to be
+    /// held in memory. Anonymous transfers are an exception: their pseudo-ID depends on the whole
+    /// payload, so the payload is collected into a temporary buffer first.
+    fn push_streaming<F>(
+        &mut self,
+        header: Header<CanTransport>,
+        loopback: bool,
+        payload_length: usize,
+        write_payload: F,
+        clock: &mut C,
+        driver: &mut D,
+    ) -> nb::Result<(), Self::Error>
+    where
+        F: FnOnce(&mut dyn FnMut(&[u8])),
+    {
+        let result = self.push_streaming_inner(
+            header,
+            loopback,
+            payload_length,
+            write_payload,
+            clock,
+            driver,
+        );
+        self.count_result(result)
+    }
 }
 
 impl<C, D> CanTransmitter<C, D>
@@ -123,91 +158,114 @@ where
         clock: &mut C,
         driver: &mut D,
     ) -> nb::Result<(), Error<D::Error>> {
-        let frame_stats = crate::calculate_frame_stats(transfer.payload.len(), self.mtu);
-        // Check that enough space is available in the queue for all the frames.
-        // Return an error if space is not available.
-        driver
-            .try_reserve(frame_stats.frames)
-            .map_err(|oom| nb::Error::Other(Error::Memory(oom)))?;
-
-        // Make an iterator over the payload bytes and padding. Run the CRC on that.
-        let mut crc = TransferCrc::new();
-        let payload_and_padding = transfer
-            .payload
-            .iter()
-            .cloned()
-            .chain(iter::repeat_n(0, frame_stats.last_frame_padding))
-            .inspect(|byte| crc.digest(*byte));
-        // Break into frames
-        let can_id = make_can_id(&transfer.header, transfer.payload);
-        let mut breakdown = Breakdown::new(self.mtu, *transfer.header.transfer_id());
-        let mut frames = 0;
-        // Do the non-last frames
-        for byte in payload_and_padding {
-            if let Some(frame_data) = breakdown.add(byte) {
-                // Filled up a frame
-                self.push_frame(
-                    transfer.header.timestamp(),
-                    transfer.loopback,
-                    can_id,
-                    &frame_data,
-                    driver,
-                    clock,
-                )
+        let mut writer = self.start_transfer(
+            &transfer.header,
+            transfer.loopback,
+            transfer.payload,
+            transfer.payload.len(),
+            driver,
+        )?;
+        for &byte in transfer.payload {
+            writer
+                .add(byte, clock, driver)
                 .map_err(|e| e.map(Error::Driver))?;
-                frames += 1;
-            }
         }
-        if frames != 0 {
-            // The payload + padding was split across at least one non-last frame (handled above)
-            // and the last frame (still in the Breakdown). It needs a CRC.
-            let crc_value = crc.get_crc();
-            // Add the CRC value, most significant byte first
-            let crc_bytes = [(crc_value >> 8) as u8, crc_value as u8];
-            for &byte in crc_bytes.iter() {
-                if let Some(frame_data) = breakdown.add(byte) {
-                    // Filled up a frame
-                    self.push_frame(
-                        transfer.header.timestamp(),
-                        transfer.loopback,
-                        can_id,
-                        &frame_data,
-                        driver,
-                        clock,
-                    )
-                    .map_err(|e| e.map(Error::Driver))?;
+        writer
+            .finish(clock, driver)
+            .map_err(|e| e.map(Error::Driver))
+    }
+
+    fn push_streaming_inner<F>(
+        &mut self,
+        header: Header<CanTransport>,
+        loopback: bool,
+        payload_length: usize,
+        write_payload: F,
+        clock: &mut C,
+        driver: &mut D,
+    ) -> nb::Result<(), Error<D::Error>>
+    where
+        F: FnOnce(&mut dyn FnMut(&[u8])),
+    {
+        if header.source().is_none() {
+            // The pseudo-ID of an anonymous transfer depends on the whole payload, so the
+            // payload needs to be collected first
+            let mut payload: Vec<u8> = FallibleVec::try_with_capacity(payload_length)
+                .map_err(|e| nb::Error::Other(Error::Memory(OutOfMemoryError::from(e))))?;
+            write_payload(&mut |bytes| payload.extend_from_slice(bytes));
+            return self.push_inner(
+                Transfer {
+                    header,
+                    loopback,
+                    payload: &payload,
+                },
+                clock,
+                driver,
+            );
+        }
+
+        let mut writer = self.start_transfer(&header, loopback, &[], payload_length, driver)?;
+        let mut bytes_written = 0;
+        let mut status = Ok(());
+        write_payload(&mut |bytes| {
+            bytes_written += bytes.len();
+            for &byte in bytes {
+                if status.is_ok() {
+                    status = writer.add(byte, clock, driver);
                 }
             }
-        }
-        let last_frame_data = breakdown.finish();
-        self.push_frame(
-            transfer.header.timestamp(),
-            transfer.loopback,
-            can_id,
-            &last_frame_data,
-            driver,
-            clock,
-        )
-        .map_err(|e| e.map(Error::Driver))?;
-        Ok(())
+        });
+        status.map_err(|e| e.map(Error::Driver))?;
+        assert_eq!(
+            bytes_written, payload_length,
+            "Payload length does not match the number of bytes written"
+        );
+        writer
+            .finish(clock, driver)
+            .map_err(|e| e.map(Error::Driver))
     }
 
-    /// Creates a frame and sends it to the driver to be transmitted
+    /// Reserves space for the frames of a transfer and returns a writer that can assemble them
     ///
-    /// If the driver returns a removed lower-priority frame, this function discards it.
-    fn push_frame(
-        &mut self,
-        timestamp: Microseconds32,
+    /// `payload` is only used to generate a pseudo-ID for anonymous transfers.
+    fn start_transfer(
+        &self,
+        header: &Header<CanTransport>,
         loopback: bool,
-        id: CanId,
-        data: &[u8],
+        payload: &[u8],
+        payload_length: usize,
         driver: &mut D,
-        clock: &mut C,
-    ) -> nb::Result<(), D::Error> {
-        let mut frame = Frame::new(timestamp, id, data);
-        frame.set_loopback(loopback);
-        // If a lower-priority frame was removed, drop it
-        driver.transmit(frame, clock).map(drop)
+    ) -> nb::Result<FrameWriter, Error<D::Error>> {
+        let frame_stats = crate::calculate_frame_stats(payload_length, self.mtu);
+        // Check that enough space is available in the queue for all the frames.
+        // Return an error if space is not available.
+        driver
+            .try_reserve(frame_stats.frames)
+            .map_err(|_| nb::Error::Other(Error::QueueFull))?;
+
+        Ok(FrameWriter {
+            breakdown: Breakdown::new(self.mtu, *header.transfer_id()),
+            crc: TransferCrc::new(),
+            id: make_can_id(header, payload),
+            timestamp: header.timestamp(),
+            loopback,
+            padding: frame_stats.last_frame_padding,
+            frames: 0,
+        })
+    }
+
+    /// Records the result of an attempt to send a transfer
+    fn count_result<E>(&mut self, result: nb::Result<(), E>) -> nb::Result<(), E> {
+        match result {
+            Ok(()) => {
+                self.transfer_count = self.transfer_count.wrapping_add(1);
+                Ok(())
+            }
+            Err(e) => {
+                self.error_count = self.error_count.wrapping_add(1);
+                Err(e)
+            }
+        }
     }
 
     /// Returns the number of transfers successfully transmitted
@@ -228,6 +286,93 @@ where
     }
 }
 
+/// Assembles the frames of one transfer and sends them to the driver
+struct FrameWriter {
+    breakdown: Breakdown,
+    /// The CRC of the payload and padding
+    crc: TransferCrc,
+    id: CanId,
+    timestamp: Microseconds32,
+    loopback: bool,
+    /// The number of padding bytes to add after the payload
+    padding: usize,
+    /// The number of frames sent so far
+    frames: usize,
+}
+
+impl FrameWriter {
+    /// Adds a byte of the payload and sends a frame if the byte fills one up
+    fn add<C, D>(&mut self, byte: u8, clock: &mut C, driver: &mut D) -> nb::Result<(), D::Error>
+    where
+        C: Clock,
+        D: TransmitDriver<C>,
+    {
+        self.crc.digest(byte);
+        self.add_without_crc(byte, clock, driver)
+    }
+
+    fn add_without_crc<C, D>(
+        &mut self,
+        byte: u8,
+        clock: &mut C,
+        driver: &mut D,
+    ) -> nb::Result<(), D::Error>
+    where
+        C: Clock,
+        D: TransmitDriver<C>,
+    {
+        if let Some(frame_data) = self.breakdown.add(byte) {
+            // Filled up a frame
+            self.push_frame(&frame_data, clock, driver)?;
+            self.frames += 1;
+        }
+        Ok(())
+    }
+
+    /// Adds the padding and transfer CRC and sends the remaining frames
+    fn finish<C, D>(mut self, clock: &mut C, driver: &mut D) -> nb::Result<(), D::Error>
+    where
+        C: Clock,
+        D: TransmitDriver<C>,
+    {
+        for _ in 0..self.padding {
+            self.add(0, clock, driver)?;
+        }
+        if self.frames != 0 {
+            // The payload + padding was split across at least one non-last frame (already sent)
+            // and the last frame (still in the Breakdown). It needs a CRC.
+            let crc_value = self.crc.get_crc();
+            // Add the CRC value, most significant byte first
+            self.add_without_crc((crc_value >> 8) as u8, clock, driver)?;
+            self.add_without_crc(crc_value as u8, clock, driver)?;
+        }
+        // This can't use push_frame because finishing the breakdown moves it out of self
+        let last_frame_data = self.breakdown.finish();
+        let mut frame = Frame::new(self.timestamp, self.id, &last_frame_data);
+        frame.set_loopback(self.loopback);
+        driver.transmit(frame, clock).map(drop)
+    }
+
+    /// Creates a frame and sends it to the driver to be transmitted
+    ///
+    /// If the driver returns a removed lower-priority frame, this function discards it.
+    fn push_frame<C, D>(
+        &self,
+        data: &[u8],
+        clock: &mut C,
+        driver: &mut D,
+    ) -> nb::Result<(), D::Error>
+    where
+        C: Clock,
+        D: TransmitDriver<C>,
+    {
+        let mut frame = Frame::new(self.timestamp, self.id, data);
+        frame.set_loopback(self.loopback);
+        // If a lower-priority frame was removed, drop it
+        driver.transmit(frame, clock).map(drop)
+    }
+}
+
 fn make_can_id(header: &Header<CanTransport>, payload: &[u8]) -> CanId {
     let mut bits = 0u32;
 
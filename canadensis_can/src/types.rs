@@ -18,6 +18,8 @@ impl Transport for CanTransport {
 pub type Header = canadensis_core::transfer::Header<CanTransport>;
 /// Convenience type alias for a transfer
 pub type Transfer<A> = canadensis_core::transfer::Transfer<A, CanTransport>;
+/// Convenience type alias for a received transfer
+pub type ReceivedTransfer<A> = canadensis_core::transfer::ReceivedTransfer<A, CanTransport>;
 
 const VALID_NODE_IDS: RangeInclusive<u8> = 0..=127;
 
@@ -39,6 +41,13 @@ impl CanNodeId {
         self.0
     }
 
+    /// Returns the integer value of this node ID as a u16
+    ///
+    /// This can be useful in code that also handles the larger node IDs of other transports.
+    pub const fn to_u16(self) -> u16 {
+        self.0 as u16
+    }
+
     /// Creates a valid NodeID from a u8, truncating values that are out of range
     pub const fn from_truncating(value: u8) -> Self {
         CanNodeId(value & *VALID_NODE_IDS.end())
@@ -117,7 +126,7 @@ impl From<CanNodeId> for usize {
 const VALID_TRANSFER_IDS: RangeInclusive<u8> = 0..=31;
 
 /// Transfer ID, 5 bits, in range 0..=31
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct CanTransferId(u8);
 
 impl CanTransferId {
@@ -196,6 +205,11 @@ impl Default for CanTransferId {
 pub enum Error<E> {
     /// Memory allocation failed
     Memory(OutOfMemoryError),
+    /// The driver did not have space for all the frames of an outgoing transfer
+    ///
+    /// This is usually temporary. The transfer can be sent again after some queued frames have
+    /// been transmitted.
+    QueueFull,
     /// The driver returned an error
     Driver(E),
 }
@@ -230,3 +244,20 @@ mod fmt_impl {
         }
     }
 }
+
+#[cfg(feature = "defmt")]
+mod defmt_impl {
+    use super::{CanNodeId, CanTransferId};
+    use defmt::{Format, Formatter};
+
+    impl Format for CanNodeId {
+        fn format(&self, f: Formatter<'_>) {
+            self.0.format(f)
+        }
+    }
+    impl Format for CanTransferId {
+        fn format(&self, f: Formatter<'_>) {
+            self.0.format(f)
+        }
+    }
+}
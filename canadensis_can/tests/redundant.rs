@@ -0,0 +1,206 @@
+//!
+//! Redundant transmitter and receiver integration tests
+//!
+
+extern crate canadensis_can;
+extern crate canadensis_core;
+
+use core::convert::{TryFrom, TryInto};
+use std::collections::VecDeque;
+use std::convert::Infallible;
+
+use canadensis_can::driver::{ReceiveDriver, TransmitDriver};
+use canadensis_can::redundant::{RedundantReceiver, RedundantTransmitter};
+use canadensis_can::{CanNodeId, CanTransferId, Frame, Mtu};
+use canadensis_core::subscription::Subscription;
+use canadensis_core::time::{milliseconds, Clock, Microseconds32};
+use canadensis_core::transfer::*;
+use canadensis_core::transport::{Receiver, Transmitter};
+use canadensis_core::{nb, OutOfMemoryError, Priority, SubjectId};
+
+const HEARTBEAT_CAN_ID: u32 = 0x107d552a;
+
+fn instant(ticks: u32) -> Microseconds32 {
+    Microseconds32::from_ticks(ticks)
+}
+
+#[test]
+fn transmit_on_all_interfaces() {
+    let mut drivers: [MockDriver; 2] = Default::default();
+    let mut tx = RedundantTransmitter::<ZeroClock, MockDriver, 2>::new(Mtu::Can8);
+    tx.push(
+        Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: instant(0),
+                transfer_id: CanTransferId::try_from(0).unwrap(),
+                priority: Priority::Nominal,
+                subject: SubjectId::try_from(7509).unwrap(),
+                source: Some(CanNodeId::try_from(42u8).unwrap()),
+            }),
+            loopback: false,
+            payload: &[0x00, 0x00, 0x00, 0x00, 0x04, 0x78, 0x68],
+        },
+        &mut ZeroClock,
+        &mut drivers,
+    )
+    .unwrap();
+
+    let expected = Frame::new(
+        instant(0),
+        HEARTBEAT_CAN_ID.try_into().unwrap(),
+        &[0x00, 0x00, 0x00, 0x00, 0x04, 0x78, 0x68, 0xe0],
+    );
+    for driver in drivers.iter_mut() {
+        assert_eq!(Some(expected.clone()), driver.queue.pop_front());
+        assert_eq!(None, driver.queue.pop_front());
+    }
+}
+
+#[test]
+fn remove_duplicates() {
+    let mut drivers: [StubDriver; 2] = Default::default();
+    let mut rx = make_receiver(&mut drivers);
+
+    // Both interfaces receive transfer IDs 0 and 1, and interface 1 receives its copy of
+    // transfer 0 after transfer 1 has been accepted
+    drivers[0].push(heartbeat_frame(100, 0));
+    drivers[0].push(heartbeat_frame(200, 1));
+    drivers[1].push(heartbeat_frame(101, 0));
+    drivers[1].push(heartbeat_frame(201, 1));
+    assert_eq!(vec![0, 1], receive_all(&mut rx, &mut drivers));
+
+    // A transfer received only on interface 1
+    drivers[1].push(heartbeat_frame(300, 2));
+    assert_eq!(vec![2], receive_all(&mut rx, &mut drivers));
+}
+
+#[test]
+fn transfer_id_wraparound() {
+    let mut drivers: [StubDriver; 2] = Default::default();
+    let mut rx = make_receiver(&mut drivers);
+
+    // 40 transfers, all within the transfer-ID timeout, on both interfaces
+    for i in 0..40u32 {
+        let transfer_id = (i % 32) as u8;
+        drivers[0].push(heartbeat_frame(1000 * i, transfer_id));
+        drivers[1].push(heartbeat_frame(1000 * i + 10, transfer_id));
+        assert_eq!(vec![transfer_id], receive_all(&mut rx, &mut drivers));
+    }
+}
+
+#[test]
+fn accept_after_timeout() {
+    let mut drivers: [StubDriver; 2] = Default::default();
+    let mut rx = make_receiver(&mut drivers);
+
+    drivers[0].push(heartbeat_frame(0, 5));
+    assert_eq!(vec![5], receive_all(&mut rx, &mut drivers));
+    // The same transfer ID within the timeout is a duplicate
+    drivers[1].push(heartbeat_frame(500_000, 5));
+    assert!(receive_all(&mut rx, &mut drivers).is_empty());
+    // After the timeout, the sender may have restarted
+    drivers[1].push(heartbeat_frame(1_600_000, 5));
+    assert_eq!(vec![5], receive_all(&mut rx, &mut drivers));
+}
+
+fn make_receiver(drivers: &mut [StubDriver; 2]) -> RedundantReceiver<ZeroClock, StubDriver, 2> {
+    let mut rx = RedundantReceiver::new(CanNodeId::try_from(0u8).unwrap());
+    rx.subscribe_message(
+        SubjectId::try_from(7509).unwrap(),
+        7,
+        milliseconds(1000),
+        drivers,
+    )
+    .unwrap();
+    rx
+}
+
+/// Receives transfers until no more are available, and returns their transfer IDs
+fn receive_all(
+    rx: &mut RedundantReceiver<ZeroClock, StubDriver, 2>,
+    drivers: &mut [StubDriver; 2],
+) -> Vec<u8> {
+    let mut transfer_ids = Vec::new();
+    while let Some(transfer) = rx.receive(&mut ZeroClock, drivers).unwrap() {
+        transfer_ids.push(transfer.transfer.header.transfer_id().to_u8());
+    }
+    transfer_ids
+}
+
+/// Creates a single-frame heartbeat with the provided timestamp and transfer ID
+fn heartbeat_frame(timestamp: u32, transfer_id: u8) -> Frame {
+    Frame::new(
+        instant(timestamp),
+        HEARTBEAT_CAN_ID.try_into().unwrap(),
+        &[0x00, 0x00, 0x00, 0x00, 0x04, 0x78, 0x68, 0xe0 | transfer_id],
+    )
+}
+
+#[derive(Default)]
+struct MockDriver {
+    queue: VecDeque<Frame>,
+}
+
+impl<C> TransmitDriver<C> for MockDriver
+where
+    C: Clock,
+{
+    type Error = Infallible;
+
+    fn try_reserve(&mut self, frames: usize) -> Result<(), OutOfMemoryError> {
+        self.queue.reserve(frames);
+        Ok(())
+    }
+
+    fn transmit(&mut self, frame: Frame, _clock: &mut C) -> nb::Result<Option<Frame>, Self::Error> {
+        self.queue.push_back(frame);
+        Ok(None)
+    }
+
+    fn flush(&mut self, _clock: &mut C) -> nb::Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct StubDriver {
+    frames: VecDeque<Frame>,
+}
+
+impl StubDriver {
+    fn push(&mut self, frame: Frame) {
+        self.frames.push_back(frame)
+    }
+}
+
+impl<C> ReceiveDriver<C> for StubDriver
+where
+    C: Clock,
+{
+    type Error = ();
+
+    fn receive(&mut self, _clock: &mut C) -> nb::Result<Frame, Self::Error> {
+        self.frames.pop_front().ok_or(nb::Error::WouldBlock)
+    }
+
+    fn apply_filters<S>(&mut self, _local_node: Option<CanNodeId>, _subscriptions: S)
+    where
+        S: IntoIterator<Item = Subscription>,
+    {
+        // Nothing to do
+    }
+
+    fn apply_accept_all(&mut self) {
+        // Nothing to do
+    }
+}
+
+/// A clock that produces a Microseconds32 value that is always zero
+#[derive(Default)]
+struct ZeroClock;
+
+impl Clock for ZeroClock {
+    fn now(&mut self) -> Microseconds32 {
+        Microseconds32::from_ticks(0)
+    }
+}
@@ -10,14 +10,14 @@ use std::cell::Cell;
 use std::collections::{HashMap, VecDeque};
 use std::iter;
 
-use canadensis_can::driver::ReceiveDriver;
+use canadensis_can::driver::{ReceiveDriver, TransmitDriver};
 use canadensis_can::{CanId, CanNodeId, CanReceiver, Frame, FRAME_CAPACITY};
 use canadensis_core::nb;
 use canadensis_core::subscription::Subscription;
 use canadensis_core::time::{milliseconds, Clock, MicrosecondDuration32, Microseconds32};
 use canadensis_core::transfer::*;
 use canadensis_core::transport::Receiver;
-use canadensis_core::{InvalidValue, Priority, ServiceId, SubjectId};
+use canadensis_core::{InvalidValue, OutOfMemoryError, Priority, ServiceId, SubjectId};
 
 type TestInstant = Microseconds32;
 type TestDuration = MicrosecondDuration32;
@@ -49,19 +49,94 @@ fn test_heartbeat() {
         .receive(&mut clock.make_clock(), &mut driver)
         .unwrap()
         .expect("Didn't get a transfer");
-    let expected = Transfer {
-        header: Header::Message(MessageHeader {
-            timestamp: instant(42),
-            transfer_id: 0.try_into().unwrap(),
-            priority: Priority::Nominal,
-            subject: heartbeat_subject,
-            source: Some(42u8.try_into().unwrap()),
-        }),
-        loopback: false,
-        payload: vec![0x00, 0x00, 0x00, 0x00, 0x04, 0x78, 0x68],
+    let expected = ReceivedTransfer {
+        transfer: Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: instant(42),
+                transfer_id: 0.try_into().unwrap(),
+                priority: Priority::Nominal,
+                subject: heartbeat_subject,
+                source: Some(42u8.try_into().unwrap()),
+            }),
+            loopback: false,
+            payload: vec![0x00, 0x00, 0x00, 0x00, 0x04, 0x78, 0x68],
+        },
+        completion_timestamp: instant(42),
+    };
+    assert_eq!(expected, transfer);
+}
+
+#[test]
+fn test_heartbeat_borrowed() {
+    let mut driver = StubDriver::default();
+    let mut rx = CanReceiver::new(0u8.try_into().unwrap());
+
+    let heartbeat_subject = SubjectId::try_from(7509).unwrap();
+    rx.subscribe_message(heartbeat_subject, 7, duration(0), &mut driver)
+        .unwrap();
+
+    driver.push(Frame::new(
+        instant(42),
+        0x107d552a.try_into().unwrap(),
+        &[0x00, 0x00, 0x00, 0x00, 0x04, 0x78, 0x68, 0xe0],
+    ));
+    let clock = ClockOwner::default();
+    clock.set_ticks(0);
+    let transfer = rx
+        .receive_borrowed(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .expect("Didn't get a transfer");
+    let expected = ReceivedTransfer {
+        transfer: Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: instant(42),
+                transfer_id: 0.try_into().unwrap(),
+                priority: Priority::Nominal,
+                subject: heartbeat_subject,
+                source: Some(42u8.try_into().unwrap()),
+            }),
+            loopback: false,
+            payload: &[0x00u8, 0x00, 0x00, 0x00, 0x04, 0x78, 0x68][..],
+        },
+        completion_timestamp: instant(42),
     };
     assert_eq!(expected, transfer);
 }
+#[test]
+fn test_heartbeat_heapless() {
+    let mut driver = StubDriver::default();
+    let mut rx = CanReceiver::new(0u8.try_into().unwrap());
+
+    let heartbeat_subject = SubjectId::try_from(7509).unwrap();
+    rx.subscribe_message(heartbeat_subject, 7, duration(0), &mut driver)
+        .unwrap();
+
+    let heartbeat = |transfer_id: u8| {
+        Frame::new(
+            instant(42),
+            0x107d552a.try_into().unwrap(),
+            &[0x00, 0x00, 0x00, 0x00, 0x04, 0x78, 0x68, 0xe0 | transfer_id],
+        )
+    };
+    let clock = ClockOwner::default();
+    driver.push(heartbeat(0));
+    let transfer = rx
+        .receive_heapless::<7>(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .expect("Didn't get a transfer");
+    assert_eq!(
+        &[0x00u8, 0x00, 0x00, 0x00, 0x04, 0x78, 0x68][..],
+        &transfer.transfer.payload[..]
+    );
+
+    // The payload does not fit into a smaller buffer
+    driver.push(heartbeat(1));
+    match rx.receive_heapless::<4>(&mut clock.make_clock(), &mut driver) {
+        Err(canadensis_can::Error::Memory(_)) => {}
+        other => panic!("Expected a memory error, got {:?}", other),
+    }
+}
+
 #[test]
 #[cfg(feature = "can-fd")]
 fn test_string() {
@@ -84,16 +159,19 @@ fn test_string() {
         .unwrap()
         .expect("Didn't get a transfer");
 
-    let expected = Transfer {
-        header: Header::Message(MessageHeader {
-            timestamp: instant(42),
-            transfer_id: 0.try_into().unwrap(),
-            priority: Priority::Nominal,
-            subject: string_subject,
-            source: None,
-        }),
-        loopback: false,
-        payload: b"\x00\x18Hello world!\x00".to_vec(),
+    let expected = ReceivedTransfer {
+        transfer: Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: instant(42),
+                transfer_id: 0.try_into().unwrap(),
+                priority: Priority::Nominal,
+                subject: string_subject,
+                source: None,
+            }),
+            loopback: false,
+            payload: b"\x00\x18Hello world!\x00".to_vec(),
+        },
+        completion_timestamp: instant(42),
     };
     assert_eq!(expected, transfer);
 }
@@ -118,17 +196,20 @@ fn test_node_info_request() {
         .unwrap()
         .expect("Didn't get a transfer");
 
-    let expected = Transfer {
-        header: Header::Request(ServiceHeader {
-            timestamp: instant(302),
-            transfer_id: 1.try_into().unwrap(),
-            priority: Priority::Nominal,
-            service,
-            source: 123u8.try_into().unwrap(),
-            destination: 42u8.try_into().unwrap(),
-        }),
-        loopback: false,
-        payload: vec![],
+    let expected = ReceivedTransfer {
+        transfer: Transfer {
+            header: Header::Request(ServiceHeader {
+                timestamp: instant(302),
+                transfer_id: 1.try_into().unwrap(),
+                priority: Priority::Nominal,
+                service,
+                source: 123u8.try_into().unwrap(),
+                destination: 42u8.try_into().unwrap(),
+            }),
+            loopback: false,
+            payload: vec![],
+        },
+        completion_timestamp: instant(302),
     };
     assert_eq!(expected, transfer);
 }
@@ -190,18 +271,22 @@ fn test_node_info_response() {
                 .unwrap()
                 .expect("Didn't get a transfer");
 
-            let expected = Transfer {
-                header: Header::Response(ServiceHeader {
-                    // Timestamp matches the first frame's timestamp
-                    timestamp: instant(100),
-                    transfer_id: 1.try_into().unwrap(),
-                    priority: Priority::Nominal,
-                    service,
-                    source: 42u8.try_into().unwrap(),
-                    destination: 123u8.try_into().unwrap(),
-                }),
-                loopback: false,
-                payload: payload.to_vec(),
+            let expected = ReceivedTransfer {
+                transfer: Transfer {
+                    header: Header::Response(ServiceHeader {
+                        // Timestamp matches the first frame's timestamp
+                        timestamp: instant(100),
+                        transfer_id: 1.try_into().unwrap(),
+                        priority: Priority::Nominal,
+                        service,
+                        source: 42u8.try_into().unwrap(),
+                        destination: 123u8.try_into().unwrap(),
+                    }),
+                    loopback: false,
+                    // Completion timestamp matches the last frame's timestamp
+                    payload: payload.to_vec(),
+                },
+                completion_timestamp: instant(200),
             };
             assert_eq!(expected, transfer);
         }
@@ -252,34 +337,566 @@ fn test_node_info_response_no_timeout() {
 }
 #[test]
 #[cfg(feature = "can-fd")]
-fn test_array() {
+fn test_classic_and_fd_same_subscription() {
+    use canadensis_can::{CanTransmitter, Mtu};
+    use canadensis_core::transport::Transmitter;
+
     let mut driver = StubDriver::default();
     let clock = ClockOwner::default();
     let mut rx = CanReceiver::new(0u8.try_into().unwrap());
 
     let subject = SubjectId::try_from(4919).unwrap();
-    rx.subscribe_message(subject, 94, duration(1), &mut driver)
+    let payload = b"\x00\x0cHello world!";
+    rx.subscribe_message(subject, payload.len(), milliseconds(1000), &mut driver)
         .unwrap();
 
-    let expected = Transfer {
-        header: Header::Message(MessageHeader {
-            timestamp: instant(0),
-            transfer_id: 0.try_into().unwrap(),
+    // The same transfer, sent once over classic CAN and once over CAN FD
+    for (transfer_id, mtu) in [(0u8, Mtu::Can8), (1, Mtu::CanFd64)] {
+        let header = Header::Message(MessageHeader {
+            timestamp: instant(100 * u32::from(transfer_id)),
+            transfer_id: transfer_id.try_into().unwrap(),
             priority: Priority::Nominal,
             subject,
             source: Some(59u8.try_into().unwrap()),
-        }),
-        loopback: false,
-        payload: [
-            0x00, 0xb8, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b,
-            0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19,
-            0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27,
-            0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35,
-            0x36, 0x37, 0x38, 0x39, 0x3a, 0x3b, 0x3c, 0x3d, 0x3e, 0x3f, 0x40, 0x41, 0x42, 0x43,
-            0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51,
-            0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x5b,
-        ]
-        .to_vec(),
+        });
+        let mut tx = CanTransmitter::new(mtu);
+        tx.push(
+            Transfer {
+                header: header.clone(),
+                loopback: false,
+                payload: &payload[..],
+            },
+            &mut clock.make_clock(),
+            &mut driver,
+        )
+        .unwrap();
+        let expected_frames = match mtu {
+            Mtu::Can8 => 3,
+            Mtu::CanFd64 => 1,
+        };
+        assert_eq!(expected_frames, driver.frames.len());
+
+        let transfer = rx
+            .receive(&mut clock.make_clock(), &mut driver)
+            .unwrap()
+            .expect("Didn't get a transfer");
+        let expected = ReceivedTransfer {
+            transfer: Transfer {
+                header,
+                loopback: false,
+                payload: payload.to_vec(),
+            },
+            completion_timestamp: instant(100 * u32::from(transfer_id)),
+        };
+        assert_eq!(expected, transfer);
+    }
+}
+
+#[test]
+#[cfg(feature = "can-fd")]
+fn test_classic_and_fd_interleaved() {
+    use canadensis_can::{CanTransmitter, Mtu};
+    use canadensis_core::transport::Transmitter;
+
+    let mut driver = StubDriver::default();
+    let clock = ClockOwner::default();
+    let mut rx = CanReceiver::new(0u8.try_into().unwrap());
+
+    let subject = SubjectId::try_from(4919).unwrap();
+    let payload: Vec<u8> = (0..100).collect();
+    rx.subscribe_message(subject, payload.len(), milliseconds(1000), &mut driver)
+        .unwrap();
+
+    // Two nodes send the same payload at the same time, one with classic CAN and one with CAN FD
+    let mut frames = Vec::new();
+    for (source, mtu) in [(59u8, Mtu::Can8), (60, Mtu::CanFd64)] {
+        CanTransmitter::new(mtu)
+            .push(
+                Transfer {
+                    header: Header::Message(MessageHeader {
+                        timestamp: instant(0),
+                        transfer_id: 0.try_into().unwrap(),
+                        priority: Priority::Nominal,
+                        subject,
+                        source: Some(source.try_into().unwrap()),
+                    }),
+                    loopback: false,
+                    payload: &payload[..],
+                },
+                &mut clock.make_clock(),
+                &mut driver,
+            )
+            .unwrap();
+        frames.push(std::mem::take(&mut driver.frames));
+    }
+    assert_eq!(15, frames[0].len());
+    assert_eq!(2, frames[1].len());
+
+    // The CAN FD frames arrive between the classic frames
+    driver.push(frames[0].pop_front().unwrap());
+    driver.push(frames[1].pop_front().unwrap());
+    driver.push(frames[0].pop_front().unwrap());
+    driver.push(frames[1].pop_front().unwrap());
+    driver.frames.extend(frames[0].drain(..));
+
+    let mut sources = Vec::new();
+    while let Some(transfer) = rx.receive(&mut clock.make_clock(), &mut driver).unwrap() {
+        assert_eq!(payload, transfer.transfer.payload);
+        sources.push(u8::from(*transfer.transfer.header.source().unwrap()));
+    }
+    assert_eq!(sources, [60, 59]);
+}
+
+#[test]
+fn test_resubscribe() {
+    use canadensis_can::{CanTransmitter, Mtu, TransferKind};
+    use canadensis_core::transport::Transmitter;
+    use canadensis_core::PortId;
+
+    let mut driver = StubDriver::default();
+    let clock = ClockOwner::default();
+    let mut rx = CanReceiver::new(0u8.try_into().unwrap());
+
+    let subject = SubjectId::try_from(4919).unwrap();
+    let port = PortId::from(subject);
+    let payload = b"\x00\x0cHello world!";
+    rx.subscribe_message(subject, payload.len(), milliseconds(1000), &mut driver)
+        .unwrap();
+    // Not subscribed
+    assert!(!rx
+        .resubscribe(TransferKind::Request, port, payload.len(), milliseconds(10))
+        .unwrap());
+
+    let header = Header::Message(MessageHeader {
+        timestamp: instant(0),
+        transfer_id: 0.try_into().unwrap(),
+        priority: Priority::Nominal,
+        subject,
+        source: Some(59u8.try_into().unwrap()),
+    });
+    let mut tx = CanTransmitter::new(Mtu::Can8);
+    tx.push(
+        Transfer {
+            header: header.clone(),
+            loopback: false,
+            payload: &payload[..],
+        },
+        &mut clock.make_clock(),
+        &mut driver,
+    )
+    .unwrap();
+    let mut frames = std::mem::take(&mut driver.frames);
+    assert_eq!(3, frames.len());
+
+    // Receive the first frame, change the timeout, and then receive the other frames
+    driver.push(frames.pop_front().unwrap());
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_none());
+    assert!(rx
+        .resubscribe(TransferKind::Message, port, payload.len(), milliseconds(10))
+        .unwrap());
+    driver.frames.extend(frames.iter().cloned());
+    let transfer = rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .expect("Didn't get a transfer");
+    assert_eq!(&payload[..], &transfer.transfer.payload[..]);
+
+    // Changing the maximum payload size discards the partial transfer
+    let header = Header::Message(MessageHeader {
+        timestamp: instant(100),
+        transfer_id: 1.try_into().unwrap(),
+        priority: Priority::Nominal,
+        subject,
+        source: Some(59u8.try_into().unwrap()),
+    });
+    tx.push(
+        Transfer {
+            header,
+            loopback: false,
+            payload: &payload[..],
+        },
+        &mut clock.make_clock(),
+        &mut driver,
+    )
+    .unwrap();
+    let mut frames = std::mem::take(&mut driver.frames);
+    driver.push(frames.pop_front().unwrap());
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_none());
+    assert!(rx
+        .resubscribe(TransferKind::Message, port, 4, milliseconds(10))
+        .unwrap());
+    driver.frames.extend(frames);
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn test_max_sessions() {
+    use canadensis_can::{CanTransmitter, Mtu};
+    use canadensis_core::transport::Transmitter;
+
+    let mut driver = StubDriver::default();
+    let clock = ClockOwner::default();
+    let mut rx = CanReceiver::new(0u8.try_into().unwrap());
+    rx.set_max_sessions(1);
+    assert_eq!(1, rx.max_sessions());
+
+    let subject = SubjectId::try_from(4919).unwrap();
+    let payload = b"\x00\x0cHello world!";
+    rx.subscribe_message(subject, payload.len(), milliseconds(1000), &mut driver)
+        .unwrap();
+
+    // Make the frames of a transfer from each of two nodes
+    let mut tx = CanTransmitter::new(Mtu::Can8);
+    let mut frames = Vec::new();
+    for (source, timestamp) in [(10u8, 100u32), (11, 200)] {
+        tx.push(
+            Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(timestamp),
+                    transfer_id: 0.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(source.try_into().unwrap()),
+                }),
+                loopback: false,
+                payload: &payload[..],
+            },
+            &mut clock.make_clock(),
+            &mut driver,
+        )
+        .unwrap();
+        frames.push(std::mem::take(&mut driver.frames));
+    }
+
+    // The first frame from node 10 starts a session
+    driver.push(frames[0].pop_front().unwrap());
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_none());
+    assert_eq!(1, rx.session_count());
+    // The first frame from node 11 replaces it
+    driver.push(frames[1].pop_front().unwrap());
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_none());
+    assert_eq!(1, rx.session_count());
+    assert_eq!(1, rx.evicted_session_count());
+
+    // The transfer from node 10 can't be completed, but the transfer from node 11 can
+    driver.frames.extend(frames[0].drain(..));
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_none());
+    driver.frames.extend(frames[1].drain(..));
+    let transfer = rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .expect("Didn't get a transfer");
+    assert_eq!(
+        Some(&CanNodeId::try_from(11u8).unwrap()),
+        transfer.transfer.header.source()
+    );
+    assert_eq!(0, rx.session_count());
+}
+
+#[test]
+fn test_max_sessions_no_eviction_without_new_session() {
+    use canadensis_can::{CanTransmitter, Mtu};
+    use canadensis_core::transport::Transmitter;
+
+    let mut driver = StubDriver::default();
+    let clock = ClockOwner::default();
+    let mut rx = CanReceiver::new(0u8.try_into().unwrap());
+    rx.set_max_sessions(1);
+
+    let subject = SubjectId::try_from(4919).unwrap();
+    let payload = b"\x00\x0cHello world!";
+    rx.subscribe_message(subject, payload.len(), milliseconds(1000), &mut driver)
+        .unwrap();
+
+    let mut tx = CanTransmitter::new(Mtu::Can8);
+    let mut frames = Vec::new();
+    for (source, timestamp) in [(10u8, 100u32), (11, 200)] {
+        tx.push(
+            Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(timestamp),
+                    transfer_id: 0.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(source.try_into().unwrap()),
+                }),
+                loopback: false,
+                payload: &payload[..],
+            },
+            &mut clock.make_clock(),
+            &mut driver,
+        )
+        .unwrap();
+        frames.push(std::mem::take(&mut driver.frames));
+    }
+
+    // Receive the complete transfer from node 11
+    driver.frames.extend(frames[1].iter().cloned());
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_some());
+    assert_eq!(0, rx.session_count());
+
+    // The first frame from node 10 starts a session
+    driver.push(frames[0].pop_front().unwrap());
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_none());
+    assert_eq!(1, rx.session_count());
+
+    // A duplicate of the transfer from node 11 and a frame from the middle of a transfer
+    // do not start sessions, so they do not replace the session
+    driver.push(frames[1][0].clone());
+    driver.push(frames[1][1].clone());
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_none());
+    assert_eq!(1, rx.session_count());
+    assert_eq!(0, rx.evicted_session_count());
+
+    // The transfer from node 10 can still be completed
+    driver.frames.extend(frames[0].drain(..));
+    let transfer = rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .expect("Didn't get a transfer");
+    assert_eq!(
+        Some(&CanNodeId::try_from(10u8).unwrap()),
+        transfer.transfer.header.source()
+    );
+    assert_eq!(0, rx.session_count());
+}
+
+#[test]
+fn test_clean_expired() {
+    use canadensis_can::{CanTransmitter, Mtu};
+    use canadensis_core::transport::Transmitter;
+
+    let mut driver = StubDriver::default();
+    let clock = ClockOwner::default();
+    let mut rx = CanReceiver::new(0u8.try_into().unwrap());
+
+    let subject = SubjectId::try_from(4919).unwrap();
+    let payload = b"\x00\x0cHello world!";
+    rx.subscribe_message(subject, payload.len(), milliseconds(1000), &mut driver)
+        .unwrap();
+
+    let mut tx = CanTransmitter::new(Mtu::Can8);
+    tx.push(
+        Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: instant(100),
+                transfer_id: 0.try_into().unwrap(),
+                priority: Priority::Nominal,
+                subject,
+                source: Some(10u8.try_into().unwrap()),
+            }),
+            loopback: false,
+            payload: &payload[..],
+        },
+        &mut clock.make_clock(),
+        &mut driver,
+    )
+    .unwrap();
+    let mut frames = std::mem::take(&mut driver.frames);
+
+    // Only the first frame arrives
+    driver.push(frames.pop_front().unwrap());
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_none());
+    assert_eq!(1, rx.session_count());
+
+    // Not expired yet
+    assert_eq!(0, rx.clean_expired(instant(1_000_100)));
+    assert_eq!(1, rx.session_count());
+    // Expired after the transfer-ID timeout
+    assert_eq!(1, rx.clean_expired(instant(1_000_101)));
+    assert_eq!(0, rx.session_count());
+    assert_eq!(0, rx.clean_expired(instant(2_000_000)));
+}
+
+#[test]
+fn test_resubscribe_shorter_timeout_expires_session() {
+    use canadensis_can::{CanTransmitter, Mtu, TransferKind};
+    use canadensis_core::transport::Transmitter;
+    use canadensis_core::PortId;
+
+    let mut driver = StubDriver::default();
+    let clock = ClockOwner::default();
+    let mut rx = CanReceiver::new(0u8.try_into().unwrap());
+
+    let subject = SubjectId::try_from(4919).unwrap();
+    let payload = b"\x00\x0cHello world!";
+    rx.subscribe_message(subject, payload.len(), milliseconds(1000), &mut driver)
+        .unwrap();
+
+    let mut tx = CanTransmitter::new(Mtu::Can8);
+    tx.push(
+        Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: instant(100),
+                transfer_id: 0.try_into().unwrap(),
+                priority: Priority::Nominal,
+                subject,
+                source: Some(10u8.try_into().unwrap()),
+            }),
+            loopback: false,
+            payload: &payload[..],
+        },
+        &mut clock.make_clock(),
+        &mut driver,
+    )
+    .unwrap();
+    let mut frames = std::mem::take(&mut driver.frames);
+
+    // Only the first frame arrives
+    driver.push(frames.pop_front().unwrap());
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_none());
+    assert_eq!(1, rx.session_count());
+
+    // The shorter timeout also applies to the transfer that is being reassembled
+    assert!(rx
+        .resubscribe(
+            TransferKind::Message,
+            PortId::from(subject),
+            payload.len(),
+            milliseconds(10)
+        )
+        .unwrap());
+    assert_eq!(1, rx.session_count());
+    assert_eq!(0, rx.clean_expired(instant(10_100)));
+    assert_eq!(1, rx.clean_expired(instant(10_101)));
+    assert_eq!(0, rx.session_count());
+}
+
+#[test]
+fn test_aborted_transfers() {
+    use canadensis_can::{CanTransmitter, Mtu};
+    use canadensis_core::transport::Transmitter;
+
+    let mut driver = StubDriver::default();
+    let clock = ClockOwner::default();
+    let mut rx = CanReceiver::new(0u8.try_into().unwrap());
+
+    let subject = SubjectId::try_from(4919).unwrap();
+    // 3 frames with classic CAN
+    let short_payload = b"\x00\x0cHello world!";
+    // 4 frames with classic CAN
+    let long_payload = b"\x00\x12Hello world again!";
+    rx.subscribe_message(subject, 64, milliseconds(1000), &mut driver)
+        .unwrap();
+
+    let mut tx = CanTransmitter::new(Mtu::Can8);
+    let mut make_frames = |transfer_id: u8, payload: &[u8]| {
+        tx.push(
+            Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(100 * u32::from(transfer_id)),
+                    transfer_id: transfer_id.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(59u8.try_into().unwrap()),
+                }),
+                loopback: false,
+                payload,
+            },
+            &mut clock.make_clock(),
+            &mut driver,
+        )
+        .unwrap();
+        std::mem::take(&mut driver.frames)
+    };
+    let mut frames0 = make_frames(0, &long_payload[..]);
+    let mut frames1 = make_frames(1, &short_payload[..]);
+    let frames2 = make_frames(2, &short_payload[..]);
+    assert_eq!(4, frames0.len());
+    assert_eq!(3, frames1.len());
+
+    // Lose the second frame of a 4-frame transfer. The transfer CRC check fails.
+    frames0.remove(1);
+    driver.frames.extend(frames0);
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_none());
+    assert_eq!(1, rx.aborted_transfer_count());
+
+    // Lose the last frame of a transfer. The next transfer replaces it.
+    frames1.pop_back();
+    driver.frames.extend(frames1);
+    assert!(rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .is_none());
+    assert_eq!(1, rx.aborted_transfer_count());
+    driver.frames.extend(frames2);
+    let transfer = rx
+        .receive(&mut clock.make_clock(), &mut driver)
+        .unwrap()
+        .expect("Didn't get a transfer");
+    assert_eq!(&short_payload[..], &transfer.transfer.payload[..]);
+    assert_eq!(2, rx.aborted_transfer_count());
+}
+
+#[test]
+#[cfg(feature = "can-fd")]
+fn test_array() {
+    let mut driver = StubDriver::default();
+    let clock = ClockOwner::default();
+    let mut rx = CanReceiver::new(0u8.try_into().unwrap());
+
+    let subject = SubjectId::try_from(4919).unwrap();
+    rx.subscribe_message(subject, 94, duration(1), &mut driver)
+        .unwrap();
+
+    let expected = ReceivedTransfer {
+        transfer: Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: instant(0),
+                transfer_id: 0.try_into().unwrap(),
+                priority: Priority::Nominal,
+                subject,
+                source: Some(59u8.try_into().unwrap()),
+            }),
+            loopback: false,
+            payload: [
+                0x00, 0xb8, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b,
+                0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19,
+                0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27,
+                0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35,
+                0x36, 0x37, 0x38, 0x39, 0x3a, 0x3b, 0x3c, 0x3d, 0x3e, 0x3f, 0x40, 0x41, 0x42, 0x43,
+                0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51,
+                0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x5b,
+            ]
+            .to_vec(),
+        },
+        completion_timestamp: instant(1),
     };
 
     let frames: [&[u8]; 2] = [
@@ -353,16 +970,19 @@ fn test_multi_frame_anonymous() {
         &[0x8, /* CRC */ 0x47, 0x92, /* tail */ 0b010_00000],
     ];
     // When the frames are not anonymous, the transfer gets received.
-    let non_anonymous_transfer = Transfer {
-        header: Header::Message(MessageHeader {
-            timestamp: instant(1),
-            transfer_id: 0.try_into().unwrap(),
-            priority: Priority::Nominal,
-            subject: subject_id,
-            source: Some(64u8.try_into().unwrap()),
-        }),
-        loopback: false,
-        payload: vec![0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7, 0x8],
+    let non_anonymous_transfer = ReceivedTransfer {
+        transfer: Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: instant(1),
+                transfer_id: 0.try_into().unwrap(),
+                priority: Priority::Nominal,
+                subject: subject_id,
+                source: Some(64u8.try_into().unwrap()),
+            }),
+            loopback: false,
+            payload: vec![0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7, 0x8],
+        },
+        completion_timestamp: instant(2),
     };
 
     let clock = ClockOwner::default();
@@ -434,16 +1054,19 @@ fn test_anonymous_receive_multi_frame() {
         ),
         Frame::new(instant(120), message_id, &[103, 0, 143, 70, 78]),
     ];
-    let expected_transfer = Transfer {
-        header: Header::Message(MessageHeader {
-            timestamp: instant(10),
-            transfer_id: 14.try_into().unwrap(),
-            priority: Priority::Nominal,
-            subject: 8166.try_into().unwrap(),
-            source: Some(126u8.try_into().unwrap()),
-        }),
-        loopback: false,
-        payload: vec![190, 159, 33, 213, 34, 64, 1, 103, 0],
+    let expected_transfer = ReceivedTransfer {
+        transfer: Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: instant(10),
+                transfer_id: 14.try_into().unwrap(),
+                priority: Priority::Nominal,
+                subject: 8166.try_into().unwrap(),
+                source: Some(126u8.try_into().unwrap()),
+            }),
+            loopback: false,
+            payload: vec![190, 159, 33, 213, 34, 64, 1, 103, 0],
+        },
+        completion_timestamp: instant(120),
     };
 
     let clock = ClockOwner::default();
@@ -492,16 +1115,19 @@ fn test_consecutive_messages() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(13309),
-                transfer_id: 29.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0x53, 0x4f, 0x4d, 0x45, 0x42, 0x4f, 0x44, 0x59],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(13309),
+                    transfer_id: 29.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0x53, 0x4f, 0x4d, 0x45, 0x42, 0x4f, 0x44, 0x59],
+            },
+            completion_timestamp: instant(13320),
         })
     );
 
@@ -529,16 +1155,19 @@ fn test_consecutive_messages() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(13331),
-                transfer_id: 30.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0x74, 0x30, 0x6c, 0x64, 0x5f, 0x6d, 0x33, 0x21],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(13331),
+                    transfer_id: 30.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0x74, 0x30, 0x6c, 0x64, 0x5f, 0x6d, 0x33, 0x21],
+            },
+            completion_timestamp: instant(13416),
         })
     );
 
@@ -586,16 +1215,19 @@ fn test_consecutive_messages() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(13490),
-                transfer_id: 31.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0x74, 0x30, 0x6c, 0x64, 0x5f, 0x6d, 0x33, 0x21],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(13490),
+                    transfer_id: 31.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0x74, 0x30, 0x6c, 0x64, 0x5f, 0x6d, 0x33, 0x21],
+            },
+            completion_timestamp: instant(13500),
         })
     );
 
@@ -638,16 +1270,19 @@ fn test_consecutive_messages() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(13513),
-                transfer_id: 0.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0x74, 0x30, 0x6c, 0x64, 0x5f, 0x6d, 0x33, 0x21],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(13513),
+                    transfer_id: 0.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0x74, 0x30, 0x6c, 0x64, 0x5f, 0x6d, 0x33, 0x21],
+            },
+            completion_timestamp: instant(13521),
         })
     );
 
@@ -690,16 +1325,19 @@ fn test_consecutive_messages() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(13862),
-                transfer_id: 2.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0x74, 0x30, 0x6c, 0x64, 0x5f, 0x00, 0x00],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(13862),
+                    transfer_id: 2.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0x74, 0x30, 0x6c, 0x64, 0x5f, 0x00, 0x00],
+            },
+            completion_timestamp: instant(13862),
         })
     );
 }
@@ -727,16 +1365,19 @@ fn test_confusing_transfer_ids() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(59),
-                transfer_id: 3.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0x01, 0x02, 0xca, 0xfe]
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(59),
+                    transfer_id: 3.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0x01, 0x02, 0xca, 0xfe]
+            },
+            completion_timestamp: instant(59),
         })
     );
 
@@ -749,16 +1390,19 @@ fn test_confusing_transfer_ids() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(199),
-                transfer_id: 2.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0x13, 0x47, 0x84, 0x20],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(199),
+                    transfer_id: 2.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0x13, 0x47, 0x84, 0x20],
+            },
+            completion_timestamp: instant(199),
         })
     );
 
@@ -773,16 +1417,19 @@ fn test_confusing_transfer_ids() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(211),
-                transfer_id: 3.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0xf1, 0x02, 0x8a, 0xf1]
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(211),
+                    transfer_id: 3.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0xf1, 0x02, 0x8a, 0xf1]
+            },
+            completion_timestamp: instant(211),
         })
     );
 
@@ -795,16 +1442,19 @@ fn test_confusing_transfer_ids() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(478),
-                transfer_id: 19.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0xa3, 0x47, 0x84, 0x20],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(478),
+                    transfer_id: 19.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0xa3, 0x47, 0x84, 0x20],
+            },
+            completion_timestamp: instant(478),
         })
     );
 
@@ -817,16 +1467,19 @@ fn test_confusing_transfer_ids() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(799),
-                transfer_id: 0.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0xa3, 0xb2, 0xee, 0x20],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(799),
+                    transfer_id: 0.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0xa3, 0xb2, 0xee, 0x20],
+            },
+            completion_timestamp: instant(799),
         })
     );
 
@@ -839,16 +1492,19 @@ fn test_confusing_transfer_ids() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(802),
-                transfer_id: 31.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0x0f, 0x22, 0x84, 0x2c],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(802),
+                    transfer_id: 31.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0x0f, 0x22, 0x84, 0x2c],
+            },
+            completion_timestamp: instant(802),
         })
     );
 }
@@ -885,25 +1541,29 @@ fn test_interleaved_frame_rejection() {
             // payload
             0x21,
             // CRC
-            0x0f, 0x99,
+            0x0f,
+            0x99,
             // tail byte: !SOF, EOF, TOGGLE = 0, transfer-ID 2
-            0b010_00010
+            0b010_00010,
         ],
     ));
 
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(13311),
-                transfer_id: 2.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0x74, 0x30, 0x6c, 0x64, 0x5f, 0x6d, 0x33, 0x21],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(13311),
+                    transfer_id: 2.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0x74, 0x30, 0x6c, 0x64, 0x5f, 0x6d, 0x33, 0x21],
+            },
+            completion_timestamp: instant(13316),
         })
     );
 
@@ -923,10 +1583,7 @@ fn test_interleaved_frame_rejection() {
     ));
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     // Shouldn't reassemble transfer 1 if we've already reassembled transfer 2
-    assert_eq!(
-        transfer,
-        None
-    );
+    assert_eq!(transfer, None);
 }
 
 #[test]
@@ -966,16 +1623,19 @@ fn test_message_payload_too_large_single_frame() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(13309),
-                transfer_id: 3.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(73u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0xab, 0x19, 0x7f],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(13309),
+                    transfer_id: 3.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(73u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0xab, 0x19, 0x7f],
+            },
+            completion_timestamp: instant(13309),
         })
     );
 }
@@ -1011,16 +1671,19 @@ fn test_message_payload_too_large_multi_frame() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(13309),
-                transfer_id: 7.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(73u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0xab, 0x19, 0x7f, 0x23, 0x03, 0xee, 0xca, 0xf1],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(13309),
+                    transfer_id: 7.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(73u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0xab, 0x19, 0x7f, 0x23, 0x03, 0xee, 0xca, 0xf1],
+            },
+            completion_timestamp: instant(13399),
         })
     );
 }
@@ -1078,16 +1741,19 @@ fn test_message_payload_too_large_multi_frame_split_crc() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(13309),
-                transfer_id: 7.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(73u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0xab, 0x19, 0x7f, 0x23],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(13309),
+                    transfer_id: 7.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(73u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0xab, 0x19, 0x7f, 0x23],
+            },
+            completion_timestamp: instant(13399),
         })
     );
 }
@@ -1159,26 +1825,29 @@ fn slow_multi_frame_no_timeout() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: instant(288644),
-                transfer_id: 17.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(127u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![
-                0x09, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 0
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 1
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 2
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 3
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 4
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 5
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 6
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 7
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 8
-            ],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(288644),
+                    transfer_id: 17.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(127u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![
+                    0x09, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 0
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 1
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 2
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 3
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 4
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 5
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 6
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 7
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Frame 8
+                ],
+            },
+            completion_timestamp: instant(315564),
         })
     );
 }
@@ -1204,16 +1873,19 @@ fn handle_transfer_id_timeout_with_clock_overflow() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: timestamp_before_overflow,
-                transfer_id: 21.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0xff, 0x4f, 0x5a, 0xa5],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: timestamp_before_overflow,
+                    transfer_id: 21.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0xff, 0x4f, 0x5a, 0xa5],
+            },
+            completion_timestamp: timestamp_before_overflow,
         })
     );
 
@@ -1235,16 +1907,19 @@ fn handle_transfer_id_timeout_with_clock_overflow() {
     let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: timestamp_after_timeout_window,
-                transfer_id: 21.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(75u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0xff, 0x4f, 0x5a, 0xa5],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: timestamp_after_timeout_window,
+                    transfer_id: 21.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(75u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0xff, 0x4f, 0x5a, 0xa5],
+            },
+            completion_timestamp: timestamp_after_timeout_window,
         })
     );
 }
@@ -1270,16 +1945,19 @@ fn single_frame_deduplicate(start_time: Microseconds32) {
     let clock = ClockOwner::default();
 
     let first_transfer_time = start_time + MicrosecondDuration32::from_ticks(10);
-    let expected_transfer = Transfer {
-        header: Header::Message(MessageHeader {
-            timestamp: first_transfer_time,
-            transfer_id: 27.try_into().unwrap(),
-            priority: Priority::Nominal,
-            subject,
-            source: Some(CanNodeId::try_from(127u8).unwrap()),
-        }),
-        loopback: false,
-        payload: vec![0x09, 0x30],
+    let expected_transfer = ReceivedTransfer {
+        transfer: Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: first_transfer_time,
+                transfer_id: 27.try_into().unwrap(),
+                priority: Priority::Nominal,
+                subject,
+                source: Some(CanNodeId::try_from(127u8).unwrap()),
+            }),
+            loopback: false,
+            payload: vec![0x09, 0x30],
+        },
+        completion_timestamp: first_transfer_time,
     };
 
     driver.push(Frame::new(
@@ -1311,16 +1989,19 @@ fn single_frame_deduplicate(start_time: Microseconds32) {
     ));
     assert_eq!(
         rx.receive(&mut clock.make_clock(), &mut driver).unwrap(),
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: second_transfer_time,
-                transfer_id: 27.try_into().unwrap(),
-                priority: Priority::Nominal,
-                subject,
-                source: Some(CanNodeId::try_from(127u8).unwrap()),
-            }),
-            loopback: false,
-            payload: vec![0x09, 0x30],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: second_transfer_time,
+                    transfer_id: 27.try_into().unwrap(),
+                    priority: Priority::Nominal,
+                    subject,
+                    source: Some(CanNodeId::try_from(127u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0x09, 0x30],
+            },
+            completion_timestamp: second_transfer_time,
         })
     );
 }
@@ -1336,18 +2017,21 @@ fn multi_frame_each_frame_duplicated() {
     let clock = ClockOwner::default();
 
     let first_transfer_time = Microseconds32::from_ticks(10);
-    let expected_transfer = Transfer {
-        header: Header::Message(MessageHeader {
-            timestamp: first_transfer_time,
-            transfer_id: 3.try_into().unwrap(),
-            priority: Priority::Low,
-            subject,
-            source: Some(CanNodeId::try_from(120u8).unwrap()),
-        }),
-        loopback: false,
-        payload: vec![
-            0x30, 0x10, 0x09, 0xff, 0xae, 0x69, 0xa2, 0x01, 0x10, 0x13, 0x22, 0x99,
-        ],
+    let expected_transfer = ReceivedTransfer {
+        transfer: Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: first_transfer_time,
+                transfer_id: 3.try_into().unwrap(),
+                priority: Priority::Low,
+                subject,
+                source: Some(CanNodeId::try_from(120u8).unwrap()),
+            }),
+            loopback: false,
+            payload: vec![
+                0x30, 0x10, 0x09, 0xff, 0xae, 0x69, 0xa2, 0x01, 0x10, 0x13, 0x22, 0x99,
+            ],
+        },
+        completion_timestamp: first_transfer_time + milliseconds(340),
     };
 
     let frames = [
@@ -1435,18 +2119,21 @@ fn multi_frame_all_frames_duplicated() {
     let clock = ClockOwner::default();
 
     let first_transfer_time = Microseconds32::from_ticks(10);
-    let expected_transfer = Transfer {
-        header: Header::Message(MessageHeader {
-            timestamp: first_transfer_time,
-            transfer_id: 3.try_into().unwrap(),
-            priority: Priority::Low,
-            subject,
-            source: Some(CanNodeId::try_from(120u8).unwrap()),
-        }),
-        loopback: false,
-        payload: vec![
-            0x30, 0x10, 0x09, 0xff, 0xae, 0x69, 0xa2, 0x01, 0x10, 0x13, 0x22, 0x99,
-        ],
+    let expected_transfer = ReceivedTransfer {
+        transfer: Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: first_transfer_time,
+                transfer_id: 3.try_into().unwrap(),
+                priority: Priority::Low,
+                subject,
+                source: Some(CanNodeId::try_from(120u8).unwrap()),
+            }),
+            loopback: false,
+            payload: vec![
+                0x30, 0x10, 0x09, 0xff, 0xae, 0x69, 0xa2, 0x01, 0x10, 0x13, 0x22, 0x99,
+            ],
+        },
+        completion_timestamp: first_transfer_time + milliseconds(340),
     };
 
     let frames = [
@@ -1658,18 +2345,21 @@ fn multi_frame_missed_frame_recovery() {
             ],
         ),
     ];
-    let expected_transfer = Transfer {
-        header: Header::Message(MessageHeader {
-            timestamp: first_transfer_time + delay_between_transfers * 2,
-            transfer_id: 3.try_into().unwrap(),
-            priority: Priority::Low,
-            subject,
-            source: Some(CanNodeId::try_from(120u8).unwrap()),
-        }),
-        loopback: false,
-        payload: vec![
-            0x29, 0xab, 0x09, 0x3c, 0xae, 0x32, 0xb6, 0x01, 0x10, 0x13, 0x1f, 0x99,
-        ],
+    let expected_transfer = ReceivedTransfer {
+        transfer: Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: first_transfer_time + delay_between_transfers * 2,
+                transfer_id: 3.try_into().unwrap(),
+                priority: Priority::Low,
+                subject,
+                source: Some(CanNodeId::try_from(120u8).unwrap()),
+            }),
+            loopback: false,
+            payload: vec![
+                0x29, 0xab, 0x09, 0x3c, 0xae, 0x32, 0xb6, 0x01, 0x10, 0x13, 0x1f, 0x99,
+            ],
+        },
+        completion_timestamp: first_transfer_time + delay_between_transfers * 2 + milliseconds(340),
     };
 
     // Skip the middle frame
@@ -1743,16 +2433,19 @@ fn test_stressed_transfer_ids_from(start_tid: u8) {
         let transfer = rx.receive(&mut clock.make_clock(), &mut driver).unwrap();
         assert_eq!(
             transfer,
-            Some(Transfer {
-                header: Header::Message(MessageHeader {
-                    timestamp: instant(i),
-                    transfer_id: tid.try_into().unwrap(),
-                    priority: Priority::Nominal,
-                    subject,
-                    source: Some(CanNodeId::try_from(75u8).unwrap()),
-                }),
-                loopback: false,
-                payload: vec![0xca, 0xfe, 0xbe, 0xef],
+            Some(ReceivedTransfer {
+                transfer: Transfer {
+                    header: Header::Message(MessageHeader {
+                        timestamp: instant(i),
+                        transfer_id: tid.try_into().unwrap(),
+                        priority: Priority::Nominal,
+                        subject,
+                        source: Some(CanNodeId::try_from(75u8).unwrap()),
+                    }),
+                    loopback: false,
+                    payload: vec![0xca, 0xfe, 0xbe, 0xef],
+                },
+                completion_timestamp: instant(i),
             })
         );
         tid = (tid + 1) % 32;
@@ -1885,16 +2578,11 @@ fn test_stressed_concurrent_subscriptions() {
     // payload size includes CRC. it's this big so this test still stresses CAN FD
     const PAYLOAD_SIZE: usize = 74;
     const PAYLOAD: [u8; PAYLOAD_SIZE] = [
-        0x32, 0x43, 0xf6, 0xa8, 0x88, 0x5a, 0x30, 0x8d,
-        0x31, 0x31, 0x98, 0xa2, 0xe0, 0x37, 0x07, 0x34,
-        0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
-        0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
-        0x32, 0x43, 0xf6, 0xa8, 0x88, 0x5a, 0x30, 0x8d,
-        0x31, 0x31, 0x98, 0xa2, 0xe0, 0x37, 0x07, 0x34,
-        0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
-        0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
-        0x32, 0x43, 0xf6, 0xa8, 0x88, 0x5a, 0x30, 0x8d,
-        // CRC
+        0x32, 0x43, 0xf6, 0xa8, 0x88, 0x5a, 0x30, 0x8d, 0x31, 0x31, 0x98, 0xa2, 0xe0, 0x37, 0x07,
+        0x34, 0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf,
+        0x4f, 0x3c, 0x32, 0x43, 0xf6, 0xa8, 0x88, 0x5a, 0x30, 0x8d, 0x31, 0x31, 0x98, 0xa2, 0xe0,
+        0x37, 0x07, 0x34, 0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88,
+        0x09, 0xcf, 0x4f, 0x3c, 0x32, 0x43, 0xf6, 0xa8, 0x88, 0x5a, 0x30, 0x8d, // CRC
         0x57, 0x59,
     ];
 
@@ -1979,38 +2667,44 @@ fn test_stressed_concurrent_subscriptions() {
                         _PortId::SubjectId(subject_id) => {
                             assert_eq!(
                                 transfer,
-                                Some(Transfer {
-                                    header: Header::Message(MessageHeader {
-                                        timestamp: *expected_transfer_times
-                                            .get(&(src_node, port_id))
-                                            .unwrap(),
-                                        transfer_id: 24.try_into().unwrap(),
-                                        priority: Priority::Nominal,
-                                        subject: subject_id,
-                                        source: Some(src_node),
-                                    }),
-                                    loopback: false,
-                                    // exclude last two CRC bytes
-                                    payload: Vec::from(&PAYLOAD[0..PAYLOAD_SIZE - 2]),
+                                Some(ReceivedTransfer {
+                                    transfer: Transfer {
+                                        header: Header::Message(MessageHeader {
+                                            timestamp: *expected_transfer_times
+                                                .get(&(src_node, port_id))
+                                                .unwrap(),
+                                            transfer_id: 24.try_into().unwrap(),
+                                            priority: Priority::Nominal,
+                                            subject: subject_id,
+                                            source: Some(src_node),
+                                        }),
+                                        loopback: false,
+                                        // exclude last two CRC bytes
+                                        payload: Vec::from(&PAYLOAD[0..PAYLOAD_SIZE - 2]),
+                                    },
+                                    completion_timestamp: instant(t),
                                 })
                             );
                         }
                         _PortId::ServiceId(service_id) => {
                             assert_eq!(
                                 transfer,
-                                Some(Transfer {
-                                    header: Header::Request(ServiceHeader {
-                                        timestamp: *expected_transfer_times
-                                            .get(&(src_node, port_id))
-                                            .unwrap(),
-                                        transfer_id: 24.try_into().unwrap(),
-                                        priority: Priority::Nominal,
-                                        service: service_id,
-                                        source: src_node,
-                                        destination: dest_node,
-                                    }),
-                                    loopback: false,
-                                    payload: Vec::from(&PAYLOAD[0..PAYLOAD_SIZE - 2]),
+                                Some(ReceivedTransfer {
+                                    transfer: Transfer {
+                                        header: Header::Request(ServiceHeader {
+                                            timestamp: *expected_transfer_times
+                                                .get(&(src_node, port_id))
+                                                .unwrap(),
+                                            transfer_id: 24.try_into().unwrap(),
+                                            priority: Priority::Nominal,
+                                            service: service_id,
+                                            source: src_node,
+                                            destination: dest_node,
+                                        }),
+                                        loopback: false,
+                                        payload: Vec::from(&PAYLOAD[0..PAYLOAD_SIZE - 2]),
+                                    },
+                                    completion_timestamp: instant(t),
                                 })
                             );
                         }
@@ -2044,6 +2738,29 @@ impl StubDriver {
     }
 }
 
+impl TransmitDriver<StubClock<'_>> for StubDriver {
+    type Error = ();
+
+    fn try_reserve(&mut self, frames: usize) -> Result<(), OutOfMemoryError> {
+        self.frames.reserve(frames);
+        Ok(())
+    }
+
+    fn transmit(
+        &mut self,
+        frame: Frame,
+        _clock: &mut StubClock<'_>,
+    ) -> nb::Result<Option<Frame>, Self::Error> {
+        // Send the frame back to the receiver
+        self.frames.push_back(frame);
+        Ok(None)
+    }
+
+    fn flush(&mut self, _clock: &mut StubClock<'_>) -> nb::Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
 impl ReceiveDriver<StubClock<'_>> for StubDriver {
     type Error = ();
 
@@ -9,17 +9,169 @@ use core::convert::TryFrom;
 use std::collections::VecDeque;
 use std::convert::Infallible;
 
-use canadensis_can::driver::TransmitDriver;
-use canadensis_can::{CanId, CanNodeId, CanTransferId, CanTransmitter, Frame, Mtu};
-use canadensis_core::time::{Clock, Microseconds32};
+use canadensis_can::driver::{ReceiveDriver, TransmitDriver};
+use canadensis_can::queue::{ArrayQueue, SingleQueueDriver};
+use canadensis_can::{
+    CanId, CanNodeId, CanReceiver, CanTransferId, CanTransmitter, Error, Frame, Mtu,
+};
+use canadensis_core::subscription::Subscription;
+use canadensis_core::time::{milliseconds, Clock, Microseconds32};
 use canadensis_core::transfer::*;
-use canadensis_core::transport::Transmitter;
-use canadensis_core::{OutOfMemoryError, Priority, ServiceId, SubjectId};
+use canadensis_core::transport::{Receiver, Transmitter};
+use canadensis_core::{nb, OutOfMemoryError, Priority, ServiceId, SubjectId};
 
 fn instant(ticks: u32) -> Microseconds32 {
     Microseconds32::from_ticks(ticks)
 }
 
+#[test]
+fn test_queue_full() {
+    let mut driver = MockDriver {
+        capacity: Some(2),
+        ..MockDriver::default()
+    };
+    let mut tx = CanTransmitter::new(Mtu::Can8);
+    // 14 payload bytes and 2 CRC bytes need 3 frames
+    let result = tx.push(
+        Transfer {
+            header: Header::Message(MessageHeader {
+                timestamp: instant(0),
+                transfer_id: CanTransferId::try_from(0).unwrap(),
+                priority: Priority::Nominal,
+                subject: SubjectId::try_from(7509).unwrap(),
+                source: Some(CanNodeId::try_from(42u8).unwrap()),
+            }),
+            loopback: false,
+            payload: &[0u8; 14],
+        },
+        &mut ZeroClock,
+        &mut driver,
+    );
+    assert_eq!(result, Err(nb::Error::Other(Error::QueueFull)));
+    assert_eq!(None, driver.pop_frame());
+}
+
+#[test]
+fn test_queue_full_all_or_nothing() {
+    let mut driver = SingleQueueDriver::new(ArrayQueue::<4>::new(), MockDriver::default());
+    let mut tx = CanTransmitter::new(Mtu::Can8);
+    let mut push = |payload_length: usize, driver: &mut SingleQueueDriver<_, _, _>| {
+        tx.push(
+            Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(0),
+                    transfer_id: CanTransferId::try_from(0).unwrap(),
+                    priority: Priority::Nominal,
+                    subject: SubjectId::try_from(7509).unwrap(),
+                    source: Some(CanNodeId::try_from(42u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0u8; payload_length],
+            },
+            &mut ZeroClock,
+            driver,
+        )
+    };
+    // 2 frames
+    push(8, &mut driver).unwrap();
+    assert_eq!(2, driver.queue().len());
+    // 3 frames, but only 2 spaces are available
+    assert_eq!(
+        Err(nb::Error::Other(Error::QueueFull)),
+        push(14, &mut driver)
+    );
+    assert_eq!(2, driver.queue().len());
+    // 2 frames
+    push(8, &mut driver).unwrap();
+    assert_eq!(4, driver.queue().len());
+}
+
+#[test]
+fn test_frame_count_for() {
+    let mut tx = CanTransmitter::new(Mtu::Can8);
+    // Up to 7 bytes fit into one frame. Larger transfers have a 2-byte CRC, and each frame
+    // has 7 bytes for the payload and CRC.
+    for (payload_length, expected_frames) in
+        [(0, 1), (7, 1), (8, 2), (12, 2), (13, 3), (19, 3), (20, 4)]
+    {
+        assert_eq!(
+            expected_frames,
+            Transmitter::<ZeroClock>::frame_count_for(&tx, payload_length),
+            "Payload length {}",
+            payload_length
+        );
+        let mut driver = MockDriver::default();
+        tx.push(
+            Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(0),
+                    transfer_id: CanTransferId::try_from(0).unwrap(),
+                    priority: Priority::Nominal,
+                    subject: SubjectId::try_from(7509).unwrap(),
+                    source: Some(CanNodeId::try_from(42u8).unwrap()),
+                }),
+                loopback: false,
+                payload: vec![0u8; payload_length],
+            },
+            &mut ZeroClock,
+            &mut driver,
+        )
+        .unwrap();
+        assert_eq!(expected_frames, driver.queue.len());
+    }
+}
+
+#[test]
+fn test_forward_received() {
+    let source = CanNodeId::try_from(42u8).unwrap();
+    let subject = SubjectId::try_from(7509).unwrap();
+    let payload: Vec<u8> = (0..20).collect();
+    let mut bus_a = MockDriver::default();
+    CanTransmitter::new(Mtu::Can8)
+        .push(
+            Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: instant(100),
+                    transfer_id: CanTransferId::try_from(3).unwrap(),
+                    priority: Priority::High,
+                    subject,
+                    source: Some(source),
+                }),
+                loopback: false,
+                payload: &payload,
+            },
+            &mut ZeroClock,
+            &mut bus_a,
+        )
+        .unwrap();
+    let original_frames: Vec<Frame> = bus_a.queue.iter().cloned().collect();
+    assert_eq!(original_frames.len(), 4);
+
+    let mut rx: CanReceiver<ZeroClock, MockDriver> = CanReceiver::new_anonymous();
+    rx.subscribe_message(subject, payload.len(), milliseconds(1000), &mut bus_a)
+        .unwrap();
+    let mut received = None;
+    while received.is_none() {
+        received = rx.receive(&mut ZeroClock, &mut bus_a).unwrap();
+    }
+    let mut received = received.unwrap().transfer;
+    assert_eq!(received.payload, payload);
+
+    // Forward to another bus with a new deadline
+    received.header.set_timestamp(instant(2000));
+    let mut bus_b = MockDriver::default();
+    CanTransmitter::new(Mtu::Can8)
+        .push(received, &mut ZeroClock, &mut bus_b)
+        .unwrap();
+    let forwarded_frames: Vec<Frame> = bus_b.queue.into_iter().collect();
+    assert_eq!(forwarded_frames.len(), original_frames.len());
+    for (forwarded, original) in forwarded_frames.iter().zip(&original_frames) {
+        assert_eq!(forwarded.id(), original.id());
+        assert_eq!(forwarded.data(), original.data());
+        assert_eq!(forwarded.timestamp(), instant(2000));
+    }
+}
+
 #[test]
 fn test_heartbeat() {
     let mut driver = MockDriver::default();
@@ -265,6 +417,8 @@ fn test_array() {
 #[derive(Default)]
 struct MockDriver {
     queue: VecDeque<Frame>,
+    /// The maximum number of frames in the queue, or None for no limit
+    capacity: Option<usize>,
 }
 
 impl MockDriver {
@@ -280,8 +434,13 @@ where
     type Error = Infallible;
 
     fn try_reserve(&mut self, frames: usize) -> Result<(), OutOfMemoryError> {
-        self.queue.reserve(frames);
-        Ok(())
+        match self.capacity {
+            Some(capacity) if self.queue.len() + frames > capacity => Err(OutOfMemoryError),
+            _ => {
+                self.queue.reserve(frames);
+                Ok(())
+            }
+        }
     }
 
     fn transmit(
@@ -298,6 +457,27 @@ where
     }
 }
 
+impl<C> ReceiveDriver<C> for MockDriver
+where
+    C: Clock,
+{
+    type Error = Infallible;
+
+    fn receive(&mut self, _clock: &mut C) -> canadensis_core::nb::Result<Frame, Self::Error> {
+        self.queue
+            .pop_front()
+            .ok_or(canadensis_core::nb::Error::WouldBlock)
+    }
+
+    fn apply_filters<S>(&mut self, _local_node: Option<CanNodeId>, _subscriptions: S)
+    where
+        S: IntoIterator<Item = Subscription>,
+    {
+    }
+
+    fn apply_accept_all(&mut self) {}
+}
+
 /// A clock that produces a Microseconds32 value that is always zero
 #[derive(Default)]
 struct ZeroClock;
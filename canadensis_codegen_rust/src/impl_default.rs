@@ -0,0 +1,68 @@
+//! Implements Default for generated types
+
+use canadensis_dsdl_frontend::types::{PrimitiveType, ResolvedScalarType, ResolvedType};
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{GeneratedField, GeneratedType, GeneratedTypeKind};
+
+/// Implements Default
+///
+/// For structs, every field gets its default value (zero, false, or empty). For enums, the
+/// default is the first variant, containing its default value if it has one.
+pub(crate) struct ImplementDefault<'t, 'c>(pub &'t GeneratedType<'c>);
+
+impl Display for ImplementDefault<'_, '_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let type_name = &self.0.name.type_name;
+        writeln!(f, "impl ::core::default::Default for {} {{", type_name)?;
+        writeln!(f, "fn default() -> Self {{")?;
+        match &self.0.kind {
+            GeneratedTypeKind::Struct(gstruct) => {
+                writeln!(f, "{} {{", type_name)?;
+                for field in &gstruct.fields {
+                    if let GeneratedField::Data(field) = field {
+                        writeln!(f, "{}: {},", field.name, DefaultValue(field.cyphal_ty))?;
+                    }
+                }
+                writeln!(f, "}}")?;
+            }
+            GeneratedTypeKind::Enum(genum) => {
+                let variant = genum
+                    .variants
+                    .first()
+                    .expect("Enum must have at least one variant");
+                match &variant.ty {
+                    Some(_) => writeln!(
+                        f,
+                        "{}::{}(::core::default::Default::default())",
+                        type_name, variant.name
+                    )?,
+                    None => writeln!(f, "{}::{}", type_name, variant.name)?,
+                }
+            }
+        }
+        writeln!(f, "}}")?;
+        writeln!(f, "}}")
+    }
+}
+
+/// Writes an expression that creates the default value of a field
+struct DefaultValue<'t>(&'t ResolvedType);
+
+impl Display for DefaultValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            // Boolean arrays become BitArrays, which implement Default
+            ResolvedType::FixedArray {
+                inner: ResolvedScalarType::Primitive(PrimitiveType::Boolean),
+                ..
+            } => write!(f, "::core::default::Default::default()"),
+            // Arrays only implement Default up to 32 elements, so build them element by element
+            ResolvedType::FixedArray { .. } => write!(
+                f,
+                "::core::array::from_fn(|_| ::core::default::Default::default())"
+            ),
+            _ => write!(f, "::core::default::Default::default()"),
+        }
+    }
+}
@@ -113,7 +113,7 @@ fn deserialize_enum(f: &mut Formatter<'_>, name: &RustTypeName, genum: &Generate
     // Wildcard pattern
     writeln!(
         f,
-        "_ => Err(::canadensis_encoding::DeserializeError::UnionTag),"
+        "_ => Err(::canadensis_encoding::DeserializeError::UnionTag {{ offset_bits: cursor.bit_offset() }}),"
     )?;
 
     // End match
@@ -192,7 +192,7 @@ impl Display for ReadUnalignedField<'_> {
                 // Length too large
                 writeln!(
                     f,
-                    "return Err(::canadensis_encoding::DeserializeError::ArrayLength)"
+                    "return Err(::canadensis_encoding::DeserializeError::ArrayLength {{ offset_bits: cursor.bit_offset() }})"
                 )?;
                 writeln!(f, "}}")?;
             }
@@ -216,7 +216,7 @@ impl Display for ReadUnalignedScalar<'_> {
                 PrimitiveType::Utf8 | PrimitiveType::Byte => {
                     Display::fmt(&CallRead { bits: 8 }, f)?
                 }
-                PrimitiveType::Int { bits } => Display::fmt(&CallRead { bits: *bits }, f)?,
+                PrimitiveType::Int { bits } => write!(f, "cursor.read_i{}() as _", *bits)?,
                 PrimitiveType::UInt { bits, .. } => Display::fmt(&CallRead { bits: *bits }, f)?,
                 PrimitiveType::Float16 { .. } => write!(f, "cursor.read_f16()")?,
                 PrimitiveType::Float32 { .. } => write!(f, "cursor.read_f32()")?,
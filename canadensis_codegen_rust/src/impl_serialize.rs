@@ -3,7 +3,7 @@ use crate::{
     round_up_integer_size, GeneratedField, GeneratedType, GeneratedTypeKind, GeneratedVariant,
 };
 use canadensis_dsdl_frontend::types::{
-    ImplicitField, PrimitiveType, ResolvedScalarType, ResolvedType,
+    CastMode, ImplicitField, PrimitiveType, ResolvedScalarType, ResolvedType,
 };
 use std::fmt::{Display, Formatter, Result};
 
@@ -21,6 +21,18 @@ impl Display for ImplementSerialize<'_, '_> {
             self.ty.name.type_name
         )?;
 
+        // Size bounds
+        writeln!(
+            f,
+            "const MIN_SIZE_BITS: usize = {};",
+            self.ty.size.min_value()
+        )?;
+        writeln!(
+            f,
+            "const MAX_SIZE_BITS: usize = {};",
+            self.ty.size.max_value()
+        )?;
+
         // Size
         writeln!(
             f,
@@ -196,6 +208,7 @@ impl Display for WriteAlignedField<'_> {
                                 bits: 8,
                                 name: self.field_expr,
                                 as_uint: false,
+                                truncate: false,
                             },
                             f,
                         )?;
@@ -206,16 +219,18 @@ impl Display for WriteAlignedField<'_> {
                                 bits: *bits,
                                 name: self.field_expr,
                                 as_uint: true,
+                                truncate: true,
                             },
                             f,
                         )?;
                     }
-                    PrimitiveType::UInt { bits, .. } => {
+                    PrimitiveType::UInt { bits, mode } => {
                         Display::fmt(
                             &CallWriteAligned {
                                 bits: *bits,
                                 name: self.field_expr,
                                 as_uint: false,
+                                truncate: *mode == CastMode::Truncated,
                             },
                             f,
                         )?;
@@ -251,6 +266,7 @@ impl Display for WriteAlignedField<'_> {
                             bits,
                             name: &format!("({}).len()", self.field_expr),
                             as_uint: true,
+                            truncate: false,
                         },
                         f,
                     )?;
@@ -276,6 +292,7 @@ impl Display for WriteAlignedField<'_> {
                             bits,
                             name: &format!("({}).len()", self.field_expr),
                             as_uint: true,
+                            truncate: false,
                         },
                         f,
                     )?;
@@ -319,6 +336,7 @@ impl Display for WriteUnalignedField<'_> {
                                 bits: 8,
                                 name: self.field_expr,
                                 as_uint: false,
+                                truncate: false,
                             },
                             f,
                         )?;
@@ -328,14 +346,16 @@ impl Display for WriteUnalignedField<'_> {
                             bits: *bits,
                             name: self.field_expr,
                             as_uint: true,
+                            truncate: true,
                         },
                         f,
                     )?,
-                    PrimitiveType::UInt { bits, .. } => Display::fmt(
+                    PrimitiveType::UInt { bits, mode } => Display::fmt(
                         &CallWrite {
                             bits: *bits,
                             name: self.field_expr,
                             as_uint: false,
+                            truncate: *mode == CastMode::Truncated,
                         },
                         f,
                     )?,
@@ -370,6 +390,7 @@ impl Display for WriteUnalignedField<'_> {
                             bits,
                             name: &format!("({}).len()", self.field_expr),
                             as_uint: true,
+                            truncate: false,
                         },
                         f,
                     )?;
@@ -395,6 +416,7 @@ impl Display for WriteUnalignedField<'_> {
                             bits,
                             name: &format!("({}).len()", self.field_expr),
                             as_uint: true,
+                            truncate: false,
                         },
                         f,
                     )?;
@@ -446,6 +468,7 @@ impl Display for WriteArrayElements<'_> {
                             bits: *bits,
                             name: "*value",
                             as_uint: true,
+                            truncate: true,
                         },
                         f,
                     )?;
@@ -455,13 +478,14 @@ impl Display for WriteArrayElements<'_> {
                     // Special case for byte arrays
                     writeln!(f, "cursor.write_bytes(&({})[..]);", self.array_expr)
                 }
-                PrimitiveType::UInt { bits, .. } => {
+                PrimitiveType::UInt { bits, mode } => {
                     write!(f, "for value in ({}).iter() {{", self.array_expr)?;
                     Display::fmt(
                         &CallWrite {
                             bits: *bits,
                             name: "*value",
                             as_uint: false,
+                            truncate: *mode == CastMode::Truncated,
                         },
                         f,
                     )?;
@@ -500,6 +524,8 @@ struct CallWriteAligned<'n> {
     bits: u8,
     name: &'n str,
     as_uint: bool,
+    /// If the value may be too large and should be truncated to the number of bits
+    truncate: bool,
 }
 
 impl Display for CallWriteAligned<'_> {
@@ -513,16 +539,26 @@ impl Display for CallWriteAligned<'_> {
             1..=7 | 9..=15 | 17..=31 | 33..=63 => format!("write_u{}", self.bits),
             65..=u8::MAX => panic!("Integer too large"),
         };
-        if self.as_uint {
-            writeln!(
+        match (self.as_uint, truncation_mask(self.bits, self.truncate)) {
+            (true, Some(mask)) => writeln!(
+                f,
+                "cursor.{}(({} as u{}) & {:#x});",
+                function_name,
+                self.name,
+                round_up_integer_size(self.bits),
+                mask
+            ),
+            (false, Some(mask)) => {
+                writeln!(f, "cursor.{}({} & {:#x});", function_name, self.name, mask)
+            }
+            (true, None) => writeln!(
                 f,
                 "cursor.{}({} as u{});",
                 function_name,
                 self.name,
                 round_up_integer_size(self.bits)
-            )
-        } else {
-            writeln!(f, "cursor.{}({});", function_name, self.name)
+            ),
+            (false, None) => writeln!(f, "cursor.{}({});", function_name, self.name),
         }
     }
 }
@@ -531,21 +567,46 @@ struct CallWrite<'n> {
     bits: u8,
     name: &'n str,
     as_uint: bool,
+    /// If the value may be too large and should be truncated to the number of bits
+    truncate: bool,
 }
 
 impl Display for CallWrite<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         let function_name = format!("write_u{}", self.bits);
-        if self.as_uint {
-            writeln!(
+        match (self.as_uint, truncation_mask(self.bits, self.truncate)) {
+            (true, Some(mask)) => writeln!(
+                f,
+                "cursor.{}(({} as u{}) & {:#x});",
+                function_name,
+                self.name,
+                round_up_integer_size(self.bits),
+                mask
+            ),
+            (false, Some(mask)) => {
+                writeln!(f, "cursor.{}({} & {:#x});", function_name, self.name, mask)
+            }
+            (true, None) => writeln!(
                 f,
                 "cursor.{}({} as u{});",
                 function_name,
                 self.name,
                 round_up_integer_size(self.bits)
-            )
-        } else {
-            writeln!(f, "cursor.{}({});", function_name, self.name)
+            ),
+            (false, None) => writeln!(f, "cursor.{}({});", function_name, self.name),
         }
     }
 }
+
+/// Returns the mask that truncates a value to `bits` bits before it is written, or None if the
+/// value does not need to be masked
+///
+/// The `write_uN` functions check that values fit in debug builds, so values of truncated fields
+/// need to be masked. Values with 8, 16, 32, or 64 bits always fit.
+fn truncation_mask(bits: u8, truncate: bool) -> Option<u64> {
+    match bits {
+        8 | 16 | 32 | 64 => None,
+        _ if truncate => Some(u64::MAX >> (64 - bits)),
+        _ => None,
+    }
+}
@@ -28,6 +28,7 @@ use crate::struct_as_enum::{generate_enum_from_struct, has_enum_directive};
 mod error;
 mod impl_constants;
 mod impl_data_type;
+mod impl_default;
 mod impl_deserialize;
 mod impl_serialize;
 mod module_tree;
@@ -740,6 +741,7 @@ mod fmt_impl {
 
     use crate::impl_constants::ImplementConstants;
     use crate::impl_data_type::ImplementDataType;
+    use crate::impl_default::ImplementDefault;
     use crate::impl_deserialize::ImplementDeserialize;
     use crate::impl_serialize::ImplementSerialize;
     use crate::{
@@ -811,6 +813,7 @@ mod fmt_impl {
 
             Display::fmt(&ImplementDataType(self), f)?;
             Display::fmt(&ImplementConstants(self), f)?;
+            Display::fmt(&ImplementDefault(self), f)?;
 
             Display::fmt(
                 &ImplementSerialize {
@@ -940,7 +943,7 @@ mod fmt_impl {
                     f,
                     "#[allow(unused_variables, unused_braces, unused_parens)]"
                 )?;
-                writeln!(f, "#[allow(clippy::identity_op)]")?;
+                writeln!(f, "#[allow(clippy::identity_op, clippy::derivable_impls)]")?;
 
                 writeln!(f, "pub mod {} {{", sub_name)?;
                 Display::fmt(submodule, f)?;
@@ -56,8 +56,15 @@ impl Default for CrcTracker {
 
 #[cfg(test)]
 mod tests {
-    use super::CrcTracker;
+    use super::{crc16_ccitt_false, CrcTracker};
 
+    #[test]
+    fn crc16_check_value() {
+        assert_eq!(crc16_ccitt_false(b""), 0xffff);
+        assert_eq!(crc16_ccitt_false(b"123456789"), 0x29b1);
+        // Payload followed by its CRC
+        assert_eq!(crc16_ccitt_false(b"123456789\x29\xb1"), 0);
+    }
     #[test]
     fn crc_tracker_empty() {
         let tracker = CrcTracker::new();
@@ -150,6 +157,9 @@ const CRC16_POLY: u16 = 0x1021;
 /// A CRC-16-CCITT-false calculator
 ///
 /// The Cyphal/CAN transfer CRC and Cyphal/Serial and Cyphal/UDP header CRC use this.
+///
+/// In a multi-frame Cyphal/CAN transfer, the payload is followed by its CRC with the most
+/// significant byte first. The CRC of the payload and CRC together is zero if the CRC is correct.
 #[derive(Debug)]
 pub struct Crc16CcittFalse {
     value: u16,
@@ -189,3 +199,12 @@ impl Default for Crc16CcittFalse {
         Self::new()
     }
 }
+
+/// Calculates the CRC-16-CCITT-false of a slice of bytes
+///
+/// This is equivalent to adding the bytes to a new [`Crc16CcittFalse`] and getting its CRC.
+pub fn crc16_ccitt_false(bytes: &[u8]) -> u16 {
+    let mut crc = Crc16CcittFalse::new();
+    crc.digest_bytes(bytes);
+    crc.get_crc()
+}
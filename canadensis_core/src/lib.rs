@@ -10,11 +10,15 @@ extern crate fallible_collections;
 extern crate fugit;
 extern crate heapless;
 extern crate log;
+
+#[cfg(feature = "defmt")]
+extern crate defmt;
 pub extern crate nb;
 
 pub mod crc;
 mod error;
 pub mod session;
+pub mod storage;
 pub mod subscription;
 pub mod time;
 pub mod transfer;
@@ -139,7 +143,7 @@ impl From<ServiceId> for usize {
 }
 
 /// A value that can represent a service ID (0..=511) or a subject ID (0..=8192)
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct PortId(u16);
 
 impl From<SubjectId> for PortId {
@@ -171,7 +175,27 @@ impl TryFrom<PortId> for ServiceId {
 /// Basic transfer priority levels that all transports should support
 ///
 /// Transports can define their own priority levels with more detail.
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+///
+/// # Ordering
+///
+/// Priorities are ordered by their numeric values, which is the order of CAN bus arbitration.
+/// A priority that compares as *less* than another is *more* urgent and wins arbitration:
+/// `Exceptional < Immediate < Fast < High < Nominal < Low < Slow < Optional`.
+///
+/// Sorting a list of priorities in ascending order therefore puts the most urgent first:
+///
+/// ```
+/// # use canadensis_core::Priority;
+/// let mut priorities = [Priority::Nominal, Priority::Optional, Priority::Exceptional];
+/// priorities.sort();
+/// assert_eq!(
+///     priorities,
+///     [Priority::Exceptional, Priority::Nominal, Priority::Optional]
+/// );
+/// assert!(Priority::Fast < Priority::Low);
+/// ```
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Priority {
     /// The bus designer can ignore these messages when calculating bus load since they should
     /// only be sent when a total system failure has occurred. For example, a self-destruct message
@@ -279,6 +303,28 @@ mod fmt_impl {
     }
 }
 
+#[cfg(feature = "defmt")]
+mod defmt_impl {
+    use super::{PortId, ServiceId, SubjectId};
+    use defmt::{Format, Formatter};
+
+    impl Format for SubjectId {
+        fn format(&self, f: Formatter<'_>) {
+            self.0.format(f)
+        }
+    }
+    impl Format for ServiceId {
+        fn format(&self, f: Formatter<'_>) {
+            self.0.format(f)
+        }
+    }
+    impl Format for PortId {
+        fn format(&self, f: Formatter<'_>) {
+            self.0.format(f)
+        }
+    }
+}
+
 /// Something that can keep track of the next transfer ID to use for each destination node
 pub trait TransferIdTracker<T: Transport>: Default {
     /// Returns the next transfer ID for the provided node, and increments the stored ID
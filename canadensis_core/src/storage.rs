@@ -0,0 +1,118 @@
+//!
+//! Persistent storage of small values
+//!
+//! Some node functions need to remember values after the node restarts, for example a node ID
+//! from plug-and-play allocation or the values of registers. The [`Storage`] trait lets those
+//! functions use any kind of non-volatile memory.
+//!
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::OutOfMemoryError;
+
+/// Something that can store values identified by keys
+///
+/// An implementation that uses flash memory usually keeps a copy of the stored values in RAM,
+/// so that [`load`](#tymethod.load) can return a reference without reading the flash.
+pub trait Storage {
+    /// The error type
+    type Error: Debug;
+
+    /// Returns the value stored with the provided key, or None if no value has been stored
+    fn load(&self, key: &str) -> Option<&[u8]>;
+
+    /// Stores a value with the provided key, replacing any value already stored with that key
+    ///
+    /// When this function returns `Ok(())`, the value must be available from
+    /// [`load`](#tymethod.load), including after the node restarts if this storage is
+    /// non-volatile.
+    fn store(&mut self, key: &str, value: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// A storage implementation that keeps values in memory
+///
+/// Values are lost when this storage is dropped. This can be used for testing, or on nodes that
+/// do not have any non-volatile memory.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStorage {
+    /// Keys and values, in the order they were first stored
+    entries: Vec<(String, Vec<u8>)>,
+}
+
+impl MemoryStorage {
+    /// Creates an empty storage
+    pub fn new() -> Self {
+        MemoryStorage {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns an iterator over the stored keys and values, in the order they were first stored
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_slice()))
+    }
+}
+
+impl Storage for MemoryStorage {
+    type Error = OutOfMemoryError;
+
+    fn load(&self, key: &str) -> Option<&[u8]> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, value)| value.as_slice())
+    }
+
+    fn store(&mut self, key: &str, value: &[u8]) -> Result<(), Self::Error> {
+        let mut new_value = Vec::new();
+        new_value
+            .try_reserve_exact(value.len())
+            .map_err(|_| OutOfMemoryError)?;
+        new_value.extend_from_slice(value);
+        match self
+            .entries
+            .iter_mut()
+            .find(|(entry_key, _)| entry_key == key)
+        {
+            Some((_, existing_value)) => *existing_value = new_value,
+            None => {
+                let mut new_key = String::new();
+                new_key
+                    .try_reserve_exact(key.len())
+                    .map_err(|_| OutOfMemoryError)?;
+                new_key.push_str(key);
+                self.entries.try_reserve(1).map_err(|_| OutOfMemoryError)?;
+                self.entries.push((new_key, new_value));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MemoryStorage, Storage};
+
+    #[test]
+    fn store_and_load() {
+        let mut storage = MemoryStorage::new();
+        assert_eq!(storage.load("uavcan.node.id"), None);
+        storage.store("uavcan.node.id", &[42, 0]).unwrap();
+        storage.store("uavcan.node.description", b"test").unwrap();
+        assert_eq!(storage.load("uavcan.node.id"), Some(&[42u8, 0][..]));
+        // Replace a value
+        storage.store("uavcan.node.id", &[7]).unwrap();
+        assert_eq!(storage.load("uavcan.node.id"), Some(&[7u8][..]));
+        assert_eq!(
+            storage.iter().collect::<Vec<_>>(),
+            [
+                ("uavcan.node.id", &[7u8][..]),
+                ("uavcan.node.description", &b"test"[..])
+            ]
+        );
+    }
+}
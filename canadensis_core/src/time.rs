@@ -10,8 +10,36 @@
 //! when overflow has happened once. If overflow has happened more than once between two instants,
 //! the calculated duration will be too short.
 //!
+//! Adding a duration to an instant or subtracting a duration from an instant always wraps
+//! around when the result overflows. This does not depend on `debug_assertions`, so debug and
+//! release builds calculate the same times.
+//!
+//! # Durations between instants
+//!
+//! Instants are compared with overflow in mind: if two instants differ by more than half the
+//! range of the underlying integer, the numerically smaller one is assumed to be later because
+//! the clock has overflowed.
+//!
+//! `checked_duration_since` uses the same comparison. It returns `None` if the other instant is
+//! later, and it never panics. Subtracting one instant from another with `-` panics in the
+//! same situation.
+//!
+//! ```
+//! # use canadensis_core::time::Microseconds32;
+//! let earlier = Microseconds32::from_ticks(1_000);
+//! let later = Microseconds32::from_ticks(3_000);
+//! assert_eq!(later.checked_duration_since(earlier).unwrap().ticks(), 2_000);
+//! assert_eq!(earlier.checked_duration_since(later), None);
+//!
+//! // The clock overflowed between these two instants
+//! let before_overflow = Microseconds32::from_ticks(u32::MAX - 99);
+//! let after_overflow = Microseconds32::from_ticks(100);
+//! assert_eq!(after_overflow.checked_duration_since(before_overflow).unwrap().ticks(), 200);
+//! assert_eq!(before_overflow.checked_duration_since(after_overflow), None);
+//! ```
+//!
 
-use fugit::{Instant, MicrosDurationU32, MillisDurationU32};
+use fugit::{Instant, MicrosDurationU32, MillisDurationU32, NanosDurationU64};
 
 /// A duration represented as a 32-bit number of microseconds
 ///
@@ -23,6 +51,26 @@ pub type MicrosecondDuration32 = MicrosDurationU32;
 /// This type overflows after about 1 hour.
 pub type Microseconds32 = Instant<u32, 1, 1_000_000>;
 
+/// A duration represented as a 64-bit number of nanoseconds
+pub type NanosecondDuration64 = NanosDurationU64;
+
+/// An instant represented as a 64-bit number of nanoseconds
+///
+/// This type overflows after about 584 years.
+///
+/// Canadensis uses [`Microseconds32`] for all times, but this type can be useful for
+/// high-resolution time sources. [`nanoseconds_to_microseconds32`] converts a value of this type
+/// into a `Microseconds32`.
+pub type Nanoseconds64 = Instant<u64, 1, 1_000_000_000>;
+
+/// Converts a 64-bit nanosecond instant into a 32-bit microsecond instant
+///
+/// The result wraps around in the same way as a 32-bit microsecond counter, so this can be used
+/// to implement [`Clock`] with a nanosecond time source.
+pub const fn nanoseconds_to_microseconds32(instant: Nanoseconds64) -> Microseconds32 {
+    Microseconds32::from_ticks((instant.ticks() / 1000) as u32)
+}
+
 /// Something that can provide the current time
 pub trait Clock {
     /// Returns the current time
@@ -41,3 +89,130 @@ pub const fn milliseconds(milliseconds: u32) -> MicrosecondDuration32 {
         .const_try_into()
         .expect("Number of milliseconds out of range")
 }
+
+/// Converts instants from one clock into instants of another clock
+///
+/// This can be used by a gateway that forwards transfers between two networks with different
+/// clocks. It holds a reference pair of instants (one from each clock) that were measured at the
+/// same time, and the rate of the destination clock relative to the source clock.
+///
+/// The rate difference is expressed in parts per million. A value of 100 means that the
+/// destination clock advances 1.0001 microseconds for every microsecond of the source clock.
+///
+/// ```
+/// # use canadensis_core::time::{ClockConverter, Microseconds32};
+/// let mut converter = ClockConverter::new(
+///     Microseconds32::from_ticks(1_000),
+///     Microseconds32::from_ticks(501_000),
+/// );
+/// assert_eq!(
+///     converter.convert(Microseconds32::from_ticks(3_000)),
+///     Microseconds32::from_ticks(503_000)
+/// );
+///
+/// // The destination clock runs 0.1% faster than the source clock
+/// converter.set_rate_ppm(1_000);
+/// assert_eq!(
+///     converter.convert(Microseconds32::from_ticks(3_000)),
+///     Microseconds32::from_ticks(503_002)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ClockConverter {
+    /// An instant from the source clock
+    source_reference: Microseconds32,
+    /// The instant from the destination clock at the same time as `source_reference`
+    destination_reference: Microseconds32,
+    /// The rate difference between the clocks, in parts per million
+    rate_ppm: i32,
+}
+
+impl ClockConverter {
+    /// Creates a converter from two instants that were measured at the same time, with no rate
+    /// difference between the clocks
+    ///
+    /// * `source`: An instant from the source clock
+    /// * `destination`: The instant from the destination clock at the same time
+    pub const fn new(source: Microseconds32, destination: Microseconds32) -> Self {
+        ClockConverter {
+            source_reference: source,
+            destination_reference: destination,
+            rate_ppm: 0,
+        }
+    }
+
+    /// Converts an instant from the source clock into the corresponding instant of the
+    /// destination clock
+    ///
+    /// `instant` may be before or after the source reference instant, but it must be within
+    /// about half an hour of it. As with other instants, the result wraps around when it
+    /// overflows.
+    pub fn convert(&self, instant: Microseconds32) -> Microseconds32 {
+        let source_elapsed =
+            i64::from(instant.ticks().wrapping_sub(self.source_reference.ticks()) as i32);
+        let destination_elapsed =
+            source_elapsed + source_elapsed * i64::from(self.rate_ppm) / 1_000_000;
+        Microseconds32::from_ticks(
+            self.destination_reference
+                .ticks()
+                .wrapping_add(destination_elapsed as u32),
+        )
+    }
+
+    /// Replaces the reference instants with two new instants that were measured at the same time
+    ///
+    /// The rate difference does not change. Calling this function regularly corrects for any
+    /// error that accumulates because the rate difference is not exact.
+    pub fn recalibrate(&mut self, source: Microseconds32, destination: Microseconds32) {
+        self.source_reference = source;
+        self.destination_reference = destination;
+    }
+
+    /// Returns the rate difference between the clocks, in parts per million
+    pub fn rate_ppm(&self) -> i32 {
+        self.rate_ppm
+    }
+
+    /// Sets the rate difference between the clocks, in parts per million
+    ///
+    /// A positive value means that the destination clock runs faster than the source clock.
+    pub fn set_rate_ppm(&mut self, rate_ppm: i32) {
+        self.rate_ppm = rate_ppm;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ClockConverter, Microseconds32};
+
+    fn instant(ticks: u32) -> Microseconds32 {
+        Microseconds32::from_ticks(ticks)
+    }
+
+    #[test]
+    fn convert_offset() {
+        let converter = ClockConverter::new(instant(10_000), instant(4_000));
+        assert_eq!(converter.convert(instant(10_000)), instant(4_000));
+        assert_eq!(converter.convert(instant(12_500)), instant(6_500));
+        // Before the reference
+        assert_eq!(converter.convert(instant(9_000)), instant(3_000));
+        // Destination clock overflows
+        assert_eq!(converter.convert(instant(5_000)), instant(u32::MAX - 999));
+        // Source clock overflows
+        let converter = ClockConverter::new(instant(u32::MAX - 99), instant(1_000));
+        assert_eq!(converter.convert(instant(100)), instant(1_200));
+    }
+
+    #[test]
+    fn convert_rate() {
+        let mut converter = ClockConverter::new(instant(0), instant(1_000_000));
+        converter.set_rate_ppm(-50);
+        assert_eq!(converter.convert(instant(2_000_000)), instant(2_999_900));
+        assert_eq!(converter.convert(instant(u32::MAX - 999_999)), instant(50));
+
+        converter.recalibrate(instant(2_000_000), instant(3_000_000));
+        assert_eq!(converter.rate_ppm(), -50);
+        assert_eq!(converter.convert(instant(2_000_000)), instant(3_000_000));
+        assert_eq!(converter.convert(instant(4_000_000)), instant(4_999_900));
+    }
+}
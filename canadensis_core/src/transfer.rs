@@ -274,6 +274,19 @@ pub struct Transfer<A, T: Transport + ?Sized> {
     pub payload: A,
 }
 
+impl<A, T: Transport + ?Sized> Transfer<A, T>
+where
+    A: AsRef<[u8]>,
+{
+    /// Returns the length of the payload in bytes
+    ///
+    /// This works for both owned and borrowed payloads.
+    #[inline]
+    pub fn payload_len(&self) -> usize {
+        self.payload.as_ref().len()
+    }
+}
+
 impl<A, T: Transport + ?Sized> Debug for Transfer<A, T>
 where
     A: Debug,
@@ -316,6 +329,56 @@ where
     }
 }
 
+/// A transfer that a receiver has assembled from incoming frames
+pub struct ReceivedTransfer<A, T: Transport + ?Sized> {
+    /// The transfer
+    ///
+    /// The header timestamp is the time when the first frame was received.
+    pub transfer: Transfer<A, T>,
+    /// The time when the last frame of the transfer was received
+    ///
+    /// For single-frame transfers, this is equal to the header timestamp.
+    pub completion_timestamp: Microseconds32,
+}
+
+impl<A, T: Transport + ?Sized> Debug for ReceivedTransfer<A, T>
+where
+    A: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReceivedTransfer")
+            .field("transfer", &self.transfer)
+            .field("completion_timestamp", &self.completion_timestamp)
+            .finish()
+    }
+}
+
+impl<A, T: Transport + ?Sized> PartialEq for ReceivedTransfer<A, T>
+where
+    A: PartialEq,
+    T::TransferId: PartialEq,
+    T::Priority: PartialEq,
+    T::NodeId: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.transfer == other.transfer && self.completion_timestamp == other.completion_timestamp
+    }
+}
+impl<A, T: Transport + ?Sized> Clone for ReceivedTransfer<A, T>
+where
+    A: Clone,
+    T::TransferId: Clone,
+    T::Priority: Clone,
+    T::NodeId: Clone,
+{
+    fn clone(&self) -> Self {
+        ReceivedTransfer {
+            transfer: self.transfer.clone(),
+            completion_timestamp: self.completion_timestamp,
+        }
+    }
+}
+
 /// A type of transfer that is always a message transfer
 #[derive(Clone)]
 pub struct MessageTransfer<A, T: Transport + ?Sized> {
@@ -330,12 +393,30 @@ pub struct MessageTransfer<A, T: Transport + ?Sized> {
     /// If the transport does not support loopback, this flag has no effect.
     ///
     pub loopback: bool,
+    /// The time when the last frame of this transfer was received
+    ///
+    /// The time when the first frame was received is the header timestamp. For single-frame
+    /// transfers, the two timestamps are equal.
+    pub completion_timestamp: Microseconds32,
     /// The actual transfer payload
     ///
     /// The type A usually implements `AsRef<[u8]>`. It is often a `Vec<u8>` or a `&[u8]`.
     pub payload: A,
 }
 
+impl<A, T: Transport + ?Sized> MessageTransfer<A, T>
+where
+    A: AsRef<[u8]>,
+{
+    /// Returns the length of the payload in bytes
+    ///
+    /// This works for both owned and borrowed payloads.
+    #[inline]
+    pub fn payload_len(&self) -> usize {
+        self.payload.as_ref().len()
+    }
+}
+
 impl<A, T: Transport + ?Sized> Debug for MessageTransfer<A, T>
 where
     A: Debug,
@@ -344,6 +425,7 @@ where
         f.debug_struct("Transfer")
             .field("header", &self.header)
             .field("loopback", &self.loopback)
+            .field("completion_timestamp", &self.completion_timestamp)
             .field("payload", &self.payload)
             .finish()
     }
@@ -359,6 +441,7 @@ where
     fn eq(&self, other: &Self) -> bool {
         self.header == other.header
             && self.loopback == other.loopback
+            && self.completion_timestamp == other.completion_timestamp
             && self.payload == other.payload
     }
 }
@@ -377,12 +460,30 @@ pub struct ServiceTransfer<A, T: Transport + ?Sized> {
     /// If the transport does not support loopback, this flag has no effect.
     ///
     pub loopback: bool,
+    /// The time when the last frame of this transfer was received
+    ///
+    /// The time when the first frame was received is the header timestamp. For single-frame
+    /// transfers, the two timestamps are equal.
+    pub completion_timestamp: Microseconds32,
     /// The actual transfer payload
     ///
     /// The type A usually implements `AsRef<[u8]>`. It is often a `Vec<u8>` or a `&[u8]`.
     pub payload: A,
 }
 
+impl<A, T: Transport + ?Sized> ServiceTransfer<A, T>
+where
+    A: AsRef<[u8]>,
+{
+    /// Returns the length of the payload in bytes
+    ///
+    /// This works for both owned and borrowed payloads.
+    #[inline]
+    pub fn payload_len(&self) -> usize {
+        self.payload.as_ref().len()
+    }
+}
+
 impl<A, T: Transport + ?Sized> Debug for ServiceTransfer<A, T>
 where
     A: Debug,
@@ -391,6 +492,7 @@ where
         f.debug_struct("Transfer")
             .field("header", &self.header)
             .field("loopback", &self.loopback)
+            .field("completion_timestamp", &self.completion_timestamp)
             .field("payload", &self.payload)
             .finish()
     }
@@ -406,6 +508,7 @@ where
     fn eq(&self, other: &Self) -> bool {
         self.header == other.header
             && self.loopback == other.loopback
+            && self.completion_timestamp == other.completion_timestamp
             && self.payload == other.payload
     }
 }
@@ -1,8 +1,8 @@
 //! Transport layer traits
 
 use crate::error::{OutOfMemoryError, ServiceSubscribeError};
-use crate::time::{Clock, MicrosecondDuration32};
-use crate::transfer::Transfer;
+use crate::time::{Clock, MicrosecondDuration32, Microseconds32};
+use crate::transfer::{Header, ReceivedTransfer, Transfer};
 use crate::{ServiceId, SubjectId};
 use alloc::vec::Vec;
 use core::convert::TryFrom;
@@ -39,6 +39,17 @@ where
     ///
     /// The transport implementation may block until the entire transfer is sent, or put frames in
     /// a queue to be sent separately.
+    ///
+    /// A received transfer can be forwarded (for example, from one CAN bus to another) by taking
+    /// the `transfer` from the [`ReceivedTransfer`], setting its header timestamp to the
+    /// transmission deadline with
+    /// [`Header::set_timestamp`](crate::transfer::Header::set_timestamp), and passing it to this
+    /// function. The payload is split into frames again, so the frames only match the original
+    /// frames if both transmitters have the same MTU.
+    ///
+    /// If the frames are put in a queue that does not have space for all of them, this function
+    /// should return an error without adding any frames to the queue. Subscribers cannot
+    /// reassemble a transfer with missing frames.
     fn push<A>(
         &mut self,
         transfer: Transfer<A, Self::Transport>,
@@ -69,6 +80,63 @@ where
     /// For example, Cyphal/CAN over classic CAN can transfer up to 7 bytes per frame (the eighth
     /// byte is used up by the tail byte), so it would return 7.
     fn mtu(&self) -> usize;
+
+    /// Returns the number of frames that a transfer with a payload of `payload_length` bytes
+    /// will use
+    ///
+    /// This includes any space used for transfer CRCs and tail bytes. It can be used to check
+    /// that a queue has enough space for a transfer before pushing it.
+    ///
+    /// The default implementation divides the payload length by [`mtu`](#tymethod.mtu),
+    /// rounding up. Transports that add a transfer CRC or other per-transfer data should
+    /// override it.
+    fn frame_count_for(&self, payload_length: usize) -> usize {
+        core::cmp::max(1, payload_length.div_ceil(self.mtu()))
+    }
+
+    /// Returns true if `error` means that the queue of outgoing frames did not have space for a
+    /// transfer
+    ///
+    /// This error is usually temporary. The transfer can be sent again after some queued frames
+    /// have been transmitted.
+    ///
+    /// The default implementation returns false.
+    fn is_queue_full(_error: &Self::Error) -> bool {
+        false
+    }
+}
+
+/// A transmitter that can send a transfer while its payload is being produced
+///
+/// This avoids the need for a buffer that holds the whole payload.
+pub trait StreamingTransmitter<C>: Transmitter<C>
+where
+    C: Clock,
+{
+    /// Starts the process of sending an outgoing transfer, calling `write_payload` to produce
+    /// the payload
+    ///
+    /// `write_payload` receives a function that accepts parts of the payload in order.
+    /// It must pass exactly `payload_length` bytes to that function in total.
+    ///
+    /// A transport that cannot send some transfers this way (for example, anonymous transfers
+    /// that depend on the whole payload) may collect the payload into a buffer and send it
+    /// normally.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if `write_payload` does not produce `payload_length` bytes.
+    fn push_streaming<F>(
+        &mut self,
+        header: Header<Self::Transport>,
+        loopback: bool,
+        payload_length: usize,
+        write_payload: F,
+        clock: &mut C,
+        driver: &mut Self::Driver,
+    ) -> nb::Result<(), Self::Error>
+    where
+        F: FnOnce(&mut dyn FnMut(&[u8]));
 }
 
 /// A receiver that can assemble incoming frames into transfers
@@ -88,7 +156,7 @@ where
     /// Checks for incoming frames and processes them, possibly returning a transfer
     ///
     /// If the frame completes a transfer and the transfer matches an active subscription, the
-    /// transfer is returned.
+    /// transfer is returned along with the time when its last frame was received.
     ///
     /// This function must not block. If no frame can immediately be read, it should return `Ok(None)`.
     ///
@@ -105,7 +173,7 @@ where
         &mut self,
         clock: &mut C,
         driver: &mut Self::Driver,
-    ) -> Result<Option<Transfer<Vec<u8>, Self::Transport>>, Self::Error>;
+    ) -> Result<Option<ReceivedTransfer<Vec<u8>, Self::Transport>>, Self::Error>;
 
     /// Subscribes to messages on a subject
     ///
@@ -192,10 +260,22 @@ where
 
     /// Returns an iterator over all servers of this Receiver
     fn servers(&self) -> impl Iterator<Item = ServiceId>;
+
+    /// Discards partially received transfers that started more than their subscriptions'
+    /// transfer-ID timeouts before `now`, and returns the number of transfers discarded
+    ///
+    /// A transport may only check for expired transfers when a new frame arrives from the same
+    /// source on the same port. Calling this function periodically frees the memory used by
+    /// incomplete transfers when no more frames arrive.
+    ///
+    /// The default implementation does nothing and returns 0.
+    fn clean_expired(&mut self, _now: Microseconds32) -> usize {
+        0
+    }
 }
 
 /// Required operations for a transfer ID
-pub trait TransferId: Default + Debug + Clone {
+pub trait TransferId: Default + Debug + Clone + Eq + Hash {
     /// Increments the value of this transfer ID by 1
     ///
     /// If this transfer ID is the maximum allowed value, this function must wrap around to the
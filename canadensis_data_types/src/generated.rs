@@ -1,7 +1,7 @@
 #[cfg(not(target_endian = "little"))]
 compile_error!("Zero-copy serialization requires a little-endian target");
 #[allow(unused_variables, unused_braces, unused_parens)]
-#[allow(clippy::identity_op)]
+#[allow(clippy::identity_op, clippy::derivable_impls)]
 pub mod reg {
     pub mod udral {
         pub mod physics {
@@ -45,7 +45,18 @@ pub mod reg {
                     }
                     impl ::canadensis_encoding::Message for Note {}
                     impl Note {}
+                    impl ::core::default::Default for Note {
+                        fn default() -> Self {
+                            Note {
+                                frequency: ::core::default::Default::default(),
+                                duration: ::core::default::Default::default(),
+                                acoustic_power: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Note {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -108,7 +119,17 @@ pub torque: crate::uavcan::si::unit::torque::scalar_1_0::Scalar,
                         }
                         impl ::canadensis_encoding::Message for Planar {}
                         impl Planar {}
+                        impl ::core::default::Default for Planar {
+                            fn default() -> Self {
+                                Planar {
+                                    kinematics: ::core::default::Default::default(),
+                                    torque: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Planar {
+                            const MIN_SIZE_BITS: usize = 128;
+                            const MAX_SIZE_BITS: usize = 128;
                             fn size_bits(&self) -> usize {
                                 128
                             }
@@ -161,7 +182,17 @@ pub value: crate::reg::udral::physics::dynamics::rotation::planar_0_1::Planar,
                         }
                         impl ::canadensis_encoding::Message for PlanarTs {}
                         impl PlanarTs {}
+                        impl ::core::default::Default for PlanarTs {
+                            fn default() -> Self {
+                                PlanarTs {
+                                    timestamp: ::core::default::Default::default(),
+                                    value: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PlanarTs {
+                            const MIN_SIZE_BITS: usize = 184;
+                            const MAX_SIZE_BITS: usize = 184;
                             fn size_bits(&self) -> usize {
                                 184
                             }
@@ -223,7 +254,17 @@ pub force: crate::uavcan::si::unit::force::scalar_1_0::Scalar,
                         }
                         impl ::canadensis_encoding::Message for Linear {}
                         impl Linear {}
+                        impl ::core::default::Default for Linear {
+                            fn default() -> Self {
+                                Linear {
+                                    kinematics: ::core::default::Default::default(),
+                                    force: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Linear {
+                            const MIN_SIZE_BITS: usize = 128;
+                            const MAX_SIZE_BITS: usize = 128;
                             fn size_bits(&self) -> usize {
                                 128
                             }
@@ -276,7 +317,17 @@ pub value: crate::reg::udral::physics::dynamics::translation::linear_0_1::Linear
                         }
                         impl ::canadensis_encoding::Message for LinearTs {}
                         impl LinearTs {}
+                        impl ::core::default::Default for LinearTs {
+                            fn default() -> Self {
+                                LinearTs {
+                                    timestamp: ::core::default::Default::default(),
+                                    value: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for LinearTs {
+                            const MIN_SIZE_BITS: usize = 184;
+                            const MAX_SIZE_BITS: usize = 184;
                             fn size_bits(&self) -> usize {
                                 184
                             }
@@ -338,7 +389,17 @@ pub value: crate::reg::udral::physics::dynamics::translation::linear_0_1::Linear
                     }
                     impl ::canadensis_encoding::Message for Power {}
                     impl Power {}
+                    impl ::core::default::Default for Power {
+                        fn default() -> Self {
+                            Power {
+                                current: ::core::default::Default::default(),
+                                voltage: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Power {
+                        const MIN_SIZE_BITS: usize = 64;
+                        const MAX_SIZE_BITS: usize = 64;
                         fn size_bits(&self) -> usize {
                             64
                         }
@@ -389,7 +450,17 @@ pub value: crate::reg::udral::physics::dynamics::translation::linear_0_1::Linear
                     }
                     impl ::canadensis_encoding::Message for PowerTs {}
                     impl PowerTs {}
+                    impl ::core::default::Default for PowerTs {
+                        fn default() -> Self {
+                            PowerTs {
+                                timestamp: ::core::default::Default::default(),
+                                value: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for PowerTs {
+                        const MIN_SIZE_BITS: usize = 120;
+                        const MAX_SIZE_BITS: usize = 120;
                         fn size_bits(&self) -> usize {
                             120
                         }
@@ -463,7 +534,18 @@ pub value: crate::reg::udral::physics::dynamics::translation::linear_0_1::Linear
                     }
                     impl ::canadensis_encoding::Message for Source {}
                     impl Source {}
+                    impl ::core::default::Default for Source {
+                        fn default() -> Self {
+                            Source {
+                                power: ::core::default::Default::default(),
+                                energy: ::core::default::Default::default(),
+                                full_energy: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Source {
+                        const MIN_SIZE_BITS: usize = 128;
+                        const MAX_SIZE_BITS: usize = 128;
                         fn size_bits(&self) -> usize {
                             128
                         }
@@ -515,7 +597,17 @@ pub value: crate::reg::udral::physics::dynamics::translation::linear_0_1::Linear
                     }
                     impl ::canadensis_encoding::Message for SourceTs {}
                     impl SourceTs {}
+                    impl ::core::default::Default for SourceTs {
+                        fn default() -> Self {
+                            SourceTs {
+                                timestamp: ::core::default::Default::default(),
+                                value: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for SourceTs {
+                        const MIN_SIZE_BITS: usize = 184;
+                        const MAX_SIZE_BITS: usize = 184;
                         fn size_bits(&self) -> usize {
                             184
                         }
@@ -569,7 +661,16 @@ pub value: crate::reg::udral::physics::dynamics::translation::linear_0_1::Linear
                         }
                         impl ::canadensis_encoding::Message for Point {}
                         impl Point {}
+                        impl ::core::default::Default for Point {
+                            fn default() -> Self {
+                                Point {
+                                    value: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Point {
+                            const MIN_SIZE_BITS: usize = 192;
+                            const MAX_SIZE_BITS: usize = 192;
                             fn size_bits(&self) -> usize {
                                 192
                             }
@@ -630,7 +731,17 @@ pub value: crate::reg::udral::physics::dynamics::translation::linear_0_1::Linear
                         }
                         impl ::canadensis_encoding::Message for PointState {}
                         impl PointState {}
+                        impl ::core::default::Default for PointState {
+                            fn default() -> Self {
+                                PointState {
+                                    position: ::core::default::Default::default(),
+                                    velocity: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PointState {
+                            const MIN_SIZE_BITS: usize = 288;
+                            const MAX_SIZE_BITS: usize = 288;
                             fn size_bits(&self) -> usize {
                                 288
                             }
@@ -688,7 +799,17 @@ pub velocity: crate::reg::udral::physics::kinematics::translation::velocity3_var
                         }
                         impl ::canadensis_encoding::Message for PointStateVar {}
                         impl PointStateVar {}
+                        impl ::core::default::Default for PointStateVar {
+                            fn default() -> Self {
+                                PointStateVar {
+                                    position: ::core::default::Default::default(),
+                                    velocity: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PointStateVar {
+                            const MIN_SIZE_BITS: usize = 480;
+                            const MAX_SIZE_BITS: usize = 480;
                             fn size_bits(&self) -> usize {
                                 480
                             }
@@ -741,7 +862,17 @@ pub value: crate::reg::udral::physics::kinematics::cartesian::point_state_var_0_
                         }
                         impl ::canadensis_encoding::Message for PointStateVarTs {}
                         impl PointStateVarTs {}
+                        impl ::core::default::Default for PointStateVarTs {
+                            fn default() -> Self {
+                                PointStateVarTs {
+                                    timestamp: ::core::default::Default::default(),
+                                    value: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PointStateVarTs {
+                            const MIN_SIZE_BITS: usize = 536;
+                            const MAX_SIZE_BITS: usize = 536;
                             fn size_bits(&self) -> usize {
                                 536
                             }
@@ -801,7 +932,19 @@ pub value: crate::reg::udral::physics::kinematics::cartesian::point_state_var_0_
                         }
                         impl ::canadensis_encoding::Message for PointVar {}
                         impl PointVar {}
+                        impl ::core::default::Default for PointVar {
+                            fn default() -> Self {
+                                PointVar {
+                                    value: ::core::default::Default::default(),
+                                    covariance_urt: ::core::array::from_fn(|_| {
+                                        ::core::default::Default::default()
+                                    }),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PointVar {
+                            const MIN_SIZE_BITS: usize = 288;
+                            const MAX_SIZE_BITS: usize = 288;
                             fn size_bits(&self) -> usize {
                                 288
                             }
@@ -860,7 +1003,17 @@ pub value: crate::reg::udral::physics::kinematics::cartesian::point_state_var_0_
                         }
                         impl ::canadensis_encoding::Message for Pose {}
                         impl Pose {}
+                        impl ::core::default::Default for Pose {
+                            fn default() -> Self {
+                                Pose {
+                                    position: ::core::default::Default::default(),
+                                    orientation: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Pose {
+                            const MIN_SIZE_BITS: usize = 320;
+                            const MAX_SIZE_BITS: usize = 320;
                             fn size_bits(&self) -> usize {
                                 320
                             }
@@ -922,7 +1075,19 @@ pub value: crate::reg::udral::physics::kinematics::cartesian::point_state_var_0_
                         }
                         impl ::canadensis_encoding::Message for PoseVar {}
                         impl PoseVar {}
+                        impl ::core::default::Default for PoseVar {
+                            fn default() -> Self {
+                                PoseVar {
+                                    value: ::core::default::Default::default(),
+                                    covariance_urt: ::core::array::from_fn(|_| {
+                                        ::core::default::Default::default()
+                                    }),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PoseVar {
+                            const MIN_SIZE_BITS: usize = 656;
+                            const MAX_SIZE_BITS: usize = 656;
                             fn size_bits(&self) -> usize {
                                 656
                             }
@@ -975,7 +1140,17 @@ pub value: crate::reg::udral::physics::kinematics::cartesian::pose_var_0_1::Pose
                         }
                         impl ::canadensis_encoding::Message for PoseVarTs {}
                         impl PoseVarTs {}
+                        impl ::core::default::Default for PoseVarTs {
+                            fn default() -> Self {
+                                PoseVarTs {
+                                    timestamp: ::core::default::Default::default(),
+                                    value: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PoseVarTs {
+                            const MIN_SIZE_BITS: usize = 712;
+                            const MAX_SIZE_BITS: usize = 712;
                             fn size_bits(&self) -> usize {
                                 712
                             }
@@ -1036,7 +1211,17 @@ pub value: crate::reg::udral::physics::kinematics::cartesian::pose_var_0_1::Pose
                         }
                         impl ::canadensis_encoding::Message for State {}
                         impl State {}
+                        impl ::core::default::Default for State {
+                            fn default() -> Self {
+                                State {
+                                    pose: ::core::default::Default::default(),
+                                    twist: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for State {
+                            const MIN_SIZE_BITS: usize = 512;
+                            const MAX_SIZE_BITS: usize = 512;
                             fn size_bits(&self) -> usize {
                                 512
                             }
@@ -1097,7 +1282,17 @@ pub twist: crate::reg::udral::physics::kinematics::cartesian::twist_var_0_1::Twi
                         }
                         impl ::canadensis_encoding::Message for StateVar {}
                         impl StateVar {}
+                        impl ::core::default::Default for StateVar {
+                            fn default() -> Self {
+                                StateVar {
+                                    pose: ::core::default::Default::default(),
+                                    twist: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for StateVar {
+                            const MIN_SIZE_BITS: usize = 1184;
+                            const MAX_SIZE_BITS: usize = 1184;
                             fn size_bits(&self) -> usize {
                                 1184
                             }
@@ -1150,7 +1345,17 @@ pub value: crate::reg::udral::physics::kinematics::cartesian::state_var_0_1::Sta
                         }
                         impl ::canadensis_encoding::Message for StateVarTs {}
                         impl StateVarTs {}
+                        impl ::core::default::Default for StateVarTs {
+                            fn default() -> Self {
+                                StateVarTs {
+                                    timestamp: ::core::default::Default::default(),
+                                    value: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for StateVarTs {
+                            const MIN_SIZE_BITS: usize = 1240;
+                            const MAX_SIZE_BITS: usize = 1240;
                             fn size_bits(&self) -> usize {
                                 1240
                             }
@@ -1215,7 +1420,17 @@ pub value: crate::reg::udral::physics::kinematics::cartesian::state_var_0_1::Sta
                         }
                         impl ::canadensis_encoding::Message for Twist {}
                         impl Twist {}
+                        impl ::core::default::Default for Twist {
+                            fn default() -> Self {
+                                Twist {
+                                    linear: ::core::default::Default::default(),
+                                    angular: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Twist {
+                            const MIN_SIZE_BITS: usize = 192;
+                            const MAX_SIZE_BITS: usize = 192;
                             fn size_bits(&self) -> usize {
                                 192
                             }
@@ -1277,7 +1492,19 @@ pub value: crate::reg::udral::physics::kinematics::cartesian::state_var_0_1::Sta
                         }
                         impl ::canadensis_encoding::Message for TwistVar {}
                         impl TwistVar {}
+                        impl ::core::default::Default for TwistVar {
+                            fn default() -> Self {
+                                TwistVar {
+                                    value: ::core::default::Default::default(),
+                                    covariance_urt: ::core::array::from_fn(|_| {
+                                        ::core::default::Default::default()
+                                    }),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for TwistVar {
+                            const MIN_SIZE_BITS: usize = 528;
+                            const MAX_SIZE_BITS: usize = 528;
                             fn size_bits(&self) -> usize {
                                 528
                             }
@@ -1330,7 +1557,17 @@ pub value: crate::reg::udral::physics::kinematics::cartesian::twist_var_0_1::Twi
                         }
                         impl ::canadensis_encoding::Message for TwistVarTs {}
                         impl TwistVarTs {}
+                        impl ::core::default::Default for TwistVarTs {
+                            fn default() -> Self {
+                                TwistVarTs {
+                                    timestamp: ::core::default::Default::default(),
+                                    value: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for TwistVarTs {
+                            const MIN_SIZE_BITS: usize = 584;
+                            const MAX_SIZE_BITS: usize = 584;
                             fn size_bits(&self) -> usize {
                                 584
                             }
@@ -1404,7 +1641,18 @@ pub value: crate::reg::udral::physics::kinematics::cartesian::twist_var_0_1::Twi
                         }
                         impl ::canadensis_encoding::Message for Point {}
                         impl Point {}
+                        impl ::core::default::Default for Point {
+                            fn default() -> Self {
+                                Point {
+                                    latitude: ::core::default::Default::default(),
+                                    longitude: ::core::default::Default::default(),
+                                    altitude: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Point {
+                            const MIN_SIZE_BITS: usize = 192;
+                            const MAX_SIZE_BITS: usize = 192;
                             fn size_bits(&self) -> usize {
                                 192
                             }
@@ -1467,7 +1715,17 @@ pub value: crate::reg::udral::physics::kinematics::cartesian::twist_var_0_1::Twi
                         }
                         impl ::canadensis_encoding::Message for PointState {}
                         impl PointState {}
+                        impl ::core::default::Default for PointState {
+                            fn default() -> Self {
+                                PointState {
+                                    position: ::core::default::Default::default(),
+                                    velocity: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PointState {
+                            const MIN_SIZE_BITS: usize = 288;
+                            const MAX_SIZE_BITS: usize = 288;
                             fn size_bits(&self) -> usize {
                                 288
                             }
@@ -1525,7 +1783,17 @@ pub velocity: crate::reg::udral::physics::kinematics::translation::velocity3_var
                         }
                         impl ::canadensis_encoding::Message for PointStateVar {}
                         impl PointStateVar {}
+                        impl ::core::default::Default for PointStateVar {
+                            fn default() -> Self {
+                                PointStateVar {
+                                    position: ::core::default::Default::default(),
+                                    velocity: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PointStateVar {
+                            const MIN_SIZE_BITS: usize = 480;
+                            const MAX_SIZE_BITS: usize = 480;
                             fn size_bits(&self) -> usize {
                                 480
                             }
@@ -1578,7 +1846,17 @@ pub value: crate::reg::udral::physics::kinematics::geodetic::point_state_var_0_1
                         }
                         impl ::canadensis_encoding::Message for PointStateVarTs {}
                         impl PointStateVarTs {}
+                        impl ::core::default::Default for PointStateVarTs {
+                            fn default() -> Self {
+                                PointStateVarTs {
+                                    timestamp: ::core::default::Default::default(),
+                                    value: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PointStateVarTs {
+                            const MIN_SIZE_BITS: usize = 536;
+                            const MAX_SIZE_BITS: usize = 536;
                             fn size_bits(&self) -> usize {
                                 536
                             }
@@ -1638,7 +1916,19 @@ pub value: crate::reg::udral::physics::kinematics::geodetic::point_state_var_0_1
                         }
                         impl ::canadensis_encoding::Message for PointVar {}
                         impl PointVar {}
+                        impl ::core::default::Default for PointVar {
+                            fn default() -> Self {
+                                PointVar {
+                                    value: ::core::default::Default::default(),
+                                    covariance_urt: ::core::array::from_fn(|_| {
+                                        ::core::default::Default::default()
+                                    }),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PointVar {
+                            const MIN_SIZE_BITS: usize = 288;
+                            const MAX_SIZE_BITS: usize = 288;
                             fn size_bits(&self) -> usize {
                                 288
                             }
@@ -1701,7 +1991,17 @@ pub value: crate::reg::udral::physics::kinematics::geodetic::point_state_var_0_1
                         }
                         impl ::canadensis_encoding::Message for Pose {}
                         impl Pose {}
+                        impl ::core::default::Default for Pose {
+                            fn default() -> Self {
+                                Pose {
+                                    position: ::core::default::Default::default(),
+                                    orientation: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Pose {
+                            const MIN_SIZE_BITS: usize = 320;
+                            const MAX_SIZE_BITS: usize = 320;
                             fn size_bits(&self) -> usize {
                                 320
                             }
@@ -1763,7 +2063,19 @@ pub value: crate::reg::udral::physics::kinematics::geodetic::point_state_var_0_1
                         }
                         impl ::canadensis_encoding::Message for PoseVar {}
                         impl PoseVar {}
+                        impl ::core::default::Default for PoseVar {
+                            fn default() -> Self {
+                                PoseVar {
+                                    value: ::core::default::Default::default(),
+                                    covariance_urt: ::core::array::from_fn(|_| {
+                                        ::core::default::Default::default()
+                                    }),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PoseVar {
+                            const MIN_SIZE_BITS: usize = 656;
+                            const MAX_SIZE_BITS: usize = 656;
                             fn size_bits(&self) -> usize {
                                 656
                             }
@@ -1826,7 +2138,17 @@ pub value: crate::reg::udral::physics::kinematics::geodetic::point_state_var_0_1
                         }
                         impl ::canadensis_encoding::Message for State {}
                         impl State {}
+                        impl ::core::default::Default for State {
+                            fn default() -> Self {
+                                State {
+                                    pose: ::core::default::Default::default(),
+                                    twist: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for State {
+                            const MIN_SIZE_BITS: usize = 512;
+                            const MAX_SIZE_BITS: usize = 512;
                             fn size_bits(&self) -> usize {
                                 512
                             }
@@ -1887,7 +2209,17 @@ pub twist: crate::reg::udral::physics::kinematics::cartesian::twist_var_0_1::Twi
                         }
                         impl ::canadensis_encoding::Message for StateVar {}
                         impl StateVar {}
+                        impl ::core::default::Default for StateVar {
+                            fn default() -> Self {
+                                StateVar {
+                                    pose: ::core::default::Default::default(),
+                                    twist: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for StateVar {
+                            const MIN_SIZE_BITS: usize = 1184;
+                            const MAX_SIZE_BITS: usize = 1184;
                             fn size_bits(&self) -> usize {
                                 1184
                             }
@@ -1940,7 +2272,17 @@ pub value: crate::reg::udral::physics::kinematics::geodetic::state_var_0_1::Stat
                         }
                         impl ::canadensis_encoding::Message for StateVarTs {}
                         impl StateVarTs {}
+                        impl ::core::default::Default for StateVarTs {
+                            fn default() -> Self {
+                                StateVarTs {
+                                    timestamp: ::core::default::Default::default(),
+                                    value: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for StateVarTs {
+                            const MIN_SIZE_BITS: usize = 1240;
+                            const MAX_SIZE_BITS: usize = 1240;
                             fn size_bits(&self) -> usize {
                                 1240
                             }
@@ -2007,7 +2349,18 @@ pub value: crate::reg::udral::physics::kinematics::geodetic::state_var_0_1::Stat
                         }
                         impl ::canadensis_encoding::Message for Planar {}
                         impl Planar {}
+                        impl ::core::default::Default for Planar {
+                            fn default() -> Self {
+                                Planar {
+                                    angular_position: ::core::default::Default::default(),
+                                    angular_velocity: ::core::default::Default::default(),
+                                    angular_acceleration: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Planar {
+                            const MIN_SIZE_BITS: usize = 96;
+                            const MAX_SIZE_BITS: usize = 96;
                             fn size_bits(&self) -> usize {
                                 96
                             }
@@ -2064,7 +2417,17 @@ pub value: crate::reg::udral::physics::kinematics::rotation::planar_0_1::Planar,
                         }
                         impl ::canadensis_encoding::Message for PlanarTs {}
                         impl PlanarTs {}
+                        impl ::core::default::Default for PlanarTs {
+                            fn default() -> Self {
+                                PlanarTs {
+                                    timestamp: ::core::default::Default::default(),
+                                    value: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for PlanarTs {
+                            const MIN_SIZE_BITS: usize = 152;
+                            const MAX_SIZE_BITS: usize = 152;
                             fn size_bits(&self) -> usize {
                                 152
                             }
@@ -2129,7 +2492,18 @@ pub value: crate::reg::udral::physics::kinematics::rotation::planar_0_1::Planar,
                         }
                         impl ::canadensis_encoding::Message for Linear {}
                         impl Linear {}
+                        impl ::core::default::Default for Linear {
+                            fn default() -> Self {
+                                Linear {
+                                    position: ::core::default::Default::default(),
+                                    velocity: ::core::default::Default::default(),
+                                    acceleration: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Linear {
+                            const MIN_SIZE_BITS: usize = 96;
+                            const MAX_SIZE_BITS: usize = 96;
                             fn size_bits(&self) -> usize {
                                 96
                             }
@@ -2183,7 +2557,17 @@ pub value: crate::reg::udral::physics::kinematics::translation::linear_0_1::Line
                         }
                         impl ::canadensis_encoding::Message for LinearTs {}
                         impl LinearTs {}
+                        impl ::core::default::Default for LinearTs {
+                            fn default() -> Self {
+                                LinearTs {
+                                    timestamp: ::core::default::Default::default(),
+                                    value: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for LinearTs {
+                            const MIN_SIZE_BITS: usize = 152;
+                            const MAX_SIZE_BITS: usize = 152;
                             fn size_bits(&self) -> usize {
                                 152
                             }
@@ -2253,7 +2637,20 @@ pub acceleration_error_variance: ::half::f16,
                         }
                         impl ::canadensis_encoding::Message for LinearVarTs {}
                         impl LinearVarTs {}
+                        impl ::core::default::Default for LinearVarTs {
+                            fn default() -> Self {
+                                LinearVarTs {
+                                    value: ::core::default::Default::default(),
+                                    position_error_variance: ::core::default::Default::default(),
+                                    velocity_error_variance: ::core::default::Default::default(),
+                                    acceleration_error_variance: ::core::default::Default::default(
+                                    ),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for LinearVarTs {
+                            const MIN_SIZE_BITS: usize = 200;
+                            const MAX_SIZE_BITS: usize = 200;
                             fn size_bits(&self) -> usize {
                                 200
                             }
@@ -2313,7 +2710,17 @@ pub acceleration_error_variance: ::half::f16,
                         }
                         impl ::canadensis_encoding::Message for Velocity1VarTs {}
                         impl Velocity1VarTs {}
+                        impl ::core::default::Default for Velocity1VarTs {
+                            fn default() -> Self {
+                                Velocity1VarTs {
+                                    value: ::core::default::Default::default(),
+                                    error_variance: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Velocity1VarTs {
+                            const MIN_SIZE_BITS: usize = 104;
+                            const MAX_SIZE_BITS: usize = 104;
                             fn size_bits(&self) -> usize {
                                 104
                             }
@@ -2375,7 +2782,19 @@ pub acceleration_error_variance: ::half::f16,
                         }
                         impl ::canadensis_encoding::Message for Velocity3Var {}
                         impl Velocity3Var {}
+                        impl ::core::default::Default for Velocity3Var {
+                            fn default() -> Self {
+                                Velocity3Var {
+                                    value: ::core::default::Default::default(),
+                                    covariance_urt: ::core::array::from_fn(|_| {
+                                        ::core::default::Default::default()
+                                    }),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Velocity3Var {
+                            const MIN_SIZE_BITS: usize = 248;
+                            const MAX_SIZE_BITS: usize = 248;
                             fn size_bits(&self) -> usize {
                                 248
                             }
@@ -2449,7 +2868,19 @@ pub acceleration_error_variance: ::half::f16,
                         }
                         impl ::canadensis_encoding::Message for Velocity3Var {}
                         impl Velocity3Var {}
+                        impl ::core::default::Default for Velocity3Var {
+                            fn default() -> Self {
+                                Velocity3Var {
+                                    value: ::core::default::Default::default(),
+                                    covariance_urt: ::core::array::from_fn(|_| {
+                                        ::core::default::Default::default()
+                                    }),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Velocity3Var {
+                            const MIN_SIZE_BITS: usize = 192;
+                            const MAX_SIZE_BITS: usize = 192;
                             fn size_bits(&self) -> usize {
                                 192
                             }
@@ -2522,7 +2953,18 @@ pub acceleration_error_variance: ::half::f16,
                         pub const MAX_GREEN: u8 = 63;
                         pub const MAX_BLUE: u8 = 31;
                     }
+                    impl ::core::default::Default for HighColor {
+                        fn default() -> Self {
+                            HighColor {
+                                red: ::core::default::Default::default(),
+                                green: ::core::default::Default::default(),
+                                blue: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for HighColor {
+                        const MIN_SIZE_BITS: usize = 16;
+                        const MAX_SIZE_BITS: usize = 16;
                         fn size_bits(&self) -> usize {
                             16
                         }
@@ -2595,7 +3037,21 @@ pub acceleration_error_variance: ::half::f16,
                     }
                     impl ::canadensis_encoding::Message for PressureTempVarTs {}
                     impl PressureTempVarTs {}
+                    impl ::core::default::Default for PressureTempVarTs {
+                        fn default() -> Self {
+                            PressureTempVarTs {
+                                timestamp: ::core::default::Default::default(),
+                                pressure: ::core::default::Default::default(),
+                                temperature: ::core::default::Default::default(),
+                                covariance_urt: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for PressureTempVarTs {
+                        const MIN_SIZE_BITS: usize = 168;
+                        const MAX_SIZE_BITS: usize = 168;
                         fn size_bits(&self) -> usize {
                             168
                         }
@@ -2659,7 +3115,16 @@ pub acceleration_error_variance: ::half::f16,
                     }
                     impl ::canadensis_encoding::Message for TAI64 {}
                     impl TAI64 {}
+                    impl ::core::default::Default for TAI64 {
+                        fn default() -> Self {
+                            TAI64 {
+                                tai64n: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for TAI64 {
+                        const MIN_SIZE_BITS: usize = 64;
+                        const MAX_SIZE_BITS: usize = 64;
                         fn size_bits(&self) -> usize {
                             64
                         }
@@ -2716,7 +3181,17 @@ pub acceleration_error_variance: ::half::f16,
                     }
                     impl ::canadensis_encoding::Message for TAI64Var {}
                     impl TAI64Var {}
+                    impl ::core::default::Default for TAI64Var {
+                        fn default() -> Self {
+                            TAI64Var {
+                                value: ::core::default::Default::default(),
+                                error_variance: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for TAI64Var {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -2767,7 +3242,17 @@ pub acceleration_error_variance: ::half::f16,
                     }
                     impl ::canadensis_encoding::Message for TAI64VarTs {}
                     impl TAI64VarTs {}
+                    impl ::core::default::Default for TAI64VarTs {
+                        fn default() -> Self {
+                            TAI64VarTs {
+                                timestamp: ::core::default::Default::default(),
+                                value: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for TAI64VarTs {
+                        const MIN_SIZE_BITS: usize = 152;
+                        const MAX_SIZE_BITS: usize = 152;
                         fn size_bits(&self) -> usize {
                             152
                         }
@@ -2826,7 +3311,14 @@ pub acceleration_error_variance: ::half::f16,
                             )]
                             pub const MAX_PUBLICATION_PERIOD: u8 = 1;
                         }
+                        impl ::core::default::Default for _0 {
+                            fn default() -> Self {
+                                _0 {}
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for _0 {
+                            const MIN_SIZE_BITS: usize = 0;
+                            const MAX_SIZE_BITS: usize = 0;
                             fn size_bits(&self) -> usize {
                                 0
                             }
@@ -2966,7 +3458,25 @@ pub acceleration_error_variance: ::half::f16,
                         }
                         impl ::canadensis_encoding::Message for FaultFlags {}
                         impl FaultFlags {}
+                        impl ::core::default::Default for FaultFlags {
+                            fn default() -> Self {
+                                FaultFlags {
+                                    overload: ::core::default::Default::default(),
+                                    voltage: ::core::default::Default::default(),
+                                    motor_temperature: ::core::default::Default::default(),
+                                    controller_temperature: ::core::default::Default::default(),
+                                    velocity: ::core::default::Default::default(),
+                                    mechanical: ::core::default::Default::default(),
+                                    vibration: ::core::default::Default::default(),
+                                    configuration: ::core::default::Default::default(),
+                                    control_mode: ::core::default::Default::default(),
+                                    other: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for FaultFlags {
+                            const MIN_SIZE_BITS: usize = 16;
+                            const MAX_SIZE_BITS: usize = 16;
                             fn size_bits(&self) -> usize {
                                 16
                             }
@@ -3050,7 +3560,17 @@ pub acceleration_error_variance: ::half::f16,
                         }
                         impl ::canadensis_encoding::Message for Feedback {}
                         impl Feedback {}
+                        impl ::core::default::Default for Feedback {
+                            fn default() -> Self {
+                                Feedback {
+                                    heartbeat: ::core::default::Default::default(),
+                                    demand_factor_pct: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Feedback {
+                            const MIN_SIZE_BITS: usize = 24;
+                            const MAX_SIZE_BITS: usize = 24;
                             fn size_bits(&self) -> usize {
                                 24
                             }
@@ -3071,7 +3591,7 @@ pub acceleration_error_variance: ::half::f16,
                             {
                                 Ok(Feedback {
                                     heartbeat: { cursor.read_composite()? },
-                                    demand_factor_pct: { cursor.read_u8() as _ },
+                                    demand_factor_pct: { cursor.read_i8() as _ },
                                 })
                             }
                         }
@@ -3103,7 +3623,14 @@ pub acceleration_error_variance: ::half::f16,
                                 )]
                                 pub const EPSILON: ::half::f16 = ::half::f16::from_bits(4096);
                             }
+                            impl ::core::default::Default for _0 {
+                                fn default() -> Self {
+                                    _0 {}
+                                }
+                            }
                             impl ::canadensis_encoding::Serialize for _0 {
+                                const MIN_SIZE_BITS: usize = 0;
+                                const MAX_SIZE_BITS: usize = 0;
                                 fn size_bits(&self) -> usize {
                                     0
                                 }
@@ -3156,7 +3683,16 @@ pub acceleration_error_variance: ::half::f16,
                             }
                             impl ::canadensis_encoding::Message for Scalar {}
                             impl Scalar {}
+                            impl ::core::default::Default for Scalar {
+                                fn default() -> Self {
+                                    Scalar {
+                                        value: ::core::default::Default::default(),
+                                    }
+                                }
+                            }
                             impl ::canadensis_encoding::Serialize for Scalar {
+                                const MIN_SIZE_BITS: usize = 16;
+                                const MAX_SIZE_BITS: usize = 16;
                                 fn size_bits(&self) -> usize {
                                     16
                                 }
@@ -3210,7 +3746,18 @@ pub acceleration_error_variance: ::half::f16,
                             }
                             impl ::canadensis_encoding::Message for Vector2 {}
                             impl Vector2 {}
+                            impl ::core::default::Default for Vector2 {
+                                fn default() -> Self {
+                                    Vector2 {
+                                        value: ::core::array::from_fn(|_| {
+                                            ::core::default::Default::default()
+                                        }),
+                                    }
+                                }
+                            }
                             impl ::canadensis_encoding::Serialize for Vector2 {
+                                const MIN_SIZE_BITS: usize = 32;
+                                const MAX_SIZE_BITS: usize = 32;
                                 fn size_bits(&self) -> usize {
                                     32
                                 }
@@ -3264,7 +3811,18 @@ pub acceleration_error_variance: ::half::f16,
                             }
                             impl ::canadensis_encoding::Message for Vector31 {}
                             impl Vector31 {}
+                            impl ::core::default::Default for Vector31 {
+                                fn default() -> Self {
+                                    Vector31 {
+                                        value: ::core::array::from_fn(|_| {
+                                            ::core::default::Default::default()
+                                        }),
+                                    }
+                                }
+                            }
                             impl ::canadensis_encoding::Serialize for Vector31 {
+                                const MIN_SIZE_BITS: usize = 496;
+                                const MAX_SIZE_BITS: usize = 496;
                                 fn size_bits(&self) -> usize {
                                     496
                                 }
@@ -3318,7 +3876,18 @@ pub acceleration_error_variance: ::half::f16,
                             }
                             impl ::canadensis_encoding::Message for Vector3 {}
                             impl Vector3 {}
+                            impl ::core::default::Default for Vector3 {
+                                fn default() -> Self {
+                                    Vector3 {
+                                        value: ::core::array::from_fn(|_| {
+                                            ::core::default::Default::default()
+                                        }),
+                                    }
+                                }
+                            }
                             impl ::canadensis_encoding::Serialize for Vector3 {
+                                const MIN_SIZE_BITS: usize = 48;
+                                const MAX_SIZE_BITS: usize = 48;
                                 fn size_bits(&self) -> usize {
                                     48
                                 }
@@ -3372,7 +3941,18 @@ pub acceleration_error_variance: ::half::f16,
                             }
                             impl ::canadensis_encoding::Message for Vector4 {}
                             impl Vector4 {}
+                            impl ::core::default::Default for Vector4 {
+                                fn default() -> Self {
+                                    Vector4 {
+                                        value: ::core::array::from_fn(|_| {
+                                            ::core::default::Default::default()
+                                        }),
+                                    }
+                                }
+                            }
                             impl ::canadensis_encoding::Serialize for Vector4 {
+                                const MIN_SIZE_BITS: usize = 64;
+                                const MAX_SIZE_BITS: usize = 64;
                                 fn size_bits(&self) -> usize {
                                     64
                                 }
@@ -3426,7 +4006,18 @@ pub acceleration_error_variance: ::half::f16,
                             }
                             impl ::canadensis_encoding::Message for Vector6 {}
                             impl Vector6 {}
+                            impl ::core::default::Default for Vector6 {
+                                fn default() -> Self {
+                                    Vector6 {
+                                        value: ::core::array::from_fn(|_| {
+                                            ::core::default::Default::default()
+                                        }),
+                                    }
+                                }
+                            }
                             impl ::canadensis_encoding::Serialize for Vector6 {
+                                const MIN_SIZE_BITS: usize = 96;
+                                const MAX_SIZE_BITS: usize = 96;
                                 fn size_bits(&self) -> usize {
                                     96
                                 }
@@ -3480,7 +4071,18 @@ pub acceleration_error_variance: ::half::f16,
                             }
                             impl ::canadensis_encoding::Message for Vector8 {}
                             impl Vector8 {}
+                            impl ::core::default::Default for Vector8 {
+                                fn default() -> Self {
+                                    Vector8 {
+                                        value: ::core::array::from_fn(|_| {
+                                            ::core::default::Default::default()
+                                        }),
+                                    }
+                                }
+                            }
                             impl ::canadensis_encoding::Serialize for Vector8 {
+                                const MIN_SIZE_BITS: usize = 128;
+                                const MAX_SIZE_BITS: usize = 128;
                                 fn size_bits(&self) -> usize {
                                     128
                                 }
@@ -3556,7 +4158,19 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                         }
                         impl ::canadensis_encoding::Message for Status {}
                         impl Status {}
+                        impl ::core::default::Default for Status {
+                            fn default() -> Self {
+                                Status {
+                                    motor_temperature: ::core::default::Default::default(),
+                                    controller_temperature: ::core::default::Default::default(),
+                                    error_count: ::core::default::Default::default(),
+                                    fault_flags: ::core::default::Default::default(),
+                                }
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for Status {
+                            const MIN_SIZE_BITS: usize = 112;
+                            const MAX_SIZE_BITS: usize = 112;
                             fn size_bits(&self) -> usize {
                                 112
                             }
@@ -3608,7 +4222,14 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                         }
                         impl ::canadensis_encoding::Message for _0 {}
                         impl _0 {}
+                        impl ::core::default::Default for _0 {
+                            fn default() -> Self {
+                                _0 {}
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for _0 {
+                            const MIN_SIZE_BITS: usize = 0;
+                            const MAX_SIZE_BITS: usize = 0;
                             fn size_bits(&self) -> usize {
                                 0
                             }
@@ -3656,7 +4277,14 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                         }
                         impl ::canadensis_encoding::Message for _0 {}
                         impl _0 {}
+                        impl ::core::default::Default for _0 {
+                            fn default() -> Self {
+                                _0 {}
+                            }
+                        }
                         impl ::canadensis_encoding::Serialize for _0 {
+                            const MIN_SIZE_BITS: usize = 0;
+                            const MAX_SIZE_BITS: usize = 0;
                             fn size_bits(&self) -> usize {
                                 0
                             }
@@ -3705,7 +4333,14 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                     }
                     impl ::canadensis_encoding::Message for _0 {}
                     impl _0 {}
+                    impl ::core::default::Default for _0 {
+                        fn default() -> Self {
+                            _0 {}
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for _0 {
+                        const MIN_SIZE_BITS: usize = 0;
+                        const MAX_SIZE_BITS: usize = 0;
                         fn size_bits(&self) -> usize {
                             0
                         }
@@ -3805,7 +4440,16 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                         )]
                         pub const TEMPERATURE_COLD: u8 = 101;
                     }
+                    impl ::core::default::Default for Error {
+                        fn default() -> Self {
+                            Error {
+                                value: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Error {
+                        const MIN_SIZE_BITS: usize = 8;
+                        const MAX_SIZE_BITS: usize = 8;
                         fn size_bits(&self) -> usize {
                             8
                         }
@@ -4026,7 +4670,34 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                     }
                     impl ::canadensis_encoding::Message for Parameters {}
                     impl Parameters {}
+                    impl ::core::default::Default for Parameters {
+                        fn default() -> Self {
+                            Parameters {
+                                unique_id: ::core::default::Default::default(),
+                                mass: ::core::default::Default::default(),
+                                design_capacity: ::core::default::Default::default(),
+                                design_cell_voltage_min_max: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                                discharge_current: ::core::default::Default::default(),
+                                discharge_current_burst: ::core::default::Default::default(),
+                                charge_current: ::core::default::Default::default(),
+                                charge_current_fast: ::core::default::Default::default(),
+                                charge_termination_threshold: ::core::default::Default::default(),
+                                charge_voltage: ::core::default::Default::default(),
+                                cycle_count: ::core::default::Default::default(),
+                                series_cell_count: ::core::default::Default::default(),
+                                state_of_health_pct: ::core::default::Default::default(),
+                                technology: ::core::default::Default::default(),
+                                nominal_voltage: ::core::default::Default::default(),
+                                unix_manufacture_time: ::core::default::Default::default(),
+                                name: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Parameters {
+                        const MIN_SIZE_BITS: usize = 512;
+                        const MAX_SIZE_BITS: usize = 1024;
                         fn size_bits(&self) -> usize {
                             64 + 32
                                 + 32
@@ -4069,7 +4740,7 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                             cursor.skip_1();
                             cursor.write_composite(&self.technology);
                             cursor.write_composite(&self.nominal_voltage);
-                            cursor.write_u40(self.unix_manufacture_time);
+                            cursor.write_u40(self.unix_manufacture_time & 0xffffffffff);
                             cursor.write_aligned_u8((self.name).len() as u8);
                             cursor.write_bytes(&(self.name)[..]);
                         }
@@ -4116,7 +4787,9 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                                         elements
                                     } else {
                                         return Err(
-                                            ::canadensis_encoding::DeserializeError::ArrayLength,
+                                            ::canadensis_encoding::DeserializeError::ArrayLength {
+                                                offset_bits: cursor.bit_offset(),
+                                            },
                                         );
                                     }
                                 },
@@ -4191,7 +4864,22 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                     impl Status {
                         pub const MAX_CELLS: u8 = 255;
                     }
+                    impl ::core::default::Default for Status {
+                        fn default() -> Self {
+                            Status {
+                                heartbeat: ::core::default::Default::default(),
+                                temperature_min_max: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                                available_charge: ::core::default::Default::default(),
+                                error: ::core::default::Default::default(),
+                                cell_voltages: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Status {
+                        const MIN_SIZE_BITS: usize = 128;
+                        const MAX_SIZE_BITS: usize = 4208;
                         fn size_bits(&self) -> usize {
                             16 + (self.temperature_min_max).len() * 32
                                 + 32
@@ -4237,7 +4925,9 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                                         elements
                                     } else {
                                         return Err(
-                                            ::canadensis_encoding::DeserializeError::ArrayLength,
+                                            ::canadensis_encoding::DeserializeError::ArrayLength {
+                                                offset_bits: cursor.bit_offset(),
+                                            },
                                         );
                                     }
                                 },
@@ -4348,7 +5038,16 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                         #[cfg_attr(not(doctest), doc = " Electrostatic double-layer capacitor")]
                         pub const EDLC: u8 = 200;
                     }
+                    impl ::core::default::Default for Technology {
+                        fn default() -> Self {
+                            Technology {
+                                value: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Technology {
+                        const MIN_SIZE_BITS: usize = 8;
+                        const MAX_SIZE_BITS: usize = 8;
                         fn size_bits(&self) -> usize {
                             8
                         }
@@ -4409,7 +5108,17 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                         )]
                         pub const MAX_PUBLICATION_PERIOD: u8 = 1;
                     }
+                    impl ::core::default::Default for Heartbeat {
+                        fn default() -> Self {
+                            Heartbeat {
+                                readiness: ::core::default::Default::default(),
+                                health: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Heartbeat {
+                        const MIN_SIZE_BITS: usize = 16;
+                        const MAX_SIZE_BITS: usize = 16;
                         fn size_bits(&self) -> usize {
                             16
                         }
@@ -4471,12 +5180,21 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                         )]
                         pub const ENGAGED: u8 = 3;
                     }
+                    impl ::core::default::Default for Readiness {
+                        fn default() -> Self {
+                            Readiness {
+                                value: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Readiness {
+                        const MIN_SIZE_BITS: usize = 8;
+                        const MAX_SIZE_BITS: usize = 8;
                         fn size_bits(&self) -> usize {
                             8
                         }
                         fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
-                            cursor.write_u2(self.value);
+                            cursor.write_u2(self.value & 0x3);
                         }
                     }
                     impl ::canadensis_encoding::Deserialize for Readiness {
@@ -4550,7 +5268,18 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
                         #[cfg_attr(not(doctest), doc = " [second]")]
                         pub const MAX_PUBLICATION_PERIOD: u8 = 1;
                     }
+                    impl ::core::default::Default for Status {
+                        fn default() -> Self {
+                            Status {
+                                data_validity_period: ::core::default::Default::default(),
+                                error_count: ::core::default::Default::default(),
+                                sensor_temperature: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Status {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -4581,7 +5310,7 @@ pub fault_flags: crate::reg::udral::service::actuator::common::fault_flags_0_1::
     }
 }
 #[allow(unused_variables, unused_braces, unused_parens)]
-#[allow(clippy::identity_op)]
+#[allow(clippy::identity_op, clippy::derivable_impls)]
 pub mod uavcan {
     pub mod diagnostic {
         #[allow(deprecated)]
@@ -4636,7 +5365,18 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Message for Record {}
             impl Record {}
+            impl ::core::default::Default for Record {
+                fn default() -> Self {
+                    Record {
+                        timestamp: ::core::default::Default::default(),
+                        severity: ::core::default::Default::default(),
+                        text: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Record {
+                const MIN_SIZE_BITS: usize = 72;
+                const MAX_SIZE_BITS: usize = 968;
                 fn size_bits(&self) -> usize {
                     56 + 8 + 8 + (self.text).len() * 8 + 0
                 }
@@ -4666,7 +5406,9 @@ pub mod uavcan {
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -4721,7 +5463,18 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Message for Record {}
             impl Record {}
+            impl ::core::default::Default for Record {
+                fn default() -> Self {
+                    Record {
+                        timestamp: ::core::default::Default::default(),
+                        severity: ::core::default::Default::default(),
+                        text: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Record {
+                const MIN_SIZE_BITS: usize = 72;
+                const MAX_SIZE_BITS: usize = 2112;
                 fn size_bits(&self) -> usize {
                     56 + 8 + 8 + (self.text).len() * 8 + 0
                 }
@@ -4751,7 +5504,9 @@ pub mod uavcan {
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -4823,7 +5578,16 @@ pub mod uavcan {
                 )]
                 pub const ALERT: u8 = 7;
             }
+            impl ::core::default::Default for Severity {
+                fn default() -> Self {
+                    Severity {
+                        value: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Severity {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 8;
                 fn size_bits(&self) -> usize {
                     8
                 }
@@ -4890,7 +5654,16 @@ pub mod uavcan {
                 pub const OUT_OF_SPACE: u16 = 28;
                 pub const NOT_SUPPORTED: u16 = 38;
             }
+            impl ::core::default::Default for Error {
+                fn default() -> Self {
+                    Error {
+                        value: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Error {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 16;
                 fn size_bits(&self) -> usize {
                     16
                 }
@@ -4945,7 +5718,16 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Request for GetInfoRequest {}
             impl GetInfoRequest {}
+            impl ::core::default::Default for GetInfoRequest {
+                fn default() -> Self {
+                    GetInfoRequest {
+                        path: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for GetInfoRequest {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 904;
                 fn size_bits(&self) -> usize {
                     (self.path).size_bits() + 0
                 }
@@ -5044,14 +5826,29 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Response for GetInfoResponse {}
             impl GetInfoResponse {}
+            impl ::core::default::Default for GetInfoResponse {
+                fn default() -> Self {
+                    GetInfoResponse {
+                        error: ::core::default::Default::default(),
+                        size: ::core::default::Default::default(),
+                        unix_timestamp_of_last_modification: ::core::default::Default::default(),
+                        is_file_not_directory: ::core::default::Default::default(),
+                        is_link: ::core::default::Default::default(),
+                        is_readable: ::core::default::Default::default(),
+                        is_writeable: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for GetInfoResponse {
+                const MIN_SIZE_BITS: usize = 104;
+                const MAX_SIZE_BITS: usize = 104;
                 fn size_bits(&self) -> usize {
                     104
                 }
                 fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
                     cursor.write_composite(&self.error);
-                    cursor.write_u40(self.size);
-                    cursor.write_u40(self.unix_timestamp_of_last_modification);
+                    cursor.write_u40(self.size & 0xffffffffff);
+                    cursor.write_u40(self.unix_timestamp_of_last_modification & 0xffffffffff);
                     cursor.write_bool(self.is_file_not_directory);
                     cursor.write_bool(self.is_link);
                     cursor.write_bool(self.is_readable);
@@ -5105,7 +5902,16 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Request for GetInfoRequest {}
             impl GetInfoRequest {}
+            impl ::core::default::Default for GetInfoRequest {
+                fn default() -> Self {
+                    GetInfoRequest {
+                        path: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for GetInfoRequest {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 2048;
                 fn size_bits(&self) -> usize {
                     (self.path).size_bits() + 0
                 }
@@ -5203,14 +6009,29 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Response for GetInfoResponse {}
             impl GetInfoResponse {}
+            impl ::core::default::Default for GetInfoResponse {
+                fn default() -> Self {
+                    GetInfoResponse {
+                        error: ::core::default::Default::default(),
+                        size: ::core::default::Default::default(),
+                        unix_timestamp_of_last_modification: ::core::default::Default::default(),
+                        is_file_not_directory: ::core::default::Default::default(),
+                        is_link: ::core::default::Default::default(),
+                        is_readable: ::core::default::Default::default(),
+                        is_writeable: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for GetInfoResponse {
+                const MIN_SIZE_BITS: usize = 104;
+                const MAX_SIZE_BITS: usize = 104;
                 fn size_bits(&self) -> usize {
                     104
                 }
                 fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
                     cursor.write_composite(&self.error);
-                    cursor.write_u40(self.size);
-                    cursor.write_u40(self.unix_timestamp_of_last_modification);
+                    cursor.write_u40(self.size & 0xffffffffff);
+                    cursor.write_u40(self.unix_timestamp_of_last_modification & 0xffffffffff);
                     cursor.write_bool(self.is_file_not_directory);
                     cursor.write_bool(self.is_link);
                     cursor.write_bool(self.is_readable);
@@ -5275,7 +6096,17 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Request for ListRequest {}
             impl ListRequest {}
+            impl ::core::default::Default for ListRequest {
+                fn default() -> Self {
+                    ListRequest {
+                        entry_index: ::core::default::Default::default(),
+                        directory_path: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ListRequest {
+                const MIN_SIZE_BITS: usize = 72;
+                const MAX_SIZE_BITS: usize = 968;
                 fn size_bits(&self) -> usize {
                     32 + 32 + (self.directory_path).size_bits() + 0
                 }
@@ -5326,7 +6157,16 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Response for ListResponse {}
             impl ListResponse {}
+            impl ::core::default::Default for ListResponse {
+                fn default() -> Self {
+                    ListResponse {
+                        entry_base_name: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ListResponse {
+                const MIN_SIZE_BITS: usize = 40;
+                const MAX_SIZE_BITS: usize = 936;
                 fn size_bits(&self) -> usize {
                     32 + (self.entry_base_name).size_bits() + 0
                 }
@@ -5385,7 +6225,17 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Request for ListRequest {}
             impl ListRequest {}
+            impl ::core::default::Default for ListRequest {
+                fn default() -> Self {
+                    ListRequest {
+                        entry_index: ::core::default::Default::default(),
+                        directory_path: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ListRequest {
+                const MIN_SIZE_BITS: usize = 72;
+                const MAX_SIZE_BITS: usize = 2112;
                 fn size_bits(&self) -> usize {
                     32 + 32 + (self.directory_path).size_bits() + 0
                 }
@@ -5435,7 +6285,16 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Response for ListResponse {}
             impl ListResponse {}
+            impl ::core::default::Default for ListResponse {
+                fn default() -> Self {
+                    ListResponse {
+                        entry_base_name: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ListResponse {
+                const MIN_SIZE_BITS: usize = 40;
+                const MAX_SIZE_BITS: usize = 2080;
                 fn size_bits(&self) -> usize {
                     32 + (self.entry_base_name).size_bits() + 0
                 }
@@ -5518,7 +6377,19 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Request for ModifyRequest {}
             impl ModifyRequest {}
+            impl ::core::default::Default for ModifyRequest {
+                fn default() -> Self {
+                    ModifyRequest {
+                        preserve_source: ::core::default::Default::default(),
+                        overwrite_destination: ::core::default::Default::default(),
+                        source: ::core::default::Default::default(),
+                        destination: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ModifyRequest {
+                const MIN_SIZE_BITS: usize = 48;
+                const MAX_SIZE_BITS: usize = 1840;
                 fn size_bits(&self) -> usize {
                     1 + 1 + 30 + (self.source).size_bits() + (self.destination).size_bits() + 0
                 }
@@ -5570,7 +6441,16 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Response for ModifyResponse {}
             impl ModifyResponse {}
+            impl ::core::default::Default for ModifyResponse {
+                fn default() -> Self {
+                    ModifyResponse {
+                        error: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ModifyResponse {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 16;
                 fn size_bits(&self) -> usize {
                     16
                 }
@@ -5648,7 +6528,19 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Request for ModifyRequest {}
             impl ModifyRequest {}
+            impl ::core::default::Default for ModifyRequest {
+                fn default() -> Self {
+                    ModifyRequest {
+                        preserve_source: ::core::default::Default::default(),
+                        overwrite_destination: ::core::default::Default::default(),
+                        source: ::core::default::Default::default(),
+                        destination: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ModifyRequest {
+                const MIN_SIZE_BITS: usize = 48;
+                const MAX_SIZE_BITS: usize = 4128;
                 fn size_bits(&self) -> usize {
                     1 + 1 + 30 + (self.source).size_bits() + (self.destination).size_bits() + 0
                 }
@@ -5699,7 +6591,16 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Response for ModifyResponse {}
             impl ModifyResponse {}
+            impl ::core::default::Default for ModifyResponse {
+                fn default() -> Self {
+                    ModifyResponse {
+                        error: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ModifyResponse {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 16;
                 fn size_bits(&self) -> usize {
                     16
                 }
@@ -5752,7 +6653,16 @@ pub mod uavcan {
                 pub const SEPARATOR: u8 = 47;
                 pub const MAX_LENGTH: u8 = 112;
             }
+            impl ::core::default::Default for Path {
+                fn default() -> Self {
+                    Path {
+                        path: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Path {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 904;
                 fn size_bits(&self) -> usize {
                     8 + (self.path).len() * 8 + 0
                 }
@@ -5778,7 +6688,9 @@ pub mod uavcan {
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -5811,7 +6723,16 @@ pub mod uavcan {
                 pub const SEPARATOR: u8 = 47;
                 pub const MAX_LENGTH: u8 = 255;
             }
+            impl ::core::default::Default for Path {
+                fn default() -> Self {
+                    Path {
+                        path: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Path {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 2048;
                 fn size_bits(&self) -> usize {
                     8 + (self.path).len() * 8 + 0
                 }
@@ -5837,7 +6758,9 @@ pub mod uavcan {
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -5881,12 +6804,22 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Request for ReadRequest {}
             impl ReadRequest {}
+            impl ::core::default::Default for ReadRequest {
+                fn default() -> Self {
+                    ReadRequest {
+                        offset: ::core::default::Default::default(),
+                        path: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ReadRequest {
+                const MIN_SIZE_BITS: usize = 48;
+                const MAX_SIZE_BITS: usize = 944;
                 fn size_bits(&self) -> usize {
                     40 + (self.path).size_bits() + 0
                 }
                 fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
-                    cursor.write_u40(self.offset);
+                    cursor.write_u40(self.offset & 0xffffffffff);
                     cursor.write_composite(&self.path);
                 }
             }
@@ -5929,7 +6862,17 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Response for ReadResponse {}
             impl ReadResponse {}
+            impl ::core::default::Default for ReadResponse {
+                fn default() -> Self {
+                    ReadResponse {
+                        error: ::core::default::Default::default(),
+                        data: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ReadResponse {
+                const MIN_SIZE_BITS: usize = 32;
+                const MAX_SIZE_BITS: usize = 2080;
                 fn size_bits(&self) -> usize {
                     16 + 16 + (self.data).len() * 8 + 0
                 }
@@ -5957,7 +6900,9 @@ pub mod uavcan {
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -5997,12 +6942,22 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Request for ReadRequest {}
             impl ReadRequest {}
+            impl ::core::default::Default for ReadRequest {
+                fn default() -> Self {
+                    ReadRequest {
+                        offset: ::core::default::Default::default(),
+                        path: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ReadRequest {
+                const MIN_SIZE_BITS: usize = 48;
+                const MAX_SIZE_BITS: usize = 2088;
                 fn size_bits(&self) -> usize {
                     40 + (self.path).size_bits() + 0
                 }
                 fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
-                    cursor.write_u40(self.offset);
+                    cursor.write_u40(self.offset & 0xffffffffff);
                     cursor.write_composite(&self.path);
                 }
             }
@@ -6044,7 +6999,17 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Response for ReadResponse {}
             impl ReadResponse {}
+            impl ::core::default::Default for ReadResponse {
+                fn default() -> Self {
+                    ReadResponse {
+                        error: ::core::default::Default::default(),
+                        data: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ReadResponse {
+                const MIN_SIZE_BITS: usize = 32;
+                const MAX_SIZE_BITS: usize = 2080;
                 fn size_bits(&self) -> usize {
                     16 + (self.data).size_bits() + 0
                 }
@@ -6114,12 +7079,23 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Request for WriteRequest {}
             impl WriteRequest {}
+            impl ::core::default::Default for WriteRequest {
+                fn default() -> Self {
+                    WriteRequest {
+                        offset: ::core::default::Default::default(),
+                        path: ::core::default::Default::default(),
+                        data: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for WriteRequest {
+                const MIN_SIZE_BITS: usize = 56;
+                const MAX_SIZE_BITS: usize = 2488;
                 fn size_bits(&self) -> usize {
                     40 + (self.path).size_bits() + 8 + (self.data).len() * 8 + 0
                 }
                 fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
-                    cursor.write_u40(self.offset);
+                    cursor.write_u40(self.offset & 0xffffffffff);
                     cursor.write_composite(&self.path);
                     cursor.write_aligned_u8((self.data).len() as u8);
                     cursor.write_bytes(&(self.data)[..]);
@@ -6144,7 +7120,9 @@ pub mod uavcan {
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -6172,7 +7150,16 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Response for WriteResponse {}
             impl WriteResponse {}
+            impl ::core::default::Default for WriteResponse {
+                fn default() -> Self {
+                    WriteResponse {
+                        error: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for WriteResponse {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 16;
                 fn size_bits(&self) -> usize {
                     16
                 }
@@ -6235,12 +7222,23 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Request for WriteRequest {}
             impl WriteRequest {}
+            impl ::core::default::Default for WriteRequest {
+                fn default() -> Self {
+                    WriteRequest {
+                        offset: ::core::default::Default::default(),
+                        path: ::core::default::Default::default(),
+                        data: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for WriteRequest {
+                const MIN_SIZE_BITS: usize = 64;
+                const MAX_SIZE_BITS: usize = 4152;
                 fn size_bits(&self) -> usize {
                     40 + (self.path).size_bits() + (self.data).size_bits() + 0
                 }
                 fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
-                    cursor.write_u40(self.offset);
+                    cursor.write_u40(self.offset & 0xffffffffff);
                     cursor.write_composite(&self.path);
                     cursor.write_composite(&self.data);
                 }
@@ -6280,7 +7278,16 @@ pub mod uavcan {
             }
             impl ::canadensis_encoding::Response for WriteResponse {}
             impl WriteResponse {}
+            impl ::core::default::Default for WriteResponse {
+                fn default() -> Self {
+                    WriteResponse {
+                        error: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for WriteResponse {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 16;
                 fn size_bits(&self) -> usize {
                     16
                 }
@@ -6352,7 +7359,17 @@ pub mod uavcan {
                 }
                 impl ::canadensis_encoding::Request for HandleIncomingPacketRequest {}
                 impl HandleIncomingPacketRequest {}
+                impl ::core::default::Default for HandleIncomingPacketRequest {
+                    fn default() -> Self {
+                        HandleIncomingPacketRequest {
+                            session_id: ::core::default::Default::default(),
+                            payload: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for HandleIncomingPacketRequest {
+                    const MIN_SIZE_BITS: usize = 32;
+                    const MAX_SIZE_BITS: usize = 2504;
                     fn size_bits(&self) -> usize {
                         16 + 16 + (self.payload).len() * 8 + 0
                     }
@@ -6381,7 +7398,9 @@ pub mod uavcan {
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -6403,7 +7422,14 @@ pub mod uavcan {
                 }
                 impl ::canadensis_encoding::Response for HandleIncomingPacketResponse {}
                 impl HandleIncomingPacketResponse {}
+                impl ::core::default::Default for HandleIncomingPacketResponse {
+                    fn default() -> Self {
+                        HandleIncomingPacketResponse {}
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for HandleIncomingPacketResponse {
+                    const MIN_SIZE_BITS: usize = 0;
+                    const MAX_SIZE_BITS: usize = 0;
                     fn size_bits(&self) -> usize {
                         0
                     }
@@ -6470,7 +7496,17 @@ pub mod uavcan {
                 }
                 impl ::canadensis_encoding::Request for HandleIncomingPacketRequest {}
                 impl HandleIncomingPacketRequest {}
+                impl ::core::default::Default for HandleIncomingPacketRequest {
+                    fn default() -> Self {
+                        HandleIncomingPacketRequest {
+                            session_id: ::core::default::Default::default(),
+                            payload: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for HandleIncomingPacketRequest {
+                    const MIN_SIZE_BITS: usize = 32;
+                    const MAX_SIZE_BITS: usize = 4096;
                     fn size_bits(&self) -> usize {
                         16 + 16 + (self.payload).len() * 8 + 0
                     }
@@ -6499,7 +7535,9 @@ pub mod uavcan {
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -6520,7 +7558,14 @@ pub mod uavcan {
                 }
                 impl ::canadensis_encoding::Response for HandleIncomingPacketResponse {}
                 impl HandleIncomingPacketResponse {}
+                impl ::core::default::Default for HandleIncomingPacketResponse {
+                    fn default() -> Self {
+                        HandleIncomingPacketResponse {}
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for HandleIncomingPacketResponse {
+                    const MIN_SIZE_BITS: usize = 0;
+                    const MAX_SIZE_BITS: usize = 0;
                     fn size_bits(&self) -> usize {
                         0
                     }
@@ -6635,7 +7680,21 @@ pub mod uavcan {
                     )]
                     pub const NAT_ENTRY_MIN_TTL: u32 = 86400;
                 }
+                impl ::core::default::Default for OutgoingPacket {
+                    fn default() -> Self {
+                        OutgoingPacket {
+                            session_id: ::core::default::Default::default(),
+                            destination_port: ::core::default::Default::default(),
+                            destination_address: ::core::default::Default::default(),
+                            use_masquerading: ::core::default::Default::default(),
+                            use_dtls: ::core::default::Default::default(),
+                            payload: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for OutgoingPacket {
+                    const MIN_SIZE_BITS: usize = 64;
+                    const MAX_SIZE_BITS: usize = 2504;
                     fn size_bits(&self) -> usize {
                         16 + 16
                             + 8
@@ -6679,7 +7738,9 @@ pub mod uavcan {
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -6696,7 +7757,9 @@ pub mod uavcan {
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -6789,7 +7852,21 @@ pub mod uavcan {
                     )]
                     pub const NAT_ENTRY_MIN_TTL: u32 = 86400;
                 }
+                impl ::core::default::Default for OutgoingPacket {
+                    fn default() -> Self {
+                        OutgoingPacket {
+                            session_id: ::core::default::Default::default(),
+                            destination_port: ::core::default::Default::default(),
+                            destination_address: ::core::default::Default::default(),
+                            use_masquerading: ::core::default::Default::default(),
+                            use_dtls: ::core::default::Default::default(),
+                            payload: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for OutgoingPacket {
+                    const MIN_SIZE_BITS: usize = 64;
+                    const MAX_SIZE_BITS: usize = 4488;
                     fn size_bits(&self) -> usize {
                         16 + 16
                             + 8
@@ -6833,7 +7910,9 @@ pub mod uavcan {
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -6850,7 +7929,9 @@ pub mod uavcan {
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -6884,7 +7965,14 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for ArbitrationID {}
                 impl ArbitrationID {}
+                impl ::core::default::Default for ArbitrationID {
+                    fn default() -> Self {
+                        ArbitrationID::Base(::core::default::Default::default())
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for ArbitrationID {
+                    const MIN_SIZE_BITS: usize = 40;
+                    const MAX_SIZE_BITS: usize = 40;
                     fn size_bits(&self) -> usize {
                         40
                     }
@@ -6911,7 +7999,9 @@ Extended
                         match cursor.read_aligned_u8() as _ {
                             0 => Ok(ArbitrationID::Base({ cursor.read_composite()? })),
                             1 => Ok(ArbitrationID::Extended({ cursor.read_composite()? })),
-                            _ => Err(::canadensis_encoding::DeserializeError::UnionTag),
+                            _ => Err(::canadensis_encoding::DeserializeError::UnionTag {
+                                offset_bits: cursor.bit_offset(),
+                            }),
                         }
                     }
                 }
@@ -6937,12 +8027,21 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for BaseArbitrationID {}
                 impl BaseArbitrationID {}
+                impl ::core::default::Default for BaseArbitrationID {
+                    fn default() -> Self {
+                        BaseArbitrationID {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for BaseArbitrationID {
+                    const MIN_SIZE_BITS: usize = 32;
+                    const MAX_SIZE_BITS: usize = 32;
                     fn size_bits(&self) -> usize {
                         32
                     }
                     fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
-                        cursor.write_u11(self.value);
+                        cursor.write_u11(self.value & 0x7ff);
                         cursor.skip_21();
                     }
                 }
@@ -6986,7 +8085,17 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for DataClassic {}
                 impl DataClassic {}
+                impl ::core::default::Default for DataClassic {
+                    fn default() -> Self {
+                        DataClassic {
+                            arbitration_id: ::core::default::Default::default(),
+                            data: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for DataClassic {
+                    const MIN_SIZE_BITS: usize = 48;
+                    const MAX_SIZE_BITS: usize = 112;
                     fn size_bits(&self) -> usize {
                         40 + 8 + (self.data).len() * 8 + 0
                     }
@@ -7015,7 +8124,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -7050,7 +8161,17 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for DataFD {}
                 impl DataFD {}
+                impl ::core::default::Default for DataFD {
+                    fn default() -> Self {
+                        DataFD {
+                            arbitration_id: ::core::default::Default::default(),
+                            data: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for DataFD {
+                    const MIN_SIZE_BITS: usize = 48;
+                    const MAX_SIZE_BITS: usize = 560;
                     fn size_bits(&self) -> usize {
                         40 + 8 + (self.data).len() * 8 + 0
                     }
@@ -7079,7 +8200,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -7105,7 +8228,14 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Error {}
                 impl Error {}
+                impl ::core::default::Default for Error {
+                    fn default() -> Self {
+                        Error {}
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Error {
+                    const MIN_SIZE_BITS: usize = 32;
+                    const MAX_SIZE_BITS: usize = 32;
                     fn size_bits(&self) -> usize {
                         32
                     }
@@ -7145,12 +8275,21 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for ExtendedArbitrationID {}
                 impl ExtendedArbitrationID {}
+                impl ::core::default::Default for ExtendedArbitrationID {
+                    fn default() -> Self {
+                        ExtendedArbitrationID {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for ExtendedArbitrationID {
+                    const MIN_SIZE_BITS: usize = 32;
+                    const MAX_SIZE_BITS: usize = 32;
                     fn size_bits(&self) -> usize {
                         32
                     }
                     fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
-                        cursor.write_u29(self.value);
+                        cursor.write_u29(self.value & 0x1fffffff);
                         cursor.skip_3();
                     }
                 }
@@ -7201,7 +8340,17 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Frame {}
                 impl Frame {}
+                impl ::core::default::Default for Frame {
+                    fn default() -> Self {
+                        Frame {
+                            timestamp: ::core::default::Default::default(),
+                            manifestation: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Frame {
+                    const MIN_SIZE_BITS: usize = 96;
+                    const MAX_SIZE_BITS: usize = 624;
                     fn size_bits(&self) -> usize {
                         56 + (self.manifestation).size_bits() + 0
                     }
@@ -7260,7 +8409,14 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Frame {}
                 impl Frame {}
+                impl ::core::default::Default for Frame {
+                    fn default() -> Self {
+                        Frame::Error(::core::default::Default::default())
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Frame {
+                    const MIN_SIZE_BITS: usize = 40;
+                    const MAX_SIZE_BITS: usize = 568;
                     fn size_bits(&self) -> usize {
                         8 + match self {
                             Frame::Error(inner) => 32,
@@ -7304,7 +8460,9 @@ Extended
                             3 => Ok(Frame::RemoteTransmissionRequest({
                                 cursor.read_composite()?
                             })),
-                            _ => Err(::canadensis_encoding::DeserializeError::UnionTag),
+                            _ => Err(::canadensis_encoding::DeserializeError::UnionTag {
+                                offset_bits: cursor.bit_offset(),
+                            }),
                         }
                     }
                 }
@@ -7345,7 +8503,14 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Manifestation {}
                 impl Manifestation {}
+                impl ::core::default::Default for Manifestation {
+                    fn default() -> Self {
+                        Manifestation::Error(::core::default::Default::default())
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Manifestation {
+                    const MIN_SIZE_BITS: usize = 40;
+                    const MAX_SIZE_BITS: usize = 568;
                     fn size_bits(&self) -> usize {
                         8 + match self {
                             Manifestation::Error(inner) => 32,
@@ -7389,7 +8554,9 @@ Extended
                             3 => Ok(Manifestation::RemoteTransmissionRequest({
                                 cursor.read_composite()?
                             })),
-                            _ => Err(::canadensis_encoding::DeserializeError::UnionTag),
+                            _ => Err(::canadensis_encoding::DeserializeError::UnionTag {
+                                offset_bits: cursor.bit_offset(),
+                            }),
                         }
                     }
                 }
@@ -7418,7 +8585,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for RTR {}
                 impl RTR {}
+                impl ::core::default::Default for RTR {
+                    fn default() -> Self {
+                        RTR {
+                            arbitration_id: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for RTR {
+                    const MIN_SIZE_BITS: usize = 40;
+                    const MAX_SIZE_BITS: usize = 40;
                     fn size_bits(&self) -> usize {
                         40
                     }
@@ -7470,7 +8646,16 @@ Extended
                     pub const ARP: u16 = 2054;
                     pub const IP_V6: u16 = 34525;
                 }
+                impl ::core::default::Default for EtherType {
+                    fn default() -> Self {
+                        EtherType {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for EtherType {
+                    const MIN_SIZE_BITS: usize = 16;
+                    const MAX_SIZE_BITS: usize = 16;
                     fn size_bits(&self) -> usize {
                         16
                     }
@@ -7540,7 +8725,21 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Frame {}
                 impl Frame {}
+                impl ::core::default::Default for Frame {
+                    fn default() -> Self {
+                        Frame {
+                            destination: ::core::array::from_fn(|_| {
+                                ::core::default::Default::default()
+                            }),
+                            source: ::core::array::from_fn(|_| ::core::default::Default::default()),
+                            ethertype: ::core::default::Default::default(),
+                            payload: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Frame {
+                    const MIN_SIZE_BITS: usize = 128;
+                    const MAX_SIZE_BITS: usize = 73856;
                     fn size_bits(&self) -> usize {
                         (self.destination).len() * 8
                             + (self.source).len() * 8
@@ -7596,7 +8795,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -7641,7 +8842,17 @@ Extended
                 impl Fragment {
                     pub const CAPACITY_BYTES: u16 = 256;
                 }
+                impl ::core::default::Default for Fragment {
+                    fn default() -> Self {
+                        Fragment {
+                            timestamp: ::core::default::Default::default(),
+                            data: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Fragment {
+                    const MIN_SIZE_BITS: usize = 72;
+                    const MAX_SIZE_BITS: usize = 2120;
                     fn size_bits(&self) -> usize {
                         56 + 16 + (self.data).len() * 8 + 0
                     }
@@ -7670,7 +8881,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -7703,7 +8916,16 @@ Extended
                 impl Fragment {
                     pub const CAPACITY_BYTES: u16 = 2048;
                 }
+                impl ::core::default::Default for Fragment {
+                    fn default() -> Self {
+                        Fragment {
+                            data: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Fragment {
+                    const MIN_SIZE_BITS: usize = 16;
+                    const MAX_SIZE_BITS: usize = 16400;
                     fn size_bits(&self) -> usize {
                         16 + (self.data).len() * 8 + 0
                     }
@@ -7730,7 +8952,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -7788,7 +9012,22 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Endpoint {}
                 impl Endpoint {}
+                impl ::core::default::Default for Endpoint {
+                    fn default() -> Self {
+                        Endpoint {
+                            ip_address: ::core::array::from_fn(|_| {
+                                ::core::default::Default::default()
+                            }),
+                            mac_address: ::core::array::from_fn(|_| {
+                                ::core::default::Default::default()
+                            }),
+                            port: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Endpoint {
+                    const MIN_SIZE_BITS: usize = 256;
+                    const MAX_SIZE_BITS: usize = 256;
                     fn size_bits(&self) -> usize {
                         256
                     }
@@ -7894,7 +9133,19 @@ Extended
                     )]
                     pub const MTU: u16 = 9188;
                 }
+                impl ::core::default::Default for Frame {
+                    fn default() -> Self {
+                        Frame {
+                            timestamp: ::core::default::Default::default(),
+                            source: ::core::default::Default::default(),
+                            destination: ::core::default::Default::default(),
+                            data: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Frame {
+                    const MIN_SIZE_BITS: usize = 592;
+                    const MAX_SIZE_BITS: usize = 74096;
                     fn size_bits(&self) -> usize {
                         56 + 8 + 256 + 256 + 16 + (self.data).len() * 8 + 0
                     }
@@ -7931,7 +9182,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -8018,7 +9271,17 @@ Extended
                 )]
                 pub const COMMAND_STORE_PERSISTENT_STATES: u16 = 65530;
             }
+            impl ::core::default::Default for ExecuteCommandRequest {
+                fn default() -> Self {
+                    ExecuteCommandRequest {
+                        command: ::core::default::Default::default(),
+                        parameter: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ExecuteCommandRequest {
+                const MIN_SIZE_BITS: usize = 24;
+                const MAX_SIZE_BITS: usize = 920;
                 fn size_bits(&self) -> usize {
                     16 + 8 + (self.parameter).len() * 8 + 0
                 }
@@ -8046,7 +9309,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -8105,7 +9370,16 @@ Extended
                 )]
                 pub const STATUS_INTERNAL_ERROR: u8 = 6;
             }
+            impl ::core::default::Default for ExecuteCommandResponse {
+                fn default() -> Self {
+                    ExecuteCommandResponse {
+                        status: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ExecuteCommandResponse {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 8;
                 fn size_bits(&self) -> usize {
                     8
                 }
@@ -8208,7 +9482,17 @@ Extended
                 )]
                 pub const COMMAND_STORE_PERSISTENT_STATES: u16 = 65530;
             }
+            impl ::core::default::Default for ExecuteCommandRequest {
+                fn default() -> Self {
+                    ExecuteCommandRequest {
+                        command: ::core::default::Default::default(),
+                        parameter: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ExecuteCommandRequest {
+                const MIN_SIZE_BITS: usize = 24;
+                const MAX_SIZE_BITS: usize = 2064;
                 fn size_bits(&self) -> usize {
                     16 + 8 + (self.parameter).len() * 8 + 0
                 }
@@ -8236,7 +9520,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -8295,7 +9581,16 @@ Extended
                 )]
                 pub const STATUS_INTERNAL_ERROR: u8 = 6;
             }
+            impl ::core::default::Default for ExecuteCommandResponse {
+                fn default() -> Self {
+                    ExecuteCommandResponse {
+                        status: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ExecuteCommandResponse {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 8;
                 fn size_bits(&self) -> usize {
                     8
                 }
@@ -8403,7 +9698,17 @@ Extended
                 )]
                 pub const COMMAND_IDENTIFY: u16 = 65529;
             }
+            impl ::core::default::Default for ExecuteCommandRequest {
+                fn default() -> Self {
+                    ExecuteCommandRequest {
+                        command: ::core::default::Default::default(),
+                        parameter: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ExecuteCommandRequest {
+                const MIN_SIZE_BITS: usize = 24;
+                const MAX_SIZE_BITS: usize = 2064;
                 fn size_bits(&self) -> usize {
                     16 + 8 + (self.parameter).len() * 8 + 0
                 }
@@ -8431,7 +9736,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -8490,7 +9797,16 @@ Extended
                 )]
                 pub const STATUS_INTERNAL_ERROR: u8 = 6;
             }
+            impl ::core::default::Default for ExecuteCommandResponse {
+                fn default() -> Self {
+                    ExecuteCommandResponse {
+                        status: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ExecuteCommandResponse {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 8;
                 fn size_bits(&self) -> usize {
                     8
                 }
@@ -8594,7 +9910,17 @@ Extended
                 )]
                 pub const COMMAND_IDENTIFY: u16 = 65529;
             }
+            impl ::core::default::Default for ExecuteCommandRequest {
+                fn default() -> Self {
+                    ExecuteCommandRequest {
+                        command: ::core::default::Default::default(),
+                        parameter: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ExecuteCommandRequest {
+                const MIN_SIZE_BITS: usize = 24;
+                const MAX_SIZE_BITS: usize = 2064;
                 fn size_bits(&self) -> usize {
                     16 + 8 + (self.parameter).len() * 8 + 0
                 }
@@ -8622,7 +9948,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -8688,7 +10016,17 @@ Extended
                 )]
                 pub const STATUS_INTERNAL_ERROR: u8 = 6;
             }
+            impl ::core::default::Default for ExecuteCommandResponse {
+                fn default() -> Self {
+                    ExecuteCommandResponse {
+                        status: ::core::default::Default::default(),
+                        output: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ExecuteCommandResponse {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 384;
                 fn size_bits(&self) -> usize {
                     8 + 8 + (self.output).len() * 8 + 0
                 }
@@ -8716,7 +10054,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -8745,7 +10085,14 @@ Extended
             }
             impl ::canadensis_encoding::Request for GetInfoRequest {}
             impl GetInfoRequest {}
+            impl ::core::default::Default for GetInfoRequest {
+                fn default() -> Self {
+                    GetInfoRequest {}
+                }
+            }
             impl ::canadensis_encoding::Serialize for GetInfoRequest {
+                const MIN_SIZE_BITS: usize = 0;
+                const MAX_SIZE_BITS: usize = 0;
                 fn size_bits(&self) -> usize {
                     0
                 }
@@ -8856,7 +10203,23 @@ Extended
             }
             impl ::canadensis_encoding::Response for GetInfoResponse {}
             impl GetInfoResponse {}
+            impl ::core::default::Default for GetInfoResponse {
+                fn default() -> Self {
+                    GetInfoResponse {
+                        protocol_version: ::core::default::Default::default(),
+                        hardware_version: ::core::default::Default::default(),
+                        software_version: ::core::default::Default::default(),
+                        software_vcs_revision_id: ::core::default::Default::default(),
+                        unique_id: ::core::array::from_fn(|_| ::core::default::Default::default()),
+                        name: ::core::default::Default::default(),
+                        software_image_crc: ::core::default::Default::default(),
+                        certificate_of_authenticity: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for GetInfoResponse {
+                const MIN_SIZE_BITS: usize = 264;
+                const MAX_SIZE_BITS: usize = 2504;
                 fn size_bits(&self) -> usize {
                     16 + 16
                         + 16
@@ -8927,7 +10290,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                         software_image_crc: {
@@ -8939,7 +10304,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                         certificate_of_authenticity: {
@@ -8951,7 +10318,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -8980,7 +10349,14 @@ Extended
             }
             impl ::canadensis_encoding::Request for GetTransportStatisticsRequest {}
             impl GetTransportStatisticsRequest {}
+            impl ::core::default::Default for GetTransportStatisticsRequest {
+                fn default() -> Self {
+                    GetTransportStatisticsRequest {}
+                }
+            }
             impl ::canadensis_encoding::Serialize for GetTransportStatisticsRequest {
+                const MIN_SIZE_BITS: usize = 0;
+                const MAX_SIZE_BITS: usize = 0;
                 fn size_bits(&self) -> usize {
                     0
                 }
@@ -9045,7 +10421,17 @@ Extended
                 )]
                 pub const MAX_NETWORK_INTERFACES: u8 = 3;
             }
+            impl ::core::default::Default for GetTransportStatisticsResponse {
+                fn default() -> Self {
+                    GetTransportStatisticsResponse {
+                        transfer_statistics: ::core::default::Default::default(),
+                        network_interface_statistics: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for GetTransportStatisticsResponse {
+                const MIN_SIZE_BITS: usize = 128;
+                const MAX_SIZE_BITS: usize = 488;
                 fn size_bits(&self) -> usize {
                     120 + 8 + (self.network_interface_statistics).len() * 120 + 0
                 }
@@ -9075,7 +10461,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -9126,7 +10514,16 @@ Extended
                 )]
                 pub const WARNING: u8 = 3;
             }
+            impl ::core::default::Default for Health {
+                fn default() -> Self {
+                    Health {
+                        value: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Health {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 8;
                 fn size_bits(&self) -> usize {
                     8
                 }
@@ -9216,7 +10613,19 @@ Extended
                 )]
                 pub const OFFLINE_TIMEOUT: u16 = 3;
             }
+            impl ::core::default::Default for Heartbeat {
+                fn default() -> Self {
+                    Heartbeat {
+                        uptime: ::core::default::Default::default(),
+                        health: ::core::default::Default::default(),
+                        mode: ::core::default::Default::default(),
+                        vendor_specific_status_code: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Heartbeat {
+                const MIN_SIZE_BITS: usize = 56;
+                const MAX_SIZE_BITS: usize = 56;
                 fn size_bits(&self) -> usize {
                     56
                 }
@@ -9268,7 +10677,16 @@ Extended
             }
             impl ::canadensis_encoding::Message for ID {}
             impl ID {}
+            impl ::core::default::Default for ID {
+                fn default() -> Self {
+                    ID {
+                        value: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ID {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 16;
                 fn size_bits(&self) -> usize {
                     16
                 }
@@ -9333,14 +10751,25 @@ Extended
             }
             impl ::canadensis_encoding::Message for IOStatistics {}
             impl IOStatistics {}
+            impl ::core::default::Default for IOStatistics {
+                fn default() -> Self {
+                    IOStatistics {
+                        num_emitted: ::core::default::Default::default(),
+                        num_received: ::core::default::Default::default(),
+                        num_errored: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for IOStatistics {
+                const MIN_SIZE_BITS: usize = 120;
+                const MAX_SIZE_BITS: usize = 120;
                 fn size_bits(&self) -> usize {
                     120
                 }
                 fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
-                    cursor.write_u40(self.num_emitted);
-                    cursor.write_u40(self.num_received);
-                    cursor.write_u40(self.num_errored);
+                    cursor.write_u40(self.num_emitted & 0xffffffffff);
+                    cursor.write_u40(self.num_received & 0xffffffffff);
+                    cursor.write_u40(self.num_errored & 0xffffffffff);
                 }
             }
             impl ::canadensis_encoding::Deserialize for IOStatistics {
@@ -9396,7 +10825,16 @@ Extended
                 )]
                 pub const SOFTWARE_UPDATE: u8 = 3;
             }
+            impl ::core::default::Default for Mode {
+                fn default() -> Self {
+                    Mode {
+                        value: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Mode {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 8;
                 fn size_bits(&self) -> usize {
                     8
                 }
@@ -9441,7 +10879,14 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for ID {}
                 impl ID {}
+                impl ::core::default::Default for ID {
+                    fn default() -> Self {
+                        ID::SubjectId(::core::default::Default::default())
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for ID {
+                    const MIN_SIZE_BITS: usize = 24;
+                    const MAX_SIZE_BITS: usize = 24;
                     fn size_bits(&self) -> usize {
                         24
                     }
@@ -9468,7 +10913,9 @@ Extended
                         match cursor.read_aligned_u8() as _ {
                             0 => Ok(ID::SubjectId({ cursor.read_composite()? })),
                             1 => Ok(ID::ServiceId({ cursor.read_composite()? })),
-                            _ => Err(::canadensis_encoding::DeserializeError::UnionTag),
+                            _ => Err(::canadensis_encoding::DeserializeError::UnionTag {
+                                offset_bits: cursor.bit_offset(),
+                            }),
                         }
                     }
                 }
@@ -9528,7 +10975,19 @@ Extended
                     )]
                     pub const MAX_PUBLICATION_PERIOD: u8 = 10;
                 }
+                impl ::core::default::Default for List {
+                    fn default() -> Self {
+                        List {
+                            publishers: ::core::default::Default::default(),
+                            subscribers: ::core::default::Default::default(),
+                            clients: ::core::default::Default::default(),
+                            servers: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for List {
+                    const MIN_SIZE_BITS: usize = 1168;
+                    const MAX_SIZE_BITS: usize = 17552;
                     fn size_bits(&self) -> usize {
                         32 + (self.publishers).size_bits()
                             + 32
@@ -9613,7 +11072,19 @@ Extended
                     )]
                     pub const MAX_PUBLICATION_PERIOD: u8 = 10;
                 }
+                impl ::core::default::Default for List {
+                    fn default() -> Self {
+                        List {
+                            publishers: ::core::default::Default::default(),
+                            subscribers: ::core::default::Default::default(),
+                            clients: ::core::default::Default::default(),
+                            servers: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for List {
+                    const MIN_SIZE_BITS: usize = 1168;
+                    const MAX_SIZE_BITS: usize = 17552;
                     fn size_bits(&self) -> usize {
                         32 + (self.publishers).size_bits()
                             + 32
@@ -9672,7 +11143,16 @@ Extended
                 impl ServiceID {
                     pub const MAX: u16 = 511;
                 }
+                impl ::core::default::Default for ServiceID {
+                    fn default() -> Self {
+                        ServiceID {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for ServiceID {
+                    const MIN_SIZE_BITS: usize = 16;
+                    const MAX_SIZE_BITS: usize = 16;
                     fn size_bits(&self) -> usize {
                         16
                     }
@@ -9725,7 +11205,16 @@ Extended
                 impl ServiceIDList {
                     pub const CAPACITY: u16 = 512;
                 }
+                impl ::core::default::Default for ServiceIDList {
+                    fn default() -> Self {
+                        ServiceIDList {
+                            mask: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for ServiceIDList {
+                    const MIN_SIZE_BITS: usize = 512;
+                    const MAX_SIZE_BITS: usize = 512;
                     fn size_bits(&self) -> usize {
                         512
                     }
@@ -9779,7 +11268,16 @@ Extended
                 impl ServiceIDList {
                     pub const CAPACITY: u16 = 512;
                 }
+                impl ::core::default::Default for ServiceIDList {
+                    fn default() -> Self {
+                        ServiceIDList {
+                            mask: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for ServiceIDList {
+                    const MIN_SIZE_BITS: usize = 512;
+                    const MAX_SIZE_BITS: usize = 512;
                     fn size_bits(&self) -> usize {
                         512
                     }
@@ -9829,7 +11327,16 @@ Extended
                 impl SubjectID {
                     pub const MAX: u16 = 8191;
                 }
+                impl ::core::default::Default for SubjectID {
+                    fn default() -> Self {
+                        SubjectID {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for SubjectID {
+                    const MIN_SIZE_BITS: usize = 16;
+                    const MAX_SIZE_BITS: usize = 16;
                     fn size_bits(&self) -> usize {
                         16
                     }
@@ -9895,7 +11402,14 @@ Extended
                 impl SubjectIDList {
                     pub const CAPACITY: u16 = 8192;
                 }
+                impl ::core::default::Default for SubjectIDList {
+                    fn default() -> Self {
+                        SubjectIDList::Mask(::core::default::Default::default())
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for SubjectIDList {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 8200;
                     fn size_bits(&self) -> usize {
                         8 + match self {
                             SubjectIDList::Mask(inner) => (inner).len() * 1,
@@ -9946,12 +11460,16 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             })),
                             2 => Ok(SubjectIDList::Total({ cursor.read_composite()? })),
-                            _ => Err(::canadensis_encoding::DeserializeError::UnionTag),
+                            _ => Err(::canadensis_encoding::DeserializeError::UnionTag {
+                                offset_bits: cursor.bit_offset(),
+                            }),
                         }
                     }
                 }
@@ -9998,7 +11516,14 @@ Extended
                 impl SubjectIDList {
                     pub const CAPACITY: u16 = 8192;
                 }
+                impl ::core::default::Default for SubjectIDList {
+                    fn default() -> Self {
+                        SubjectIDList::Mask(::core::default::Default::default())
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for SubjectIDList {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 8200;
                     fn size_bits(&self) -> usize {
                         8 + match self {
                             SubjectIDList::Mask(inner) => (inner).len() * 1,
@@ -10049,12 +11574,16 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             })),
                             2 => Ok(SubjectIDList::Total({ cursor.read_composite()? })),
-                            _ => Err(::canadensis_encoding::DeserializeError::UnionTag),
+                            _ => Err(::canadensis_encoding::DeserializeError::UnionTag {
+                                offset_bits: cursor.bit_offset(),
+                            }),
                         }
                     }
                 }
@@ -10091,7 +11620,17 @@ Extended
             }
             impl ::canadensis_encoding::Message for Version {}
             impl Version {}
+            impl ::core::default::Default for Version {
+                fn default() -> Self {
+                    Version {
+                        major: ::core::default::Default::default(),
+                        minor: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Version {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 16;
                 fn size_bits(&self) -> usize {
                     16
                 }
@@ -10183,7 +11722,20 @@ Extended
                     )]
                     pub const DEFAULT_MAX_ELECTION_TIMEOUT: u8 = 4;
                 }
+                impl ::core::default::Default for AppendEntriesRequest {
+                    fn default() -> Self {
+                        AppendEntriesRequest {
+                            term: ::core::default::Default::default(),
+                            prev_log_term: ::core::default::Default::default(),
+                            prev_log_index: ::core::default::Default::default(),
+                            leader_commit: ::core::default::Default::default(),
+                            entries: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for AppendEntriesRequest {
+                    const MIN_SIZE_BITS: usize = 104;
+                    const MAX_SIZE_BITS: usize = 280;
                     fn size_bits(&self) -> usize {
                         32 + 32 + 16 + 16 + 8 + (self.entries).len() * 176 + 0
                     }
@@ -10220,7 +11772,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -10253,7 +11807,17 @@ Extended
                 }
                 impl ::canadensis_encoding::Response for AppendEntriesResponse {}
                 impl AppendEntriesResponse {}
+                impl ::core::default::Default for AppendEntriesResponse {
+                    fn default() -> Self {
+                        AppendEntriesResponse {
+                            term: ::core::default::Default::default(),
+                            success: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for AppendEntriesResponse {
+                    const MIN_SIZE_BITS: usize = 40;
+                    const MAX_SIZE_BITS: usize = 40;
                     fn size_bits(&self) -> usize {
                         40
                     }
@@ -10329,7 +11893,17 @@ Extended
                     )]
                     pub const MAX_CLUSTER_SIZE: u8 = 5;
                 }
+                impl ::core::default::Default for Discovery {
+                    fn default() -> Self {
+                        Discovery {
+                            configured_cluster_size: ::core::default::Default::default(),
+                            known_nodes: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Discovery {
+                    const MIN_SIZE_BITS: usize = 16;
+                    const MAX_SIZE_BITS: usize = 96;
                     fn size_bits(&self) -> usize {
                         3 + 5 + 8 + (self.known_nodes).len() * 16 + 0
                     }
@@ -10362,7 +11936,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -10413,7 +11989,20 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Entry {}
                 impl Entry {}
+                impl ::core::default::Default for Entry {
+                    fn default() -> Self {
+                        Entry {
+                            term: ::core::default::Default::default(),
+                            unique_id: ::core::array::from_fn(|_| {
+                                ::core::default::Default::default()
+                            }),
+                            node_id: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Entry {
+                    const MIN_SIZE_BITS: usize = 176;
+                    const MAX_SIZE_BITS: usize = 176;
                     fn size_bits(&self) -> usize {
                         176
                     }
@@ -10481,7 +12070,18 @@ Extended
                 }
                 impl ::canadensis_encoding::Request for RequestVoteRequest {}
                 impl RequestVoteRequest {}
+                impl ::core::default::Default for RequestVoteRequest {
+                    fn default() -> Self {
+                        RequestVoteRequest {
+                            term: ::core::default::Default::default(),
+                            last_log_term: ::core::default::Default::default(),
+                            last_log_index: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for RequestVoteRequest {
+                    const MIN_SIZE_BITS: usize = 80;
+                    const MAX_SIZE_BITS: usize = 80;
                     fn size_bits(&self) -> usize {
                         80
                     }
@@ -10538,7 +12138,17 @@ Extended
                 }
                 impl ::canadensis_encoding::Response for RequestVoteResponse {}
                 impl RequestVoteResponse {}
+                impl ::core::default::Default for RequestVoteResponse {
+                    fn default() -> Self {
+                        RequestVoteResponse {
+                            term: ::core::default::Default::default(),
+                            vote_granted: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for RequestVoteResponse {
+                    const MIN_SIZE_BITS: usize = 40;
+                    const MAX_SIZE_BITS: usize = 40;
                     fn size_bits(&self) -> usize {
                         40
                     }
@@ -10603,12 +12213,22 @@ Extended
             }
             impl ::canadensis_encoding::Message for NodeIDAllocationData {}
             impl NodeIDAllocationData {}
+            impl ::core::default::Default for NodeIDAllocationData {
+                fn default() -> Self {
+                    NodeIDAllocationData {
+                        unique_id_hash: ::core::default::Default::default(),
+                        allocated_node_id: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for NodeIDAllocationData {
+                const MIN_SIZE_BITS: usize = 56;
+                const MAX_SIZE_BITS: usize = 72;
                 fn size_bits(&self) -> usize {
                     48 + 8 + (self.allocated_node_id).len() * 16 + 0
                 }
                 fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
-                    cursor.write_u48(self.unique_id_hash);
+                    cursor.write_u48(self.unique_id_hash & 0xffffffffffff);
                     cursor.write_aligned_u8((self.allocated_node_id).len() as u8);
                     for value in (self.allocated_node_id).iter() {
                         cursor.write_composite(value);
@@ -10633,7 +12253,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -10683,7 +12305,17 @@ Extended
             }
             impl ::canadensis_encoding::Message for NodeIDAllocationData {}
             impl NodeIDAllocationData {}
+            impl ::core::default::Default for NodeIDAllocationData {
+                fn default() -> Self {
+                    NodeIDAllocationData {
+                        node_id: ::core::default::Default::default(),
+                        unique_id: ::core::array::from_fn(|_| ::core::default::Default::default()),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for NodeIDAllocationData {
+                const MIN_SIZE_BITS: usize = 144;
+                const MAX_SIZE_BITS: usize = 144;
                 fn size_bits(&self) -> usize {
                     144
                 }
@@ -10740,7 +12372,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Bit {}
                 impl Bit {}
+                impl ::core::default::Default for Bit {
+                    fn default() -> Self {
+                        Bit {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Bit {
+                    const MIN_SIZE_BITS: usize = 16;
+                    const MAX_SIZE_BITS: usize = 2064;
                     fn size_bits(&self) -> usize {
                         16 + (self.value).len() * 1 + 0
                     }
@@ -10788,7 +12429,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Integer16 {}
                 impl Integer16 {}
+                impl ::core::default::Default for Integer16 {
+                    fn default() -> Self {
+                        Integer16 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Integer16 {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 2056;
                     fn size_bits(&self) -> usize {
                         8 + (self.value).len() * 16 + 0
                     }
@@ -10812,12 +12462,14 @@ Extended
                                 if length <= 128 {
                                     let mut elements = ::heapless::Vec::new();
                                     for _ in 0..length {
-                                        let _ = elements.push(cursor.read_u16() as _);
+                                        let _ = elements.push(cursor.read_i16() as _);
                                     }
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -10844,7 +12496,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Integer32 {}
                 impl Integer32 {}
+                impl ::core::default::Default for Integer32 {
+                    fn default() -> Self {
+                        Integer32 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Integer32 {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 2056;
                     fn size_bits(&self) -> usize {
                         8 + (self.value).len() * 32 + 0
                     }
@@ -10868,12 +12529,14 @@ Extended
                                 if length <= 64 {
                                     let mut elements = ::heapless::Vec::new();
                                     for _ in 0..length {
-                                        let _ = elements.push(cursor.read_u32() as _);
+                                        let _ = elements.push(cursor.read_i32() as _);
                                     }
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -10900,7 +12563,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Integer64 {}
                 impl Integer64 {}
+                impl ::core::default::Default for Integer64 {
+                    fn default() -> Self {
+                        Integer64 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Integer64 {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 2056;
                     fn size_bits(&self) -> usize {
                         8 + (self.value).len() * 64 + 0
                     }
@@ -10924,12 +12596,14 @@ Extended
                                 if length <= 32 {
                                     let mut elements = ::heapless::Vec::new();
                                     for _ in 0..length {
-                                        let _ = elements.push(cursor.read_u64() as _);
+                                        let _ = elements.push(cursor.read_i64() as _);
                                     }
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -10956,7 +12630,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Integer8 {}
                 impl Integer8 {}
+                impl ::core::default::Default for Integer8 {
+                    fn default() -> Self {
+                        Integer8 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Integer8 {
+                    const MIN_SIZE_BITS: usize = 16;
+                    const MAX_SIZE_BITS: usize = 2064;
                     fn size_bits(&self) -> usize {
                         16 + (self.value).len() * 8 + 0
                     }
@@ -10980,12 +12663,14 @@ Extended
                                 if length <= 256 {
                                     let mut elements = ::heapless::Vec::new();
                                     for _ in 0..length {
-                                        let _ = elements.push(cursor.read_u8() as _);
+                                        let _ = elements.push(cursor.read_i8() as _);
                                     }
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -11012,7 +12697,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Natural16 {}
                 impl Natural16 {}
+                impl ::core::default::Default for Natural16 {
+                    fn default() -> Self {
+                        Natural16 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Natural16 {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 2056;
                     fn size_bits(&self) -> usize {
                         8 + (self.value).len() * 16 + 0
                     }
@@ -11041,7 +12735,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -11068,7 +12764,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Natural32 {}
                 impl Natural32 {}
+                impl ::core::default::Default for Natural32 {
+                    fn default() -> Self {
+                        Natural32 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Natural32 {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 2056;
                     fn size_bits(&self) -> usize {
                         8 + (self.value).len() * 32 + 0
                     }
@@ -11097,7 +12802,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -11124,7 +12831,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Natural64 {}
                 impl Natural64 {}
+                impl ::core::default::Default for Natural64 {
+                    fn default() -> Self {
+                        Natural64 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Natural64 {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 2056;
                     fn size_bits(&self) -> usize {
                         8 + (self.value).len() * 64 + 0
                     }
@@ -11153,7 +12869,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -11180,7 +12898,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Natural8 {}
                 impl Natural8 {}
+                impl ::core::default::Default for Natural8 {
+                    fn default() -> Self {
+                        Natural8 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Natural8 {
+                    const MIN_SIZE_BITS: usize = 16;
+                    const MAX_SIZE_BITS: usize = 2064;
                     fn size_bits(&self) -> usize {
                         16 + (self.value).len() * 8 + 0
                     }
@@ -11207,7 +12934,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -11235,7 +12964,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Real16 {}
                 impl Real16 {}
+                impl ::core::default::Default for Real16 {
+                    fn default() -> Self {
+                        Real16 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Real16 {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 2056;
                     fn size_bits(&self) -> usize {
                         8 + (self.value).len() * 16 + 0
                     }
@@ -11264,7 +13002,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -11295,7 +13035,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Real32 {}
                 impl Real32 {}
+                impl ::core::default::Default for Real32 {
+                    fn default() -> Self {
+                        Real32 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Real32 {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 2056;
                     fn size_bits(&self) -> usize {
                         8 + (self.value).len() * 32 + 0
                     }
@@ -11324,7 +13073,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -11355,7 +13106,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Real64 {}
                 impl Real64 {}
+                impl ::core::default::Default for Real64 {
+                    fn default() -> Self {
+                        Real64 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Real64 {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 2056;
                     fn size_bits(&self) -> usize {
                         8 + (self.value).len() * 64 + 0
                     }
@@ -11384,7 +13144,9 @@ Extended
                                     elements
                                 } else {
                                     return Err(
-                                        ::canadensis_encoding::DeserializeError::ArrayLength,
+                                        ::canadensis_encoding::DeserializeError::ArrayLength {
+                                            offset_bits: cursor.bit_offset(),
+                                        },
                                     );
                                 }
                             },
@@ -11407,7 +13169,14 @@ Extended
             }
             impl ::canadensis_encoding::Message for Empty {}
             impl Empty {}
+            impl ::core::default::Default for Empty {
+                fn default() -> Self {
+                    Empty {}
+                }
+            }
             impl ::canadensis_encoding::Serialize for Empty {
+                const MIN_SIZE_BITS: usize = 0;
+                const MAX_SIZE_BITS: usize = 0;
                 fn size_bits(&self) -> usize {
                     0
                 }
@@ -11450,7 +13219,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Bit {}
                 impl Bit {}
+                impl ::core::default::Default for Bit {
+                    fn default() -> Self {
+                        Bit {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Bit {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 8;
                     fn size_bits(&self) -> usize {
                         8
                     }
@@ -11492,7 +13270,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Integer16 {}
                 impl Integer16 {}
+                impl ::core::default::Default for Integer16 {
+                    fn default() -> Self {
+                        Integer16 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Integer16 {
+                    const MIN_SIZE_BITS: usize = 16;
+                    const MAX_SIZE_BITS: usize = 16;
                     fn size_bits(&self) -> usize {
                         16
                     }
@@ -11537,7 +13324,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Integer32 {}
                 impl Integer32 {}
+                impl ::core::default::Default for Integer32 {
+                    fn default() -> Self {
+                        Integer32 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Integer32 {
+                    const MIN_SIZE_BITS: usize = 32;
+                    const MAX_SIZE_BITS: usize = 32;
                     fn size_bits(&self) -> usize {
                         32
                     }
@@ -11582,7 +13378,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Integer64 {}
                 impl Integer64 {}
+                impl ::core::default::Default for Integer64 {
+                    fn default() -> Self {
+                        Integer64 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Integer64 {
+                    const MIN_SIZE_BITS: usize = 64;
+                    const MAX_SIZE_BITS: usize = 64;
                     fn size_bits(&self) -> usize {
                         64
                     }
@@ -11627,7 +13432,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Integer8 {}
                 impl Integer8 {}
+                impl ::core::default::Default for Integer8 {
+                    fn default() -> Self {
+                        Integer8 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Integer8 {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 8;
                     fn size_bits(&self) -> usize {
                         8
                     }
@@ -11672,7 +13486,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Natural16 {}
                 impl Natural16 {}
+                impl ::core::default::Default for Natural16 {
+                    fn default() -> Self {
+                        Natural16 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Natural16 {
+                    const MIN_SIZE_BITS: usize = 16;
+                    const MAX_SIZE_BITS: usize = 16;
                     fn size_bits(&self) -> usize {
                         16
                     }
@@ -11717,7 +13540,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Natural32 {}
                 impl Natural32 {}
+                impl ::core::default::Default for Natural32 {
+                    fn default() -> Self {
+                        Natural32 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Natural32 {
+                    const MIN_SIZE_BITS: usize = 32;
+                    const MAX_SIZE_BITS: usize = 32;
                     fn size_bits(&self) -> usize {
                         32
                     }
@@ -11762,7 +13594,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Natural64 {}
                 impl Natural64 {}
+                impl ::core::default::Default for Natural64 {
+                    fn default() -> Self {
+                        Natural64 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Natural64 {
+                    const MIN_SIZE_BITS: usize = 64;
+                    const MAX_SIZE_BITS: usize = 64;
                     fn size_bits(&self) -> usize {
                         64
                     }
@@ -11807,7 +13648,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Natural8 {}
                 impl Natural8 {}
+                impl ::core::default::Default for Natural8 {
+                    fn default() -> Self {
+                        Natural8 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Natural8 {
+                    const MIN_SIZE_BITS: usize = 8;
+                    const MAX_SIZE_BITS: usize = 8;
                     fn size_bits(&self) -> usize {
                         8
                     }
@@ -11856,7 +13706,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Real16 {}
                 impl Real16 {}
+                impl ::core::default::Default for Real16 {
+                    fn default() -> Self {
+                        Real16 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Real16 {
+                    const MIN_SIZE_BITS: usize = 16;
+                    const MAX_SIZE_BITS: usize = 16;
                     fn size_bits(&self) -> usize {
                         16
                     }
@@ -11905,7 +13764,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Real32 {}
                 impl Real32 {}
+                impl ::core::default::Default for Real32 {
+                    fn default() -> Self {
+                        Real32 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Real32 {
+                    const MIN_SIZE_BITS: usize = 32;
+                    const MAX_SIZE_BITS: usize = 32;
                     fn size_bits(&self) -> usize {
                         32
                     }
@@ -11954,7 +13822,16 @@ Extended
                 }
                 impl ::canadensis_encoding::Message for Real64 {}
                 impl Real64 {}
+                impl ::core::default::Default for Real64 {
+                    fn default() -> Self {
+                        Real64 {
+                            value: ::core::default::Default::default(),
+                        }
+                    }
+                }
                 impl ::canadensis_encoding::Serialize for Real64 {
+                    const MIN_SIZE_BITS: usize = 64;
+                    const MAX_SIZE_BITS: usize = 64;
                     fn size_bits(&self) -> usize {
                         64
                     }
@@ -12002,7 +13879,16 @@ Extended
             }
             impl ::canadensis_encoding::Message for String {}
             impl String {}
+            impl ::core::default::Default for String {
+                fn default() -> Self {
+                    String {
+                        value: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for String {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 2064;
                 fn size_bits(&self) -> usize {
                     16 + (self.value).len() * 8 + 0
                 }
@@ -12028,7 +13914,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -12058,7 +13946,16 @@ Extended
             }
             impl ::canadensis_encoding::Message for Unstructured {}
             impl Unstructured {}
+            impl ::core::default::Default for Unstructured {
+                fn default() -> Self {
+                    Unstructured {
+                        value: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Unstructured {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 2064;
                 fn size_bits(&self) -> usize {
                     16 + (self.value).len() * 8 + 0
                 }
@@ -12084,7 +13981,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -12134,7 +14033,17 @@ Extended
             }
             impl ::canadensis_encoding::Request for AccessRequest {}
             impl AccessRequest {}
+            impl ::core::default::Default for AccessRequest {
+                fn default() -> Self {
+                    AccessRequest {
+                        name: ::core::default::Default::default(),
+                        value: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for AccessRequest {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 4120;
                 fn size_bits(&self) -> usize {
                     (self.name).size_bits() + (self.value).size_bits() + 0
                 }
@@ -12211,7 +14120,19 @@ Extended
             }
             impl ::canadensis_encoding::Response for AccessResponse {}
             impl AccessResponse {}
+            impl ::core::default::Default for AccessResponse {
+                fn default() -> Self {
+                    AccessResponse {
+                        timestamp: ::core::default::Default::default(),
+                        mutable: ::core::default::Default::default(),
+                        persistent: ::core::default::Default::default(),
+                        value: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for AccessResponse {
+                const MIN_SIZE_BITS: usize = 72;
+                const MAX_SIZE_BITS: usize = 2136;
                 fn size_bits(&self) -> usize {
                     56 + 1 + 1 + 6 + (self.value).size_bits() + 0
                 }
@@ -12271,7 +14192,16 @@ Extended
             }
             impl ::canadensis_encoding::Request for ListRequest {}
             impl ListRequest {}
+            impl ::core::default::Default for ListRequest {
+                fn default() -> Self {
+                    ListRequest {
+                        index: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ListRequest {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 16;
                 fn size_bits(&self) -> usize {
                     16
                 }
@@ -12317,7 +14247,16 @@ Extended
             }
             impl ::canadensis_encoding::Response for ListResponse {}
             impl ListResponse {}
+            impl ::core::default::Default for ListResponse {
+                fn default() -> Self {
+                    ListResponse {
+                        name: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for ListResponse {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 2048;
                 fn size_bits(&self) -> usize {
                     (self.name).size_bits() + 0
                 }
@@ -12358,7 +14297,16 @@ Extended
             }
             impl ::canadensis_encoding::Message for Name {}
             impl Name {}
+            impl ::core::default::Default for Name {
+                fn default() -> Self {
+                    Name {
+                        name: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Name {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 2048;
                 fn size_bits(&self) -> usize {
                     8 + (self.name).len() * 8 + 0
                 }
@@ -12384,7 +14332,9 @@ Extended
                                 }
                                 elements
                             } else {
-                                return Err(::canadensis_encoding::DeserializeError::ArrayLength);
+                                return Err(::canadensis_encoding::DeserializeError::ArrayLength {
+                                    offset_bits: cursor.bit_offset(),
+                                });
                             }
                         },
                     })
@@ -12477,7 +14427,14 @@ Extended
             }
             impl ::canadensis_encoding::Message for Value {}
             impl Value {}
+            impl ::core::default::Default for Value {
+                fn default() -> Self {
+                    Value::Empty(::core::default::Default::default())
+                }
+            }
             impl ::canadensis_encoding::Serialize for Value {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 2072;
                 fn size_bits(&self) -> usize {
                     8 + match self {
                         Value::Empty(inner) => 0,
@@ -12585,7 +14542,9 @@ Extended
                         12 => Ok(Value::Real64({ cursor.read_composite()? })),
                         13 => Ok(Value::Real32({ cursor.read_composite()? })),
                         14 => Ok(Value::Real16({ cursor.read_composite()? })),
-                        _ => Err(::canadensis_encoding::DeserializeError::UnionTag),
+                        _ => Err(::canadensis_encoding::DeserializeError::UnionTag {
+                            offset_bits: cursor.bit_offset(),
+                        }),
                     }
                 }
             }
@@ -12620,7 +14579,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                meter_per_second_per_second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -12669,7 +14638,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                timestamp: ::core::default::Default::default(),
+                                meter_per_second_per_second: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 152;
+                        const MAX_SIZE_BITS: usize = 152;
                         fn size_bits(&self) -> usize {
                             152
                         }
@@ -12724,7 +14705,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for NarrowScalar {}
                     impl NarrowScalar {}
+                    impl ::core::default::Default for NarrowScalar {
+                        fn default() -> Self {
+                            NarrowScalar {
+                                timestamp: ::core::default::Default::default(),
+                                radian: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for NarrowScalar {
+                        const MIN_SIZE_BITS: usize = 72;
+                        const MAX_SIZE_BITS: usize = 72;
                         fn size_bits(&self) -> usize {
                             72
                         }
@@ -12773,7 +14764,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for NarrowVector3 {}
                     impl NarrowVector3 {}
+                    impl ::core::default::Default for NarrowVector3 {
+                        fn default() -> Self {
+                            NarrowVector3 {
+                                timestamp: ::core::default::Default::default(),
+                                radian: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for NarrowVector3 {
+                        const MIN_SIZE_BITS: usize = 104;
+                        const MAX_SIZE_BITS: usize = 104;
                         fn size_bits(&self) -> usize {
                             104
                         }
@@ -12826,7 +14829,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Quaternion {}
                     impl Quaternion {}
+                    impl ::core::default::Default for Quaternion {
+                        fn default() -> Self {
+                            Quaternion {
+                                timestamp: ::core::default::Default::default(),
+                                wxyz: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Quaternion {
+                        const MIN_SIZE_BITS: usize = 184;
+                        const MAX_SIZE_BITS: usize = 184;
                         fn size_bits(&self) -> usize {
                             184
                         }
@@ -12884,7 +14899,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                radian: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -12933,7 +14958,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                timestamp: ::core::default::Default::default(),
+                                radian: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 152;
+                        const MAX_SIZE_BITS: usize = 152;
                         fn size_bits(&self) -> usize {
                             152
                         }
@@ -12986,7 +15023,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for WideScalar {}
                     impl WideScalar {}
+                    impl ::core::default::Default for WideScalar {
+                        fn default() -> Self {
+                            WideScalar {
+                                timestamp: ::core::default::Default::default(),
+                                radian: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for WideScalar {
+                        const MIN_SIZE_BITS: usize = 120;
+                        const MAX_SIZE_BITS: usize = 120;
                         fn size_bits(&self) -> usize {
                             120
                         }
@@ -13035,7 +15082,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for WideVector3 {}
                     impl WideVector3 {}
+                    impl ::core::default::Default for WideVector3 {
+                        fn default() -> Self {
+                            WideVector3 {
+                                timestamp: ::core::default::Default::default(),
+                                radian: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for WideVector3 {
+                        const MIN_SIZE_BITS: usize = 248;
+                        const MAX_SIZE_BITS: usize = 248;
                         fn size_bits(&self) -> usize {
                             248
                         }
@@ -13090,7 +15149,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                radian_per_second_per_second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -13139,7 +15208,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                timestamp: ::core::default::Default::default(),
+                                radian_per_second_per_second: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 152;
+                        const MAX_SIZE_BITS: usize = 152;
                         fn size_bits(&self) -> usize {
                             152
                         }
@@ -13194,7 +15275,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                radian_per_second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -13243,7 +15334,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                timestamp: ::core::default::Default::default(),
+                                radian_per_second: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 152;
+                        const MAX_SIZE_BITS: usize = 152;
                         fn size_bits(&self) -> usize {
                             152
                         }
@@ -13298,7 +15401,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -13347,7 +15460,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for WideScalar {}
                     impl WideScalar {}
+                    impl ::core::default::Default for WideScalar {
+                        fn default() -> Self {
+                            WideScalar {
+                                timestamp: ::core::default::Default::default(),
+                                second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for WideScalar {
+                        const MIN_SIZE_BITS: usize = 120;
+                        const MAX_SIZE_BITS: usize = 120;
                         fn size_bits(&self) -> usize {
                             120
                         }
@@ -13398,7 +15521,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                coulomb: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -13449,7 +15582,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                ampere: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -13500,7 +15643,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                joule: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -13551,7 +15704,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                newton: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -13600,7 +15763,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                timestamp: ::core::default::Default::default(),
+                                newton: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 152;
+                        const MAX_SIZE_BITS: usize = 152;
                         fn size_bits(&self) -> usize {
                             152
                         }
@@ -13655,7 +15830,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                hertz: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -13706,7 +15891,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for NarrowScalar {}
                     impl NarrowScalar {}
+                    impl ::core::default::Default for NarrowScalar {
+                        fn default() -> Self {
+                            NarrowScalar {
+                                timestamp: ::core::default::Default::default(),
+                                meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for NarrowScalar {
+                        const MIN_SIZE_BITS: usize = 72;
+                        const MAX_SIZE_BITS: usize = 72;
                         fn size_bits(&self) -> usize {
                             72
                         }
@@ -13755,7 +15950,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for NarrowVector3 {}
                     impl NarrowVector3 {}
+                    impl ::core::default::Default for NarrowVector3 {
+                        fn default() -> Self {
+                            NarrowVector3 {
+                                timestamp: ::core::default::Default::default(),
+                                meter: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for NarrowVector3 {
+                        const MIN_SIZE_BITS: usize = 104;
+                        const MAX_SIZE_BITS: usize = 104;
                         fn size_bits(&self) -> usize {
                             104
                         }
@@ -13808,7 +16015,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -13857,7 +16074,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                timestamp: ::core::default::Default::default(),
+                                meter: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 152;
+                        const MAX_SIZE_BITS: usize = 152;
                         fn size_bits(&self) -> usize {
                             152
                         }
@@ -13910,7 +16139,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for WideScalar {}
                     impl WideScalar {}
+                    impl ::core::default::Default for WideScalar {
+                        fn default() -> Self {
+                            WideScalar {
+                                timestamp: ::core::default::Default::default(),
+                                meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for WideScalar {
+                        const MIN_SIZE_BITS: usize = 120;
+                        const MAX_SIZE_BITS: usize = 120;
                         fn size_bits(&self) -> usize {
                             120
                         }
@@ -13959,7 +16198,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for WideVector3 {}
                     impl WideVector3 {}
+                    impl ::core::default::Default for WideVector3 {
+                        fn default() -> Self {
+                            WideVector3 {
+                                timestamp: ::core::default::Default::default(),
+                                meter: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for WideVector3 {
+                        const MIN_SIZE_BITS: usize = 248;
+                        const MAX_SIZE_BITS: usize = 248;
                         fn size_bits(&self) -> usize {
                             248
                         }
@@ -14014,7 +16265,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                candela_per_square_meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14072,7 +16333,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                tesla: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14121,7 +16392,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                ampere_per_meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14177,7 +16458,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                timestamp: ::core::default::Default::default(),
+                                tesla: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 152;
+                        const MAX_SIZE_BITS: usize = 152;
                         fn size_bits(&self) -> usize {
                             152
                         }
@@ -14230,7 +16523,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                timestamp: ::core::default::Default::default(),
+                                ampere_per_meter: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 152;
+                        const MAX_SIZE_BITS: usize = 152;
                         fn size_bits(&self) -> usize {
                             152
                         }
@@ -14285,7 +16590,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                tesla: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14334,7 +16649,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                timestamp: ::core::default::Default::default(),
+                                tesla: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 152;
+                        const MAX_SIZE_BITS: usize = 152;
                         fn size_bits(&self) -> usize {
                             152
                         }
@@ -14389,7 +16716,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                kilogram: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14440,7 +16777,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                watt: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14491,7 +16838,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                pascal: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14542,7 +16899,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                kelvin: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14593,7 +16960,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                newton_meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14642,7 +17019,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                timestamp: ::core::default::Default::default(),
+                                newton_meter: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 152;
+                        const MAX_SIZE_BITS: usize = 152;
                         fn size_bits(&self) -> usize {
                             152
                         }
@@ -14697,7 +17086,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                meter_per_second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14746,7 +17145,19 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                timestamp: ::core::default::Default::default(),
+                                meter_per_second: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 152;
+                        const MAX_SIZE_BITS: usize = 152;
                         fn size_bits(&self) -> usize {
                             152
                         }
@@ -14801,7 +17212,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                volt: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14852,7 +17273,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                cubic_meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14903,7 +17334,17 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                timestamp: ::core::default::Default::default(),
+                                cubic_meter_per_second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 88;
+                        const MAX_SIZE_BITS: usize = 88;
                         fn size_bits(&self) -> usize {
                             88
                         }
@@ -14953,7 +17394,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                meter_per_second_per_second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -15003,7 +17453,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                meter_per_second_per_second: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -15055,7 +17516,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for NarrowScalar {}
                     impl NarrowScalar {}
+                    impl ::core::default::Default for NarrowScalar {
+                        fn default() -> Self {
+                            NarrowScalar {
+                                radian: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for NarrowScalar {
+                        const MIN_SIZE_BITS: usize = 16;
+                        const MAX_SIZE_BITS: usize = 16;
                         fn size_bits(&self) -> usize {
                             16
                         }
@@ -15102,7 +17572,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for NarrowVector3 {}
                     impl NarrowVector3 {}
+                    impl ::core::default::Default for NarrowVector3 {
+                        fn default() -> Self {
+                            NarrowVector3 {
+                                radian: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for NarrowVector3 {
+                        const MIN_SIZE_BITS: usize = 48;
+                        const MAX_SIZE_BITS: usize = 48;
                         fn size_bits(&self) -> usize {
                             48
                         }
@@ -15149,7 +17630,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Quaternion {}
                     impl Quaternion {}
+                    impl ::core::default::Default for Quaternion {
+                        fn default() -> Self {
+                            Quaternion {
+                                wxyz: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Quaternion {
+                        const MIN_SIZE_BITS: usize = 128;
+                        const MAX_SIZE_BITS: usize = 128;
                         fn size_bits(&self) -> usize {
                             128
                         }
@@ -15196,7 +17688,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                radian: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -15243,7 +17744,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                radian: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -15290,7 +17802,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for WideScalar {}
                     impl WideScalar {}
+                    impl ::core::default::Default for WideScalar {
+                        fn default() -> Self {
+                            WideScalar {
+                                radian: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for WideScalar {
+                        const MIN_SIZE_BITS: usize = 64;
+                        const MAX_SIZE_BITS: usize = 64;
                         fn size_bits(&self) -> usize {
                             64
                         }
@@ -15337,7 +17858,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for WideVector3 {}
                     impl WideVector3 {}
+                    impl ::core::default::Default for WideVector3 {
+                        fn default() -> Self {
+                            WideVector3 {
+                                radian: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for WideVector3 {
+                        const MIN_SIZE_BITS: usize = 192;
+                        const MAX_SIZE_BITS: usize = 192;
                         fn size_bits(&self) -> usize {
                             192
                         }
@@ -15386,7 +17918,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                radian_per_second_per_second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -15436,7 +17977,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                radian_per_second_per_second: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -15488,7 +18040,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                radian_per_second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -15535,7 +18096,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                radian_per_second: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -15584,7 +18156,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -15631,7 +18212,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for WideScalar {}
                     impl WideScalar {}
+                    impl ::core::default::Default for WideScalar {
+                        fn default() -> Self {
+                            WideScalar {
+                                second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for WideScalar {
+                        const MIN_SIZE_BITS: usize = 64;
+                        const MAX_SIZE_BITS: usize = 64;
                         fn size_bits(&self) -> usize {
                             64
                         }
@@ -15680,7 +18270,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                coulomb: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -15729,7 +18328,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                ampere: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -15778,7 +18386,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                joule: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -15827,7 +18444,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                newton: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -15874,7 +18500,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                newton: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -15923,7 +18560,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                hertz: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -15972,7 +18618,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for NarrowScalar {}
                     impl NarrowScalar {}
+                    impl ::core::default::Default for NarrowScalar {
+                        fn default() -> Self {
+                            NarrowScalar {
+                                meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for NarrowScalar {
+                        const MIN_SIZE_BITS: usize = 16;
+                        const MAX_SIZE_BITS: usize = 16;
                         fn size_bits(&self) -> usize {
                             16
                         }
@@ -16019,7 +18674,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for NarrowVector3 {}
                     impl NarrowVector3 {}
+                    impl ::core::default::Default for NarrowVector3 {
+                        fn default() -> Self {
+                            NarrowVector3 {
+                                meter: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for NarrowVector3 {
+                        const MIN_SIZE_BITS: usize = 48;
+                        const MAX_SIZE_BITS: usize = 48;
                         fn size_bits(&self) -> usize {
                             48
                         }
@@ -16066,7 +18732,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -16113,7 +18788,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                meter: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -16160,7 +18846,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for WideScalar {}
                     impl WideScalar {}
+                    impl ::core::default::Default for WideScalar {
+                        fn default() -> Self {
+                            WideScalar {
+                                meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for WideScalar {
+                        const MIN_SIZE_BITS: usize = 64;
+                        const MAX_SIZE_BITS: usize = 64;
                         fn size_bits(&self) -> usize {
                             64
                         }
@@ -16207,7 +18902,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for WideVector3 {}
                     impl WideVector3 {}
+                    impl ::core::default::Default for WideVector3 {
+                        fn default() -> Self {
+                            WideVector3 {
+                                meter: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for WideVector3 {
+                        const MIN_SIZE_BITS: usize = 192;
+                        const MAX_SIZE_BITS: usize = 192;
                         fn size_bits(&self) -> usize {
                             192
                         }
@@ -16256,7 +18962,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                candela_per_square_meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -16315,7 +19030,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                tesla: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -16362,7 +19086,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                ampere_per_meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -16416,7 +19149,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                tesla: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -16463,7 +19207,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                ampere_per_meter: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -16512,7 +19267,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                tesla: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -16559,7 +19323,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                tesla: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -16608,7 +19383,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                kilogram: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -16657,7 +19441,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                watt: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -16706,7 +19499,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                pascal: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -16755,7 +19557,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                kelvin: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -16804,7 +19615,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                newton_meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -16851,7 +19671,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                newton_meter: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -16900,7 +19731,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                meter_per_second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -16947,7 +19787,18 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Vector3 {}
                     impl Vector3 {}
+                    impl ::core::default::Default for Vector3 {
+                        fn default() -> Self {
+                            Vector3 {
+                                meter_per_second: ::core::array::from_fn(|_| {
+                                    ::core::default::Default::default()
+                                }),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Vector3 {
+                        const MIN_SIZE_BITS: usize = 96;
+                        const MAX_SIZE_BITS: usize = 96;
                         fn size_bits(&self) -> usize {
                             96
                         }
@@ -16996,7 +19847,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                volt: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -17045,7 +19905,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                cubic_meter: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -17094,7 +19963,16 @@ Extended
                     }
                     impl ::canadensis_encoding::Message for Scalar {}
                     impl Scalar {}
+                    impl ::core::default::Default for Scalar {
+                        fn default() -> Self {
+                            Scalar {
+                                cubic_meter_per_second: ::core::default::Default::default(),
+                            }
+                        }
+                    }
                     impl ::canadensis_encoding::Serialize for Scalar {
+                        const MIN_SIZE_BITS: usize = 32;
+                        const MAX_SIZE_BITS: usize = 32;
                         fn size_bits(&self) -> usize {
                             32
                         }
@@ -17147,7 +20025,14 @@ Extended
             }
             impl ::canadensis_encoding::Request for GetSynchronizationMasterInfoRequest {}
             impl GetSynchronizationMasterInfoRequest {}
+            impl ::core::default::Default for GetSynchronizationMasterInfoRequest {
+                fn default() -> Self {
+                    GetSynchronizationMasterInfoRequest {}
+                }
+            }
             impl ::canadensis_encoding::Serialize for GetSynchronizationMasterInfoRequest {
+                const MIN_SIZE_BITS: usize = 0;
+                const MAX_SIZE_BITS: usize = 0;
                 fn size_bits(&self) -> usize {
                     0
                 }
@@ -17215,7 +20100,18 @@ Extended
             }
             impl ::canadensis_encoding::Response for GetSynchronizationMasterInfoResponse {}
             impl GetSynchronizationMasterInfoResponse {}
+            impl ::core::default::Default for GetSynchronizationMasterInfoResponse {
+                fn default() -> Self {
+                    GetSynchronizationMasterInfoResponse {
+                        error_variance: ::core::default::Default::default(),
+                        time_system: ::core::default::Default::default(),
+                        tai_info: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for GetSynchronizationMasterInfoResponse {
+                const MIN_SIZE_BITS: usize = 56;
+                const MAX_SIZE_BITS: usize = 56;
                 fn size_bits(&self) -> usize {
                     56
                 }
@@ -17282,12 +20178,22 @@ Extended
                 )]
                 pub const PUBLISHER_TIMEOUT_PERIOD_MULTIPLIER: u8 = 3;
             }
+            impl ::core::default::Default for Synchronization {
+                fn default() -> Self {
+                    Synchronization {
+                        previous_transmission_timestamp_microsecond:
+                            ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for Synchronization {
+                const MIN_SIZE_BITS: usize = 56;
+                const MAX_SIZE_BITS: usize = 56;
                 fn size_bits(&self) -> usize {
                     56
                 }
                 fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
-                    cursor.write_u56(self.previous_transmission_timestamp_microsecond);
+                    cursor.write_u56(self.previous_transmission_timestamp_microsecond & 0xffffffffffffff);
                 }
             }
             impl ::canadensis_encoding::Deserialize for Synchronization {
@@ -17333,12 +20239,21 @@ Extended
                 #[cfg_attr(not(doctest), doc = " Zero means that the time is not known.")]
                 pub const UNKNOWN: u64 = 0;
             }
+            impl ::core::default::Default for SynchronizedTimestamp {
+                fn default() -> Self {
+                    SynchronizedTimestamp {
+                        microsecond: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for SynchronizedTimestamp {
+                const MIN_SIZE_BITS: usize = 56;
+                const MAX_SIZE_BITS: usize = 56;
                 fn size_bits(&self) -> usize {
                     56
                 }
                 fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
-                    cursor.write_u56(self.microsecond);
+                    cursor.write_u56(self.microsecond & 0xffffffffffffff);
                 }
             }
             impl ::canadensis_encoding::Deserialize for SynchronizedTimestamp {
@@ -17388,7 +20303,16 @@ Extended
                 pub const DIFFERENCE_TAI_MINUS_GPS: u8 = 19;
                 pub const DIFFERENCE_TAI_MINUS_UTC_UNKNOWN: u16 = 0;
             }
+            impl ::core::default::Default for TAIInfo {
+                fn default() -> Self {
+                    TAIInfo {
+                        difference_tai_minus_utc: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for TAIInfo {
+                const MIN_SIZE_BITS: usize = 16;
+                const MAX_SIZE_BITS: usize = 16;
                 fn size_bits(&self) -> usize {
                     16
                 }
@@ -17448,12 +20372,21 @@ Extended
                 )]
                 pub const APPLICATION_SPECIFIC: u8 = 15;
             }
+            impl ::core::default::Default for TimeSystem {
+                fn default() -> Self {
+                    TimeSystem {
+                        value: ::core::default::Default::default(),
+                    }
+                }
+            }
             impl ::canadensis_encoding::Serialize for TimeSystem {
+                const MIN_SIZE_BITS: usize = 8;
+                const MAX_SIZE_BITS: usize = 8;
                 fn size_bits(&self) -> usize {
                     8
                 }
                 fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
-                    cursor.write_u4(self.value);
+                    cursor.write_u4(self.value & 0xf);
                 }
             }
             impl ::canadensis_encoding::Deserialize for TimeSystem {
@@ -24,6 +24,10 @@ impl<const N: usize> canadensis_encoding::DataType for SubjectIdList<N> {
 }
 impl<const N: usize> canadensis_encoding::Message for SubjectIdList<N> {}
 impl<const N: usize> canadensis_encoding::Serialize for SubjectIdList<N> {
+    // Only the sparse list variant is used
+    const MIN_SIZE_BITS: usize = 8 + 8;
+    const MAX_SIZE_BITS: usize = 8 + 8 + N * 16;
+
     fn size_bits(&self) -> usize {
         // 8 bits of union discriminant, 8 bits of length, 16 bits for each ID
         8 + 8 + self.0.len() * 16
@@ -41,23 +45,38 @@ impl<const N: usize> canadensis_encoding::Serialize for SubjectIdList<N> {
 }
 impl<const N: usize> canadensis_encoding::Deserialize for SubjectIdList<N> {
     fn deserialize(cursor: &mut ReadCursor<'_>) -> Result<Self, DeserializeError>
+    where
+        Self: Sized,
+    {
+        let mut list = SubjectIdList(Vec::new());
+        list.deserialize_in_place(cursor)?;
+        Ok(list)
+    }
+
+    fn deserialize_in_place(&mut self, cursor: &mut ReadCursor<'_>) -> Result<(), DeserializeError>
     where
         Self: Sized,
     {
         let discriminant = cursor.read_aligned_u8();
         if discriminant != SUBJECT_ID_SPARSE_LIST_DISCRIMINANT {
             // Not a sparse list
-            return Err(DeserializeError::UnionTag);
+            return Err(DeserializeError::UnionTag {
+                offset_bits: cursor.bit_offset(),
+            });
         }
         let length = cursor.read_aligned_u8();
         if usize::from(length) > N {
-            return Err(DeserializeError::ArrayLength);
+            return Err(DeserializeError::ArrayLength {
+                offset_bits: cursor.bit_offset(),
+            });
         }
-        let mut ids = Vec::new();
+        self.0.clear();
         for _ in 0..length {
             // Push can't fail because of the above length check
-            let _ = ids.push(SubjectId::from_truncating(cursor.read_aligned_u16()));
+            let _ = self
+                .0
+                .push(SubjectId::from_truncating(cursor.read_aligned_u16()));
         }
-        Ok(SubjectIdList(ids))
+        Ok(())
     }
 }
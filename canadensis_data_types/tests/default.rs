@@ -0,0 +1,31 @@
+extern crate canadensis_data_types;
+extern crate heapless;
+
+use canadensis_data_types::uavcan::metatransport::can::arbitration_id_0_1::ArbitrationID;
+use canadensis_data_types::uavcan::node::get_info_1_0::GetInfoResponse;
+use canadensis_data_types::uavcan::node::version_1_0::Version;
+use canadensis_data_types::uavcan::register::value_1_0::Value;
+
+#[test]
+fn struct_default() {
+    let info = GetInfoResponse {
+        protocol_version: Version { major: 1, minor: 0 },
+        name: heapless::Vec::from_slice(b"org.example.node").unwrap(),
+        ..Default::default()
+    };
+    assert_eq!(info.hardware_version.major, 0);
+    assert_eq!(info.software_vcs_revision_id, 0);
+    assert_eq!(info.unique_id, [0; 16]);
+    assert!(info.software_image_crc.is_empty());
+    assert!(info.certificate_of_authenticity.is_empty());
+}
+
+#[test]
+fn enum_default() {
+    // The default is the first variant
+    assert!(matches!(Value::default(), Value::Empty(_)));
+    match ArbitrationID::default() {
+        ArbitrationID::Base(base) => assert_eq!(base.value, 0),
+        ArbitrationID::Extended(_) => panic!("Expected base ID"),
+    }
+}
@@ -0,0 +1,17 @@
+extern crate canadensis_data_types;
+extern crate canadensis_encoding;
+
+use canadensis_data_types::uavcan::diagnostic::record_1_1::Record;
+use canadensis_data_types::uavcan::node::heartbeat_1_0::Heartbeat;
+use canadensis_data_types::uavcan::node::port::list_1_0::List;
+use canadensis_encoding::Serialize;
+
+#[test]
+fn max_size_bytes() {
+    // uint32 uptime, Health, Mode, uint8 vendor_specific_status_code
+    assert_eq!(Heartbeat::MAX_SIZE_BYTES, 7);
+    // SynchronizedTimestamp, Severity, uint8[<=255] text
+    assert_eq!(Record::MAX_SIZE_BYTES, 7 + 1 + 1 + 255);
+    // Rounded up from bits
+    assert_eq!(List::MAX_SIZE_BYTES, List::MAX_SIZE_BITS.div_ceil(8));
+}
@@ -6,7 +6,7 @@ extern crate heapless;
 use canadensis_data_types::optimized::SubjectIdList;
 use canadensis_data_types::uavcan::node::port::subject_id_1_0::SubjectID;
 use canadensis_data_types::uavcan::node::port::subject_id_list_1_0::SubjectIDList as GeneratedSubjectIdList;
-use canadensis_encoding::{Deserialize, Serialize};
+use canadensis_encoding::{Deserialize, ReadCursor, Serialize};
 use std::convert::TryFrom;
 use std::iter::FromIterator;
 
@@ -17,6 +17,24 @@ fn same_serialization_basic() {
     check_same_serialization(&[1, 8191]);
 }
 
+#[test]
+fn deserialize_in_place() {
+    let mut list: SubjectIdList<4> = SubjectIdList::new();
+    // A sparse list with three IDs, and then a sparse list with one ID
+    for (bytes, expected) in [
+        (&[1u8, 3, 1, 0, 2, 0, 3, 0][..], &[1u16, 2, 3][..]),
+        (&[1u8, 1, 9, 0][..], &[9u16][..]),
+    ] {
+        let mut cursor = ReadCursor::new(bytes);
+        list.deserialize_in_place(&mut cursor).unwrap();
+        let ids: Vec<u16> = list.0.iter().map(|&id| u16::from(id)).collect();
+        assert_eq!(ids, expected);
+    }
+    // Too many IDs
+    let mut cursor = ReadCursor::new(&[1u8, 5]);
+    assert!(list.deserialize_in_place(&mut cursor).is_err());
+}
+
 fn check_same_serialization(ids: &[u16]) {
     let generated_ids = ids.iter().map(|&id| SubjectID { value: id });
     let generated = GeneratedSubjectIdList::SparseList(heapless::Vec::from_iter(generated_ids));
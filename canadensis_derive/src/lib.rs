@@ -0,0 +1,495 @@
+//!
+//! Derive macros that implement the `canadensis_encoding` traits `DataType`, `Serialize`,
+//! and `Deserialize` for hand-written structs
+//!
+//! The fields of a struct are serialized in order. The only padding is the padding that aligns
+//! composite fields to a multiple of 8 bits, and the padding at the end that makes the
+//! size of the whole struct a multiple of 8 bits.
+//!
+//! # Field types
+//!
+//! Field type | Default length | Attributes
+//! -----------|----------------|-----------
+//! `bool` | 1 bit |
+//! `u8`, `u16`, `u32`, `u64` | 8, 16, 32, or 64 bits | `#[canadensis(bits = N)]` to use fewer bits
+//! `i8`, `i16`, `i32`, `i64` | 8, 16, 32, or 64 bits | `#[canadensis(bits = N)]` to use fewer bits
+//! `f16`, `f32`, `f64` | 16, 32, or 64 bits |
+//! Any type that implements `Serialize` and/or `Deserialize` | | `#[canadensis(composite)]` (required)
+//!
+//! # Data type attributes
+//!
+//! By default, `#[derive(DataType)]` makes a sealed type. For a delimited type, add
+//! `#[canadensis(extent = N)]` with the extent in bytes.
+//!
+//! # Example
+//!
+//! ```
+//! use canadensis_derive::{DataType, Deserialize, Serialize};
+//!
+//! #[derive(DataType, Serialize, Deserialize)]
+//! struct Inner {
+//!     a: bool,
+//!     b: bool,
+//!     c: bool,
+//!     #[canadensis(bits = 5)]
+//!     d: u8,
+//! }
+//!
+//! #[derive(DataType, Serialize, Deserialize)]
+//! #[canadensis(extent = 12)]
+//! struct Outer {
+//!     #[canadensis(bits = 13)]
+//!     a: u16,
+//!     #[canadensis(composite)]
+//!     inner: Inner,
+//!     #[canadensis(bits = 41)]
+//!     b: u64,
+//! }
+//! ```
+//!
+
+extern crate proc_macro;
+use proc_macro::TokenStream;
+
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Field, Fields, LitInt, Member, Result, Type,
+};
+
+/// Implements `canadensis_encoding::DataType` for a struct
+///
+/// The type is sealed unless it has a `#[canadensis(extent = N)]` attribute.
+#[proc_macro_derive(DataType, attributes(canadensis))]
+pub fn derive_data_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    implement_data_type(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Implements `canadensis_encoding::Serialize` for a struct
+#[proc_macro_derive(Serialize, attributes(canadensis))]
+pub fn derive_serialize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    implement_serialize(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Implements `canadensis_encoding::Deserialize` for a struct
+#[proc_macro_derive(Deserialize, attributes(canadensis))]
+pub fn derive_deserialize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    implement_deserialize(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn implement_data_type(input: DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let mut extent: Option<u32> = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("canadensis"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("extent") {
+                let value: LitInt = meta.value()?.parse()?;
+                extent = Some(value.base10_parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported canadensis attribute"))
+            }
+        })?;
+    }
+    let extent = match extent {
+        Some(extent) => quote! { ::core::option::Option::Some(#extent) },
+        None => quote! { ::core::option::Option::None },
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::canadensis_encoding::DataType for #name #ty_generics #where_clause {
+            const EXTENT_BYTES: ::core::option::Option<u32> = #extent;
+        }
+    })
+}
+
+fn implement_serialize(input: DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let fields = parse_fields(&input)?;
+
+    let size_bits = total_size_bits(&fields, |field| {
+        let member = &field.member;
+        quote! { ::canadensis_encoding::Serialize::size_bits(&self.#member) }
+    });
+    let min_size_bits = total_size_bits(&fields, |field| {
+        let ty = field.ty;
+        quote! { <#ty as ::canadensis_encoding::Serialize>::MIN_SIZE_BITS }
+    });
+    let max_size_bits = total_size_bits(&fields, |field| {
+        let ty = field.ty;
+        quote! { <#ty as ::canadensis_encoding::Serialize>::MAX_SIZE_BITS }
+    });
+    let writes = fields.iter().map(FieldInfo::write);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::canadensis_encoding::Serialize for #name #ty_generics #where_clause {
+            const MIN_SIZE_BITS: usize = { #min_size_bits };
+            const MAX_SIZE_BITS: usize = { #max_size_bits };
+
+            fn size_bits(&self) -> usize {
+                #size_bits
+            }
+
+            fn serialize(&self, cursor: &mut ::canadensis_encoding::WriteCursor<'_>) {
+                #( #writes )*
+            }
+        }
+    })
+}
+
+/// Returns an expression that calculates the total size of some fields in bits
+///
+/// `composite_size` returns an expression for the size of a composite field. The resulting
+/// expression can be used in a const context if all the composite size expressions can.
+fn total_size_bits<F>(fields: &[FieldInfo<'_>], composite_size: F) -> proc_macro2::TokenStream
+where
+    F: Fn(&FieldInfo<'_>) -> proc_macro2::TokenStream,
+{
+    if fields.is_empty() {
+        quote! { 0 }
+    } else {
+        let field_sizes = fields
+            .iter()
+            .map(|field| field.size_bits(composite_size(field)));
+        quote! {
+            let mut bits: usize = 0;
+            #( #field_sizes )*
+            // Composite types always have a length that is a multiple of 8 bits
+            bits.div_ceil(8) * 8
+        }
+    }
+}
+
+fn implement_deserialize(input: DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let fields = parse_fields(&input)?;
+    let reads = fields.iter().map(FieldInfo::read);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::canadensis_encoding::Deserialize for #name #ty_generics #where_clause {
+            fn deserialize(
+                cursor: &mut ::canadensis_encoding::ReadCursor<'_>,
+            ) -> ::core::result::Result<Self, ::canadensis_encoding::DeserializeError>
+            where
+                Self: Sized,
+            {
+                ::core::result::Result::Ok(Self {
+                    #( #reads, )*
+                })
+            }
+        }
+    })
+}
+
+/// The way a field gets serialized
+enum FieldKind {
+    /// A boolean (1 bit)
+    Bool,
+    /// An unsigned integer with a specified number of bits
+    Unsigned { bits: u8, type_bits: u8 },
+    /// A signed integer with a specified number of bits
+    Signed { bits: u8, type_bits: u8 },
+    /// A floating-point value with 16, 32, or 64 bits
+    Float { bits: u8 },
+    /// A composite type, aligned to 8 bits
+    Composite,
+}
+
+impl FieldKind {
+    /// Returns an unsigned integer kind that uses all bits of the field type
+    fn unsigned(bits: u8) -> Self {
+        FieldKind::Unsigned {
+            bits,
+            type_bits: bits,
+        }
+    }
+    /// Returns a signed integer kind that uses all bits of the field type
+    fn signed(bits: u8) -> Self {
+        FieldKind::Signed {
+            bits,
+            type_bits: bits,
+        }
+    }
+}
+
+struct FieldInfo<'f> {
+    /// The name or index of the field
+    member: Member,
+    /// The type of the field
+    ty: &'f Type,
+    kind: FieldKind,
+}
+
+impl FieldInfo<'_> {
+    /// Returns statements that add the length of this field to a `bits` variable
+    ///
+    /// For a composite field, `composite_size` is the size of the composite value.
+    fn size_bits(&self, composite_size: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let ty = self.ty;
+        match self.kind {
+            FieldKind::Bool => quote! { bits += 1; },
+            FieldKind::Unsigned { bits, .. }
+            | FieldKind::Signed { bits, .. }
+            | FieldKind::Float { bits } => {
+                let bits = usize::from(bits);
+                quote! { bits += #bits; }
+            }
+            FieldKind::Composite => quote! {
+                bits = bits.div_ceil(8) * 8;
+                if <#ty as ::canadensis_encoding::DataType>::EXTENT_BYTES.is_some() {
+                    // Delimiter header
+                    bits += 32;
+                }
+                bits += #composite_size;
+                bits = bits.div_ceil(8) * 8;
+            },
+        }
+    }
+
+    /// Returns a statement that writes this field to a `cursor` variable
+    fn write(&self) -> proc_macro2::TokenStream {
+        let member = &self.member;
+        match self.kind {
+            FieldKind::Bool => quote! { cursor.write_bool(self.#member); },
+            FieldKind::Unsigned { bits, type_bits } => {
+                let function = format_ident!("write_u{}", bits);
+                let value = cast_if_needed(
+                    quote! { self.#member },
+                    bits,
+                    type_bits,
+                    format_ident!("u{}", round_up_integer_size(bits)),
+                );
+                quote! { cursor.#function(#value); }
+            }
+            FieldKind::Signed { bits, type_bits } => {
+                let function = format_ident!("write_i{}", bits);
+                let value = cast_if_needed(
+                    quote! { self.#member },
+                    bits,
+                    type_bits,
+                    format_ident!("i{}", round_up_integer_size(bits)),
+                );
+                quote! { cursor.#function(#value); }
+            }
+            FieldKind::Float { bits } => {
+                let function = format_ident!("write_f{}", bits);
+                quote! { cursor.#function(self.#member); }
+            }
+            FieldKind::Composite => quote! {
+                cursor.align_to_8_bits();
+                cursor.write_composite(&self.#member);
+                cursor.align_to_8_bits();
+            },
+        }
+    }
+
+    /// Returns a field initializer that reads this field from a `cursor` variable
+    fn read(&self) -> proc_macro2::TokenStream {
+        let member = &self.member;
+        let ty = self.ty;
+        match self.kind {
+            FieldKind::Bool => quote! { #member: cursor.read_bool() },
+            FieldKind::Unsigned { bits, type_bits } => {
+                let function = format_ident!("read_u{}", bits);
+                let value = cast_if_needed(quote! { cursor.#function() }, bits, type_bits, ty);
+                quote! { #member: #value }
+            }
+            FieldKind::Signed { bits, type_bits } => {
+                let function = format_ident!("read_i{}", bits);
+                let value = cast_if_needed(quote! { cursor.#function() }, bits, type_bits, ty);
+                quote! { #member: #value }
+            }
+            FieldKind::Float { bits } => {
+                let function = format_ident!("read_f{}", bits);
+                quote! { #member: cursor.#function() }
+            }
+            FieldKind::Composite => quote! { #member: cursor.read_composite()? },
+        }
+    }
+}
+
+/// Adds a cast to an integer expression if the cursor function for an integer with `bits` bits
+/// uses a different type than the field
+fn cast_if_needed<T: quote::ToTokens>(
+    value: proc_macro2::TokenStream,
+    bits: u8,
+    type_bits: u8,
+    target: T,
+) -> proc_macro2::TokenStream {
+    if round_up_integer_size(bits) == type_bits {
+        value
+    } else {
+        quote! { #value as #target }
+    }
+}
+
+/// Returns the number of bits in the smallest primitive integer type that can hold a value
+/// with the provided number of bits
+fn round_up_integer_size(bits: u8) -> u8 {
+    match bits {
+        0..=8 => 8,
+        9..=16 => 16,
+        17..=32 => 32,
+        _ => 64,
+    }
+}
+
+fn parse_fields(input: &DeriveInput) -> Result<Vec<FieldInfo<'_>>> {
+    let fields = match &input.data {
+        Data::Struct(data_struct) => &data_struct.fields,
+        Data::Enum(_) | Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "This can only be derived for structs",
+            ))
+        }
+    };
+    match fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .map(|field| {
+                let member = Member::Named(field.ident.clone().expect("Field must have a name"));
+                parse_field(field, member)
+            })
+            .collect(),
+        Fields::Unnamed(unnamed) => unnamed
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, field)| parse_field(field, Member::from(i)))
+            .collect(),
+        Fields::Unit => Ok(vec![]),
+    }
+}
+
+fn parse_field(field: &Field, member: Member) -> Result<FieldInfo<'_>> {
+    let mut bits: Option<LitInt> = None;
+    let mut composite = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("canadensis"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bits") {
+                bits = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("composite") {
+                composite = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported canadensis attribute"))
+            }
+        })?;
+    }
+
+    let kind = if composite {
+        if let Some(bits) = bits {
+            return Err(Error::new(
+                bits.span(),
+                "bits can't be used with a composite field",
+            ));
+        }
+        FieldKind::Composite
+    } else {
+        let default_kind = primitive_type_name(&field.ty)
+            .and_then(|type_name| default_field_kind(&type_name))
+            .ok_or_else(|| {
+                Error::new(
+                    field.ty.span(),
+                    "Unsupported field type (add #[canadensis(composite)] for a composite type)",
+                )
+            })?;
+        match (default_kind, bits) {
+            (kind, None) => kind,
+            (FieldKind::Unsigned { type_bits, .. }, Some(bits)) => FieldKind::Unsigned {
+                bits: parse_bits(&bits, type_bits)?,
+                type_bits,
+            },
+            (FieldKind::Signed { type_bits, .. }, Some(bits)) => FieldKind::Signed {
+                bits: parse_bits(&bits, type_bits)?,
+                type_bits,
+            },
+            (_, Some(bits)) => {
+                return Err(Error::new(
+                    bits.span(),
+                    "bits can only be used with an integer field",
+                ))
+            }
+        }
+    };
+
+    Ok(FieldInfo {
+        member,
+        ty: &field.ty,
+        kind,
+    })
+}
+
+/// Returns the way that a field with a primitive type is serialized when it does not have a
+/// `bits` attribute
+fn default_field_kind(type_name: &str) -> Option<FieldKind> {
+    let kind = match type_name {
+        "bool" => FieldKind::Bool,
+        "u8" => FieldKind::unsigned(8),
+        "u16" => FieldKind::unsigned(16),
+        "u32" => FieldKind::unsigned(32),
+        "u64" => FieldKind::unsigned(64),
+        "i8" => FieldKind::signed(8),
+        "i16" => FieldKind::signed(16),
+        "i32" => FieldKind::signed(32),
+        "i64" => FieldKind::signed(64),
+        "f16" => FieldKind::Float { bits: 16 },
+        "f32" => FieldKind::Float { bits: 32 },
+        "f64" => FieldKind::Float { bits: 64 },
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// Parses and checks a number of bits for an integer field with a type that has `type_bits` bits
+fn parse_bits(bits: &LitInt, type_bits: u8) -> Result<u8> {
+    let value: u8 = bits.base10_parse()?;
+    if value == 0 || value > type_bits {
+        Err(Error::new(
+            bits.span(),
+            format!("bits must be in the range 1..={}", type_bits),
+        ))
+    } else {
+        Ok(value)
+    }
+}
+
+/// If a type is a path with no generic arguments, this function returns the last component of
+/// the path (for example, `f16` for `half::f16`)
+fn primitive_type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => {
+            let last = path.path.segments.last()?;
+            if last.arguments.is_empty() {
+                Some(last.ident.to_string())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
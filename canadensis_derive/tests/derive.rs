@@ -0,0 +1,130 @@
+extern crate canadensis_derive;
+extern crate canadensis_encoding;
+
+use canadensis_derive::{DataType, Deserialize, Serialize};
+use canadensis_encoding::{DataType, Deserialize, Serialize};
+
+/// The same as the `Inner` type in the `canadensis_encoding` `composite1` test
+#[derive(Debug, PartialEq, DataType, Serialize, Deserialize)]
+struct Inner {
+    a: bool,
+    b: bool,
+    c: bool,
+    #[canadensis(bits = 5)]
+    d: u8,
+}
+
+/// The same as the `Outer` type in the `canadensis_encoding` `composite1` test
+#[derive(Debug, PartialEq, DataType, Serialize, Deserialize)]
+#[canadensis(extent = 12)]
+struct Outer {
+    #[canadensis(bits = 13)]
+    a: u16,
+    #[canadensis(composite)]
+    inner: Inner,
+    #[canadensis(bits = 41)]
+    b: u64,
+}
+
+/// A type with a delimited composite field, signed integers, and a float
+#[derive(Debug, PartialEq, DataType, Serialize, Deserialize)]
+struct Mixed {
+    #[canadensis(bits = 5)]
+    a: i8,
+    #[canadensis(composite)]
+    outer: Outer,
+    b: i16,
+    #[canadensis(bits = 3)]
+    c: u32,
+    d: f32,
+}
+
+#[derive(Debug, PartialEq, DataType, Serialize, Deserialize)]
+struct Tuple(#[canadensis(bits = 4)] u8, #[canadensis(bits = 4)] i8);
+
+fn make_outer() -> Outer {
+    Outer {
+        a: 0x1621,
+        inner: Inner {
+            a: false,
+            b: true,
+            c: true,
+            d: 0x19,
+        },
+        b: 0x137ab90ceda,
+    }
+}
+
+#[test]
+fn extent() {
+    assert_eq!(Inner::EXTENT_BYTES, None);
+    assert_eq!(Outer::EXTENT_BYTES, Some(12));
+}
+
+#[test]
+fn size_bounds() {
+    assert_eq!((Inner::MIN_SIZE_BITS, Inner::MAX_SIZE_BITS), (8, 8));
+    assert_eq!((Outer::MIN_SIZE_BITS, Outer::MAX_SIZE_BITS), (72, 72));
+    assert_eq!((Mixed::MIN_SIZE_BITS, Mixed::MAX_SIZE_BITS), (168, 168));
+    assert_eq!((Tuple::MIN_SIZE_BITS, Tuple::MAX_SIZE_BITS), (8, 8));
+    // The bounds can size a buffer at compile time
+    let buffer = [0u8; Mixed::MAX_SIZE_BITS.div_ceil(8)];
+    assert_eq!(buffer.len(), 21);
+}
+
+#[test]
+fn same_as_hand_written() {
+    let value = make_outer();
+    assert_eq!(value.inner.size_bits(), 8);
+    assert_eq!(value.size_bits(), 72);
+
+    #[rustfmt::skip]
+    let expected_bytes: [u8; 9] = [
+        // value.a and 3 bits of padding
+        0x21, 0x16,
+        // value.inner
+        0b1100_1110,
+        // value.b and 7 bits of padding
+        0xda, 0xce, 0x90, 0xab, 0x37, 0x01,
+    ];
+
+    let mut actual_bytes = [0u8; 9];
+    value.serialize_to_bytes(&mut actual_bytes);
+    assert_eq!(expected_bytes, actual_bytes);
+
+    let deserialized = Outer::deserialize_from_bytes(&actual_bytes).unwrap();
+    assert_eq!(value, deserialized);
+}
+
+#[test]
+fn mixed_round_trip() {
+    let value = Mixed {
+        a: -3,
+        outer: make_outer(),
+        b: -1234,
+        c: 5,
+        d: 1.5,
+    };
+    // 5 bits, 3 bits of padding, 32-bit delimiter header, 72 bits, 16 bits, 3 bits, 32 bits,
+    // 5 bits of padding
+    assert_eq!(value.size_bits(), 168);
+
+    let mut bytes = [0u8; 21];
+    value.serialize_to_bytes(&mut bytes);
+    assert_eq!(bytes[0], 0b11101);
+    // Delimiter header
+    assert_eq!(bytes[1..5], [9, 0, 0, 0]);
+
+    let deserialized = Mixed::deserialize_from_bytes(&bytes).unwrap();
+    assert_eq!(value, deserialized);
+}
+
+#[test]
+fn tuple_round_trip() {
+    let value = Tuple(0xa, -2);
+    assert_eq!(value.size_bits(), 8);
+    let mut bytes = [0u8; 1];
+    value.serialize_to_bytes(&mut bytes);
+    assert_eq!(bytes, [0xea]);
+    assert_eq!(Tuple::deserialize_from_bytes(&bytes).unwrap(), value);
+}
@@ -978,9 +978,12 @@ fn apply_sealed_or_extent(
 
 /// Calculates the number of bits needed for the discriminant of a union with the provided number
 /// of variants
+///
+/// The discriminant must hold the largest variant index, `num_variants - 1`.
 fn calculate_discriminant_bits(num_variants: usize) -> u8 {
     array_length_bits(
         num_variants
+            .saturating_sub(1)
             .try_into()
             .expect("Number of union variants too large for u64"),
     )
@@ -1001,3 +1004,20 @@ fn make_union_bit_length(variants: &[Variant]) -> BitLengthSet {
     // Concatenate the discriminant and the variant lengths
     discriminant_length.concatenate([variant_lengths])
 }
+
+#[cfg(test)]
+mod test {
+    use super::calculate_discriminant_bits;
+
+    #[test]
+    fn test_discriminant_bits() {
+        assert_eq!(8, calculate_discriminant_bits(2));
+        // ...
+        assert_eq!(8, calculate_discriminant_bits(255));
+        assert_eq!(8, calculate_discriminant_bits(256));
+        assert_eq!(16, calculate_discriminant_bits(257));
+        // ...
+        assert_eq!(16, calculate_discriminant_bits(65536));
+        assert_eq!(32, calculate_discriminant_bits(65537));
+    }
+}
@@ -15,10 +15,12 @@ use crate::types::set::Set;
 use crate::types::string::StringValue;
 use crate::TypeKey;
 use canadensis_bit_length_set::BitLengthSet;
-use canadensis_dsdl_parser::{CastMode, Span};
+use canadensis_dsdl_parser::Span;
 use num_rational::BigRational;
 use std::convert::TryInto;
 
+pub use canadensis_dsdl_parser::CastMode;
+
 /// A DSDL expression value
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub(crate) enum Value {
@@ -14,6 +14,10 @@ def round_to_primitive(bits)
     end
 end
 
+def round_to_signed_primitive(bits)
+    round_to_primitive(bits).sub('u', 'i')
+end
+
 BIT_NUMBERS = 1..64
 
 read_functions = BIT_NUMBERS.map do |i|
@@ -23,6 +27,13 @@ read_functions = BIT_NUMBERS.map do |i|
 pub fn read_u#{i}(&mut self) -> #{primitive_type} { self.read_up_to_#{primitive_type}(#{i}) }"
 end
 
+read_signed_functions = BIT_NUMBERS.map do |i|
+    primitive_type = round_to_signed_primitive(i)
+    "/// Reads a #{i}-bit signed integer
+#[inline]
+pub fn read_i#{i}(&mut self) -> #{primitive_type} { self.read_up_to_#{primitive_type}(#{i}) }"
+end
+
 skip_functions = BIT_NUMBERS.map do |i|
     if i == 1
         bit_or_bits = 'bit'
@@ -41,6 +52,14 @@ write_functions = BIT_NUMBERS.map do |i|
 pub fn write_u#{i}(&mut self, value: #{primitive_type}) { self.write_up_to_#{primitive_type}(value, #{i}) }"
 end
 
+write_signed_functions = BIT_NUMBERS.map do |i|
+    primitive_type = round_to_primitive(i)
+    signed_type = round_to_signed_primitive(i)
+    "/// Writes a #{i}-bit signed integer
+#[inline]
+pub fn write_i#{i}(&mut self, value: #{signed_type}) { self.write_up_to_#{primitive_type}(value as #{primitive_type}, #{i}) }"
+end
+
 write_skip_functions = BIT_NUMBERS.map do |i|
     if i == 1
         bit_or_bits = 'bit'
@@ -53,6 +72,8 @@ pub fn skip_#{i}(&mut self) { self.skip_bits(#{i}); }"
 end
 
 # puts read_functions.join("\n")
+# puts read_signed_functions.join("\n")
 # puts skip_functions.join("\n")
 # puts write_functions.join("\n")
+# puts write_signed_functions.join("\n")
 puts write_skip_functions.join("\n")
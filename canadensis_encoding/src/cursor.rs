@@ -2,3 +2,13 @@
 
 pub mod deserialize;
 pub mod serialize;
+
+/// Returns the length in bits of the tag of a union with the provided number of variants
+///
+/// This is the smallest of 8, 16, 32, and 64 that is enough to hold the largest tag value,
+/// `variant_count - 1`.
+fn union_tag_bits(variant_count: usize) -> u8 {
+    let max_tag = variant_count.saturating_sub(1);
+    let needed_bits = usize::BITS - max_tag.leading_zeros();
+    needed_bits.max(8).next_power_of_two() as u8
+}
@@ -6,12 +6,20 @@ use core::cmp;
 
 use half::f16;
 
+use crate::cursor::union_tag_bits;
 use crate::{Deserialize, DeserializeError};
+use core::convert::TryFrom;
 
 /// A cursor over a byte slice for easy deserializing of Cyphal data types
 ///
 /// Functions that read values will return zero when reading beyond the end of the bytes,
 /// in accordance with the implicit zero extension rule (specification section 3.7.1.5)
+///
+/// A cursor created with [`new_strict`](#method.new_strict) also reads zeros beyond the end,
+/// but [`read_composite`](#method.read_composite) and
+/// [`check_truncated`](#method.check_truncated) will then return an error. A strict cursor
+/// also checks that padding fields are zero (see
+/// [`check_constraints`](#method.check_constraints)).
 #[derive(Debug)]
 pub struct ReadCursor<'b> {
     /// The bytes available to read from
@@ -26,6 +34,20 @@ pub struct ReadCursor<'b> {
     ///
     /// Invariant: This is in the range 0..=7.
     bit_index: u8,
+    /// The number of bytes that have been fully read since the beginning of the input
+    ///
+    /// This includes bytes past the end of the input that have been read as implicit zeros.
+    bytes_read: usize,
+    /// If this is true, reading beyond the end of the input is an error
+    strict: bool,
+    /// The bit offset of the first read that extended beyond the end of the input, if any
+    truncated_at: Option<usize>,
+    /// If this is true, padding fields that are not zero are an error
+    ///
+    /// Unlike `strict`, this also applies to cursors forked to read delimited composite types.
+    check_padding: bool,
+    /// The bit offset just after the first padding field that was not zero, if any
+    padding_error_at: Option<usize>,
 }
 
 impl<'b> ReadCursor<'b> {
@@ -34,6 +56,59 @@ impl<'b> ReadCursor<'b> {
         ReadCursor {
             bytes,
             bit_index: 0,
+            bytes_read: 0,
+            strict: false,
+            truncated_at: None,
+            check_padding: false,
+            padding_error_at: None,
+        }
+    }
+
+    /// Creates a cursor that will read starting at the beginning of the provided slice,
+    /// and will report an error if anything is read beyond the end of the slice
+    ///
+    /// This can be used to tell a truncated transfer apart from a valid transfer that is
+    /// shorter than the maximum size of its type.
+    ///
+    /// The implicit zero extension rule still applies to nested delimited composite
+    /// types, which can validly be shorter than their extents.
+    ///
+    /// A strict cursor also records padding fields that are not zero, including in nested
+    /// composite types. [`check_constraints`](#method.check_constraints) reports them.
+    pub fn new_strict(bytes: &'b [u8]) -> Self {
+        ReadCursor {
+            strict: true,
+            check_padding: true,
+            ..ReadCursor::new(bytes)
+        }
+    }
+
+    /// Returns an error if this is a strict cursor and any value has been read beyond the end
+    /// of the input
+    ///
+    /// The offset in the error is the bit offset of the first read that extended beyond the
+    /// end of the input.
+    pub fn check_truncated(&self) -> Result<(), DeserializeError> {
+        match self.truncated_at {
+            Some(offset_bits) if self.strict => Err(DeserializeError::Truncated { offset_bits }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns an error if this is a strict cursor and any padding field that has been read
+    /// was not zero
+    ///
+    /// The Cyphal specification requires padding fields to be zero when serializing, so
+    /// a padding field that is not zero indicates a malformed transfer. The bit widths of other
+    /// fields already limit them to valid values, and invalid array lengths and union tags are
+    /// always errors.
+    ///
+    /// The offset in the error is the bit offset just after the first padding field that was
+    /// not zero.
+    pub fn check_constraints(&self) -> Result<(), DeserializeError> {
+        match self.padding_error_at {
+            Some(offset_bits) => Err(DeserializeError::ConstraintViolation { offset_bits }),
+            None => Ok(()),
         }
     }
 
@@ -127,6 +202,36 @@ impl<'b> ReadCursor<'b> {
         value
     }
 
+    /// Reads an x-bit signed integer and sign-extends it (x must be in the range 1..=8)
+    fn read_up_to_i8(&mut self, bits: u8) -> i8 {
+        debug_assert!((1..=8).contains(&bits));
+        // Move the sign bit to the most significant position, then shift back
+        // (an arithmetic shift copies the sign bit into the upper bits)
+        let shift = 8 - bits;
+        ((self.read_up_to_u8(bits) << shift) as i8) >> shift
+    }
+
+    /// Reads an x-bit signed integer and sign-extends it (x must be in the range 1..=16)
+    fn read_up_to_i16(&mut self, bits: u8) -> i16 {
+        debug_assert!((1..=16).contains(&bits));
+        let shift = 16 - bits;
+        ((self.read_up_to_u16(bits) << shift) as i16) >> shift
+    }
+
+    /// Reads an x-bit signed integer and sign-extends it (x must be in the range 1..=32)
+    fn read_up_to_i32(&mut self, bits: u8) -> i32 {
+        debug_assert!((1..=32).contains(&bits));
+        let shift = 32 - bits;
+        ((self.read_up_to_u32(bits) << shift) as i32) >> shift
+    }
+
+    /// Reads an x-bit signed integer and sign-extends it (x must be in the range 1..=64)
+    fn read_up_to_i64(&mut self, bits: u8) -> i64 {
+        debug_assert!((1..=64).contains(&bits));
+        let shift = 64 - bits;
+        ((self.read_up_to_u64(bits) << shift) as i64) >> shift
+    }
+
     /// Reads an 8-bit integer
     ///
     /// # Panics
@@ -188,6 +293,9 @@ impl<'b> ReadCursor<'b> {
     /// Advances self.bit_index and self.bytes to reflect that bits have been read
     fn advance_bits(&mut self, bits: usize) {
         let extended_bit_index = usize::from(self.bit_index) + bits;
+        if extended_bit_index > self.bytes.len() * 8 {
+            self.record_truncation();
+        }
         self.bit_index = (extended_bit_index % 8) as u8;
         let byte_increment = extended_bit_index / 8;
         self.advance_bytes(byte_increment);
@@ -201,8 +309,31 @@ impl<'b> ReadCursor<'b> {
         // Advance by the byte increment or number of bytes remaining, whichever is less
         // If the number of bytes remaining is smaller,
         // self.bytes will end up empty.
+        if byte_increment > self.bytes.len() {
+            self.record_truncation();
+        }
         let real_byte_increment = cmp::min(byte_increment, self.bytes.len());
         self.bytes = &self.bytes[real_byte_increment..];
+        self.bytes_read += byte_increment;
+    }
+
+    /// Skips a padding field of up to 64 bits, and records an error if this cursor checks padding
+    /// and the field is not zero
+    fn skip_padding(&mut self, bits: u8) {
+        if self.check_padding {
+            if self.read_up_to_u64(bits) != 0 && self.padding_error_at.is_none() {
+                self.padding_error_at = Some(self.bit_offset());
+            }
+        } else {
+            self.advance_bits(usize::from(bits));
+        }
+    }
+
+    /// Records that a read extended beyond the end of the input, if that has not happened already
+    fn record_truncation(&mut self) {
+        if self.truncated_at.is_none() {
+            self.truncated_at = Some(self.bit_offset());
+        }
     }
 
     /// Skips up to 7 bits so that this cursor is aligned to 8 bits (one byte)
@@ -217,6 +348,28 @@ impl<'b> ReadCursor<'b> {
         self.bit_index == 0
     }
 
+    /// Returns the number of bits that remain to be read before the end of the input
+    ///
+    /// Values read after this reaches zero will be zero, in accordance with the implicit zero
+    /// extension rule.
+    pub fn remaining_bits(&self) -> usize {
+        (self.bytes.len() * 8).saturating_sub(usize::from(self.bit_index))
+    }
+
+    /// Returns true if all bits of the input have been read
+    pub fn is_empty(&self) -> bool {
+        self.remaining_bits() == 0
+    }
+
+    /// Returns the number of bits between the beginning of the input and the current position
+    /// of this cursor
+    ///
+    /// For a cursor that reads a nested delimited composite, this is counted from the beginning
+    /// of the outermost input.
+    pub fn bit_offset(&self) -> usize {
+        self.bytes_read * 8 + usize::from(self.bit_index)
+    }
+
     /// Reads a 16-bit floating-point value
     #[inline]
     pub fn read_f16(&mut self) -> f16 {
@@ -233,6 +386,25 @@ impl<'b> ReadCursor<'b> {
         f16::from_bits(self.read_aligned_u16())
     }
 
+    /// Reads a 16-bit floating-point value and converts it into a 32-bit floating-point value
+    ///
+    /// Every 16-bit value, including subnormals, infinities, and NaN, can be represented
+    /// exactly as a 32-bit value.
+    #[inline]
+    pub fn read_f16_as_f32(&mut self) -> f32 {
+        self.read_f16().to_f32()
+    }
+
+    /// Reads a 16-bit floating-point value and converts it into a 32-bit floating-point value
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the cursor is not aligned to a multiple of 8 bits.
+    #[inline]
+    pub fn read_aligned_f16_as_f32(&mut self) -> f32 {
+        self.read_aligned_f16().to_f32()
+    }
+
     /// Reads a 32-bit floating-point value
     #[inline]
     pub fn read_f32(&mut self) -> f32 {
@@ -266,14 +438,43 @@ impl<'b> ReadCursor<'b> {
     }
 
     /// Reads a byte array
+    ///
+    /// If the cursor is aligned to a multiple of 8 bits, this is equivalent to
+    /// [`read_aligned_bytes()`](#method.read_aligned_bytes).
     pub fn read_bytes(&mut self, bytes: &mut [u8]) {
-        for byte in bytes {
-            *byte = self.read_u8();
+        if self.is_aligned_to_8_bits() {
+            self.read_aligned_bytes(bytes);
+        } else {
+            for byte in bytes {
+                *byte = self.read_u8();
+            }
         }
     }
 
+    /// Reads a sequence of bytes, copying them all at once
+    ///
+    /// Any bytes past the end of the input are set to zero.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the cursor is not aligned to a multiple of 8 bits.
+    pub fn read_aligned_bytes(&mut self, bytes: &mut [u8]) {
+        assert!(self.is_aligned_to_8_bits());
+        let available = cmp::min(bytes.len(), self.bytes.len());
+        let (present, missing) = bytes.split_at_mut(available);
+        present.copy_from_slice(&self.bytes[..available]);
+        // Implicit zero extension
+        missing.iter_mut().for_each(|byte| *byte = 0);
+        self.advance_bytes(bytes.len());
+    }
+
     /// Reads a composite object
     ///
+    /// If T is delimited (its `EXTENT_BYTES` is `Some`), this function reads the 32-bit delimiter
+    /// header and then reads T from the number of bytes that the header specifies. Afterwards,
+    /// this cursor is always advanced past all of those bytes, even if T did not read all of them.
+    /// This allows a newer version of T with additional fields to be read as an older version.
+    ///
     /// This function returns an error if T is delimited and the delimiter header has an
     /// invalid length.
     ///
@@ -287,17 +488,27 @@ impl<'b> ReadCursor<'b> {
             // This is a delimited type. Read the header and fork to read the object
             let composite_length_bytes = self.read_aligned_u32() as usize;
             if composite_length_bytes > self.bytes.len() {
-                Err(DeserializeError::DelimitedLength)
+                // If the header itself was truncated, report that instead
+                self.check_truncated()?;
+                Err(DeserializeError::DelimitedLength {
+                    offset_bits: self.bit_offset(),
+                })
             } else {
                 let mut forked = self.fork(composite_length_bytes);
-                T::deserialize(&mut forked)
+                let status = T::deserialize(&mut forked);
+                if self.padding_error_at.is_none() {
+                    self.padding_error_at = forked.padding_error_at;
+                }
+                status
             }
         } else {
             // Sealed type, read directly
             T::deserialize(self)
         };
         self.align_to_8_bits();
-        status
+        let value = status?;
+        self.check_truncated()?;
+        Ok(value)
     }
 
     /// Reads a boolean value (1 bit)
@@ -305,6 +516,22 @@ impl<'b> ReadCursor<'b> {
         self.read_u1() == 1
     }
 
+    /// Reads the tag of a union that has `variant_count` variants
+    ///
+    /// The length of the tag is the smallest of 8, 16, 32, and 64 bits that can hold the largest
+    /// tag value, `variant_count - 1`, as the Cyphal specification requires.
+    ///
+    /// This function returns an error if the tag is not less than `variant_count`.
+    pub fn read_union_tag(&mut self, variant_count: usize) -> Result<u32, DeserializeError> {
+        let tag = self.read_up_to_u64(union_tag_bits(variant_count));
+        match u32::try_from(tag) {
+            Ok(tag) if u64::from(tag) < variant_count as u64 => Ok(tag),
+            _ => Err(DeserializeError::UnionTag {
+                offset_bits: self.bit_offset(),
+            }),
+        }
+    }
+
     /// Creates another cursor to read a specified number of bytes, and skips this cursor past
     /// those bytes
     ///
@@ -331,8 +558,14 @@ impl<'b> ReadCursor<'b> {
         let forked_cursor = ReadCursor {
             bytes: &self.bytes[..fork_bytes],
             bit_index: 0,
+            bytes_read: self.bytes_read,
+            // The forked cursor reads a delimited composite, which may be shorter than its type
+            strict: false,
+            truncated_at: None,
+            check_padding: self.check_padding,
+            padding_error_at: None,
         };
-        self.bytes = &self.bytes[fork_bytes..];
+        self.advance_bytes(fork_bytes);
         forked_cursor
     }
 }
@@ -660,332 +893,656 @@ impl ReadCursor<'_> {
     }
 }
 
+impl ReadCursor<'_> {
+    /// Reads a 1-bit signed integer
+    #[inline]
+    pub fn read_i1(&mut self) -> i8 {
+        self.read_up_to_i8(1)
+    }
+    /// Reads a 2-bit signed integer
+    #[inline]
+    pub fn read_i2(&mut self) -> i8 {
+        self.read_up_to_i8(2)
+    }
+    /// Reads a 3-bit signed integer
+    #[inline]
+    pub fn read_i3(&mut self) -> i8 {
+        self.read_up_to_i8(3)
+    }
+    /// Reads a 4-bit signed integer
+    #[inline]
+    pub fn read_i4(&mut self) -> i8 {
+        self.read_up_to_i8(4)
+    }
+    /// Reads a 5-bit signed integer
+    #[inline]
+    pub fn read_i5(&mut self) -> i8 {
+        self.read_up_to_i8(5)
+    }
+    /// Reads a 6-bit signed integer
+    #[inline]
+    pub fn read_i6(&mut self) -> i8 {
+        self.read_up_to_i8(6)
+    }
+    /// Reads a 7-bit signed integer
+    #[inline]
+    pub fn read_i7(&mut self) -> i8 {
+        self.read_up_to_i8(7)
+    }
+    /// Reads a 8-bit signed integer
+    #[inline]
+    pub fn read_i8(&mut self) -> i8 {
+        self.read_up_to_i8(8)
+    }
+    /// Reads a 9-bit signed integer
+    #[inline]
+    pub fn read_i9(&mut self) -> i16 {
+        self.read_up_to_i16(9)
+    }
+    /// Reads a 10-bit signed integer
+    #[inline]
+    pub fn read_i10(&mut self) -> i16 {
+        self.read_up_to_i16(10)
+    }
+    /// Reads a 11-bit signed integer
+    #[inline]
+    pub fn read_i11(&mut self) -> i16 {
+        self.read_up_to_i16(11)
+    }
+    /// Reads a 12-bit signed integer
+    #[inline]
+    pub fn read_i12(&mut self) -> i16 {
+        self.read_up_to_i16(12)
+    }
+    /// Reads a 13-bit signed integer
+    #[inline]
+    pub fn read_i13(&mut self) -> i16 {
+        self.read_up_to_i16(13)
+    }
+    /// Reads a 14-bit signed integer
+    #[inline]
+    pub fn read_i14(&mut self) -> i16 {
+        self.read_up_to_i16(14)
+    }
+    /// Reads a 15-bit signed integer
+    #[inline]
+    pub fn read_i15(&mut self) -> i16 {
+        self.read_up_to_i16(15)
+    }
+    /// Reads a 16-bit signed integer
+    #[inline]
+    pub fn read_i16(&mut self) -> i16 {
+        self.read_up_to_i32(16) as i16
+    }
+    /// Reads a 17-bit signed integer
+    #[inline]
+    pub fn read_i17(&mut self) -> i32 {
+        self.read_up_to_i32(17)
+    }
+    /// Reads a 18-bit signed integer
+    #[inline]
+    pub fn read_i18(&mut self) -> i32 {
+        self.read_up_to_i32(18)
+    }
+    /// Reads a 19-bit signed integer
+    #[inline]
+    pub fn read_i19(&mut self) -> i32 {
+        self.read_up_to_i32(19)
+    }
+    /// Reads a 20-bit signed integer
+    #[inline]
+    pub fn read_i20(&mut self) -> i32 {
+        self.read_up_to_i32(20)
+    }
+    /// Reads a 21-bit signed integer
+    #[inline]
+    pub fn read_i21(&mut self) -> i32 {
+        self.read_up_to_i32(21)
+    }
+    /// Reads a 22-bit signed integer
+    #[inline]
+    pub fn read_i22(&mut self) -> i32 {
+        self.read_up_to_i32(22)
+    }
+    /// Reads a 23-bit signed integer
+    #[inline]
+    pub fn read_i23(&mut self) -> i32 {
+        self.read_up_to_i32(23)
+    }
+    /// Reads a 24-bit signed integer
+    #[inline]
+    pub fn read_i24(&mut self) -> i32 {
+        self.read_up_to_i32(24)
+    }
+    /// Reads a 25-bit signed integer
+    #[inline]
+    pub fn read_i25(&mut self) -> i32 {
+        self.read_up_to_i32(25)
+    }
+    /// Reads a 26-bit signed integer
+    #[inline]
+    pub fn read_i26(&mut self) -> i32 {
+        self.read_up_to_i32(26)
+    }
+    /// Reads a 27-bit signed integer
+    #[inline]
+    pub fn read_i27(&mut self) -> i32 {
+        self.read_up_to_i32(27)
+    }
+    /// Reads a 28-bit signed integer
+    #[inline]
+    pub fn read_i28(&mut self) -> i32 {
+        self.read_up_to_i32(28)
+    }
+    /// Reads a 29-bit signed integer
+    #[inline]
+    pub fn read_i29(&mut self) -> i32 {
+        self.read_up_to_i32(29)
+    }
+    /// Reads a 30-bit signed integer
+    #[inline]
+    pub fn read_i30(&mut self) -> i32 {
+        self.read_up_to_i32(30)
+    }
+    /// Reads a 31-bit signed integer
+    #[inline]
+    pub fn read_i31(&mut self) -> i32 {
+        self.read_up_to_i32(31)
+    }
+    /// Reads a 32-bit signed integer
+    #[inline]
+    pub fn read_i32(&mut self) -> i32 {
+        self.read_up_to_i64(32) as i32
+    }
+    /// Reads a 33-bit signed integer
+    #[inline]
+    pub fn read_i33(&mut self) -> i64 {
+        self.read_up_to_i64(33)
+    }
+    /// Reads a 34-bit signed integer
+    #[inline]
+    pub fn read_i34(&mut self) -> i64 {
+        self.read_up_to_i64(34)
+    }
+    /// Reads a 35-bit signed integer
+    #[inline]
+    pub fn read_i35(&mut self) -> i64 {
+        self.read_up_to_i64(35)
+    }
+    /// Reads a 36-bit signed integer
+    #[inline]
+    pub fn read_i36(&mut self) -> i64 {
+        self.read_up_to_i64(36)
+    }
+    /// Reads a 37-bit signed integer
+    #[inline]
+    pub fn read_i37(&mut self) -> i64 {
+        self.read_up_to_i64(37)
+    }
+    /// Reads a 38-bit signed integer
+    #[inline]
+    pub fn read_i38(&mut self) -> i64 {
+        self.read_up_to_i64(38)
+    }
+    /// Reads a 39-bit signed integer
+    #[inline]
+    pub fn read_i39(&mut self) -> i64 {
+        self.read_up_to_i64(39)
+    }
+    /// Reads a 40-bit signed integer
+    #[inline]
+    pub fn read_i40(&mut self) -> i64 {
+        self.read_up_to_i64(40)
+    }
+    /// Reads a 41-bit signed integer
+    #[inline]
+    pub fn read_i41(&mut self) -> i64 {
+        self.read_up_to_i64(41)
+    }
+    /// Reads a 42-bit signed integer
+    #[inline]
+    pub fn read_i42(&mut self) -> i64 {
+        self.read_up_to_i64(42)
+    }
+    /// Reads a 43-bit signed integer
+    #[inline]
+    pub fn read_i43(&mut self) -> i64 {
+        self.read_up_to_i64(43)
+    }
+    /// Reads a 44-bit signed integer
+    #[inline]
+    pub fn read_i44(&mut self) -> i64 {
+        self.read_up_to_i64(44)
+    }
+    /// Reads a 45-bit signed integer
+    #[inline]
+    pub fn read_i45(&mut self) -> i64 {
+        self.read_up_to_i64(45)
+    }
+    /// Reads a 46-bit signed integer
+    #[inline]
+    pub fn read_i46(&mut self) -> i64 {
+        self.read_up_to_i64(46)
+    }
+    /// Reads a 47-bit signed integer
+    #[inline]
+    pub fn read_i47(&mut self) -> i64 {
+        self.read_up_to_i64(47)
+    }
+    /// Reads a 48-bit signed integer
+    #[inline]
+    pub fn read_i48(&mut self) -> i64 {
+        self.read_up_to_i64(48)
+    }
+    /// Reads a 49-bit signed integer
+    #[inline]
+    pub fn read_i49(&mut self) -> i64 {
+        self.read_up_to_i64(49)
+    }
+    /// Reads a 50-bit signed integer
+    #[inline]
+    pub fn read_i50(&mut self) -> i64 {
+        self.read_up_to_i64(50)
+    }
+    /// Reads a 51-bit signed integer
+    #[inline]
+    pub fn read_i51(&mut self) -> i64 {
+        self.read_up_to_i64(51)
+    }
+    /// Reads a 52-bit signed integer
+    #[inline]
+    pub fn read_i52(&mut self) -> i64 {
+        self.read_up_to_i64(52)
+    }
+    /// Reads a 53-bit signed integer
+    #[inline]
+    pub fn read_i53(&mut self) -> i64 {
+        self.read_up_to_i64(53)
+    }
+    /// Reads a 54-bit signed integer
+    #[inline]
+    pub fn read_i54(&mut self) -> i64 {
+        self.read_up_to_i64(54)
+    }
+    /// Reads a 55-bit signed integer
+    #[inline]
+    pub fn read_i55(&mut self) -> i64 {
+        self.read_up_to_i64(55)
+    }
+    /// Reads a 56-bit signed integer
+    #[inline]
+    pub fn read_i56(&mut self) -> i64 {
+        self.read_up_to_i64(56)
+    }
+    /// Reads a 57-bit signed integer
+    #[inline]
+    pub fn read_i57(&mut self) -> i64 {
+        self.read_up_to_i64(57)
+    }
+    /// Reads a 58-bit signed integer
+    #[inline]
+    pub fn read_i58(&mut self) -> i64 {
+        self.read_up_to_i64(58)
+    }
+    /// Reads a 59-bit signed integer
+    #[inline]
+    pub fn read_i59(&mut self) -> i64 {
+        self.read_up_to_i64(59)
+    }
+    /// Reads a 60-bit signed integer
+    #[inline]
+    pub fn read_i60(&mut self) -> i64 {
+        self.read_up_to_i64(60)
+    }
+    /// Reads a 61-bit signed integer
+    #[inline]
+    pub fn read_i61(&mut self) -> i64 {
+        self.read_up_to_i64(61)
+    }
+    /// Reads a 62-bit signed integer
+    #[inline]
+    pub fn read_i62(&mut self) -> i64 {
+        self.read_up_to_i64(62)
+    }
+    /// Reads a 63-bit signed integer
+    #[inline]
+    pub fn read_i63(&mut self) -> i64 {
+        self.read_up_to_i64(63)
+    }
+    /// Reads a 64-bit signed integer
+    #[inline]
+    pub fn read_i64(&mut self) -> i64 {
+        self.read_up_to_i64(64)
+    }
+}
+
 impl ReadCursor<'_> {
     /// Advances the cursor to skip 1 bit
     #[inline]
     pub fn skip_1(&mut self) {
-        self.advance_bits(1)
+        self.skip_padding(1)
     }
     /// Advances the cursor to skip 2 bits
     #[inline]
     pub fn skip_2(&mut self) {
-        self.advance_bits(2)
+        self.skip_padding(2)
     }
     /// Advances the cursor to skip 3 bits
     #[inline]
     pub fn skip_3(&mut self) {
-        self.advance_bits(3)
+        self.skip_padding(3)
     }
     /// Advances the cursor to skip 4 bits
     #[inline]
     pub fn skip_4(&mut self) {
-        self.advance_bits(4)
+        self.skip_padding(4)
     }
     /// Advances the cursor to skip 5 bits
     #[inline]
     pub fn skip_5(&mut self) {
-        self.advance_bits(5)
+        self.skip_padding(5)
     }
     /// Advances the cursor to skip 6 bits
     #[inline]
     pub fn skip_6(&mut self) {
-        self.advance_bits(6)
+        self.skip_padding(6)
     }
     /// Advances the cursor to skip 7 bits
     #[inline]
     pub fn skip_7(&mut self) {
-        self.advance_bits(7)
+        self.skip_padding(7)
     }
     /// Advances the cursor to skip 8 bits
     #[inline]
     pub fn skip_8(&mut self) {
-        self.advance_bits(8)
+        self.skip_padding(8)
     }
     /// Advances the cursor to skip 9 bits
     #[inline]
     pub fn skip_9(&mut self) {
-        self.advance_bits(9)
+        self.skip_padding(9)
     }
     /// Advances the cursor to skip 10 bits
     #[inline]
     pub fn skip_10(&mut self) {
-        self.advance_bits(10)
+        self.skip_padding(10)
     }
     /// Advances the cursor to skip 11 bits
     #[inline]
     pub fn skip_11(&mut self) {
-        self.advance_bits(11)
+        self.skip_padding(11)
     }
     /// Advances the cursor to skip 12 bits
     #[inline]
     pub fn skip_12(&mut self) {
-        self.advance_bits(12)
+        self.skip_padding(12)
     }
     /// Advances the cursor to skip 13 bits
     #[inline]
     pub fn skip_13(&mut self) {
-        self.advance_bits(13)
+        self.skip_padding(13)
     }
     /// Advances the cursor to skip 14 bits
     #[inline]
     pub fn skip_14(&mut self) {
-        self.advance_bits(14)
+        self.skip_padding(14)
     }
     /// Advances the cursor to skip 15 bits
     #[inline]
     pub fn skip_15(&mut self) {
-        self.advance_bits(15)
+        self.skip_padding(15)
     }
     /// Advances the cursor to skip 16 bits
     #[inline]
     pub fn skip_16(&mut self) {
-        self.advance_bits(16)
+        self.skip_padding(16)
     }
     /// Advances the cursor to skip 17 bits
     #[inline]
     pub fn skip_17(&mut self) {
-        self.advance_bits(17)
+        self.skip_padding(17)
     }
     /// Advances the cursor to skip 18 bits
     #[inline]
     pub fn skip_18(&mut self) {
-        self.advance_bits(18)
+        self.skip_padding(18)
     }
     /// Advances the cursor to skip 19 bits
     #[inline]
     pub fn skip_19(&mut self) {
-        self.advance_bits(19)
+        self.skip_padding(19)
     }
     /// Advances the cursor to skip 20 bits
     #[inline]
     pub fn skip_20(&mut self) {
-        self.advance_bits(20)
+        self.skip_padding(20)
     }
     /// Advances the cursor to skip 21 bits
     #[inline]
     pub fn skip_21(&mut self) {
-        self.advance_bits(21)
+        self.skip_padding(21)
     }
     /// Advances the cursor to skip 22 bits
     #[inline]
     pub fn skip_22(&mut self) {
-        self.advance_bits(22)
+        self.skip_padding(22)
     }
     /// Advances the cursor to skip 23 bits
     #[inline]
     pub fn skip_23(&mut self) {
-        self.advance_bits(23)
+        self.skip_padding(23)
     }
     /// Advances the cursor to skip 24 bits
     #[inline]
     pub fn skip_24(&mut self) {
-        self.advance_bits(24)
+        self.skip_padding(24)
     }
     /// Advances the cursor to skip 25 bits
     #[inline]
     pub fn skip_25(&mut self) {
-        self.advance_bits(25)
+        self.skip_padding(25)
     }
     /// Advances the cursor to skip 26 bits
     #[inline]
     pub fn skip_26(&mut self) {
-        self.advance_bits(26)
+        self.skip_padding(26)
     }
     /// Advances the cursor to skip 27 bits
     #[inline]
     pub fn skip_27(&mut self) {
-        self.advance_bits(27)
+        self.skip_padding(27)
     }
     /// Advances the cursor to skip 28 bits
     #[inline]
     pub fn skip_28(&mut self) {
-        self.advance_bits(28)
+        self.skip_padding(28)
     }
     /// Advances the cursor to skip 29 bits
     #[inline]
     pub fn skip_29(&mut self) {
-        self.advance_bits(29)
+        self.skip_padding(29)
     }
     /// Advances the cursor to skip 30 bits
     #[inline]
     pub fn skip_30(&mut self) {
-        self.advance_bits(30)
+        self.skip_padding(30)
     }
     /// Advances the cursor to skip 31 bits
     #[inline]
     pub fn skip_31(&mut self) {
-        self.advance_bits(31)
+        self.skip_padding(31)
     }
     /// Advances the cursor to skip 32 bits
     #[inline]
     pub fn skip_32(&mut self) {
-        self.advance_bits(32)
+        self.skip_padding(32)
     }
     /// Advances the cursor to skip 33 bits
     #[inline]
     pub fn skip_33(&mut self) {
-        self.advance_bits(33)
+        self.skip_padding(33)
     }
     /// Advances the cursor to skip 34 bits
     #[inline]
     pub fn skip_34(&mut self) {
-        self.advance_bits(34)
+        self.skip_padding(34)
     }
     /// Advances the cursor to skip 35 bits
     #[inline]
     pub fn skip_35(&mut self) {
-        self.advance_bits(35)
+        self.skip_padding(35)
     }
     /// Advances the cursor to skip 36 bits
     #[inline]
     pub fn skip_36(&mut self) {
-        self.advance_bits(36)
+        self.skip_padding(36)
     }
     /// Advances the cursor to skip 37 bits
     #[inline]
     pub fn skip_37(&mut self) {
-        self.advance_bits(37)
+        self.skip_padding(37)
     }
     /// Advances the cursor to skip 38 bits
     #[inline]
     pub fn skip_38(&mut self) {
-        self.advance_bits(38)
+        self.skip_padding(38)
     }
     /// Advances the cursor to skip 39 bits
     #[inline]
     pub fn skip_39(&mut self) {
-        self.advance_bits(39)
+        self.skip_padding(39)
     }
     /// Advances the cursor to skip 40 bits
     #[inline]
     pub fn skip_40(&mut self) {
-        self.advance_bits(40)
+        self.skip_padding(40)
     }
     /// Advances the cursor to skip 41 bits
     #[inline]
     pub fn skip_41(&mut self) {
-        self.advance_bits(41)
+        self.skip_padding(41)
     }
     /// Advances the cursor to skip 42 bits
     #[inline]
     pub fn skip_42(&mut self) {
-        self.advance_bits(42)
+        self.skip_padding(42)
     }
     /// Advances the cursor to skip 43 bits
     #[inline]
     pub fn skip_43(&mut self) {
-        self.advance_bits(43)
+        self.skip_padding(43)
     }
     /// Advances the cursor to skip 44 bits
     #[inline]
     pub fn skip_44(&mut self) {
-        self.advance_bits(44)
+        self.skip_padding(44)
     }
     /// Advances the cursor to skip 45 bits
     #[inline]
     pub fn skip_45(&mut self) {
-        self.advance_bits(45)
+        self.skip_padding(45)
     }
     /// Advances the cursor to skip 46 bits
     #[inline]
     pub fn skip_46(&mut self) {
-        self.advance_bits(46)
+        self.skip_padding(46)
     }
     /// Advances the cursor to skip 47 bits
     #[inline]
     pub fn skip_47(&mut self) {
-        self.advance_bits(47)
+        self.skip_padding(47)
     }
     /// Advances the cursor to skip 48 bits
     #[inline]
     pub fn skip_48(&mut self) {
-        self.advance_bits(48)
+        self.skip_padding(48)
     }
     /// Advances the cursor to skip 49 bits
     #[inline]
     pub fn skip_49(&mut self) {
-        self.advance_bits(49)
+        self.skip_padding(49)
     }
     /// Advances the cursor to skip 50 bits
     #[inline]
     pub fn skip_50(&mut self) {
-        self.advance_bits(50)
+        self.skip_padding(50)
     }
     /// Advances the cursor to skip 51 bits
     #[inline]
     pub fn skip_51(&mut self) {
-        self.advance_bits(51)
+        self.skip_padding(51)
     }
     /// Advances the cursor to skip 52 bits
     #[inline]
     pub fn skip_52(&mut self) {
-        self.advance_bits(52)
+        self.skip_padding(52)
     }
     /// Advances the cursor to skip 53 bits
     #[inline]
     pub fn skip_53(&mut self) {
-        self.advance_bits(53)
+        self.skip_padding(53)
     }
     /// Advances the cursor to skip 54 bits
     #[inline]
     pub fn skip_54(&mut self) {
-        self.advance_bits(54)
+        self.skip_padding(54)
     }
     /// Advances the cursor to skip 55 bits
     #[inline]
     pub fn skip_55(&mut self) {
-        self.advance_bits(55)
+        self.skip_padding(55)
     }
     /// Advances the cursor to skip 56 bits
     #[inline]
     pub fn skip_56(&mut self) {
-        self.advance_bits(56)
+        self.skip_padding(56)
     }
     /// Advances the cursor to skip 57 bits
     #[inline]
     pub fn skip_57(&mut self) {
-        self.advance_bits(57)
+        self.skip_padding(57)
     }
     /// Advances the cursor to skip 58 bits
     #[inline]
     pub fn skip_58(&mut self) {
-        self.advance_bits(58)
+        self.skip_padding(58)
     }
     /// Advances the cursor to skip 59 bits
     #[inline]
     pub fn skip_59(&mut self) {
-        self.advance_bits(59)
+        self.skip_padding(59)
     }
     /// Advances the cursor to skip 60 bits
     #[inline]
     pub fn skip_60(&mut self) {
-        self.advance_bits(60)
+        self.skip_padding(60)
     }
     /// Advances the cursor to skip 61 bits
     #[inline]
     pub fn skip_61(&mut self) {
-        self.advance_bits(61)
+        self.skip_padding(61)
     }
     /// Advances the cursor to skip 62 bits
     #[inline]
     pub fn skip_62(&mut self) {
-        self.advance_bits(62)
+        self.skip_padding(62)
     }
     /// Advances the cursor to skip 63 bits
     #[inline]
     pub fn skip_63(&mut self) {
-        self.advance_bits(63)
+        self.skip_padding(63)
     }
     /// Advances the cursor to skip 64 bits
     #[inline]
     pub fn skip_64(&mut self) {
-        self.advance_bits(64)
+        self.skip_padding(64)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::WriteCursor;
 
     #[test]
     fn u8_one() {
@@ -1022,6 +1579,27 @@ mod test {
         assert_eq!(cursor.read_f16(), f16::from_bits(0xABCD));
     }
 
+    #[test]
+    fn f16_as_f32() {
+        let cases: [(u16, f32); 6] = [
+            (0x3c00, 1.0),
+            (0xc100, -2.5),
+            (0x7bff, 65504.0),
+            // Smallest subnormal
+            (0x0001, 5.960_464_5e-8),
+            (0x7c00, f32::INFINITY),
+            (0xfc00, f32::NEG_INFINITY),
+        ];
+        for (bits, expected) in cases {
+            let bytes = bits.to_le_bytes();
+            let mut cursor = ReadCursor::new(&bytes);
+            assert_eq!(cursor.read_f16_as_f32(), expected);
+        }
+        let bytes = 0x7e00_u16.to_le_bytes();
+        let mut cursor = ReadCursor::new(&bytes);
+        assert!(cursor.read_aligned_f16_as_f32().is_nan());
+    }
+
     #[test]
     fn f32_one() {
         let bytes = [0xD4u8, 0xC3, 0xB2, 0xA1];
@@ -1035,4 +1613,276 @@ mod test {
         let mut cursor = ReadCursor::new(&bytes);
         assert_eq!(cursor.read_f64(), f64::from_bits(0xA1B2C3D401234567));
     }
+
+    #[test]
+    fn aligned_bytes_zero_extend() {
+        let bytes = [0x01u8, 0x02, 0x03];
+        let mut cursor = ReadCursor::new(&bytes);
+        assert_eq!(cursor.read_aligned_u8(), 0x01);
+        let mut out = [0xffu8; 4];
+        cursor.read_aligned_bytes(&mut out);
+        assert_eq!(out, [0x02, 0x03, 0x00, 0x00]);
+        assert_eq!(cursor.read_u8(), 0);
+    }
+
+    #[test]
+    fn unaligned_bytes() {
+        let bytes = [0b0011_0101u8, 0b1100_0001];
+        let mut cursor = ReadCursor::new(&bytes);
+        assert_eq!(cursor.read_u4(), 0b0101);
+        let mut out = [0u8; 1];
+        cursor.read_bytes(&mut out);
+        assert_eq!(out, [0b0001_0011]);
+        assert_eq!(cursor.read_u4(), 0b1100);
+    }
+
+    #[test]
+    fn remaining_bits() {
+        let bytes = [0u8; 3];
+        let mut cursor = ReadCursor::new(&bytes);
+        assert_eq!(cursor.remaining_bits(), 24);
+        cursor.read_u5();
+        assert_eq!(cursor.remaining_bits(), 19);
+        cursor.read_u16();
+        assert_eq!(cursor.remaining_bits(), 3);
+        assert!(!cursor.is_empty());
+        cursor.read_u3();
+        assert_eq!(cursor.remaining_bits(), 0);
+        assert!(cursor.is_empty());
+        // Reading past the end
+        cursor.read_u7();
+        assert_eq!(cursor.remaining_bits(), 0);
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn bit_offset() {
+        let bytes = [0u8; 2];
+        let mut cursor = ReadCursor::new(&bytes);
+        assert_eq!(cursor.bit_offset(), 0);
+        cursor.read_u3();
+        assert_eq!(cursor.bit_offset(), 3);
+        cursor.read_u8();
+        assert_eq!(cursor.bit_offset(), 11);
+        // Reading past the end
+        cursor.read_u16();
+        assert_eq!(cursor.bit_offset(), 27);
+    }
+
+    #[test]
+    fn nested_error_offset() {
+        /// A delimited type that always fails to deserialize after reading one byte
+        struct Invalid;
+        impl crate::DataType for Invalid {
+            const EXTENT_BYTES: Option<u32> = Some(8);
+        }
+        impl Deserialize for Invalid {
+            fn deserialize(cursor: &mut ReadCursor<'_>) -> Result<Self, DeserializeError> {
+                cursor.read_aligned_u8();
+                Err(DeserializeError::ArrayLength {
+                    offset_bits: cursor.bit_offset(),
+                })
+            }
+        }
+
+        // One byte, then a delimiter header and one byte of the nested object
+        let bytes = [0xffu8, 1, 0, 0, 0, 0xff];
+        let mut cursor = ReadCursor::new(&bytes);
+        cursor.read_aligned_u8();
+        let error = cursor.read_composite::<Invalid>().err().unwrap();
+        assert_eq!(error.offset_bits(), 6 * 8);
+        // The outer cursor is after the end of the nested object
+        assert_eq!(cursor.bit_offset(), 6 * 8);
+
+        // A delimiter header with an invalid length
+        let bytes = [0xffu8, 9, 0, 0, 0, 0xff];
+        let mut cursor = ReadCursor::new(&bytes);
+        cursor.read_aligned_u8();
+        match cursor.read_composite::<Invalid>() {
+            Err(DeserializeError::DelimitedLength { offset_bits }) => {
+                assert_eq!(offset_bits, 5 * 8)
+            }
+            _ => panic!("Expected a delimited length error"),
+        }
+    }
+
+    #[test]
+    fn strict_truncation() {
+        let bytes = [0xffu8, 0x01];
+        // Reading exactly to the end is not an error
+        let mut cursor = ReadCursor::new_strict(&bytes);
+        cursor.read_u3();
+        cursor.read_u13();
+        assert!(cursor.check_truncated().is_ok());
+        // Reading past the end reads zeros, and then reports an error
+        let mut cursor = ReadCursor::new_strict(&bytes);
+        cursor.read_u3();
+        assert_eq!(cursor.read_u16(), 0x003f);
+        cursor.read_u8();
+        match cursor.check_truncated() {
+            // The first byte of the u16 was available, but the second was not
+            Err(DeserializeError::Truncated { offset_bits }) => assert_eq!(offset_bits, 11),
+            _ => panic!("Expected a truncated error"),
+        }
+        // A non-strict cursor never reports an error
+        let mut cursor = ReadCursor::new(&bytes);
+        cursor.read_u32();
+        assert!(cursor.check_truncated().is_ok());
+        // Byte reads are checked too
+        let mut cursor = ReadCursor::new_strict(&bytes);
+        let mut buffer = [0xaau8; 3];
+        cursor.read_aligned_bytes(&mut buffer);
+        assert_eq!(buffer, [0xff, 0x01, 0x00]);
+        assert!(cursor.check_truncated().is_err());
+    }
+
+    #[test]
+    fn strict_delimited_zero_extension() {
+        /// A delimited type containing two bytes
+        struct TwoBytes(u8, u8);
+        impl crate::DataType for TwoBytes {
+            const EXTENT_BYTES: Option<u32> = Some(8);
+        }
+        impl Deserialize for TwoBytes {
+            fn deserialize(cursor: &mut ReadCursor<'_>) -> Result<Self, DeserializeError> {
+                Ok(TwoBytes(cursor.read_aligned_u8(), cursor.read_aligned_u8()))
+            }
+        }
+
+        // A delimiter header and a nested object that is shorter than its type, which is valid
+        let bytes = [1u8, 0, 0, 0, 0x37];
+        let mut cursor = ReadCursor::new_strict(&bytes);
+        let value = cursor.read_composite::<TwoBytes>().unwrap();
+        assert_eq!((value.0, value.1), (0x37, 0));
+
+        // The delimiter header itself is truncated
+        let bytes = [1u8, 0];
+        let mut cursor = ReadCursor::new_strict(&bytes);
+        match cursor.read_composite::<TwoBytes>() {
+            Err(DeserializeError::Truncated { offset_bits }) => assert_eq!(offset_bits, 16),
+            _ => panic!("Expected a truncated error"),
+        }
+    }
+
+    #[test]
+    fn strict_padding() {
+        // A u3, 5 bits of padding, and a u8
+        let bytes = [0b0100_0101u8, 0x37];
+        let mut cursor = ReadCursor::new_strict(&bytes);
+        assert_eq!(cursor.read_u3(), 0b101);
+        cursor.skip_5();
+        assert_eq!(cursor.read_u8(), 0x37);
+        match cursor.check_constraints() {
+            Err(DeserializeError::ConstraintViolation { offset_bits }) => {
+                assert_eq!(offset_bits, 8)
+            }
+            _ => panic!("Expected a constraint violation error"),
+        }
+        // Padding that is zero is valid
+        let bytes = [0b0000_0101u8, 0x37];
+        let mut cursor = ReadCursor::new_strict(&bytes);
+        cursor.read_u3();
+        cursor.skip_5();
+        cursor.read_u8();
+        assert!(cursor.check_constraints().is_ok());
+        // A non-strict cursor does not check padding
+        let bytes = [0xffu8; 9];
+        let mut cursor = ReadCursor::new(&bytes);
+        cursor.skip_64();
+        assert_eq!(cursor.read_u8(), 0xff);
+        assert!(cursor.check_constraints().is_ok());
+    }
+
+    #[test]
+    fn strict_padding_delimited() {
+        /// A delimited type containing 4 bits of padding and a u4
+        struct Padded(u8);
+        impl crate::DataType for Padded {
+            const EXTENT_BYTES: Option<u32> = Some(8);
+        }
+        impl Deserialize for Padded {
+            fn deserialize(cursor: &mut ReadCursor<'_>) -> Result<Self, DeserializeError> {
+                cursor.skip_4();
+                Ok(Padded(cursor.read_u4()))
+            }
+        }
+
+        let bytes = [1u8, 0, 0, 0, 0x91];
+        let mut cursor = ReadCursor::new_strict(&bytes);
+        let value = cursor.read_composite::<Padded>().unwrap();
+        assert_eq!(value.0, 0x9);
+        match cursor.check_constraints() {
+            // The offset is counted from the beginning of the outer input
+            Err(DeserializeError::ConstraintViolation { offset_bits }) => {
+                assert_eq!(offset_bits, 36)
+            }
+            _ => panic!("Expected a constraint violation error"),
+        }
+    }
+
+    #[test]
+    fn union_tag() {
+        let bytes = [3u8, 0x2c, 0x01, 4];
+        let mut cursor = ReadCursor::new(&bytes);
+        assert_eq!(cursor.read_union_tag(4).unwrap(), 3);
+        assert_eq!(cursor.read_union_tag(301).unwrap(), 300);
+        match cursor.read_union_tag(4) {
+            Err(DeserializeError::UnionTag { offset_bits }) => assert_eq!(offset_bits, 32),
+            _ => panic!("Expected a union tag error"),
+        }
+    }
+
+    #[test]
+    fn union_tag_256_variants() {
+        // Tags 0 through 255 fit in 8 bits
+        let bytes = [0xffu8, 0x00, 0x01];
+        let mut cursor = ReadCursor::new(&bytes);
+        assert_eq!(cursor.read_union_tag(256).unwrap(), 255);
+        assert_eq!(cursor.read_union_tag(257).unwrap(), 256);
+    }
+
+    #[test]
+    fn i5_sign_extension() {
+        let bytes = [0b0001_1111u8];
+        let mut cursor = ReadCursor::new(&bytes);
+        assert_eq!(cursor.read_i5(), -1);
+        let bytes = [0b0001_0000u8];
+        let mut cursor = ReadCursor::new(&bytes);
+        assert_eq!(cursor.read_i5(), -16);
+        let bytes = [0b1110_1111u8];
+        let mut cursor = ReadCursor::new(&bytes);
+        assert_eq!(cursor.read_i5(), 15);
+    }
+
+    /// Writes and then reads the minimum, -1, 0, and maximum values of a signed integer type,
+    /// starting at a position that is not aligned to a byte boundary
+    macro_rules! check_signed_round_trip {
+        ($write:ident, $read:ident, $bits:expr, $ty:ty) => {{
+            let max: $ty = <$ty>::MAX >> (<$ty>::BITS - $bits);
+            let min: $ty = -max - 1;
+            for &value in &[min, -1, 0, max] {
+                let mut bytes = [0u8; 9];
+                let mut cursor = WriteCursor::new(&mut bytes);
+                cursor.write_u3(0b101);
+                cursor.$write(value);
+                let mut cursor = ReadCursor::new(&bytes);
+                assert_eq!(cursor.read_u3(), 0b101);
+                assert_eq!(cursor.$read(), value, "{}-bit value {}", $bits, value);
+            }
+        }};
+    }
+
+    #[test]
+    fn signed_round_trip() {
+        check_signed_round_trip!(write_i1, read_i1, 1, i8);
+        check_signed_round_trip!(write_i5, read_i5, 5, i8);
+        check_signed_round_trip!(write_i8, read_i8, 8, i8);
+        check_signed_round_trip!(write_i13, read_i13, 13, i16);
+        check_signed_round_trip!(write_i16, read_i16, 16, i16);
+        check_signed_round_trip!(write_i24, read_i24, 24, i32);
+        check_signed_round_trip!(write_i32, read_i32, 32, i32);
+        check_signed_round_trip!(write_i41, read_i41, 41, i64);
+        check_signed_round_trip!(write_i63, read_i63, 63, i64);
+        check_signed_round_trip!(write_i64, read_i64, 64, i64);
+    }
 }
@@ -1,11 +1,34 @@
 use half::f16;
 
+use crate::cursor::union_tag_bits;
 use crate::Serialize;
 use core::convert::TryInto;
 
+/// A function that receives serialized bytes from a streaming [`WriteCursor`]
+type Sink<'b> = dyn FnMut(&[u8]) + 'b;
+
 /// A cursor over a byte slice for easy serializing of Cyphal data types
 ///
 /// Functions that write values will panic if no space is available in the slice.
+///
+/// # Narrow integers
+///
+/// Functions like `write_u13` check in debug builds that the value fits in the number of bits
+/// (13 in this case). In release builds, they write only the least significant bits and ignore
+/// any other bits, so a value that is too large never changes the fields around it.
+///
+/// Code that needs the DSDL `truncated` behavior should mask the value before writing it, or use
+/// [`write_uint_truncating`](#method.write_uint_truncating).
+/// [`write_uint_saturating`](#method.write_uint_saturating) clamps the value instead. Functions
+/// like `write_i13` write the two's complement representation of the value, truncated to the
+/// number of bits.
+///
+/// # Streaming
+///
+/// A cursor created with [`with_sink`](#method.with_sink) uses the slice as a small window.
+/// When the window is full, the cursor passes the completed bytes to a sink function and
+/// starts again at the beginning of the window. This makes it possible to serialize a large value
+/// without a buffer that can hold all of it.
 pub struct WriteCursor<'b> {
     /// The bytes available to write to
     ///
@@ -21,6 +44,10 @@ pub struct WriteCursor<'b> {
     ///
     /// Invariant: This is in the range 0..=7.
     bit_index: u8,
+    /// The number of bytes that have been passed to the sink
+    bytes_flushed: usize,
+    /// A function that receives bytes when the slice is full, for a streaming cursor
+    sink: Option<&'b mut Sink<'b>>,
 }
 
 impl<'b> WriteCursor<'b> {
@@ -32,6 +59,65 @@ impl<'b> WriteCursor<'b> {
             bytes,
             bytes_written: 0,
             bit_index: 0,
+            bytes_flushed: 0,
+            sink: None,
+        }
+    }
+
+    /// Creates a streaming cursor that uses the provided slice as a window and passes each
+    /// completed part of the serialized value to `sink`
+    ///
+    /// After writing the value, call [`finish`](#method.finish) to pass the remaining bytes
+    /// to the sink.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bytes` is shorter than 9 bytes, which is the space needed for
+    /// the largest single write.
+    pub fn with_sink(bytes: &'b mut [u8], sink: &'b mut dyn FnMut(&[u8])) -> Self {
+        assert!(
+            bytes.len() >= 9,
+            "Streaming cursor buffer must be at least 9 bytes"
+        );
+        bytes.iter_mut().for_each(|b| *b = 0);
+        WriteCursor {
+            bytes,
+            bytes_written: 0,
+            bit_index: 0,
+            bytes_flushed: 0,
+            sink: Some(sink),
+        }
+    }
+
+    /// Passes all bytes that have been written, including a partially filled byte, to the sink
+    ///
+    /// This function does nothing if this cursor does not have a sink.
+    pub fn finish(mut self) {
+        if let Some(sink) = self.sink.as_mut() {
+            let length = self.bytes_written + usize::from(self.bit_index != 0);
+            if length != 0 {
+                sink(&self.bytes[..length]);
+            }
+        }
+    }
+
+    /// If this cursor has a sink and the window does not have space for the specified number
+    /// of bits, passes the completed bytes to the sink and moves the current partially filled
+    /// byte to the beginning of the window
+    fn make_space(&mut self, bits: usize) {
+        let bytes_needed = (usize::from(self.bit_index) + bits).div_ceil(8);
+        if self.bytes.len() - self.bytes_written >= bytes_needed {
+            return;
+        }
+        if let Some(sink) = self.sink.as_mut() {
+            if self.bytes_written != 0 {
+                sink(&self.bytes[..self.bytes_written]);
+            }
+            self.bytes_flushed += self.bytes_written;
+            let partial_byte = self.bytes.get(self.bytes_written).copied().unwrap_or(0);
+            self.bytes.iter_mut().for_each(|b| *b = 0);
+            self.bytes[0] = partial_byte;
+            self.bytes_written = 0;
         }
     }
 
@@ -92,6 +178,7 @@ impl<'b> WriteCursor<'b> {
         if bits == 0 {
             return;
         }
+        self.make_space(usize::from(bits));
         self.check_length(usize::from(bits));
         // Constrain value to fit with the correct number of bits
         // Use 16 bits to correctly handle the case when bits = 8
@@ -124,6 +211,7 @@ impl<'b> WriteCursor<'b> {
     /// This function panics if the cursor is not aligned to a multiple of 8 bits.
     pub fn write_aligned_u8(&mut self, value: u8) {
         assert!(self.is_aligned_to_8_bits());
+        self.make_space(8);
         self.remaining_bytes()[0] = value;
         self.advance_bits(8);
     }
@@ -135,6 +223,7 @@ impl<'b> WriteCursor<'b> {
     /// This function panics if the cursor is not aligned to a multiple of 8 bits.
     pub fn write_aligned_u16(&mut self, value: u16) {
         assert!(self.is_aligned_to_8_bits());
+        self.make_space(2 * 8);
         let space = &mut self.remaining_bytes()[..2];
         space.copy_from_slice(&value.to_le_bytes());
         self.advance_bits(2 * 8);
@@ -147,6 +236,7 @@ impl<'b> WriteCursor<'b> {
     /// This function panics if the cursor is not aligned to a multiple of 8 bits.
     pub fn write_aligned_u32(&mut self, value: u32) {
         assert!(self.is_aligned_to_8_bits());
+        self.make_space(4 * 8);
         let space = &mut self.remaining_bytes()[..4];
         space.copy_from_slice(&value.to_le_bytes());
         self.advance_bits(4 * 8);
@@ -159,6 +249,7 @@ impl<'b> WriteCursor<'b> {
     /// This function panics if the cursor is not aligned to a multiple of 8 bits.
     pub fn write_aligned_u64(&mut self, value: u64) {
         assert!(self.is_aligned_to_8_bits());
+        self.make_space(8 * 8);
         let space = &mut self.remaining_bytes()[..8];
         space.copy_from_slice(&value.to_le_bytes());
         self.advance_bits(8 * 8);
@@ -183,6 +274,7 @@ impl<'b> WriteCursor<'b> {
     }
 
     fn skip_bits(&mut self, bits: u8) {
+        self.make_space(usize::from(bits));
         self.check_length(usize::from(bits));
         self.advance_bits(usize::from(bits));
     }
@@ -200,6 +292,17 @@ impl<'b> WriteCursor<'b> {
         self.write_u16(value.to_bits());
     }
 
+    /// Converts a 32-bit floating-point value into a 16-bit floating-point value and writes it
+    ///
+    /// The value is rounded to the nearest 16-bit value (ties to even), and values too small
+    /// for a normal 16-bit value become subnormal or zero. Finite values outside the 16-bit range
+    /// saturate to the largest finite 16-bit value with the same sign, as required for
+    /// `saturated float16` fields. Infinities and NaN are preserved.
+    #[inline]
+    pub fn write_f16_from_f32(&mut self, value: f32) {
+        self.write_f16(f16_from_f32_saturating(value));
+    }
+
     /// Writes a 32-bit floating-point value
     #[inline]
     pub fn write_f32(&mut self, value: f32) {
@@ -213,18 +316,36 @@ impl<'b> WriteCursor<'b> {
     }
 
     /// Writes a byte array
+    ///
+    /// If the cursor is aligned to a multiple of 8 bits, this is equivalent to
+    /// [`write_aligned_bytes()`](#method.write_aligned_bytes).
     pub fn write_bytes(&mut self, bytes: &[u8]) {
-        for byte in bytes {
-            self.write_u8(*byte);
+        if self.is_aligned_to_8_bits() {
+            self.write_aligned_bytes(bytes);
+        } else {
+            for byte in bytes {
+                self.write_u8(*byte);
+            }
         }
     }
 
-    /// Writes a sequence of bytes
+    /// Writes a sequence of bytes, copying them all at once
     ///
     /// # Panics
     ///
     /// This function panics if the cursor is not aligned to a multiple of 8 bits.
-    pub fn write_aligned_bytes(&mut self, bytes: &[u8]) {
+    pub fn write_aligned_bytes(&mut self, mut bytes: &[u8]) {
+        assert!(self.is_aligned_to_8_bits());
+        if self.sink.is_some() {
+            // Copy as many bytes as fit into the window, then pass them to the sink
+            while bytes.len() > self.bytes.len() - self.bytes_written {
+                let (chunk, rest) = bytes.split_at(self.bytes.len() - self.bytes_written);
+                self.remaining_bytes().copy_from_slice(chunk);
+                self.advance_bits(8 * chunk.len());
+                self.make_space(8);
+                bytes = rest;
+            }
+        }
         let remaining_bytes = self.remaining_bytes();
         assert!(remaining_bytes.len() >= bytes.len());
         remaining_bytes[..bytes.len()].copy_from_slice(bytes);
@@ -232,6 +353,12 @@ impl<'b> WriteCursor<'b> {
     }
 
     /// Writes a composite value, aligned to 8 bits
+    ///
+    /// If T is delimited (its `EXTENT_BYTES` is `Some`), this function first writes a 32-bit
+    /// delimiter header with the length of the serialized value in bytes. The length is calculated
+    /// from [`Serialize::size_bits`] before writing the value, so it does not need to be filled in
+    /// afterwards. This also works with a streaming cursor that has already passed the header
+    /// to its sink.
     pub fn write_composite<T>(&mut self, value: &T)
     where
         T: Serialize,
@@ -258,9 +385,67 @@ impl<'b> WriteCursor<'b> {
         self.write_u1(value as u8)
     }
 
+    /// Writes the tag of a union that has `variant_count` variants
+    ///
+    /// The length of the tag is the smallest of 8, 16, 32, and 64 bits that can hold the largest
+    /// tag value, `variant_count - 1`, as the Cyphal specification requires.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `tag` is not less than `variant_count`.
+    pub fn write_union_tag(&mut self, tag: u32, variant_count: usize) {
+        assert!(
+            u64::from(tag) < variant_count as u64,
+            "Union tag out of range"
+        );
+        self.write_up_to_u64(u64::from(tag), union_tag_bits(variant_count));
+    }
+
+    /// Writes the `bits` least significant bits of an unsigned integer
+    ///
+    /// In debug builds, this function panics if `value` does not fit in `bits` bits. In release
+    /// builds, it ignores the other bits like
+    /// [`write_uint_truncating`](#method.write_uint_truncating).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bits` is not in the range 1..=64.
+    pub fn write_uint(&mut self, value: u64, bits: u8) {
+        debug_assert!(
+            value <= max_uint(bits),
+            "Value {} does not fit in {} bits",
+            value,
+            bits
+        );
+        self.write_uint_truncating(value, bits);
+    }
+
+    /// Writes an unsigned integer in `bits` bits, replacing it with the largest value that fits
+    /// if it is too large
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bits` is not in the range 1..=64.
+    pub fn write_uint_saturating(&mut self, value: u64, bits: u8) {
+        self.write_uint_truncating(value.min(max_uint(bits)), bits);
+    }
+
+    /// Writes the `bits` least significant bits of an unsigned integer, ignoring the other bits
+    ///
+    /// This does the same thing as the `write_uN` functions in release builds, without the debug
+    /// check.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bits` is not in the range 1..=64.
+    pub fn write_uint_truncating(&mut self, value: u64, bits: u8) {
+        assert!((1..=64).contains(&bits), "Invalid integer length {}", bits);
+        self.write_up_to_u64(value, bits);
+    }
+
     /// Returns the number of bits that have been written to this cursor
     pub fn bits_written(&self) -> usize {
-        self.bytes_written * 8 + usize::from(self.bit_index)
+        (self.bytes_flushed + self.bytes_written) * 8 + usize::from(self.bit_index)
     }
 }
 
@@ -269,36 +454,43 @@ impl WriteCursor<'_> {
     /// Writes a 1-bit unsigned integer
     #[inline]
     pub fn write_u1(&mut self, value: u8) {
+        debug_assert!(value < 1 << 1);
         self.write_up_to_u8(value, 1)
     }
     /// Writes a 2-bit unsigned integer
     #[inline]
     pub fn write_u2(&mut self, value: u8) {
+        debug_assert!(value < 1 << 2);
         self.write_up_to_u8(value, 2)
     }
     /// Writes a 3-bit unsigned integer
     #[inline]
     pub fn write_u3(&mut self, value: u8) {
+        debug_assert!(value < 1 << 3);
         self.write_up_to_u8(value, 3)
     }
     /// Writes a 4-bit unsigned integer
     #[inline]
     pub fn write_u4(&mut self, value: u8) {
+        debug_assert!(value < 1 << 4);
         self.write_up_to_u8(value, 4)
     }
     /// Writes a 5-bit unsigned integer
     #[inline]
     pub fn write_u5(&mut self, value: u8) {
+        debug_assert!(value < 1 << 5);
         self.write_up_to_u8(value, 5)
     }
     /// Writes a 6-bit unsigned integer
     #[inline]
     pub fn write_u6(&mut self, value: u8) {
+        debug_assert!(value < 1 << 6);
         self.write_up_to_u8(value, 6)
     }
     /// Writes a 7-bit unsigned integer
     #[inline]
     pub fn write_u7(&mut self, value: u8) {
+        debug_assert!(value < 1 << 7);
         self.write_up_to_u8(value, 7)
     }
     /// Writes a 8-bit unsigned integer
@@ -309,36 +501,43 @@ impl WriteCursor<'_> {
     /// Writes a 9-bit unsigned integer
     #[inline]
     pub fn write_u9(&mut self, value: u16) {
+        debug_assert!(value < 1 << 9);
         self.write_up_to_u16(value, 9)
     }
     /// Writes a 10-bit unsigned integer
     #[inline]
     pub fn write_u10(&mut self, value: u16) {
+        debug_assert!(value < 1 << 10);
         self.write_up_to_u16(value, 10)
     }
     /// Writes a 11-bit unsigned integer
     #[inline]
     pub fn write_u11(&mut self, value: u16) {
+        debug_assert!(value < 1 << 11);
         self.write_up_to_u16(value, 11)
     }
     /// Writes a 12-bit unsigned integer
     #[inline]
     pub fn write_u12(&mut self, value: u16) {
+        debug_assert!(value < 1 << 12);
         self.write_up_to_u16(value, 12)
     }
     /// Writes a 13-bit unsigned integer
     #[inline]
     pub fn write_u13(&mut self, value: u16) {
+        debug_assert!(value < 1 << 13);
         self.write_up_to_u16(value, 13)
     }
     /// Writes a 14-bit unsigned integer
     #[inline]
     pub fn write_u14(&mut self, value: u16) {
+        debug_assert!(value < 1 << 14);
         self.write_up_to_u16(value, 14)
     }
     /// Writes a 15-bit unsigned integer
     #[inline]
     pub fn write_u15(&mut self, value: u16) {
+        debug_assert!(value < 1 << 15);
         self.write_up_to_u16(value, 15)
     }
     /// Writes a 16-bit unsigned integer
@@ -349,76 +548,91 @@ impl WriteCursor<'_> {
     /// Writes a 17-bit unsigned integer
     #[inline]
     pub fn write_u17(&mut self, value: u32) {
+        debug_assert!(value < 1 << 17);
         self.write_up_to_u32(value, 17)
     }
     /// Writes a 18-bit unsigned integer
     #[inline]
     pub fn write_u18(&mut self, value: u32) {
+        debug_assert!(value < 1 << 18);
         self.write_up_to_u32(value, 18)
     }
     /// Writes a 19-bit unsigned integer
     #[inline]
     pub fn write_u19(&mut self, value: u32) {
+        debug_assert!(value < 1 << 19);
         self.write_up_to_u32(value, 19)
     }
     /// Writes a 20-bit unsigned integer
     #[inline]
     pub fn write_u20(&mut self, value: u32) {
+        debug_assert!(value < 1 << 20);
         self.write_up_to_u32(value, 20)
     }
     /// Writes a 21-bit unsigned integer
     #[inline]
     pub fn write_u21(&mut self, value: u32) {
+        debug_assert!(value < 1 << 21);
         self.write_up_to_u32(value, 21)
     }
     /// Writes a 22-bit unsigned integer
     #[inline]
     pub fn write_u22(&mut self, value: u32) {
+        debug_assert!(value < 1 << 22);
         self.write_up_to_u32(value, 22)
     }
     /// Writes a 23-bit unsigned integer
     #[inline]
     pub fn write_u23(&mut self, value: u32) {
+        debug_assert!(value < 1 << 23);
         self.write_up_to_u32(value, 23)
     }
     /// Writes a 24-bit unsigned integer
     #[inline]
     pub fn write_u24(&mut self, value: u32) {
+        debug_assert!(value < 1 << 24);
         self.write_up_to_u32(value, 24)
     }
     /// Writes a 25-bit unsigned integer
     #[inline]
     pub fn write_u25(&mut self, value: u32) {
+        debug_assert!(value < 1 << 25);
         self.write_up_to_u32(value, 25)
     }
     /// Writes a 26-bit unsigned integer
     #[inline]
     pub fn write_u26(&mut self, value: u32) {
+        debug_assert!(value < 1 << 26);
         self.write_up_to_u32(value, 26)
     }
     /// Writes a 27-bit unsigned integer
     #[inline]
     pub fn write_u27(&mut self, value: u32) {
+        debug_assert!(value < 1 << 27);
         self.write_up_to_u32(value, 27)
     }
     /// Writes a 28-bit unsigned integer
     #[inline]
     pub fn write_u28(&mut self, value: u32) {
+        debug_assert!(value < 1 << 28);
         self.write_up_to_u32(value, 28)
     }
     /// Writes a 29-bit unsigned integer
     #[inline]
     pub fn write_u29(&mut self, value: u32) {
+        debug_assert!(value < 1 << 29);
         self.write_up_to_u32(value, 29)
     }
     /// Writes a 30-bit unsigned integer
     #[inline]
     pub fn write_u30(&mut self, value: u32) {
+        debug_assert!(value < 1 << 30);
         self.write_up_to_u32(value, 30)
     }
     /// Writes a 31-bit unsigned integer
     #[inline]
     pub fn write_u31(&mut self, value: u32) {
+        debug_assert!(value < 1 << 31);
         self.write_up_to_u32(value, 31)
     }
     /// Writes a 32-bit unsigned integer
@@ -429,156 +643,187 @@ impl WriteCursor<'_> {
     /// Writes a 33-bit unsigned integer
     #[inline]
     pub fn write_u33(&mut self, value: u64) {
+        debug_assert!(value < 1 << 33);
         self.write_up_to_u64(value, 33)
     }
     /// Writes a 34-bit unsigned integer
     #[inline]
     pub fn write_u34(&mut self, value: u64) {
+        debug_assert!(value < 1 << 34);
         self.write_up_to_u64(value, 34)
     }
     /// Writes a 35-bit unsigned integer
     #[inline]
     pub fn write_u35(&mut self, value: u64) {
+        debug_assert!(value < 1 << 35);
         self.write_up_to_u64(value, 35)
     }
     /// Writes a 36-bit unsigned integer
     #[inline]
     pub fn write_u36(&mut self, value: u64) {
+        debug_assert!(value < 1 << 36);
         self.write_up_to_u64(value, 36)
     }
     /// Writes a 37-bit unsigned integer
     #[inline]
     pub fn write_u37(&mut self, value: u64) {
+        debug_assert!(value < 1 << 37);
         self.write_up_to_u64(value, 37)
     }
     /// Writes a 38-bit unsigned integer
     #[inline]
     pub fn write_u38(&mut self, value: u64) {
+        debug_assert!(value < 1 << 38);
         self.write_up_to_u64(value, 38)
     }
     /// Writes a 39-bit unsigned integer
     #[inline]
     pub fn write_u39(&mut self, value: u64) {
+        debug_assert!(value < 1 << 39);
         self.write_up_to_u64(value, 39)
     }
     /// Writes a 40-bit unsigned integer
     #[inline]
     pub fn write_u40(&mut self, value: u64) {
+        debug_assert!(value < 1 << 40);
         self.write_up_to_u64(value, 40)
     }
     /// Writes a 41-bit unsigned integer
     #[inline]
     pub fn write_u41(&mut self, value: u64) {
+        debug_assert!(value < 1 << 41);
         self.write_up_to_u64(value, 41)
     }
     /// Writes a 42-bit unsigned integer
     #[inline]
     pub fn write_u42(&mut self, value: u64) {
+        debug_assert!(value < 1 << 42);
         self.write_up_to_u64(value, 42)
     }
     /// Writes a 43-bit unsigned integer
     #[inline]
     pub fn write_u43(&mut self, value: u64) {
+        debug_assert!(value < 1 << 43);
         self.write_up_to_u64(value, 43)
     }
     /// Writes a 44-bit unsigned integer
     #[inline]
     pub fn write_u44(&mut self, value: u64) {
+        debug_assert!(value < 1 << 44);
         self.write_up_to_u64(value, 44)
     }
     /// Writes a 45-bit unsigned integer
     #[inline]
     pub fn write_u45(&mut self, value: u64) {
+        debug_assert!(value < 1 << 45);
         self.write_up_to_u64(value, 45)
     }
     /// Writes a 46-bit unsigned integer
     #[inline]
     pub fn write_u46(&mut self, value: u64) {
+        debug_assert!(value < 1 << 46);
         self.write_up_to_u64(value, 46)
     }
     /// Writes a 47-bit unsigned integer
     #[inline]
     pub fn write_u47(&mut self, value: u64) {
+        debug_assert!(value < 1 << 47);
         self.write_up_to_u64(value, 47)
     }
     /// Writes a 48-bit unsigned integer
     #[inline]
     pub fn write_u48(&mut self, value: u64) {
+        debug_assert!(value < 1 << 48);
         self.write_up_to_u64(value, 48)
     }
     /// Writes a 49-bit unsigned integer
     #[inline]
     pub fn write_u49(&mut self, value: u64) {
+        debug_assert!(value < 1 << 49);
         self.write_up_to_u64(value, 49)
     }
     /// Writes a 50-bit unsigned integer
     #[inline]
     pub fn write_u50(&mut self, value: u64) {
+        debug_assert!(value < 1 << 50);
         self.write_up_to_u64(value, 50)
     }
     /// Writes a 51-bit unsigned integer
     #[inline]
     pub fn write_u51(&mut self, value: u64) {
+        debug_assert!(value < 1 << 51);
         self.write_up_to_u64(value, 51)
     }
     /// Writes a 52-bit unsigned integer
     #[inline]
     pub fn write_u52(&mut self, value: u64) {
+        debug_assert!(value < 1 << 52);
         self.write_up_to_u64(value, 52)
     }
     /// Writes a 53-bit unsigned integer
     #[inline]
     pub fn write_u53(&mut self, value: u64) {
+        debug_assert!(value < 1 << 53);
         self.write_up_to_u64(value, 53)
     }
     /// Writes a 54-bit unsigned integer
     #[inline]
     pub fn write_u54(&mut self, value: u64) {
+        debug_assert!(value < 1 << 54);
         self.write_up_to_u64(value, 54)
     }
     /// Writes a 55-bit unsigned integer
     #[inline]
     pub fn write_u55(&mut self, value: u64) {
+        debug_assert!(value < 1 << 55);
         self.write_up_to_u64(value, 55)
     }
     /// Writes a 56-bit unsigned integer
     #[inline]
     pub fn write_u56(&mut self, value: u64) {
+        debug_assert!(value < 1 << 56);
         self.write_up_to_u64(value, 56)
     }
     /// Writes a 57-bit unsigned integer
     #[inline]
     pub fn write_u57(&mut self, value: u64) {
+        debug_assert!(value < 1 << 57);
         self.write_up_to_u64(value, 57)
     }
     /// Writes a 58-bit unsigned integer
     #[inline]
     pub fn write_u58(&mut self, value: u64) {
+        debug_assert!(value < 1 << 58);
         self.write_up_to_u64(value, 58)
     }
     /// Writes a 59-bit unsigned integer
     #[inline]
     pub fn write_u59(&mut self, value: u64) {
+        debug_assert!(value < 1 << 59);
         self.write_up_to_u64(value, 59)
     }
     /// Writes a 60-bit unsigned integer
     #[inline]
     pub fn write_u60(&mut self, value: u64) {
+        debug_assert!(value < 1 << 60);
         self.write_up_to_u64(value, 60)
     }
     /// Writes a 61-bit unsigned integer
     #[inline]
     pub fn write_u61(&mut self, value: u64) {
+        debug_assert!(value < 1 << 61);
         self.write_up_to_u64(value, 61)
     }
     /// Writes a 62-bit unsigned integer
     #[inline]
     pub fn write_u62(&mut self, value: u64) {
+        debug_assert!(value < 1 << 62);
         self.write_up_to_u64(value, 62)
     }
     /// Writes a 63-bit unsigned integer
     #[inline]
     pub fn write_u63(&mut self, value: u64) {
+        debug_assert!(value < 1 << 63);
         self.write_up_to_u64(value, 63)
     }
     /// Writes a 64-bit unsigned integer
@@ -587,6 +832,329 @@ impl WriteCursor<'_> {
         self.write_up_to_u64(value, 64)
     }
 }
+
+impl WriteCursor<'_> {
+    /// Writes a 1-bit signed integer
+    #[inline]
+    pub fn write_i1(&mut self, value: i8) {
+        self.write_u1((value as u8) & 0x1)
+    }
+    /// Writes a 2-bit signed integer
+    #[inline]
+    pub fn write_i2(&mut self, value: i8) {
+        self.write_u2((value as u8) & 0x3)
+    }
+    /// Writes a 3-bit signed integer
+    #[inline]
+    pub fn write_i3(&mut self, value: i8) {
+        self.write_u3((value as u8) & 0x7)
+    }
+    /// Writes a 4-bit signed integer
+    #[inline]
+    pub fn write_i4(&mut self, value: i8) {
+        self.write_u4((value as u8) & 0xf)
+    }
+    /// Writes a 5-bit signed integer
+    #[inline]
+    pub fn write_i5(&mut self, value: i8) {
+        self.write_u5((value as u8) & 0x1f)
+    }
+    /// Writes a 6-bit signed integer
+    #[inline]
+    pub fn write_i6(&mut self, value: i8) {
+        self.write_u6((value as u8) & 0x3f)
+    }
+    /// Writes a 7-bit signed integer
+    #[inline]
+    pub fn write_i7(&mut self, value: i8) {
+        self.write_u7((value as u8) & 0x7f)
+    }
+    /// Writes a 8-bit signed integer
+    #[inline]
+    pub fn write_i8(&mut self, value: i8) {
+        self.write_up_to_u8(value as u8, 8)
+    }
+    /// Writes a 9-bit signed integer
+    #[inline]
+    pub fn write_i9(&mut self, value: i16) {
+        self.write_u9((value as u16) & 0x1ff)
+    }
+    /// Writes a 10-bit signed integer
+    #[inline]
+    pub fn write_i10(&mut self, value: i16) {
+        self.write_u10((value as u16) & 0x3ff)
+    }
+    /// Writes a 11-bit signed integer
+    #[inline]
+    pub fn write_i11(&mut self, value: i16) {
+        self.write_u11((value as u16) & 0x7ff)
+    }
+    /// Writes a 12-bit signed integer
+    #[inline]
+    pub fn write_i12(&mut self, value: i16) {
+        self.write_u12((value as u16) & 0xfff)
+    }
+    /// Writes a 13-bit signed integer
+    #[inline]
+    pub fn write_i13(&mut self, value: i16) {
+        self.write_u13((value as u16) & 0x1fff)
+    }
+    /// Writes a 14-bit signed integer
+    #[inline]
+    pub fn write_i14(&mut self, value: i16) {
+        self.write_u14((value as u16) & 0x3fff)
+    }
+    /// Writes a 15-bit signed integer
+    #[inline]
+    pub fn write_i15(&mut self, value: i16) {
+        self.write_u15((value as u16) & 0x7fff)
+    }
+    /// Writes a 16-bit signed integer
+    #[inline]
+    pub fn write_i16(&mut self, value: i16) {
+        self.write_up_to_u16(value as u16, 16)
+    }
+    /// Writes a 17-bit signed integer
+    #[inline]
+    pub fn write_i17(&mut self, value: i32) {
+        self.write_u17((value as u32) & 0x1ffff)
+    }
+    /// Writes a 18-bit signed integer
+    #[inline]
+    pub fn write_i18(&mut self, value: i32) {
+        self.write_u18((value as u32) & 0x3ffff)
+    }
+    /// Writes a 19-bit signed integer
+    #[inline]
+    pub fn write_i19(&mut self, value: i32) {
+        self.write_u19((value as u32) & 0x7ffff)
+    }
+    /// Writes a 20-bit signed integer
+    #[inline]
+    pub fn write_i20(&mut self, value: i32) {
+        self.write_u20((value as u32) & 0xfffff)
+    }
+    /// Writes a 21-bit signed integer
+    #[inline]
+    pub fn write_i21(&mut self, value: i32) {
+        self.write_u21((value as u32) & 0x1fffff)
+    }
+    /// Writes a 22-bit signed integer
+    #[inline]
+    pub fn write_i22(&mut self, value: i32) {
+        self.write_u22((value as u32) & 0x3fffff)
+    }
+    /// Writes a 23-bit signed integer
+    #[inline]
+    pub fn write_i23(&mut self, value: i32) {
+        self.write_u23((value as u32) & 0x7fffff)
+    }
+    /// Writes a 24-bit signed integer
+    #[inline]
+    pub fn write_i24(&mut self, value: i32) {
+        self.write_u24((value as u32) & 0xffffff)
+    }
+    /// Writes a 25-bit signed integer
+    #[inline]
+    pub fn write_i25(&mut self, value: i32) {
+        self.write_u25((value as u32) & 0x1ffffff)
+    }
+    /// Writes a 26-bit signed integer
+    #[inline]
+    pub fn write_i26(&mut self, value: i32) {
+        self.write_u26((value as u32) & 0x3ffffff)
+    }
+    /// Writes a 27-bit signed integer
+    #[inline]
+    pub fn write_i27(&mut self, value: i32) {
+        self.write_u27((value as u32) & 0x7ffffff)
+    }
+    /// Writes a 28-bit signed integer
+    #[inline]
+    pub fn write_i28(&mut self, value: i32) {
+        self.write_u28((value as u32) & 0xfffffff)
+    }
+    /// Writes a 29-bit signed integer
+    #[inline]
+    pub fn write_i29(&mut self, value: i32) {
+        self.write_u29((value as u32) & 0x1fffffff)
+    }
+    /// Writes a 30-bit signed integer
+    #[inline]
+    pub fn write_i30(&mut self, value: i32) {
+        self.write_u30((value as u32) & 0x3fffffff)
+    }
+    /// Writes a 31-bit signed integer
+    #[inline]
+    pub fn write_i31(&mut self, value: i32) {
+        self.write_u31((value as u32) & 0x7fffffff)
+    }
+    /// Writes a 32-bit signed integer
+    #[inline]
+    pub fn write_i32(&mut self, value: i32) {
+        self.write_up_to_u32(value as u32, 32)
+    }
+    /// Writes a 33-bit signed integer
+    #[inline]
+    pub fn write_i33(&mut self, value: i64) {
+        self.write_u33((value as u64) & 0x1ffffffff)
+    }
+    /// Writes a 34-bit signed integer
+    #[inline]
+    pub fn write_i34(&mut self, value: i64) {
+        self.write_u34((value as u64) & 0x3ffffffff)
+    }
+    /// Writes a 35-bit signed integer
+    #[inline]
+    pub fn write_i35(&mut self, value: i64) {
+        self.write_u35((value as u64) & 0x7ffffffff)
+    }
+    /// Writes a 36-bit signed integer
+    #[inline]
+    pub fn write_i36(&mut self, value: i64) {
+        self.write_u36((value as u64) & 0xfffffffff)
+    }
+    /// Writes a 37-bit signed integer
+    #[inline]
+    pub fn write_i37(&mut self, value: i64) {
+        self.write_u37((value as u64) & 0x1fffffffff)
+    }
+    /// Writes a 38-bit signed integer
+    #[inline]
+    pub fn write_i38(&mut self, value: i64) {
+        self.write_u38((value as u64) & 0x3fffffffff)
+    }
+    /// Writes a 39-bit signed integer
+    #[inline]
+    pub fn write_i39(&mut self, value: i64) {
+        self.write_u39((value as u64) & 0x7fffffffff)
+    }
+    /// Writes a 40-bit signed integer
+    #[inline]
+    pub fn write_i40(&mut self, value: i64) {
+        self.write_u40((value as u64) & 0xffffffffff)
+    }
+    /// Writes a 41-bit signed integer
+    #[inline]
+    pub fn write_i41(&mut self, value: i64) {
+        self.write_u41((value as u64) & 0x1ffffffffff)
+    }
+    /// Writes a 42-bit signed integer
+    #[inline]
+    pub fn write_i42(&mut self, value: i64) {
+        self.write_u42((value as u64) & 0x3ffffffffff)
+    }
+    /// Writes a 43-bit signed integer
+    #[inline]
+    pub fn write_i43(&mut self, value: i64) {
+        self.write_u43((value as u64) & 0x7ffffffffff)
+    }
+    /// Writes a 44-bit signed integer
+    #[inline]
+    pub fn write_i44(&mut self, value: i64) {
+        self.write_u44((value as u64) & 0xfffffffffff)
+    }
+    /// Writes a 45-bit signed integer
+    #[inline]
+    pub fn write_i45(&mut self, value: i64) {
+        self.write_u45((value as u64) & 0x1fffffffffff)
+    }
+    /// Writes a 46-bit signed integer
+    #[inline]
+    pub fn write_i46(&mut self, value: i64) {
+        self.write_u46((value as u64) & 0x3fffffffffff)
+    }
+    /// Writes a 47-bit signed integer
+    #[inline]
+    pub fn write_i47(&mut self, value: i64) {
+        self.write_u47((value as u64) & 0x7fffffffffff)
+    }
+    /// Writes a 48-bit signed integer
+    #[inline]
+    pub fn write_i48(&mut self, value: i64) {
+        self.write_u48((value as u64) & 0xffffffffffff)
+    }
+    /// Writes a 49-bit signed integer
+    #[inline]
+    pub fn write_i49(&mut self, value: i64) {
+        self.write_u49((value as u64) & 0x1ffffffffffff)
+    }
+    /// Writes a 50-bit signed integer
+    #[inline]
+    pub fn write_i50(&mut self, value: i64) {
+        self.write_u50((value as u64) & 0x3ffffffffffff)
+    }
+    /// Writes a 51-bit signed integer
+    #[inline]
+    pub fn write_i51(&mut self, value: i64) {
+        self.write_u51((value as u64) & 0x7ffffffffffff)
+    }
+    /// Writes a 52-bit signed integer
+    #[inline]
+    pub fn write_i52(&mut self, value: i64) {
+        self.write_u52((value as u64) & 0xfffffffffffff)
+    }
+    /// Writes a 53-bit signed integer
+    #[inline]
+    pub fn write_i53(&mut self, value: i64) {
+        self.write_u53((value as u64) & 0x1fffffffffffff)
+    }
+    /// Writes a 54-bit signed integer
+    #[inline]
+    pub fn write_i54(&mut self, value: i64) {
+        self.write_u54((value as u64) & 0x3fffffffffffff)
+    }
+    /// Writes a 55-bit signed integer
+    #[inline]
+    pub fn write_i55(&mut self, value: i64) {
+        self.write_u55((value as u64) & 0x7fffffffffffff)
+    }
+    /// Writes a 56-bit signed integer
+    #[inline]
+    pub fn write_i56(&mut self, value: i64) {
+        self.write_u56((value as u64) & 0xffffffffffffff)
+    }
+    /// Writes a 57-bit signed integer
+    #[inline]
+    pub fn write_i57(&mut self, value: i64) {
+        self.write_u57((value as u64) & 0x1ffffffffffffff)
+    }
+    /// Writes a 58-bit signed integer
+    #[inline]
+    pub fn write_i58(&mut self, value: i64) {
+        self.write_u58((value as u64) & 0x3ffffffffffffff)
+    }
+    /// Writes a 59-bit signed integer
+    #[inline]
+    pub fn write_i59(&mut self, value: i64) {
+        self.write_u59((value as u64) & 0x7ffffffffffffff)
+    }
+    /// Writes a 60-bit signed integer
+    #[inline]
+    pub fn write_i60(&mut self, value: i64) {
+        self.write_u60((value as u64) & 0xfffffffffffffff)
+    }
+    /// Writes a 61-bit signed integer
+    #[inline]
+    pub fn write_i61(&mut self, value: i64) {
+        self.write_u61((value as u64) & 0x1fffffffffffffff)
+    }
+    /// Writes a 62-bit signed integer
+    #[inline]
+    pub fn write_i62(&mut self, value: i64) {
+        self.write_u62((value as u64) & 0x3fffffffffffffff)
+    }
+    /// Writes a 63-bit signed integer
+    #[inline]
+    pub fn write_i63(&mut self, value: i64) {
+        self.write_u63((value as u64) & 0x7fffffffffffffff)
+    }
+    /// Writes a 64-bit signed integer
+    #[inline]
+    pub fn write_i64(&mut self, value: i64) {
+        self.write_up_to_u64(value as u64, 64)
+    }
+}
 impl WriteCursor<'_> {
     /// Advances the cursor to skip 1 bit
     #[inline]
@@ -910,10 +1478,150 @@ impl WriteCursor<'_> {
     }
 }
 
+/// Returns the largest unsigned integer that fits in `bits` bits (which must be in the
+/// range 1..=64)
+fn max_uint(bits: u8) -> u64 {
+    u64::MAX >> (64 - u32::from(bits))
+}
+
+/// Converts a 32-bit floating-point value into a 16-bit floating-point value, saturating finite
+/// values that are out of range
+fn f16_from_f32_saturating(value: f32) -> f16 {
+    let converted = f16::from_f32(value);
+    if converted.is_infinite() && value.is_finite() {
+        if value.is_sign_negative() {
+            f16::MIN
+        } else {
+            f16::MAX
+        }
+    } else {
+        converted
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    /// Writes values of various lengths and alignments
+    fn write_mixed(cursor: &mut WriteCursor<'_>) {
+        for i in 0..8u8 {
+            cursor.write_u3(i);
+            cursor.write_u64(0x0123_4567_89ab_cdef_u64.rotate_left(u32::from(i)));
+            cursor.skip_5();
+            cursor.write_u13(u16::from(i) * 1000);
+            cursor.align_to_8_bits();
+            cursor.write_aligned_u32(0xdead_beef ^ u32::from(i));
+            cursor.write_aligned_bytes(b"The quick brown fox");
+            cursor.write_bool(true);
+        }
+    }
+
+    #[test]
+    fn streaming_same_as_slice() {
+        let mut expected = [0u8; 512];
+        let mut cursor = WriteCursor::new(&mut expected);
+        write_mixed(&mut cursor);
+        let expected_bits = cursor.bits_written();
+        let expected = &expected[..expected_bits.div_ceil(8)];
+
+        for window in [9, 10, 16, 63] {
+            let mut actual = Vec::new();
+            let mut sink = |bytes: &[u8]| actual.extend_from_slice(bytes);
+            let mut buffer = [0u8; 64];
+            let mut cursor = WriteCursor::with_sink(&mut buffer[..window], &mut sink);
+            write_mixed(&mut cursor);
+            assert_eq!(expected_bits, cursor.bits_written());
+            cursor.finish();
+            assert_eq!(expected, &actual[..], "Window size {}", window);
+        }
+    }
+
+    #[test]
+    fn narrow_too_large_does_not_change_neighbors() {
+        let mut bytes = [0u8; 4];
+        let mut cursor = WriteCursor::new(&mut bytes);
+        cursor.write_u3(0);
+        cursor.write_uint_truncating(0xffff, 13);
+        cursor.write_u16(0);
+        assert_eq!(bytes, [0xf8, 0xff, 0x00, 0x00]);
+
+        let mut bytes = [0u8; 4];
+        let mut cursor = WriteCursor::new(&mut bytes);
+        cursor.write_u3(0);
+        cursor.write_i13(-1);
+        cursor.write_u16(0);
+        assert_eq!(bytes, [0xf8, 0xff, 0x00, 0x00]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn narrow_too_large() {
+        let mut bytes = [0u8; 2];
+        let mut cursor = WriteCursor::new(&mut bytes);
+        cursor.write_u13(0x2000);
+    }
+
+    #[test]
+    fn uint_saturating_truncating() {
+        let mut bytes = [0u8; 4];
+        let mut cursor = WriteCursor::new(&mut bytes);
+        cursor.write_uint_saturating(0x2fff, 13);
+        cursor.write_uint_truncating(0x7, 2);
+        cursor.write_uint(0x1, 1);
+        cursor.write_uint_saturating(u64::MAX, 16);
+        assert_eq!(bytes, [0xff, 0xff, 0xff, 0xff]);
+
+        let mut bytes = [0u8; 2];
+        let mut cursor = WriteCursor::new(&mut bytes);
+        cursor.write_uint_saturating(0x0123, 13);
+        cursor.write_uint_truncating(0x2fff, 3);
+        assert_eq!(bytes, [0x23, 0xe1]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "does not fit")]
+    fn uint_too_large() {
+        let mut bytes = [0u8; 2];
+        let mut cursor = WriteCursor::new(&mut bytes);
+        cursor.write_uint(0x2000, 13);
+    }
+
+    #[test]
+    fn f16_from_f32() {
+        let cases: [(f32, u16); 12] = [
+            (0.0, 0x0000),
+            (-0.0, 0x8000),
+            (1.0, 0x3c00),
+            (-2.5, 0xc100),
+            // Rounds to nearest, ties to even
+            (1.0 + 1.0 / 2048.0, 0x3c00),
+            (1.0 + 3.0 / 2048.0, 0x3c02),
+            // Smallest subnormal
+            (5.960_464_5e-8, 0x0001),
+            // Too small even for a subnormal
+            (1.0e-9, 0x0000),
+            // Out of range saturates
+            (1.0e6, 0x7bff),
+            (-1.0e6, 0xfbff),
+            (f32::INFINITY, 0x7c00),
+            (f32::NEG_INFINITY, 0xfc00),
+        ];
+        for (value, expected) in cases {
+            let mut bytes = [0u8; 2];
+            let mut cursor = WriteCursor::new(&mut bytes);
+            cursor.write_f16_from_f32(value);
+            assert_eq!(u16::from_le_bytes(bytes), expected, "Value {}", value);
+        }
+
+        let mut bytes = [0u8; 2];
+        let mut cursor = WriteCursor::new(&mut bytes);
+        cursor.write_f16_from_f32(f32::NAN);
+        assert!(f16::from_le_bytes(bytes).is_nan());
+    }
+
     #[test]
     fn u8_one() {
         let mut bytes = [0u8];
@@ -1042,14 +1750,66 @@ mod test {
     fn complex_example() {
         let mut bytes = [0u8; 4];
         let mut cursor = WriteCursor::new(&mut bytes);
-        cursor.write_u12(48858);
-        cursor.write_u3((-1i8) as u8);
-        cursor.write_u4((-5i8) as u8);
-        cursor.write_u2((-1i8) as u8);
-        cursor.write_u4(136);
+        // Truncated fields, masked like in generated code
+        cursor.write_u12(48858 & 0xfff);
+        cursor.write_u3(((-1i8) as u8) & 0x7);
+        cursor.write_u4(((-5i8) as u8) & 0xf);
+        cursor.write_u2(((-1i8) as u8) & 0x3);
+        cursor.write_u4(136 & 0xf);
+        assert_eq!(bytes, [0b1101_1010, 0b1111_1110, 0b0001_1101, 0x1]);
+    }
+
+    /// Tests the example in section 3.7.5 of the specification, using the signed integer functions
+    #[test]
+    fn complex_example_signed() {
+        let mut bytes = [0u8; 4];
+        let mut cursor = WriteCursor::new(&mut bytes);
+        cursor.write_u12(48858 & 0xfff);
+        cursor.write_i3(-1);
+        cursor.write_i4(-5);
+        cursor.write_i2(-1);
+        cursor.write_u4(136 & 0xf);
         assert_eq!(bytes, [0b1101_1010, 0b1111_1110, 0b0001_1101, 0x1]);
     }
 
+    #[test]
+    fn bytes_aligned_and_unaligned() {
+        let mut bytes = [0u8; 4];
+        let mut cursor = WriteCursor::new(&mut bytes);
+        cursor.write_bytes(&[0x12, 0x34]);
+        cursor.write_u4(0xa);
+        cursor.write_bytes(&[0xbc]);
+        assert_eq!(cursor.bits_written(), 28);
+        assert_eq!(bytes, [0x12, 0x34, 0xca, 0x0b]);
+    }
+
+    #[test]
+    fn union_tag() {
+        let mut bytes = [0u8; 3];
+        let mut cursor = WriteCursor::new(&mut bytes);
+        cursor.write_union_tag(3, 4);
+        cursor.write_union_tag(300, 301);
+        assert_eq!(bytes, [3, 0x2c, 0x01]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn union_tag_out_of_range() {
+        let mut bytes = [0u8; 1];
+        let mut cursor = WriteCursor::new(&mut bytes);
+        cursor.write_union_tag(4, 4);
+    }
+
+    #[test]
+    fn union_tag_256_variants() {
+        // Tags 0 through 255 fit in 8 bits
+        let mut bytes = [0u8; 3];
+        let mut cursor = WriteCursor::new(&mut bytes);
+        cursor.write_union_tag(255, 256);
+        cursor.write_union_tag(256, 257);
+        assert_eq!(bytes, [0xff, 0x00, 0x01]);
+    }
+
     #[test]
     fn u64_basic() {
         let mut bytes = [0u8; 8];
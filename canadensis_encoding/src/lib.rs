@@ -24,9 +24,30 @@ pub trait DataType {
 
 /// Trait for types that can be serialized into Cyphal transfers
 pub trait Serialize: DataType {
+    /// The smallest number of bits that [`size_bits()`](#tymethod.size_bits) can return for
+    /// any value of this type
+    const MIN_SIZE_BITS: usize;
+    /// The largest number of bits that [`size_bits()`](#tymethod.size_bits) can return for
+    /// any value of this type
+    ///
+    /// This can be used to size a buffer that can hold any serialized value of this type:
+    /// `[0u8; T::MAX_SIZE_BITS.div_ceil(8)]`
+    const MAX_SIZE_BITS: usize;
+    /// The largest number of bytes that any serialized value of this type can occupy
+    ///
+    /// This is [`MAX_SIZE_BITS`](#associatedconstant.MAX_SIZE_BITS) rounded up to a whole
+    /// number of bytes.
+    ///
+    /// A received payload can be longer than this. The Cyphal specification requires receivers
+    /// to ignore extra bytes at the end of a payload (implicit truncation), which allows newer
+    /// versions of a type to add fields. A payload longer than this limit is therefore not
+    /// necessarily invalid, but the extra bytes will not be used.
+    const MAX_SIZE_BYTES: usize = Self::MAX_SIZE_BITS.div_ceil(8);
+
     /// Returns the size of the encoded form of this value, in bits
     ///
-    /// The returned value may not be a multiple of 8.
+    /// The returned value may not be a multiple of 8. It is always in the range
+    /// `MIN_SIZE_BITS..=MAX_SIZE_BITS`.
     fn size_bits(&self) -> usize;
 
     /// Serializes this value into a buffer
@@ -37,9 +58,47 @@ pub trait Serialize: DataType {
 
     /// A convenience function that creates a cursor around the provided bytes and calls
     /// [`serialize`](#tymethod.serialize)
+    ///
+    /// In debug builds, this function panics if the number of bytes written does not match
+    /// the value returned by [`size_bits()`](#tymethod.size_bits).
     fn serialize_to_bytes(&self, bytes: &mut [u8]) {
+        self.serialize_to_bytes_len(bytes);
+    }
+
+    /// Serializes this value into the provided bytes and returns the number of bytes written
+    ///
+    /// A partially filled last byte is counted. The returned length can be used to trim the
+    /// buffer to the serialized value.
+    ///
+    /// In debug builds, this function panics if the number of bytes written does not match
+    /// the value returned by [`size_bits()`](#tymethod.size_bits). Padding bits at the end of the
+    /// last byte do not need to be written.
+    fn serialize_to_bytes_len(&self, bytes: &mut [u8]) -> usize {
         let mut cursor = WriteCursor::new(bytes);
         self.serialize(&mut cursor);
+        let bytes_written = cursor.bits_written().div_ceil(8);
+        debug_assert_eq!(
+            bytes_written,
+            self.size_bits().div_ceil(8),
+            "Serialized length does not match size_bits()"
+        );
+        bytes_written
+    }
+
+    /// A convenience function that serializes this value without a buffer large enough to hold
+    /// all of it
+    ///
+    /// `buffer` is used as a window and must be at least 9 bytes long. The serialized bytes are
+    /// passed to `sink` in order, a few at a time.
+    fn serialize_streaming(&self, buffer: &mut [u8], sink: &mut dyn FnMut(&[u8])) {
+        let mut cursor = WriteCursor::with_sink(buffer, sink);
+        self.serialize(&mut cursor);
+        debug_assert_eq!(
+            cursor.bits_written().div_ceil(8),
+            self.size_bits().div_ceil(8),
+            "Serialized length does not match size_bits()"
+        );
+        cursor.finish();
     }
 }
 
@@ -50,6 +109,25 @@ pub trait Deserialize: DataType {
     where
         Self: Sized;
 
+    /// Deserializes a value and stores it in `self`, reusing any storage that `self` owns
+    ///
+    /// Implementations should clear and refill any variable-length containers in `self`
+    /// instead of replacing them, so that calling this function repeatedly on the same value
+    /// does not allocate more memory. Containers with inline storage, like `heapless::Vec`,
+    /// keep their storage in any case.
+    ///
+    /// If this function returns an error, `self` may contain a partially deserialized value.
+    ///
+    /// The default implementation calls [`deserialize`](#tymethod.deserialize) and replaces
+    /// `self` with the result.
+    fn deserialize_in_place(&mut self, cursor: &mut ReadCursor<'_>) -> Result<(), DeserializeError>
+    where
+        Self: Sized,
+    {
+        *self = Self::deserialize(cursor)?;
+        Ok(())
+    }
+
     /// Deserializes a value from a slice of bytes and returns it
     ///
     /// This is available only for types that implement [`Sized`], [`IntoBytes`], and [`FromBytes`].
@@ -71,7 +149,8 @@ pub trait Deserialize: DataType {
         let bytes_to_copy = cmp::min(value_bytes.len(), cursor_bytes.len());
         value_bytes[..bytes_to_copy].copy_from_slice(&cursor_bytes[..bytes_to_copy]);
 
-        cursor.advance_bytes(bytes_to_copy);
+        // Advance past any implicit zero bytes too, so that a strict cursor can detect them
+        cursor.advance_bytes(value_bytes.len());
 
         value
     }
@@ -85,6 +164,23 @@ pub trait Deserialize: DataType {
         let mut cursor = ReadCursor::new(bytes);
         Self::deserialize(&mut cursor)
     }
+
+    /// A convenience function that creates a [strict cursor](ReadCursor::new_strict) around the
+    /// provided bytes and calls [`deserialize`](#tymethod.deserialize)
+    ///
+    /// This function returns a [`DeserializeError::Truncated`] error if the bytes are too short
+    /// to contain the value, or a [`DeserializeError::ConstraintViolation`] error if a padding
+    /// field is not zero.
+    fn deserialize_from_bytes_strict(bytes: &[u8]) -> Result<Self, DeserializeError>
+    where
+        Self: Sized,
+    {
+        let mut cursor = ReadCursor::new_strict(bytes);
+        let value = Self::deserialize(&mut cursor)?;
+        cursor.check_truncated()?;
+        cursor.check_constraints()?;
+        Ok(value)
+    }
 }
 
 /// Marker for message data types
@@ -95,13 +191,50 @@ pub trait Request {}
 pub trait Response {}
 
 /// Errors that can occur when deserializing
+///
+/// Each variant contains the [bit offset](ReadCursor::bit_offset) of the cursor when the
+/// error was detected, which is just after the field with the invalid value.
 #[non_exhaustive]
 #[derive(Debug)]
 pub enum DeserializeError {
     /// A variable-length array length field was greater than the maximum allowed length
-    ArrayLength,
+    ArrayLength {
+        /// The offset from the beginning of the input, in bits
+        offset_bits: usize,
+    },
     /// A union tag field did not correspond to a known variant
-    UnionTag,
+    UnionTag {
+        /// The offset from the beginning of the input, in bits
+        offset_bits: usize,
+    },
     /// A delimiter header had a length that was not valid for the expected type
-    DelimitedLength,
+    DelimitedLength {
+        /// The offset from the beginning of the input, in bits
+        offset_bits: usize,
+    },
+    /// A strict cursor read beyond the end of the input
+    ///
+    /// The offset is the position of the first read that extended beyond the end.
+    Truncated {
+        /// The offset from the beginning of the input, in bits
+        offset_bits: usize,
+    },
+    /// A strict cursor read a padding field that was not zero
+    ConstraintViolation {
+        /// The offset from the beginning of the input, in bits
+        offset_bits: usize,
+    },
+}
+
+impl DeserializeError {
+    /// Returns the offset from the beginning of the input, in bits, where this error was detected
+    pub fn offset_bits(&self) -> usize {
+        match self {
+            DeserializeError::ArrayLength { offset_bits }
+            | DeserializeError::UnionTag { offset_bits }
+            | DeserializeError::DelimitedLength { offset_bits }
+            | DeserializeError::Truncated { offset_bits }
+            | DeserializeError::ConstraintViolation { offset_bits } => *offset_bits,
+        }
+    }
 }
@@ -37,6 +37,9 @@ impl DataType for Outer {
 }
 
 impl Serialize for Inner {
+    const MIN_SIZE_BITS: usize = 8;
+    const MAX_SIZE_BITS: usize = 8;
+
     fn size_bits(&self) -> usize {
         8
     }
@@ -64,6 +67,9 @@ impl Deserialize for Inner {
 }
 
 impl Serialize for Outer {
+    const MIN_SIZE_BITS: usize = 72;
+    const MAX_SIZE_BITS: usize = 72;
+
     fn size_bits(&self) -> usize {
         // This gets rounded up to a multiple of 8, because composite types always have 8-bit
         // alignment
@@ -122,4 +128,10 @@ fn round_trip_1() {
 
     let deserialized = Outer::deserialize_from_bytes(&actual_bytes).unwrap();
     assert_eq!(value, deserialized);
+
+    // A larger buffer can be trimmed to the serialized length
+    let mut large_buffer = [0u8; 16];
+    let length = value.serialize_to_bytes_len(&mut large_buffer);
+    assert_eq!(length, 9);
+    assert_eq!(&expected_bytes[..], &large_buffer[..length]);
 }
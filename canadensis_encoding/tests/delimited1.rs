@@ -22,6 +22,9 @@ impl DataType for A {
 }
 
 impl Serialize for A {
+    const MIN_SIZE_BITS: usize = 56;
+    const MAX_SIZE_BITS: usize = 280;
+
     fn size_bits(&self) -> usize {
         match self {
             A::Sea(sealed) => 8 + 32 + align_up_to_8_bits(sealed.size_bits()),
@@ -54,7 +57,9 @@ impl Deserialize for A {
         match tag {
             0 => Ok(A::Sea(cursor.read_composite()?)),
             1 => Ok(A::Del(cursor.read_composite()?)),
-            _ => Err(DeserializeError::UnionTag),
+            _ => Err(DeserializeError::UnionTag {
+                offset_bits: cursor.bit_offset(),
+            }),
         }
     }
 }
@@ -79,6 +84,9 @@ impl DataType for BSealed {
 }
 
 impl Serialize for BSealed {
+    const MIN_SIZE_BITS: usize = 16;
+    const MAX_SIZE_BITS: usize = 240;
+
     fn size_bits(&self) -> usize {
         8 + self
             .var
@@ -129,7 +137,9 @@ impl Deserialize for BSealed {
                 }
                 var
             } else {
-                return Err(DeserializeError::ArrayLength);
+                return Err(DeserializeError::ArrayLength {
+                    offset_bits: cursor.bit_offset(),
+                });
             }
         };
         let fix = {
@@ -141,7 +151,9 @@ impl Deserialize for BSealed {
                 }
                 fix
             } else {
-                return Err(DeserializeError::ArrayLength);
+                return Err(DeserializeError::ArrayLength {
+                    offset_bits: cursor.bit_offset(),
+                });
             }
         };
         Ok(BSealed { var, fix })
@@ -169,6 +181,9 @@ impl DataType for BDelimited {
 }
 
 impl Serialize for BDelimited {
+    const MIN_SIZE_BITS: usize = 16;
+    const MAX_SIZE_BITS: usize = 240;
+
     fn size_bits(&self) -> usize {
         8 + self
             .var
@@ -218,7 +233,9 @@ impl Deserialize for BDelimited {
                 }
                 var
             } else {
-                return Err(DeserializeError::ArrayLength);
+                return Err(DeserializeError::ArrayLength {
+                    offset_bits: cursor.bit_offset(),
+                });
             }
         };
         let fix = {
@@ -230,7 +247,9 @@ impl Deserialize for BDelimited {
                 }
                 fix
             } else {
-                return Err(DeserializeError::ArrayLength);
+                return Err(DeserializeError::ArrayLength {
+                    offset_bits: cursor.bit_offset(),
+                });
             }
         };
         Ok(BDelimited { var, fix })
@@ -257,6 +276,9 @@ impl DataType for CVariable {
 }
 
 impl Serialize for CVariable {
+    const MIN_SIZE_BITS: usize = 16;
+    const MAX_SIZE_BITS: usize = 32;
+
     fn size_bits(&self) -> usize {
         8 + self.a.len() * 8 + 8
     }
@@ -299,6 +321,9 @@ struct CFixed {
 }
 
 impl Serialize for CFixed {
+    const MIN_SIZE_BITS: usize = 16;
+    const MAX_SIZE_BITS: usize = 16;
+
     fn size_bits(&self) -> usize {
         16
     }
@@ -393,6 +418,9 @@ impl DataType for A11 {
 }
 
 impl Serialize for A11 {
+    const MIN_SIZE_BITS: usize = 56;
+    const MAX_SIZE_BITS: usize = 296;
+
     fn size_bits(&self) -> usize {
         match self {
             A11::Sea(sealed) => 8 + 32 + align_up_to_8_bits(sealed.size_bits()),
@@ -425,7 +453,9 @@ impl Deserialize for A11 {
         match tag {
             0 => Ok(A11::Sea(cursor.read_composite()?)),
             1 => Ok(A11::Del(cursor.read_composite()?)),
-            _ => Err(DeserializeError::UnionTag),
+            _ => Err(DeserializeError::UnionTag {
+                offset_bits: cursor.bit_offset(),
+            }),
         }
     }
 }
@@ -451,6 +481,9 @@ impl DataType for BDelimited11 {
 }
 
 impl Serialize for BDelimited11 {
+    const MIN_SIZE_BITS: usize = 16;
+    const MAX_SIZE_BITS: usize = 256;
+
     fn size_bits(&self) -> usize {
         8 + self
             .var
@@ -500,7 +533,9 @@ impl Deserialize for BDelimited11 {
                 }
                 var
             } else {
-                return Err(DeserializeError::ArrayLength);
+                return Err(DeserializeError::ArrayLength {
+                    offset_bits: cursor.bit_offset(),
+                });
             }
         };
         let fix = {
@@ -512,7 +547,9 @@ impl Deserialize for BDelimited11 {
                 }
                 fix
             } else {
-                return Err(DeserializeError::ArrayLength);
+                return Err(DeserializeError::ArrayLength {
+                    offset_bits: cursor.bit_offset(),
+                });
             }
         };
         Ok(BDelimited11 { var, fix })
@@ -537,6 +574,9 @@ impl DataType for CVariable11 {
 }
 
 impl Serialize for CVariable11 {
+    const MIN_SIZE_BITS: usize = 8;
+    const MAX_SIZE_BITS: usize = 24;
+
     fn size_bits(&self) -> usize {
         8 + self.a.len() * 8
     }
@@ -579,6 +619,9 @@ struct CFixed11 {
 }
 
 impl Serialize for CFixed11 {
+    const MIN_SIZE_BITS: usize = 32;
+    const MAX_SIZE_BITS: usize = 32;
+
     fn size_bits(&self) -> usize {
         32
     }
@@ -13,7 +13,10 @@ use core::mem;
 use zerocopy::byteorder::{U16, U32, U64};
 use zerocopy::{BigEndian, FromBytes, Immutable, IntoBytes, LittleEndian};
 
+/// The size of a header on the wire, in bytes
 pub const SIZE: usize = mem::size_of::<RawHeader>();
+// The header format specifies 24 bytes
+const _: () = assert!(SIZE == 24);
 
 /// A header, with memory layout matching the on-wire representation
 ///
@@ -336,6 +339,18 @@ pub struct NodeId16(u16);
 
 const NODE_ID_RESERVED_ANONYMOUS_OR_BROADCAST: u16 = 0xffff;
 
+impl NodeId16 {
+    /// The smallest allowed node ID (0)
+    pub const MIN: NodeId16 = NodeId16(0);
+    /// The largest allowed node ID (65534)
+    pub const MAX: NodeId16 = NodeId16(NODE_ID_RESERVED_ANONYMOUS_OR_BROADCAST - 1);
+
+    /// Returns the integer value of this node ID
+    pub const fn to_u16(self) -> u16 {
+        self.0
+    }
+}
+
 impl TryFrom<u16> for NodeId16 {
     type Error = InvalidValue;
 
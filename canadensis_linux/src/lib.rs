@@ -7,28 +7,242 @@
 extern crate canadensis_can;
 extern crate canadensis_core;
 extern crate canadensis_filter_config;
+extern crate libc;
 extern crate log;
 extern crate socketcan;
 
 use canadensis_can::driver::{optimize_filters, ReceiveDriver, TransmitDriver};
 use canadensis_can::{CanNodeId, Frame};
 use canadensis_core::subscription::Subscription;
-use canadensis_core::time::{Clock, Microseconds32};
+use canadensis_core::time::{nanoseconds_to_microseconds32, Clock, Microseconds32, Nanoseconds64};
 use canadensis_core::{nb, OutOfMemoryError};
+#[cfg(feature = "can-fd")]
+use socketcan::{CanAnyFrame, CanFdSocket};
 use socketcan::{CanSocket, EmbeddedFrame, Id, Socket, SocketOptions};
 use std::convert::TryInto;
 use std::io;
 use std::io::ErrorKind;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::path::Path;
+use std::process::Command;
+use std::{mem, ptr};
 
 /// An adapter between SocketCAN and the canadensis frame format
+///
+/// By default, this sends and receives classic CAN frames. With the `can-fd` feature enabled,
+/// [`LinuxCan::new_fd`] creates an adapter that can also send and receive CAN FD frames.
 pub struct LinuxCan {
-    socket: CanSocket,
+    socket: LinuxSocket,
+    /// The maximum number of filters to apply to the socket
+    max_filters: usize,
+}
+
+/// The maximum number of filters that the Linux kernel accepts on a raw CAN socket
+/// (`CAN_RAW_FILTER_MAX`)
+pub const KERNEL_MAX_FILTERS: usize = 512;
+
+/// The flag in a SocketCAN ID that indicates an extended (29-bit) ID
+const CAN_EFF_FLAG: u32 = 0x8000_0000;
+
+/// The maximum number of frames that [`LinuxCan::receive_batch`] reads in one system call
+const BATCH_SIZE: usize = 32;
+
+/// A classic CAN or CAN FD socket
+enum LinuxSocket {
+    Classic(CanSocket),
+    #[cfg(feature = "can-fd")]
+    Fd(CanFdSocket),
+}
+
+impl LinuxSocket {
+    fn as_raw_fd(&self) -> RawFd {
+        match self {
+            LinuxSocket::Classic(socket) => socket.as_raw_fd(),
+            #[cfg(feature = "can-fd")]
+            LinuxSocket::Fd(socket) => socket.as_raw_fd(),
+        }
+    }
 }
 
 impl LinuxCan {
     /// Creates a Linux CAN adapter around a SocketCAN socket
+    ///
+    /// The adapter will send and receive only classic CAN frames.
     pub fn new(socket: CanSocket) -> Self {
-        LinuxCan { socket }
+        LinuxCan {
+            socket: LinuxSocket::Classic(socket),
+            max_filters: KERNEL_MAX_FILTERS,
+        }
+    }
+
+    /// Creates a Linux CAN adapter around a SocketCAN socket with CAN FD frames enabled
+    ///
+    /// The adapter will send all frames as CAN FD frames, and receive both classic CAN and
+    /// CAN FD frames. The transmitter should use [`Mtu::CanFd64`](canadensis_can::Mtu::CanFd64).
+    #[cfg(feature = "can-fd")]
+    pub fn new_fd(socket: CanFdSocket) -> Self {
+        LinuxCan {
+            socket: LinuxSocket::Fd(socket),
+            max_filters: KERNEL_MAX_FILTERS,
+        }
+    }
+
+    /// Returns the maximum number of filters that will be applied to the socket
+    pub fn max_filters(&self) -> usize {
+        self.max_filters
+    }
+
+    /// Sets the maximum number of filters that will be applied to the socket
+    ///
+    /// When the subscriptions need more filters than this, some filters are combined so that
+    /// they accept more frames. The default is [`KERNEL_MAX_FILTERS`].
+    ///
+    /// The new maximum takes effect the next time the filters are applied.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `max_filters` is zero.
+    pub fn set_max_filters(&mut self, max_filters: usize) {
+        assert_ne!(max_filters, 0, "Maximum number of filters must not be zero");
+        self.max_filters = max_filters;
+    }
+
+    /// Reads up to `max` frames from the socket and appends them to `out`
+    ///
+    /// This function uses the `recvmmsg` system call to read many frames at once, and returns the
+    /// number of frames read. It does not block: it stops early when no more frames are
+    /// available, even if the socket is in blocking mode. Frames that are not compatible with
+    /// Cyphal are discarded and not counted.
+    ///
+    /// If an error occurs after some frames have been read, this function returns the number of
+    /// frames read instead of the error. An error that persists is returned by the next call.
+    pub fn receive_batch(
+        &mut self,
+        clock: &mut SystemClock,
+        out: &mut Vec<Frame>,
+        max: usize,
+    ) -> io::Result<usize> {
+        let fd = self.socket.as_raw_fd();
+        let mut count = 0;
+        while count < max {
+            // A CAN FD frame buffer is large enough for a classic CAN frame
+            let mut buffers: [libc::canfd_frame; BATCH_SIZE] = unsafe { mem::zeroed() };
+            let mut iovecs: [libc::iovec; BATCH_SIZE] = unsafe { mem::zeroed() };
+            let mut headers: [libc::mmsghdr; BATCH_SIZE] = unsafe { mem::zeroed() };
+            for ((buffer, iovec), header) in buffers
+                .iter_mut()
+                .zip(iovecs.iter_mut())
+                .zip(headers.iter_mut())
+            {
+                iovec.iov_base = (buffer as *mut libc::canfd_frame).cast();
+                iovec.iov_len = mem::size_of::<libc::canfd_frame>();
+                header.msg_hdr.msg_iov = iovec;
+                header.msg_hdr.msg_iovlen = 1;
+            }
+            let batch_size = (max - count).min(BATCH_SIZE);
+            let status = unsafe {
+                libc::recvmmsg(
+                    fd,
+                    headers.as_mut_ptr(),
+                    batch_size as _,
+                    libc::MSG_DONTWAIT as _,
+                    ptr::null_mut(),
+                )
+            };
+            if status < 0 {
+                let e = io::Error::last_os_error();
+                if count != 0 || e.kind() == ErrorKind::WouldBlock {
+                    break;
+                }
+                return Err(e);
+            }
+            let received = status as usize;
+            let now = clock.now();
+            for (buffer, header) in buffers.iter().zip(headers.iter()).take(received) {
+                if let Some(frame) = convert_raw_frame(buffer, header.msg_len as usize, now) {
+                    out.push(frame);
+                    count += 1;
+                }
+            }
+            if received < batch_size {
+                // No more frames available
+                break;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Returns an iterator that reads frames from the socket
+    ///
+    /// The iterator records the time when each frame is read from `clock` and converts the frame
+    /// into a Cyphal frame. Frames that are not compatible with Cyphal are discarded.
+    ///
+    /// The iterator ends when no more frames are available (the socket returns a `WouldBlock` or
+    /// `TimedOut` error). If the socket is in blocking mode and has no read timeout, the iterator
+    /// waits for more frames instead and never ends.
+    ///
+    /// This can replace a manual read loop:
+    ///
+    /// ```no_run
+    /// # use canadensis_linux::{LinuxCan, SystemClock};
+    /// # use socketcan::{CanSocket, Socket};
+    /// let can = LinuxCan::new(CanSocket::open("vcan0")?);
+    /// let mut clock = SystemClock::new();
+    /// for frame in can.frames(&mut clock) {
+    ///     let frame = frame?;
+    ///     println!("{:?}", frame);
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn frames<'a>(&'a self, clock: &'a mut SystemClock) -> Frames<'a> {
+        Frames { can: self, clock }
+    }
+
+    /// Reads one frame from the socket
+    ///
+    /// This function returns None if the frame was read successfully but is not compatible
+    /// with Cyphal.
+    fn read_frame(&self, clock: &mut SystemClock) -> io::Result<Option<Frame>> {
+        let cyphal_frame = match &self.socket {
+            LinuxSocket::Classic(socket) => {
+                let socketcan_frame = socket.read_frame()?;
+                convert_frame(&socketcan_frame, clock.now())
+            }
+            #[cfg(feature = "can-fd")]
+            LinuxSocket::Fd(socket) => match socket.read_frame()? {
+                CanAnyFrame::Normal(socketcan_frame) => {
+                    convert_frame(&socketcan_frame, clock.now())
+                }
+                CanAnyFrame::Fd(socketcan_frame) => convert_frame(&socketcan_frame, clock.now()),
+                CanAnyFrame::Remote(_) | CanAnyFrame::Error(_) => None,
+            },
+        };
+        Ok(cyphal_frame)
+    }
+}
+
+/// An iterator that reads frames from a [`LinuxCan`]
+///
+/// This is created by [`LinuxCan::frames`].
+pub struct Frames<'a> {
+    can: &'a LinuxCan,
+    clock: &'a mut SystemClock,
+}
+
+impl Iterator for Frames<'_> {
+    type Item = io::Result<Frame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.can.read_frame(self.clock) {
+                Ok(Some(frame)) => return Some(Ok(frame)),
+                Ok(None) => {}
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    return None
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
     }
 }
 
@@ -51,23 +265,29 @@ impl TransmitDriver<SystemClock> for LinuxCan {
             log::warn!("Dropping frame that has missed its deadline");
             return Ok(None);
         }
-        let socketcan_frame = socketcan::CanFrame::new(
-            socketcan::Id::Extended(
-                socketcan::ExtendedId::new(frame.id().into()).expect("Invalid CAN ID"),
-            ),
-            frame.data(),
-        )
-        .expect("Invalid frame format");
-        self.socket
-            .write_frame_insist(&socketcan_frame)
-            .map(|()| None)
-            .map_err(|e| {
-                if e.kind() == ErrorKind::WouldBlock {
-                    nb::Error::WouldBlock
-                } else {
-                    nb::Error::Other(e)
-                }
-            })
+        let id = socketcan::Id::Extended(
+            socketcan::ExtendedId::new(frame.id().into()).expect("Invalid CAN ID"),
+        );
+        let status = match &self.socket {
+            LinuxSocket::Classic(socket) => {
+                let socketcan_frame =
+                    socketcan::CanFrame::new(id, frame.data()).expect("Invalid frame format");
+                socket.write_frame_insist(&socketcan_frame)
+            }
+            #[cfg(feature = "can-fd")]
+            LinuxSocket::Fd(socket) => {
+                let socketcan_frame =
+                    socketcan::CanFdFrame::new(id, frame.data()).expect("Invalid frame format");
+                socket.write_frame_insist(&socketcan_frame)
+            }
+        };
+        status.map(|()| None).map_err(|e| {
+            if e.kind() == ErrorKind::WouldBlock {
+                nb::Error::WouldBlock
+            } else {
+                nb::Error::Other(e)
+            }
+        })
     }
 
     fn flush(&mut self, _clock: &mut SystemClock) -> canadensis_core::nb::Result<(), Self::Error> {
@@ -81,23 +301,8 @@ impl ReceiveDriver<SystemClock> for LinuxCan {
 
     fn receive(&mut self, clock: &mut SystemClock) -> nb::Result<Frame, Self::Error> {
         loop {
-            let socketcan_frame = self.socket.read_frame()?;
-            if socketcan_frame.data().len() <= canadensis_can::FRAME_CAPACITY {
-                let raw_id = match socketcan_frame.id() {
-                    Id::Standard(_) => continue,
-                    Id::Extended(id) => id.as_raw(),
-                };
-                let cyphal_frame = canadensis_can::Frame::new(
-                    clock.now(),
-                    raw_id.try_into().expect("Invalid CAN ID"),
-                    socketcan_frame.data(),
-                );
+            if let Some(cyphal_frame) = self.read_frame(clock)? {
                 return Ok(cyphal_frame);
-            } else {
-                log::warn!(
-                    "Ignoring a frame {} bytes long, which is too large",
-                    socketcan_frame.data().len()
-                );
             }
         }
     }
@@ -106,22 +311,129 @@ impl ReceiveDriver<SystemClock> for LinuxCan {
     where
         S: IntoIterator<Item = Subscription>,
     {
-        optimize_filters(local_node, subscriptions, usize::MAX, |optimized| {
+        optimize_filters(local_node, subscriptions, self.max_filters, |optimized| {
+            // Include the extended ID flag so that the kernel also rejects frames with standard IDs
             let socketcan_filters = optimized
                 .iter()
-                .map(|filter| socketcan::CanFilter::new(filter.id(), filter.mask()))
+                .map(|filter| {
+                    socketcan::CanFilter::new(
+                        filter.id() | CAN_EFF_FLAG,
+                        filter.mask() | CAN_EFF_FLAG,
+                    )
+                })
                 .collect::<Vec<_>>();
-            self.socket.set_filters(&socketcan_filters).unwrap();
+            match &self.socket {
+                LinuxSocket::Classic(socket) => socket.set_filters(&socketcan_filters).unwrap(),
+                #[cfg(feature = "can-fd")]
+                LinuxSocket::Fd(socket) => socket.set_filters(&socketcan_filters).unwrap(),
+            }
         })
         .unwrap()
     }
 
     fn apply_accept_all(&mut self) {
-        self.socket.set_filter_accept_all().unwrap();
+        match &self.socket {
+            LinuxSocket::Classic(socket) => socket.set_filter_accept_all().unwrap(),
+            #[cfg(feature = "can-fd")]
+            LinuxSocket::Fd(socket) => socket.set_filter_accept_all().unwrap(),
+        }
+    }
+}
+
+/// Opens a virtual CAN (vcan) interface, creating it first if it does not exist
+///
+/// If no network interface called `name` exists, this function runs `ip link` to create a vcan
+/// interface with that name and bring it up. Creating an interface usually requires root
+/// privileges or the `CAP_NET_ADMIN` capability.
+///
+/// The returned adapter sends and receives classic CAN frames.
+pub fn open_or_create_vcan(name: &str) -> io::Result<LinuxCan> {
+    let exists = Path::new("/sys/class/net").join(name).exists();
+    if !exists {
+        run_ip(&["link", "add", "dev", name, "type", "vcan"])?;
+        run_ip(&["link", "set", "up", name])?;
+    }
+    let socket = CanSocket::open(name)?;
+    Ok(LinuxCan::new(socket))
+}
+
+/// Runs the `ip` command with the provided arguments and returns an error if it fails
+fn run_ip(args: &[&str]) -> io::Result<()> {
+    let output = Command::new("ip").args(args).output()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!(
+            "ip {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+/// Converts a SocketCAN frame into a canadensis frame
+///
+/// This function returns None if the frame has a standard ID or is too long.
+fn convert_frame<F: EmbeddedFrame>(
+    socketcan_frame: &F,
+    timestamp: Microseconds32,
+) -> Option<Frame> {
+    if socketcan_frame.data().len() > canadensis_can::FRAME_CAPACITY {
+        log::warn!(
+            "Ignoring a frame {} bytes long, which is too large",
+            socketcan_frame.data().len()
+        );
+        return None;
+    }
+    let raw_id = match socketcan_frame.id() {
+        Id::Standard(_) => return None,
+        Id::Extended(id) => id.as_raw(),
+    };
+    Some(Frame::new(
+        timestamp,
+        raw_id.try_into().expect("Invalid CAN ID"),
+        socketcan_frame.data(),
+    ))
+}
+
+/// Converts a frame read directly from a socket into a Cyphal frame
+///
+/// `length` is the number of bytes that the socket wrote into `raw_frame`.
+fn convert_raw_frame(
+    raw_frame: &libc::canfd_frame,
+    length: usize,
+    timestamp: Microseconds32,
+) -> Option<Frame> {
+    if length == mem::size_of::<libc::can_frame>() {
+        // Classic CAN frame
+        let raw_frame =
+            unsafe { ptr::read((raw_frame as *const libc::canfd_frame).cast::<libc::can_frame>()) };
+        match socketcan::CanFrame::from(raw_frame) {
+            socketcan::CanFrame::Data(socketcan_frame) => {
+                convert_frame(&socketcan_frame, timestamp)
+            }
+            socketcan::CanFrame::Remote(_) | socketcan::CanFrame::Error(_) => None,
+        }
+    } else {
+        #[cfg(feature = "can-fd")]
+        if length == mem::size_of::<libc::canfd_frame>() {
+            let socketcan_frame = socketcan::CanFdFrame::from(*raw_frame);
+            return convert_frame(&socketcan_frame, timestamp);
+        }
+        log::warn!("Ignoring a frame with unexpected size {}", length);
+        None
     }
 }
 
-/// A clock that uses the operating system's clock
+/// A clock that uses the operating system's monotonic clock
+///
+/// This clock uses [`std::time::Instant`], which on Linux reads `CLOCK_MONOTONIC`. It is not
+/// affected by changes to the system's wall-clock time.
+///
+/// Times are measured from when the clock was created. [`Clock::now`] returns a 32-bit
+/// microsecond value that wraps around about every 71 minutes, which canadensis handles correctly.
+/// [`SystemClock::now_nanoseconds`] returns a 64-bit nanosecond value that does not wrap around
+/// for about 584 years.
 #[derive(Debug, Clone)]
 pub struct SystemClock {
     start_time: std::time::Instant,
@@ -134,6 +446,13 @@ impl SystemClock {
             start_time: std::time::Instant::now(),
         }
     }
+
+    /// Returns the time since this clock was created as a 64-bit number of nanoseconds
+    pub fn now_nanoseconds(&self) -> Nanoseconds64 {
+        let since_start = std::time::Instant::now().duration_since(self.start_time);
+        // This truncation only happens after about 584 years
+        Nanoseconds64::from_ticks(since_start.as_nanos() as u64)
+    }
 }
 
 impl Default for SystemClock {
@@ -144,8 +463,6 @@ impl Default for SystemClock {
 
 impl Clock for SystemClock {
     fn now(&mut self) -> Microseconds32 {
-        let since_start = std::time::Instant::now().duration_since(self.start_time);
-        let microseconds = since_start.as_micros();
-        Microseconds32::from_ticks(microseconds as u32)
+        nanoseconds_to_microseconds32(self.now_nanoseconds())
     }
 }
@@ -3,6 +3,14 @@
 //!
 //! This library implements the Cyphal plug-and-play node ID allocation protocol.
 //!
+//! The allocation protocol version is selected by the message type parameter of [`PnpClient`]:
+//!
+//! * `uavcan.pnp.NodeIDAllocationData.1.0` sends a 48-bit hash of the unique ID, so that a request
+//!   fits into one classic CAN frame.
+//! * `uavcan.pnp.NodeIDAllocationData.2.0` sends the full 128-bit unique ID. A request is 18 bytes
+//!   long, so this version requires a transport that can send 18 bytes in one frame, such as
+//!   CAN FD, Cyphal/UDP, or Cyphal/serial.
+//!
 
 #![no_std]
 #![deny(missing_docs)]
@@ -20,7 +28,10 @@ use canadensis::core::time::{milliseconds, Clock};
 use canadensis::core::transport::{Receiver, Transmitter, Transport};
 use canadensis::core::{Priority, SubjectId};
 use canadensis::encoding::{Deserialize, Message, Serialize};
-use canadensis_data_types::uavcan::pnp::node_id_allocation_data_1_0::{self, NodeIDAllocationData};
+use canadensis_data_types::uavcan::node::id_1_0::ID;
+use canadensis_data_types::uavcan::pnp::{
+    node_id_allocation_data_1_0, node_id_allocation_data_2_0,
+};
 use core::convert::TryFrom;
 use core::marker::PhantomData;
 use crc_any::CRCu64;
@@ -55,7 +66,7 @@ where
         unique_id: [u8; 16],
         driver: &mut R::Driver,
     ) -> Result<Self, R::Error> {
-        receiver.subscribe_message(M::SUBJECT, 9, milliseconds(1000), driver)?;
+        receiver.subscribe_message(M::SUBJECT, M::PAYLOAD_SIZE_MAX, milliseconds(1000), driver)?;
 
         Ok(PnpClient {
             unique_id,
@@ -91,7 +102,7 @@ where
         driver: &mut R::Driver,
     ) -> Result<Option<P::NodeId>, R::Error> {
         if let Some(transfer_in) = self.receiver.receive(clock, driver)? {
-            if let Ok(message) = M::deserialize_from_bytes(&transfer_in.payload) {
+            if let Ok(message) = M::deserialize_from_bytes(&transfer_in.transfer.payload) {
                 if message.matches_unique_id(&self.unique_id) {
                     if let Some(node_id) = message.node_id() {
                         return Ok(Some(node_id));
@@ -122,12 +133,19 @@ where
 
 /// A node ID allocation message
 ///
-/// This is currently implemented for `uavcan.pnp.NodeIdAllocationData` version 1.0. In the future,
-/// it may also be implemented for version 2.0 of that data type.
+/// This is implemented for `uavcan.pnp.NodeIdAllocationData` versions 1.0 and 2.0.
 pub trait AllocationMessage<T: Transport>: Message + Serialize + Deserialize {
     /// The fixed subject ID for this message
     const SUBJECT: SubjectId;
 
+    /// The maximum payload size for this message
+    ///
+    /// The default is the extent of the message type, or its maximum size if it is sealed.
+    const PAYLOAD_SIZE_MAX: usize = match Self::EXTENT_BYTES {
+        Some(extent) => extent as usize,
+        None => Self::MAX_SIZE_BYTES,
+    };
+
     /// Creates a message with the provided unique ID and no allocated node ID
     ///
     /// The message must fit into one frame of the transport that is being used.
@@ -140,12 +158,12 @@ pub trait AllocationMessage<T: Transport>: Message + Serialize + Deserialize {
     fn node_id(&self) -> Option<T::NodeId>;
 }
 
-impl<T: Transport> AllocationMessage<T> for NodeIDAllocationData {
+impl<T: Transport> AllocationMessage<T> for node_id_allocation_data_1_0::NodeIDAllocationData {
     const SUBJECT: SubjectId = node_id_allocation_data_1_0::SUBJECT;
 
     fn with_unique_id(id: &[u8; 16]) -> Self {
         let id_hash = crc_64we_48_bits(id);
-        NodeIDAllocationData {
+        node_id_allocation_data_1_0::NodeIDAllocationData {
             unique_id_hash: id_hash,
             allocated_node_id: heapless::Vec::new(),
         }
@@ -173,3 +191,24 @@ fn crc_64we_48_bits(id: &[u8; 16]) -> u64 {
     let value = crc.get_crc();
     value & 0x0000_ffff_ffff_ffff
 }
+
+impl<T: Transport> AllocationMessage<T> for node_id_allocation_data_2_0::NodeIDAllocationData {
+    const SUBJECT: SubjectId = node_id_allocation_data_2_0::SUBJECT;
+
+    fn with_unique_id(id: &[u8; 16]) -> Self {
+        node_id_allocation_data_2_0::NodeIDAllocationData {
+            unique_id: *id,
+            node_id: ID { value: 0 },
+        }
+    }
+
+    fn matches_unique_id(&self, id: &[u8; 16]) -> bool {
+        self.unique_id == *id
+    }
+
+    fn node_id(&self) -> Option<T::NodeId> {
+        // The message may allow a wider range of node IDs than the transport allows.
+        // If the ID is too large, return None.
+        T::NodeId::try_from(self.node_id.value).ok()
+    }
+}
@@ -16,3 +16,79 @@ pub trait ReceiveDriver {
     /// Attempts to receive a byte without blocking
     fn receive_byte(&mut self) -> nb::Result<u8, Self::Error>;
 }
+
+#[cfg(feature = "std")]
+pub use self::std_io::IoDriver;
+
+#[cfg(feature = "std")]
+mod std_io {
+    use super::{ReceiveDriver, TransmitDriver};
+    use canadensis_core::nb;
+    use std::io::{self, ErrorKind, Read, Write};
+
+    /// A driver that sends and receives bytes through a standard library byte stream,
+    /// such as a `TcpStream` or a serial port that implements `Read` and `Write`
+    ///
+    /// Reads and writes that fail with `ErrorKind::WouldBlock`, `ErrorKind::TimedOut`, or
+    /// `ErrorKind::Interrupted` are reported as `nb::Error::WouldBlock`. To avoid blocking,
+    /// configure the stream with non-blocking mode or a short timeout.
+    ///
+    /// If the stream reaches end-of-file, `receive_byte` returns an error with
+    /// `ErrorKind::UnexpectedEof`.
+    #[derive(Debug)]
+    pub struct IoDriver<S>(S);
+
+    impl<S> IoDriver<S> {
+        /// Creates a driver that uses the provided stream
+        pub fn new(stream: S) -> Self {
+            IoDriver(stream)
+        }
+
+        /// Returns a reference to the stream
+        pub fn stream(&self) -> &S {
+            &self.0
+        }
+        /// Returns a mutable reference to the stream
+        pub fn stream_mut(&mut self) -> &mut S {
+            &mut self.0
+        }
+        /// Consumes this driver and returns its stream
+        pub fn into_stream(self) -> S {
+            self.0
+        }
+    }
+
+    impl<S: Write> TransmitDriver for IoDriver<S> {
+        type Error = io::Error;
+
+        fn send_byte(&mut self, byte: u8) -> nb::Result<(), Self::Error> {
+            match self.0.write(&[byte]) {
+                Ok(0) => Err(nb::Error::Other(ErrorKind::WriteZero.into())),
+                Ok(_) => Ok(()),
+                Err(e) => Err(convert_error(e)),
+            }
+        }
+    }
+
+    impl<S: Read> ReceiveDriver for IoDriver<S> {
+        type Error = io::Error;
+
+        fn receive_byte(&mut self) -> nb::Result<u8, Self::Error> {
+            let mut byte = 0;
+            match self.0.read(core::slice::from_mut(&mut byte)) {
+                Ok(0) => Err(nb::Error::Other(ErrorKind::UnexpectedEof.into())),
+                Ok(_) => Ok(byte),
+                Err(e) => Err(convert_error(e)),
+            }
+        }
+    }
+
+    fn convert_error(e: io::Error) -> nb::Error<io::Error> {
+        match e.kind() {
+            ErrorKind::WouldBlock | ErrorKind::TimedOut | ErrorKind::Interrupted => {
+                nb::Error::WouldBlock
+            }
+            _ => nb::Error::Other(e),
+        }
+    }
+}
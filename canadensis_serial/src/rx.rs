@@ -7,7 +7,7 @@ use fallible_collections::TryHashMap;
 use canadensis_core::crc::CrcTracker;
 use canadensis_core::subscription::SubscriptionManager;
 use canadensis_core::time::{Clock, MicrosecondDuration32, Microseconds32};
-use canadensis_core::transfer::{Header, Transfer};
+use canadensis_core::transfer::{Header, ReceivedTransfer, Transfer};
 use canadensis_core::transport::Receiver;
 use canadensis_core::{nb, OutOfMemoryError, ServiceId, ServiceSubscribeError, SubjectId};
 use canadensis_header::Header as SerialHeader;
@@ -66,7 +66,7 @@ where
         &mut self,
         byte: u8,
         now: Microseconds32,
-    ) -> Result<Option<Transfer<Vec<u8>, SerialTransport>>, Error<D::Error>> {
+    ) -> Result<Option<ReceivedTransfer<Vec<u8>, SerialTransport>>, Error<D::Error>> {
         let state = mem::replace(&mut self.state, State::Idle);
         self.state = match state {
             State::Idle => {
@@ -191,7 +191,7 @@ where
                         log::debug!("Got a zero (end delimiter)");
                         self.state = State::BetweenTransfers;
                         // Check and finish the transfer
-                        return Ok(self.complete_transfer(header, payload, crc));
+                        return Ok(self.complete_transfer(header, payload, crc, now));
                     }
                 }
             }
@@ -214,7 +214,7 @@ where
         &mut self,
         clock: &mut C,
         driver: &mut D,
-    ) -> Result<Option<Transfer<Vec<u8>, Self::Transport>>, Self::Error> {
+    ) -> Result<Option<ReceivedTransfer<Vec<u8>, Self::Transport>>, Self::Error> {
         self.clean_expired_sessions(clock.now());
         loop {
             match driver.receive_byte() {
@@ -369,7 +369,8 @@ where
         header: Header<SerialTransport>,
         payload: Vec<u8>,
         crc: CrcTracker,
-    ) -> Option<Transfer<Vec<u8>, SerialTransport>> {
+        now: Microseconds32,
+    ) -> Option<ReceivedTransfer<Vec<u8>, SerialTransport>> {
         if !crc.correct() {
             log::debug!("Dropping transfer due to incorrect transfer CRC");
             return None;
@@ -387,10 +388,13 @@ where
                     },
                 );
             }
-            Some(Transfer {
-                header,
-                loopback: false,
-                payload,
+            Some(ReceivedTransfer {
+                transfer: Transfer {
+                    header,
+                    loopback: false,
+                    payload,
+                },
+                completion_timestamp: now,
             })
         } else {
             // The subscription was removed while receiving the transfer
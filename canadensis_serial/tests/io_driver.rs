@@ -0,0 +1,68 @@
+#![cfg(feature = "std")]
+
+extern crate canadensis_core;
+extern crate canadensis_serial;
+
+mod utils;
+
+use self::utils::ZeroClock;
+use canadensis_core::subscription::DynamicSubscriptionManager;
+use canadensis_core::time::{MicrosecondDuration32, Microseconds32};
+use canadensis_core::transfer::{Header, MessageHeader, Transfer};
+use canadensis_core::transport::{Receiver, Transmitter};
+use canadensis_core::{Priority, SubjectId};
+use canadensis_serial::driver::IoDriver;
+use canadensis_serial::{
+    Error, SerialNodeId, SerialReceiver, SerialTransmitter, SerialTransport, Subscription,
+};
+use std::convert::{TryFrom, TryInto};
+use std::io::ErrorKind;
+
+#[test]
+fn io_round_trip() {
+    let subject = SubjectId::try_from(9u16).unwrap();
+    let transfer: Transfer<Vec<u8>, SerialTransport> = Transfer {
+        header: Header::Message(MessageHeader {
+            timestamp: Microseconds32::from_ticks(0),
+            transfer_id: 330.into(),
+            priority: Priority::Low,
+            subject,
+            source: Some(37u16.try_into().unwrap()),
+        }),
+        loopback: false,
+        payload: vec![1, 2, 3, 0, 4],
+    };
+
+    let mut tx_driver = IoDriver::new(Vec::new());
+    let mut tx = SerialTransmitter::<_, 64>::new();
+    tx.push(transfer.clone(), &mut ZeroClock, &mut tx_driver)
+        .unwrap();
+    tx.flush(&mut ZeroClock, &mut tx_driver).unwrap();
+    let wire_bytes = tx_driver.into_stream();
+
+    let mut rx_driver = IoDriver::new(&wire_bytes[..]);
+    let mut rx: SerialReceiver<
+        ZeroClock,
+        IoDriver<&[u8]>,
+        DynamicSubscriptionManager<Subscription>,
+    > = SerialReceiver::new(SerialNodeId::try_from(360).unwrap());
+    rx.subscribe_message(
+        subject,
+        16,
+        MicrosecondDuration32::from_ticks(0),
+        &mut rx_driver,
+    )
+    .unwrap();
+
+    let received = rx
+        .receive(&mut ZeroClock, &mut rx_driver)
+        .unwrap()
+        .expect("No transfer");
+    assert_eq!(transfer, received.transfer);
+
+    // All bytes have been read, so the stream is at end-of-file
+    match rx.receive(&mut ZeroClock, &mut rx_driver) {
+        Err(Error::Driver(e)) => assert_eq!(e.kind(), ErrorKind::UnexpectedEof),
+        other => panic!("Unexpected result {:?}", other),
+    }
+}
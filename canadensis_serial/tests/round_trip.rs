@@ -52,5 +52,5 @@ fn round_trip_no_payload() {
         .unwrap()
         .expect("No transfer");
 
-    assert_eq!(transfer, received);
+    assert_eq!(transfer, received.transfer);
 }
@@ -6,7 +6,7 @@ mod utils;
 use self::utils::{MockDriver, ZeroClock};
 use canadensis_core::subscription::DynamicSubscriptionManager;
 use canadensis_core::time::{milliseconds, Clock, Microseconds32};
-use canadensis_core::transfer::{Header, MessageHeader, Transfer};
+use canadensis_core::transfer::{Header, MessageHeader, ReceivedTransfer, Transfer};
 use canadensis_core::transport::Receiver;
 use canadensis_core::{Priority, SubjectId};
 use canadensis_serial::driver::TransmitDriver;
@@ -45,16 +45,19 @@ fn test_receive_payload_too_large() {
     IntoIterator::into_iter(wire_bytes).for_each(|byte: u8| driver.send_byte(byte).unwrap());
     assert_eq!(
         rx.receive(&mut ZeroClock, &mut driver).unwrap(),
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: Microseconds32::from_ticks(0),
-                transfer_id: SerialTransferId::try_from(0x3009).unwrap(),
-                priority: Priority::Fast,
-                subject,
-                source: Some(SerialNodeId::try_from(0x3fe).unwrap())
-            }),
-            loopback: false,
-            payload: vec![0xab, 0xac, 0xad,],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: Microseconds32::from_ticks(0),
+                    transfer_id: SerialTransferId::try_from(0x3009).unwrap(),
+                    priority: Priority::Fast,
+                    subject,
+                    source: Some(SerialNodeId::try_from(0x3fe).unwrap())
+                }),
+                loopback: false,
+                payload: vec![0xab, 0xac, 0xad,],
+            },
+            completion_timestamp: Microseconds32::from_ticks(0),
         })
     )
 }
@@ -93,16 +96,19 @@ fn test_duplicate_transfers() {
     clock.set_ticks(100);
     assert_eq!(
         rx.receive(&mut clock, &mut driver).unwrap(),
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: Microseconds32::from_ticks(100),
-                transfer_id: SerialTransferId::try_from(0x3009).unwrap(),
-                priority: Priority::Fast,
-                subject,
-                source: Some(SerialNodeId::try_from(0x3fe).unwrap())
-            }),
-            loopback: false,
-            payload: vec![0xab, 0xac, 0xad,],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: Microseconds32::from_ticks(100),
+                    transfer_id: SerialTransferId::try_from(0x3009).unwrap(),
+                    priority: Priority::Fast,
+                    subject,
+                    source: Some(SerialNodeId::try_from(0x3fe).unwrap())
+                }),
+                loopback: false,
+                payload: vec![0xab, 0xac, 0xad,],
+            },
+            completion_timestamp: Microseconds32::from_ticks(100),
         })
     );
     // Send the same bytes again (same transfer ID) within the transfer ID timeout
@@ -115,16 +121,19 @@ fn test_duplicate_transfers() {
     clock.set_ticks(1000 * 1000 + 101);
     assert_eq!(
         rx.receive(&mut clock, &mut driver).unwrap(),
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: Microseconds32::from_ticks(1000 * 1000 + 101),
-                transfer_id: SerialTransferId::try_from(0x3009).unwrap(),
-                priority: Priority::Fast,
-                subject,
-                source: Some(SerialNodeId::try_from(0x3fe).unwrap())
-            }),
-            loopback: false,
-            payload: vec![0xab, 0xac, 0xad,],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: Microseconds32::from_ticks(1000 * 1000 + 101),
+                    transfer_id: SerialTransferId::try_from(0x3009).unwrap(),
+                    priority: Priority::Fast,
+                    subject,
+                    source: Some(SerialNodeId::try_from(0x3fe).unwrap())
+                }),
+                loopback: false,
+                payload: vec![0xab, 0xac, 0xad,],
+            },
+            completion_timestamp: Microseconds32::from_ticks(1000 * 1000 + 101),
         })
     );
 }
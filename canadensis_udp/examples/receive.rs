@@ -54,7 +54,8 @@ fn main() {
     // Instead of a real asynchronous IO system, just poll periodically
     loop {
         match receiver.receive(&mut clock, &mut socket) {
-            Ok(Some(transfer)) => {
+            Ok(Some(received)) => {
+                let transfer = received.transfer;
                 println!("{:#?}", transfer.header);
                 for byte in transfer.payload.as_bytes() {
                     if byte.is_ascii() {
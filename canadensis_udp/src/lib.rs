@@ -20,6 +20,13 @@
 //! All transfers are received through one socket, which joins any multicast groups required to
 //! receive the correct frames.
 //!
+//! ## Using the transport with a node
+//!
+//! [`UdpTransmitter`] and [`UdpReceiver`] implement the `Transmitter` and `Receiver` traits, so
+//! they can be used with any `canadensis` node type in the same way as the CAN transport. With
+//! the `std` feature, [`driver::StdUdpSocket`] provides a socket based on `std::net::UdpSocket`.
+//! The `udp_basic_node` example in the `canadensis` crate shows a complete node.
+//!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -8,7 +8,7 @@ use zerocopy::FromBytes;
 use canadensis_core::crc::CrcTracker;
 use canadensis_core::session::{ActiveSession, Session, SessionTracker};
 use canadensis_core::time::{Clock, MicrosecondDuration32, Microseconds32};
-use canadensis_core::transfer::{Header, MessageHeader, ServiceHeader, Transfer};
+use canadensis_core::transfer::{Header, MessageHeader, ReceivedTransfer, ServiceHeader, Transfer};
 use canadensis_core::transport::Receiver;
 use canadensis_core::{OutOfMemoryError, ServiceId, ServiceSubscribeError, SubjectId};
 use canadensis_header::{DataSpecifier, Header as UdpHeader, NodeId16, RawHeader};
@@ -63,7 +63,7 @@ where
         &mut self,
         clock: &mut C,
         socket: &mut S,
-    ) -> Result<Option<Transfer<Vec<u8>, UdpTransport>>, Error<nb::Error<S::Error>>> {
+    ) -> Result<Option<ReceivedTransfer<Vec<u8>, UdpTransport>>, Error<nb::Error<S::Error>>> {
         let mut buffer: [u8; MTU] = [0; MTU];
         let bytes_received = socket.recv(&mut buffer).map_err(Error::Socket)?;
         let buffer = &buffer[..bytes_received];
@@ -163,7 +163,7 @@ where
         &mut self,
         clock: &mut C,
         socket: &mut S,
-    ) -> Result<Option<Transfer<Vec<u8>, Self::Transport>>, Self::Error> {
+    ) -> Result<Option<ReceivedTransfer<Vec<u8>, Self::Transport>>, Self::Error> {
         // Loop until all incoming packets have been read
         loop {
             match self.accept_inner(clock, socket) {
@@ -282,7 +282,7 @@ where
         header: &UdpHeader,
         bytes_after_header: &[u8],
         now: Microseconds32,
-    ) -> Result<Option<Transfer<Vec<u8>, UdpTransport>>, OutOfMemoryError> {
+    ) -> Result<Option<ReceivedTransfer<Vec<u8>, UdpTransport>>, OutOfMemoryError> {
         if let Some(source_node_id) = header.data_specifier.source_node_id() {
             self.handle_frame_non_anonymous(header, source_node_id, now, bytes_after_header)
         } else {
@@ -294,7 +294,9 @@ where
             };
             let result = session.handle_frame(header, bytes_after_header, self.payload_size_max);
             match result {
-                Ok(Some(payload)) => Ok(Some(self.convert_reassembly_result(payload, header, now))),
+                Ok(Some(payload)) => Ok(Some(
+                    self.convert_reassembly_result(payload, header, now, now),
+                )),
                 Ok(None) => Ok(None),
                 Err(BuildupError::Memory(_)) => Err(OutOfMemoryError),
                 Err(_) => Ok(None),
@@ -308,7 +310,7 @@ where
         source_node_id: NodeId16,
         now: Microseconds32,
         bytes_after_header: &[u8],
-    ) -> Result<Option<Transfer<Vec<u8>, UdpTransport>>, OutOfMemoryError> {
+    ) -> Result<Option<ReceivedTransfer<Vec<u8>, UdpTransport>>, OutOfMemoryError> {
         let session = self.sessions.get_mut_or_insert_with(source_node_id, || {
             Session::Active(ActiveSession {
                 time: now,
@@ -353,6 +355,7 @@ where
                     payload,
                     header,
                     first_frame_time,
+                    now,
                 )))
             }
             Ok(None) => Ok(None),
@@ -369,7 +372,8 @@ where
         reassembled: Vec<u8>,
         header: &UdpHeader,
         first_frame_time: Microseconds32,
-    ) -> Transfer<Vec<u8>, UdpTransport> {
+        last_frame_time: Microseconds32,
+    ) -> ReceivedTransfer<Vec<u8>, UdpTransport> {
         // Add the transfer headers and record the completed transfer
         let header = match header.data_specifier {
             DataSpecifier::Subject { from, subject, .. } => Header::Message(MessageHeader {
@@ -398,10 +402,13 @@ where
                 })
             }
         };
-        Transfer {
-            header,
-            loopback: false,
-            payload: reassembled,
+        ReceivedTransfer {
+            transfer: Transfer {
+                header,
+                loopback: false,
+                payload: reassembled,
+            },
+            completion_timestamp: last_frame_time,
         }
     }
 }
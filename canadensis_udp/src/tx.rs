@@ -169,6 +169,11 @@ where
         // Subtract to get the maximum number of payload bytes per frame
         MTU - canadensis_header::SIZE - TRANSFER_CRC_SIZE
     }
+
+    fn frame_count_for(&self, payload_length: usize) -> usize {
+        // The transfer CRC is sent once, after the payload, and may be split across frames
+        (payload_length + TRANSFER_CRC_SIZE).div_ceil(MTU - canadensis_header::SIZE)
+    }
 }
 
 #[derive(Eq, PartialEq, Debug)]
@@ -176,3 +181,55 @@ pub(crate) struct UdpFrame {
     deadline: Microseconds32,
     data: Vec<u8>,
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use core::convert::TryFrom;
+    use core::iter;
+
+    use canadensis_core::time::{Clock, Microseconds32};
+    use canadensis_core::transport::Transmitter;
+    use canadensis_core::{Priority, SubjectId};
+    use canadensis_header::DataSpecifier;
+
+    use super::breakdown::{Breakdown, HeaderBase};
+    use super::UdpTransmitter;
+    use crate::driver::StdUdpSocket;
+    use crate::UdpNodeId;
+
+    #[test]
+    fn frame_count_matches_breakdown() {
+        // Each frame has 6 bytes for the payload and transfer CRC
+        const MTU: usize = 30;
+        let tx = UdpTransmitter::<StdUdpSocket, MTU>::new(9382);
+        for payload_length in 0..20 {
+            let header_base = HeaderBase {
+                data_specifier: DataSpecifier::Subject {
+                    from: Some(UdpNodeId::try_from(1).unwrap()),
+                    subject: SubjectId::try_from(10).unwrap(),
+                },
+                transfer_id: 0.into(),
+                priority: Priority::Nominal,
+                data: 0,
+            };
+            let frames = Breakdown::new(
+                header_base,
+                Microseconds32::from_ticks(0),
+                iter::repeat_n(0, payload_length),
+                MTU,
+            )
+            .count();
+            let expected = Transmitter::<ZeroClock>::frame_count_for(&tx, payload_length);
+            assert_eq!(frames, expected, "Payload length {}", payload_length);
+        }
+    }
+
+    /// A clock that always returns zero
+    struct ZeroClock;
+
+    impl Clock for ZeroClock {
+        fn now(&mut self) -> Microseconds32 {
+            Microseconds32::from_ticks(0)
+        }
+    }
+}
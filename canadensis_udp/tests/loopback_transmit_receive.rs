@@ -205,7 +205,7 @@ fn check_loopback<S, U, const MTU: usize>(
         loop {
             match receiver.receive(clock, &mut receive_socket) {
                 Ok(Some(received_transfer)) => {
-                    assert_eq!(&received_transfer.payload, &transfer.payload);
+                    assert_eq!(&received_transfer.transfer.payload, &transfer.payload);
                     break;
                 }
                 Ok(None) => {
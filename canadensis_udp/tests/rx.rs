@@ -6,7 +6,7 @@ mod utils;
 use crate::utils::init_test_logging;
 use canadensis_core::session::SessionDynamicMap;
 use canadensis_core::time::{milliseconds, Clock, Microseconds32};
-use canadensis_core::transfer::{Header, MessageHeader, Transfer};
+use canadensis_core::transfer::{Header, MessageHeader, ReceivedTransfer, Transfer};
 use canadensis_core::transport::Receiver;
 use canadensis_core::{Priority, SubjectId};
 use canadensis_udp::driver::{StdUdpSocket, UdpSocket};
@@ -99,16 +99,19 @@ fn receive_payload_too_large_single_frame() -> Result<(), Box<dyn Error>> {
     let transfer = rx.receive(&mut clock, &mut receive_socket).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: Microseconds32::from_ticks(109932),
-                transfer_id: UdpTransferId::try_from(0xfaa8a7df3248e7fb).unwrap(),
-                priority: Priority::Slow,
-                subject,
-                source: Some(UdpNodeId::try_from(0xf0e3).unwrap())
-            }),
-            loopback: false,
-            payload: vec![0xe3, 0x39, 0x5a, 0xbe, 0x93],
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: Microseconds32::from_ticks(109932),
+                    transfer_id: UdpTransferId::try_from(0xfaa8a7df3248e7fb).unwrap(),
+                    priority: Priority::Slow,
+                    subject,
+                    source: Some(UdpNodeId::try_from(0xf0e3).unwrap())
+                }),
+                loopback: false,
+                payload: vec![0xe3, 0x39, 0x5a, 0xbe, 0x93],
+            },
+            completion_timestamp: Microseconds32::from_ticks(109932),
         })
     );
     Ok(())
@@ -144,16 +147,19 @@ fn receive_payload_too_large_three_frames() -> Result<(), Box<dyn Error>> {
     let transfer = rx.receive(&mut clock, &mut receive_socket).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: Microseconds32::from_ticks(109932),
-                transfer_id: UdpTransferId::try_from(0xfaa8a7df3248e7fb).unwrap(),
-                priority: Priority::Slow,
-                subject,
-                source: Some(UdpNodeId::try_from(0xf0e3).unwrap())
-            }),
-            loopback: false,
-            payload: VALID_THREE_FRAME_TRANSFER_PAYLOAD[..10].to_vec(),
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: Microseconds32::from_ticks(109932),
+                    transfer_id: UdpTransferId::try_from(0xfaa8a7df3248e7fb).unwrap(),
+                    priority: Priority::Slow,
+                    subject,
+                    source: Some(UdpNodeId::try_from(0xf0e3).unwrap())
+                }),
+                loopback: false,
+                payload: VALID_THREE_FRAME_TRANSFER_PAYLOAD[..10].to_vec(),
+            },
+            completion_timestamp: Microseconds32::from_ticks(109932),
         })
     );
     Ok(())
@@ -194,16 +200,19 @@ fn multi_frame_no_timeout() -> Result<(), Box<dyn Error>> {
     let transfer = rx.receive(&mut clock, &mut receive_socket).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                timestamp: Microseconds32::from_ticks(100 * 1000),
-                transfer_id: UdpTransferId::try_from(0xfaa8a7df3248e7fb).unwrap(),
-                priority: Priority::Slow,
-                subject,
-                source: Some(UdpNodeId::try_from(0xf0e3).unwrap())
-            }),
-            loopback: false,
-            payload: VALID_THREE_FRAME_TRANSFER_PAYLOAD.to_vec(),
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    timestamp: Microseconds32::from_ticks(100 * 1000),
+                    transfer_id: UdpTransferId::try_from(0xfaa8a7df3248e7fb).unwrap(),
+                    priority: Priority::Slow,
+                    subject,
+                    source: Some(UdpNodeId::try_from(0xf0e3).unwrap())
+                }),
+                loopback: false,
+                payload: VALID_THREE_FRAME_TRANSFER_PAYLOAD.to_vec(),
+            },
+            completion_timestamp: Microseconds32::from_ticks(39101 * 1000),
         })
     );
     Ok(())
@@ -243,17 +252,20 @@ fn multi_frame_duplicate() -> Result<(), Box<dyn Error>> {
     let transfer = rx.receive(&mut clock, &mut receive_socket).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                // Timestamp matches the timestamp of the first frame
-                timestamp: Microseconds32::from_ticks(100 * 1000),
-                transfer_id: UdpTransferId::try_from(0xfaa8a7df3248e7fb).unwrap(),
-                priority: Priority::Slow,
-                subject,
-                source: Some(UdpNodeId::try_from(0xf0e3).unwrap())
-            }),
-            loopback: false,
-            payload: VALID_THREE_FRAME_TRANSFER_PAYLOAD.to_vec(),
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    // Timestamp matches the timestamp of the first frame
+                    timestamp: Microseconds32::from_ticks(100 * 1000),
+                    transfer_id: UdpTransferId::try_from(0xfaa8a7df3248e7fb).unwrap(),
+                    priority: Priority::Slow,
+                    subject,
+                    source: Some(UdpNodeId::try_from(0xf0e3).unwrap())
+                }),
+                loopback: false,
+                payload: VALID_THREE_FRAME_TRANSFER_PAYLOAD.to_vec(),
+            },
+            completion_timestamp: Microseconds32::from_ticks(300 * 1000),
         })
     );
 
@@ -272,17 +284,20 @@ fn multi_frame_duplicate() -> Result<(), Box<dyn Error>> {
     let transfer = rx.receive(&mut clock, &mut receive_socket).unwrap();
     assert_eq!(
         transfer,
-        Some(Transfer {
-            header: Header::Message(MessageHeader {
-                // Timestamp matches the timestamp of the first frame
-                timestamp: Microseconds32::from_ticks(1100 * 1000 + 1),
-                transfer_id: UdpTransferId::try_from(0xfaa8a7df3248e7fb).unwrap(),
-                priority: Priority::Slow,
-                subject,
-                source: Some(UdpNodeId::try_from(0xf0e3).unwrap())
-            }),
-            loopback: false,
-            payload: VALID_THREE_FRAME_TRANSFER_PAYLOAD.to_vec(),
+        Some(ReceivedTransfer {
+            transfer: Transfer {
+                header: Header::Message(MessageHeader {
+                    // Timestamp matches the timestamp of the first frame
+                    timestamp: Microseconds32::from_ticks(1100 * 1000 + 1),
+                    transfer_id: UdpTransferId::try_from(0xfaa8a7df3248e7fb).unwrap(),
+                    priority: Priority::Slow,
+                    subject,
+                    source: Some(UdpNodeId::try_from(0xf0e3).unwrap())
+                }),
+                loopback: false,
+                payload: VALID_THREE_FRAME_TRANSFER_PAYLOAD.to_vec(),
+            },
+            completion_timestamp: Microseconds32::from_ticks(1100 * 1000 + 1),
         })
     );
 